@@ -0,0 +1,32 @@
+//! Embeds the git commit of the build as `MERIDIAN_GIT_SHA`
+//!
+//! Taken from the `GIT_SHA` environment variable when set (container builds
+//! have no `.git`), otherwise from `git rev-parse`. Falls back to "unknown".
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    // Only watch git state that exists, or cargo would rebuild every time
+    for path in ["../../.git/HEAD", "../../.git/refs/heads"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+
+    let sha = std::env::var("GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.trim().is_empty())
+        .or_else(git_head_sha)
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=MERIDIAN_GIT_SHA={}", sha.trim());
+}
+
+fn git_head_sha() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "--short=12", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
@@ -0,0 +1,274 @@
+//! Signed reserve attestations
+//!
+//! An `Attestation` states each active stablecoin's supply and reserve
+//! value at a point in time, signed with the Ed25519 key in
+//! `ATTESTATION_SIGNING_KEY` (hex-encoded 32-byte seed). The signature
+//! covers `Attestation::canonical_payload`, so anyone holding the public
+//! key can check that the figures were issued by Meridian and not altered.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Tags the signed payload so signatures can't be replayed across formats
+const PAYLOAD_DOMAIN: &str = "meridian-reserve-attestation-v1";
+
+/// Errors loading an attestation signing key
+#[derive(Debug, thiserror::Error)]
+pub enum AttestationKeyError {
+    #[error("ATTESTATION_SIGNING_KEY must be hex: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
+    #[error("ATTESTATION_SIGNING_KEY must be 32 bytes, got {0}")]
+    InvalidLength(usize),
+}
+
+/// Attested totals for one stablecoin
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
+pub struct AttestedReserve {
+    /// Stablecoin symbol
+    #[schema(example = "EURM")]
+    pub symbol: String,
+    /// Tokens in circulation
+    #[schema(value_type = String, example = "1000000.00")]
+    pub total_supply: Decimal,
+    /// Value of the reserves backing them
+    #[schema(value_type = String, example = "1004225.00")]
+    pub total_reserve_value: Decimal,
+}
+
+/// Reserve attestation with an Ed25519 signature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Attestation {
+    /// When the attestation was issued (whole seconds)
+    #[schema(value_type = String, example = "2025-01-01T11:15:00Z")]
+    pub timestamp: DateTime<Utc>,
+    /// Per-stablecoin totals, sorted by symbol
+    pub reserves: Vec<AttestedReserve>,
+    /// Hex-encoded Ed25519 public key of the signer
+    pub public_key: String,
+    /// Hex-encoded Ed25519 signature over `canonical_payload`
+    pub signature: String,
+}
+
+/// Field order and formatting are part of the signature; don't reorder
+#[derive(Serialize)]
+struct CanonicalPayload<'a> {
+    domain: &'a str,
+    timestamp: String,
+    reserves: Vec<CanonicalReserve<'a>>,
+}
+
+#[derive(Serialize)]
+struct CanonicalReserve<'a> {
+    symbol: &'a str,
+    total_supply: String,
+    total_reserve_value: String,
+}
+
+impl Attestation {
+    /// Bytes covered by the signature
+    ///
+    /// Compact JSON of the timestamp (RFC 3339, seconds) and the reserves
+    /// sorted by symbol, with decimals normalized so `"100.00"` and `"100"`
+    /// sign the same. The public key and signature are excluded.
+    pub fn canonical_payload(&self) -> Vec<u8> {
+        let mut reserves: Vec<CanonicalReserve> = self
+            .reserves
+            .iter()
+            .map(|r| CanonicalReserve {
+                symbol: &r.symbol,
+                total_supply: r.total_supply.normalize().to_string(),
+                total_reserve_value: r.total_reserve_value.normalize().to_string(),
+            })
+            .collect();
+        reserves.sort_by(|a, b| a.symbol.cmp(b.symbol));
+
+        let payload = CanonicalPayload {
+            domain: PAYLOAD_DOMAIN,
+            timestamp: self.timestamp.to_rfc3339_opts(SecondsFormat::Secs, true),
+            reserves,
+        };
+        serde_json::to_vec(&payload).expect("canonical payload serializes")
+    }
+}
+
+/// Signs and verifies attestations with the configured key
+pub struct AttestationSigner {
+    key: SigningKey,
+}
+
+impl std::fmt::Debug for AttestationSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AttestationSigner")
+            .field("public_key", &self.public_key_hex())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AttestationSigner {
+    /// Loads the key from `ATTESTATION_SIGNING_KEY`
+    ///
+    /// Returns `None` (attestations disabled) when the variable is unset or
+    /// invalid.
+    pub fn from_env() -> Option<Self> {
+        let seed = std::env::var("ATTESTATION_SIGNING_KEY").ok()?;
+        match Self::from_hex(&seed) {
+            Ok(signer) => {
+                tracing::info!(public_key = %signer.public_key_hex(), "Attestation signing key loaded");
+                Some(signer)
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Attestation signing disabled");
+                None
+            }
+        }
+    }
+
+    /// Parses a hex-encoded 32-byte Ed25519 seed (optional `0x` prefix)
+    pub fn from_hex(seed: &str) -> Result<Self, AttestationKeyError> {
+        let seed = seed.trim();
+        let bytes = hex::decode(seed.strip_prefix("0x").unwrap_or(seed))?;
+        let seed: [u8; 32] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| AttestationKeyError::InvalidLength(bytes.len()))?;
+        Ok(Self { key: SigningKey::from_bytes(&seed) })
+    }
+
+    /// Hex-encoded public key third parties verify against
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().as_bytes())
+    }
+
+    /// Signs `reserves` as of `timestamp`
+    ///
+    /// Amounts are normalized and the timestamp truncated to the second, so
+    /// the returned attestation reads the same as its canonical payload.
+    pub fn sign(&self, timestamp: DateTime<Utc>, mut reserves: Vec<AttestedReserve>) -> Attestation {
+        for reserve in &mut reserves {
+            reserve.total_supply = reserve.total_supply.normalize();
+            reserve.total_reserve_value = reserve.total_reserve_value.normalize();
+        }
+        reserves.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        let timestamp = DateTime::from_timestamp(timestamp.timestamp(), 0).unwrap_or(timestamp);
+
+        let mut attestation = Attestation {
+            timestamp,
+            reserves,
+            public_key: self.public_key_hex(),
+            signature: String::new(),
+        };
+        let signature = self.key.sign(&attestation.canonical_payload());
+        attestation.signature = hex::encode(signature.to_bytes());
+        attestation
+    }
+
+    /// Whether `attestation` carries a valid signature from this key
+    ///
+    /// The attestation's own `public_key` is ignored: a signature is only
+    /// accepted if it was made with the key configured here.
+    pub fn verify(&self, attestation: &Attestation) -> bool {
+        let Ok(bytes) = hex::decode(attestation.signature.trim()) else {
+            return false;
+        };
+        let Ok(signature) = Signature::from_slice(&bytes) else {
+            return false;
+        };
+        self.key
+            .verifying_key()
+            .verify(&attestation.canonical_payload(), &signature)
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+
+    fn reserves() -> Vec<AttestedReserve> {
+        vec![
+            AttestedReserve {
+                symbol: "GBPM".to_string(),
+                total_supply: Decimal::new(50000000, 2),
+                total_reserve_value: Decimal::new(50100000, 2),
+            },
+            AttestedReserve {
+                symbol: "EURM".to_string(),
+                total_supply: Decimal::new(100000000, 2),
+                total_reserve_value: Decimal::new(100422500, 2),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_from_hex_validates_seed() {
+        let signer = AttestationSigner::from_hex(&format!("0x{}", SEED)).unwrap();
+        // RFC 8032 test vector 1
+        assert_eq!(
+            signer.public_key_hex(),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        assert!(matches!(
+            AttestationSigner::from_hex("zz"),
+            Err(AttestationKeyError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            AttestationSigner::from_hex("abcd"),
+            Err(AttestationKeyError::InvalidLength(2))
+        ));
+    }
+
+    #[test]
+    fn test_sign_verify_roundtrip() {
+        let signer = AttestationSigner::from_hex(SEED).unwrap();
+        let attestation = signer.sign(Utc::now(), reserves());
+
+        assert_eq!(attestation.reserves[0].symbol, "EURM");
+        assert_eq!(attestation.reserves[0].total_supply.to_string(), "1000000");
+        assert_eq!(attestation.timestamp.timestamp_subsec_nanos(), 0);
+        assert!(signer.verify(&attestation));
+
+        // Survives a JSON round trip, as a third party would submit it
+        let json = serde_json::to_string(&attestation).unwrap();
+        let submitted: Attestation = serde_json::from_str(&json).unwrap();
+        assert!(signer.verify(&submitted));
+    }
+
+    #[test]
+    fn test_decimal_formatting_does_not_change_payload() {
+        let signer = AttestationSigner::from_hex(SEED).unwrap();
+        let mut attestation = signer.sign(Utc::now(), reserves());
+        attestation.reserves[0].total_supply = Decimal::new(1000000, 0);
+        assert!(signer.verify(&attestation));
+    }
+
+    #[test]
+    fn test_tampered_attestation_fails_verification() {
+        let signer = AttestationSigner::from_hex(SEED).unwrap();
+        let attestation = signer.sign(Utc::now(), reserves());
+
+        let mut inflated = attestation.clone();
+        inflated.reserves[0].total_reserve_value += Decimal::ONE;
+        assert!(!signer.verify(&inflated));
+
+        let mut backdated = attestation.clone();
+        backdated.timestamp -= chrono::Duration::days(1);
+        assert!(!signer.verify(&backdated));
+
+        let mut garbled = attestation.clone();
+        garbled.signature = "not-hex".to_string();
+        assert!(!signer.verify(&garbled));
+    }
+
+    #[test]
+    fn test_signature_from_other_key_is_rejected() {
+        let signer = AttestationSigner::from_hex(SEED).unwrap();
+        let other = AttestationSigner::from_hex(&"11".repeat(32)).unwrap();
+        let forged = other.sign(Utc::now(), reserves());
+        assert!(!signer.verify(&forged));
+    }
+}
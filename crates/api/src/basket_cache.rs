@@ -0,0 +1,253 @@
+//! Basket valuation cache
+//!
+//! `get_basket_value` results only change when the basket definition or the
+//! oracle prices change. Entries are keyed by `(basket_id, basket_version,
+//! price_epoch)`, where the version is a fingerprint of the basket definition
+//! and the epoch comes from `ChainlinkOracle::price_epoch()`, so a refresh or
+//! a basket edit naturally misses the cache. Each basket keeps only its
+//! latest entry.
+
+use chrono::{DateTime, Utc};
+use meridian_basket::CurrencyBasket;
+use rust_decimal::Decimal;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::RwLock;
+use uuid::Uuid;
+
+/// Cache key for a basket valuation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BasketValueKey {
+    pub basket_id: Uuid,
+    pub basket_version: u64,
+    pub price_epoch: u64,
+}
+
+impl BasketValueKey {
+    /// Build the key for `basket` at the given oracle price epoch
+    pub fn new(basket: &CurrencyBasket, price_epoch: u64) -> Self {
+        Self {
+            basket_id: basket.id,
+            basket_version: basket_version(basket),
+            price_epoch,
+        }
+    }
+}
+
+/// A computed basket valuation
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedBasketValue {
+    pub value_usd: Decimal,
+    pub prices_used: HashMap<String, Decimal>,
+    pub needs_rebalancing: bool,
+    pub calculated_at: DateTime<Utc>,
+}
+
+/// Fingerprint of a basket definition; changes when components, weights,
+/// or the rebalance strategy change
+pub fn basket_version(basket: &CurrencyBasket) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(basket)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// In-memory basket valuation cache
+#[derive(Debug, Default)]
+pub struct BasketValueCache {
+    entries: RwLock<HashMap<BasketValueKey, CachedBasketValue>>,
+}
+
+impl BasketValueCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached value for `key`, if present
+    pub fn get(&self, key: &BasketValueKey) -> Option<CachedBasketValue> {
+        self.entries
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(key)
+            .cloned()
+    }
+
+    /// Store a value, evicting the same basket's entries from older price
+    /// epochs or basket versions; other baskets' entries are kept
+    pub fn insert(&self, key: BasketValueKey, value: CachedBasketValue) {
+        let mut entries = self
+            .entries
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.retain(|k, _| k.basket_id != key.basket_id || k.price_epoch > key.price_epoch);
+        entries.insert(key, value);
+    }
+
+    /// Return the cached value for `basket` at the current `price_epoch()`,
+    /// computing and storing it on a miss.
+    ///
+    /// The value is stored under the epoch read after `compute` finishes, so
+    /// prices refreshed while computing (including by `compute` itself) are
+    /// never cached under the older epoch. Errors from `compute` are
+    /// returned as-is and nothing is cached.
+    pub async fn get_or_try_insert_with<P, F, Fut, E>(
+        &self,
+        basket: &CurrencyBasket,
+        price_epoch: P,
+        compute: F,
+    ) -> Result<CachedBasketValue, E>
+    where
+        P: Fn() -> u64,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<CachedBasketValue, E>>,
+    {
+        let key = BasketValueKey::new(basket, price_epoch());
+        if let Some(cached) = self.get(&key) {
+            tracing::debug!(basket_id = %key.basket_id, epoch = key.price_epoch, "Basket value cache hit");
+            return Ok(cached);
+        }
+
+        let value = compute().await?;
+        self.insert(BasketValueKey::new(basket, price_epoch()), value.clone());
+        Ok(value)
+    }
+
+    /// Number of cached entries
+    pub fn len(&self) -> usize {
+        self.entries
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use meridian_basket::BasketType;
+    use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+    fn eur_basket() -> CurrencyBasket {
+        CurrencyBasket::new_single_currency(
+            "EUR Basket".to_string(),
+            "EUR".to_string(),
+            "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn valuation(value: i64) -> CachedBasketValue {
+        CachedBasketValue {
+            value_usd: Decimal::from(value),
+            prices_used: HashMap::new(),
+            needs_rebalancing: false,
+            calculated_at: Utc::now(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_same_epoch_served_from_cache() {
+        let cache = BasketValueCache::new();
+        let basket = eur_basket();
+        let fetches = AtomicU32::new(0);
+
+        let compute = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, ()>(valuation(108))
+        };
+
+        let first = cache.get_or_try_insert_with(&basket, || 7, compute).await.unwrap();
+        let second = cache.get_or_try_insert_with(&basket, || 7, compute).await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_new_epoch_recomputes_and_evicts() {
+        let cache = BasketValueCache::new();
+        let basket = eur_basket();
+        let fetches = AtomicU32::new(0);
+
+        for epoch in [1, 1, 2] {
+            cache
+                .get_or_try_insert_with(&basket, || epoch, || async {
+                    fetches.fetch_add(1, Ordering::SeqCst);
+                    Ok::<_, ()>(valuation(epoch as i64))
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&BasketValueKey::new(&basket, 1)).is_none());
+    }
+
+    #[test]
+    fn test_insert_keeps_other_baskets() {
+        let cache = BasketValueCache::new();
+        let eur = eur_basket();
+        let gbp = CurrencyBasket::new_single_currency(
+            "GBP Basket".to_string(),
+            "GBP".to_string(),
+            "0x5c0Ab2d9b5a7ed9f470386e82BB36A3613cDd4b5".to_string(),
+        )
+        .unwrap();
+
+        cache.insert(BasketValueKey::new(&gbp, 1), valuation(127));
+        cache.insert(BasketValueKey::new(&eur, 2), valuation(108));
+
+        assert_eq!(cache.len(), 2);
+        let gbp_value = cache.get(&BasketValueKey::new(&gbp, 1)).expect("GBP entry kept");
+        assert_eq!(gbp_value.value_usd, Decimal::from(127));
+    }
+
+    #[actix_web::test]
+    async fn test_value_stored_under_epoch_read_after_compute() {
+        let cache = BasketValueCache::new();
+        let basket = eur_basket();
+        let epoch = AtomicU64::new(1);
+
+        // A refresh lands while the value is being computed
+        cache
+            .get_or_try_insert_with(&basket, || epoch.load(Ordering::SeqCst), || async {
+                epoch.store(2, Ordering::SeqCst);
+                Ok::<_, ()>(valuation(108))
+            })
+            .await
+            .unwrap();
+
+        assert!(cache.get(&BasketValueKey::new(&basket, 1)).is_none());
+        assert!(cache.get(&BasketValueKey::new(&basket, 2)).is_some());
+    }
+
+    #[actix_web::test]
+    async fn test_errors_are_not_cached() {
+        let cache = BasketValueCache::new();
+
+        let result = cache
+            .get_or_try_insert_with(&eur_basket(), || 1, || async { Err::<CachedBasketValue, _>("oracle down") })
+            .await;
+        assert!(result.is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_basket_version_tracks_definition() {
+        let basket = eur_basket();
+        assert_eq!(basket_version(&basket), basket_version(&basket.clone()));
+
+        let mut edited = basket.clone();
+        edited.basket_type = BasketType::CustomBasket;
+        assert_ne!(basket_version(&basket), basket_version(&edited));
+    }
+}
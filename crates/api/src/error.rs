@@ -1,8 +1,11 @@
 //! Error types for API operations
 
-use actix_web::{http::StatusCode, HttpMessage, HttpRequest, HttpResponse, ResponseError};
+use actix_web::{
+    http::{header, StatusCode},
+    HttpMessage, HttpRequest, HttpResponse, ResponseError,
+};
 use meridian_basket::BasketError;
-use meridian_db::DbError;
+use meridian_db::{DbError, RetryableError};
 use meridian_oracle::OracleError;
 use serde::Serialize;
 use std::fmt;
@@ -20,6 +23,41 @@ pub struct ErrorResponse {
     pub request_id: Option<String>,
 }
 
+/// Media type for RFC 7807 problem details
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// RFC 7807 problem details, rendered when the client sends
+/// `Accept: application/problem+json`
+#[derive(Debug, Serialize)]
+pub struct ProblemDetails {
+    /// URI reference identifying the problem type
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    /// Short, human-readable summary of the problem type
+    pub title: String,
+    /// HTTP status code
+    pub status: u16,
+    /// Explanation specific to this occurrence
+    pub detail: String,
+    /// Correlation ID of the failing request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+}
+
+/// Whether the client asked for problem+json error bodies
+pub fn accepts_problem_json(req: &HttpRequest) -> bool {
+    req.headers()
+        .get_all(header::ACCEPT)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media| {
+            media
+                .split(';')
+                .next()
+                .is_some_and(|m| m.trim().eq_ignore_ascii_case(PROBLEM_JSON))
+        })
+}
+
 /// API errors
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -31,7 +69,16 @@ pub enum ApiError {
     BadRequest(String),
     Unauthorized(String),
     Forbidden(String),
+    Conflict(String),
+    /// A system-wide quota (e.g. a daily currency cap) would be exceeded
+    LimitExceeded(String),
     OracleNotConfigured,
+    /// CRIT-002: Oracle circuit breaker is open
+    OracleUnavailable,
+    /// A component price is below the basket's required confidence
+    PriceConfidenceTooLow(String),
+    /// `ATTESTATION_SIGNING_KEY` is not set
+    AttestationNotConfigured,
     InternalError(String),
 }
 
@@ -45,7 +92,12 @@ impl fmt::Display for ApiError {
             ApiError::BadRequest(msg) => write!(f, "Bad request: {}", msg),
             ApiError::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
             ApiError::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
+            ApiError::Conflict(msg) => write!(f, "Conflict: {}", msg),
+            ApiError::LimitExceeded(msg) => write!(f, "Limit exceeded: {}", msg),
             ApiError::OracleNotConfigured => write!(f, "Oracle not configured"),
+            ApiError::OracleUnavailable => write!(f, "Oracle temporarily unavailable"),
+            ApiError::PriceConfidenceTooLow(msg) => write!(f, "Price confidence too low: {}", msg),
+            ApiError::AttestationNotConfigured => write!(f, "Attestation signing not configured"),
             ApiError::InternalError(msg) => write!(f, "Internal error: {}", msg),
         }
     }
@@ -66,6 +118,10 @@ impl ApiError {
     /// HIGH-012: This method should be used instead of automatic ResponseError conversion
     /// when you have access to the HttpRequest
     pub fn to_response(&self, req: &HttpRequest) -> HttpResponse {
+        if accepts_problem_json(req) {
+            return self.to_problem_response(req);
+        }
+
         let error_type = self.error_type();
         let request_id = get_correlation_id(req);
 
@@ -77,6 +133,25 @@ impl ApiError {
         })
     }
 
+    /// RFC 7807 rendering of this error, with the correlation ID as `instance`
+    pub fn to_problem(&self, instance: Option<String>) -> ProblemDetails {
+        let status = self.status_code();
+        ProblemDetails {
+            problem_type: format!("urn:meridian:error:{}", self.error_type()),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            detail: self.to_string(),
+            instance,
+        }
+    }
+
+    /// Build an `application/problem+json` response for this error
+    pub fn to_problem_response(&self, req: &HttpRequest) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(PROBLEM_JSON)
+            .json(self.to_problem(get_correlation_id(req)))
+    }
+
     /// Get the error type string for this error
     fn error_type(&self) -> &'static str {
         match self {
@@ -87,7 +162,12 @@ impl ApiError {
             ApiError::BadRequest(_) => "bad_request",
             ApiError::Unauthorized(_) => "unauthorized",
             ApiError::Forbidden(_) => "forbidden",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::LimitExceeded(_) => "limit_exceeded",
             ApiError::OracleNotConfigured => "oracle_not_configured",
+            ApiError::OracleUnavailable => "oracle_unavailable",
+            ApiError::PriceConfidenceTooLow(_) => "price_confidence_too_low",
+            ApiError::AttestationNotConfigured => "attestation_not_configured",
             ApiError::InternalError(_) => "internal_error",
         }
     }
@@ -100,7 +180,12 @@ impl ResponseError for ApiError {
             ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
             ApiError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             ApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::LimitExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
             ApiError::OracleNotConfigured => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::OracleUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::PriceConfidenceTooLow(_) => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::AttestationNotConfigured => StatusCode::SERVICE_UNAVAILABLE,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -136,6 +221,12 @@ impl From<DbError> for ApiError {
     }
 }
 
+impl RetryableError for ApiError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, ApiError::DatabaseError(err) if err.is_retryable())
+    }
+}
+
 /// Helper function to handle database errors safely.
 /// Logs the actual error server-side but returns a generic message to clients.
 /// This prevents information disclosure of database structure/constraints.
@@ -149,3 +240,27 @@ pub fn handle_db_error<E: std::fmt::Display>(error: E, context: &str) -> ApiErro
     // Return generic error to client - never expose internal details
     ApiError::InternalError("A database error occurred. Please try again later.".to_string())
 }
+
+/// `handle_db_error` for statements run inside `meridian_db::with_retry`.
+/// Serialization failures and deadlocks are kept as `DatabaseError` so the
+/// transaction gets retried; anything else becomes the generic error.
+pub fn handle_tx_error(error: sqlx::Error, context: &str) -> ApiError {
+    if meridian_db::is_transient(&error) {
+        return ApiError::DatabaseError(DbError::from(error));
+    }
+    handle_db_error(error, context)
+}
+
+/// Maps the final error of a `meridian_db::with_retry` call for clients.
+/// A conflict that outlasted every retry becomes a 409 the caller can
+/// retry; other database errors get the generic message.
+pub fn surface_tx_error(error: ApiError, context: &str) -> ApiError {
+    match error {
+        ApiError::DatabaseError(err) if err.is_retryable() => {
+            tracing::warn!(error = %err, context = %context, "Transaction conflict persisted after retries");
+            ApiError::Conflict("Too many concurrent operations; please retry".to_string())
+        }
+        ApiError::DatabaseError(err) => handle_db_error(err, context),
+        other => other,
+    }
+}
@@ -0,0 +1,198 @@
+//! Admin diagnostics handlers
+//!
+//! Gives support engineers visibility into the effective runtime configuration
+//! without shell access. Everything returned goes through
+//! `RuntimeConfig::redacted()` so no secret value leaves the process.
+//! Also reports fees accrued to the configured fee account.
+
+use crate::config::{RedactedConfig, RuntimeConfig};
+use crate::error::{handle_db_error, ApiError};
+use crate::handlers::auth_utils::require_role;
+use crate::query_metrics::TrackQuery;
+use crate::state::AppState;
+use actix_web::{web, HttpRequest, HttpResponse};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Serialize)]
+pub struct OracleDiagnostics {
+    pub enabled: bool,
+    pub registered_feeds: Vec<String>,
+    pub stale_threshold_secs: Option<u64>,
+    pub circuit_state: String,
+    pub circuit_failure_count: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChainDiagnostics {
+    pub executor_enabled: bool,
+    pub executor_chain_id: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiagnosticsResponse {
+    pub version: String,
+    pub migration_version: Option<i64>,
+    pub config: RedactedConfig,
+    pub oracle: OracleDiagnostics,
+    pub chain: ChainDiagnostics,
+    pub custody_provider: String,
+}
+
+/// GET /api/v1/admin/diagnostics
+///
+/// Requires ADMIN role.
+pub async fn get_diagnostics(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    require_role(state.db_pool.as_ref(), &req, "ADMIN").await?;
+
+    let config = RuntimeConfig::from_env().redacted();
+
+    // Latest applied sqlx migration (None if the table is missing or empty)
+    let migration_version: Option<i64> =
+        sqlx::query_scalar("SELECT MAX(version) FROM _sqlx_migrations WHERE success")
+            .fetch_one(state.db_pool.as_ref())
+            .tracked()
+            .await
+            .unwrap_or(None);
+
+    let oracle = {
+        let oracle_guard = state.oracle.read().await;
+        let metrics = state.oracle_circuit_breaker.metrics();
+        match oracle_guard.as_ref() {
+            Some(oracle) => {
+                let mut registered_feeds = oracle.list_feeds().await;
+                registered_feeds.sort();
+                OracleDiagnostics {
+                    enabled: true,
+                    registered_feeds,
+                    stale_threshold_secs: Some(oracle.stale_threshold()),
+                    circuit_state: format!("{:?}", metrics.state),
+                    circuit_failure_count: metrics.failure_count,
+                }
+            }
+            None => OracleDiagnostics {
+                enabled: false,
+                registered_feeds: vec![],
+                stale_threshold_secs: None,
+                circuit_state: format!("{:?}", metrics.state),
+                circuit_failure_count: metrics.failure_count,
+            },
+        }
+    };
+
+    let chain = ChainDiagnostics {
+        executor_enabled: state.evm_executor.is_some(),
+        executor_chain_id: state.evm_executor.as_ref().map(|e| e.chain_id()),
+    };
+
+    Ok(HttpResponse::Ok().json(DiagnosticsResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        migration_version,
+        config,
+        oracle,
+        chain,
+        custody_provider: state.custody.provider_name().to_string(),
+    }))
+}
+
+/// Fees accrued on operations in one currency, in USD
+#[derive(Debug, Serialize)]
+pub struct CurrencyFees {
+    pub currency: String,
+    pub operations: i64,
+    pub mint_fees: String,
+    pub burn_fees: String,
+    pub total_fees: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeeSummaryResponse {
+    pub fee_account: String,
+    /// Currency all fee amounts are denominated in
+    pub fee_currency: String,
+    pub currencies: Vec<CurrencyFees>,
+    pub total_fees: String,
+}
+
+/// GET /api/v1/admin/fees/summary
+///
+/// Requires ADMIN role. Sums `fee_ledger` for the configured fee account per
+/// operation currency; fees on failed or cancelled operations are excluded.
+pub async fn get_fee_summary(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    require_role(state.db_pool.as_ref(), &req, "ADMIN").await?;
+
+    let rows: Vec<(String, i64, Decimal, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT f.currency,
+               COUNT(*),
+               COALESCE(SUM(f.amount) FILTER (WHERE f.operation_type = 'MINT'), 0),
+               COALESCE(SUM(f.amount) FILTER (WHERE f.operation_type = 'BURN'), 0)
+        FROM fee_ledger f
+        JOIN operations o ON o.id = f.operation_id
+        WHERE f.fee_account = $1
+          AND o.status NOT IN ('FAILED', 'CANCELLED')
+        GROUP BY f.currency
+        ORDER BY f.currency
+        "#,
+    )
+    .bind(&state.fee_config.account)
+    .fetch_all(state.db_pool.as_ref())
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "fee_ledger"))?;
+
+    let (currencies, total_fees) = summarize_fees(rows);
+    Ok(HttpResponse::Ok().json(FeeSummaryResponse {
+        fee_account: state.fee_config.account.clone(),
+        fee_currency: "USD".to_string(),
+        currencies,
+        total_fees: total_fees.to_string(),
+    }))
+}
+
+/// Per-currency fee totals and their grand total
+fn summarize_fees(rows: Vec<(String, i64, Decimal, Decimal)>) -> (Vec<CurrencyFees>, Decimal) {
+    let mut grand_total = Decimal::ZERO;
+    let currencies = rows
+        .into_iter()
+        .map(|(currency, operations, mint_fees, burn_fees)| {
+            let total = mint_fees + burn_fees;
+            grand_total += total;
+            CurrencyFees {
+                currency,
+                operations,
+                mint_fees: mint_fees.to_string(),
+                burn_fees: burn_fees.to_string(),
+                total_fees: total.to_string(),
+            }
+        })
+        .collect();
+    (currencies, grand_total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_summarize_fees_sums_exactly() {
+        let d = |s: &str| Decimal::from_str(s).unwrap();
+        let (currencies, total) = summarize_fees(vec![
+            ("EUR".to_string(), 3, d("0.10"), d("0.20")),
+            ("GBP".to_string(), 1, d("1234567890.123456789"), d("0")),
+        ]);
+
+        assert_eq!(currencies[0].total_fees, "0.30");
+        assert_eq!(currencies[1].mint_fees, "1234567890.123456789");
+        assert_eq!(total, d("1234567890.423456789"));
+        assert!(summarize_fees(vec![]).0.is_empty());
+    }
+}
@@ -1,9 +1,16 @@
 //! x402 Agent payment handlers
 
 use crate::error::{ApiError, handle_db_error};
+use crate::handlers::operations::validate_idempotency_key;
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{web, HttpRequest, HttpResponse};
 use ethers::types::Address;
+use meridian_basket::currency::Money;
+use meridian_chains::execution::ExecutionError;
+use meridian_chains::transfer::BlockchainExecutor;
+use meridian_chains::ChainError;
+use meridian_compliance::ComplianceStatus;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
@@ -11,21 +18,83 @@ use std::str::FromStr;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Default cap on active agent wallets per user (override with MAX_AGENTS_PER_USER)
+const DEFAULT_MAX_AGENTS_PER_USER: i64 = 10;
+
+/// Default maximum payment memo length in characters (override with MAX_MEMO_LENGTH)
+const DEFAULT_MAX_MEMO_LENGTH: usize = 500;
+
+/// When an agent's daily spend resets
+///
+/// Stored as `agent_wallets.spend_window` plus `spend_window_tz` for the
+/// calendar variants. Agents created before windows existed are `Rolling24h`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "timezone", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SpendWindow {
+    /// Spend in the 24 hours before now
+    #[default]
+    #[serde(rename = "ROLLING_24H")]
+    Rolling24h,
+    /// Spend since midnight UTC
+    CalendarDayUtc,
+    /// Spend since midnight in an IANA timezone (e.g. "Europe/Berlin")
+    CalendarDay(String),
+}
+
+impl SpendWindow {
+    /// `(spend_window, spend_window_tz)` column values
+    pub fn to_db_columns(&self) -> (&'static str, Option<&str>) {
+        match self {
+            SpendWindow::Rolling24h => ("ROLLING_24H", None),
+            SpendWindow::CalendarDayUtc => ("CALENDAR_DAY", Some("UTC")),
+            SpendWindow::CalendarDay(tz) => ("CALENDAR_DAY", Some(tz.as_str())),
+        }
+    }
+
+    /// Inverse of `to_db_columns`; unknown values fall back to `Rolling24h`
+    pub fn from_db_columns(window: &str, timezone: Option<String>) -> Self {
+        match (window, timezone) {
+            ("CALENDAR_DAY", Some(tz)) if tz == "UTC" => SpendWindow::CalendarDayUtc,
+            ("CALENDAR_DAY", Some(tz)) => SpendWindow::CalendarDay(tz),
+            _ => SpendWindow::Rolling24h,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateAgentRequest {
     pub user_id: i32,
     pub agent_name: String,
     pub spending_limit_daily: String,
     pub spending_limit_transaction: String,
+    /// Makes creation safe to retry: a repeat with the same key returns the
+    /// original agent. Must be unique per user. Recommended: UUID v4
+    pub idempotency_key: Option<String>,
+    /// Daily-limit reset window (default: rolling 24 hours)
+    #[serde(default)]
+    pub spend_window: SpendWindow,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CreateAgentResponse {
     pub agent_id: String,
-    pub api_key: String,
+    /// Only present on the original creation; the key is hashed at rest and
+    /// cannot be re-shown when an idempotent retry is replayed
+    pub api_key: Option<String>,
     pub wallet_address: String,
     pub spending_limit_daily: String,
     pub spending_limit_transaction: String,
+    pub spend_window: SpendWindow,
+    /// True when this response replays an earlier creation
+    pub idempotent_replay: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RotateAgentKeyResponse {
+    pub agent_id: String,
+    /// Only ever shown in this response; the key is hashed at rest
+    pub api_key: String,
+    pub rotated_at: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -51,6 +120,17 @@ pub struct AgentPaymentResponse {
     pub created_at: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ValidatePaymentResponse {
+    pub agent_id: String,
+    /// True when `agent_pay` would accept this payment
+    pub valid: bool,
+    /// Why the payment would be rejected (empty when valid)
+    pub reasons: Vec<String>,
+    pub daily_spent: String,
+    pub daily_remaining: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct AgentWalletResponse {
     pub agent_id: String,
@@ -58,6 +138,7 @@ pub struct AgentWalletResponse {
     pub wallet_address: String,
     pub spending_limit_daily: String,
     pub spending_limit_transaction: String,
+    pub spend_window: SpendWindow,
     pub daily_spent: String,
     pub is_active: bool,
     pub created_at: String,
@@ -97,6 +178,16 @@ pub async fn create_agent(
         ));
     }
 
+    if let Some(ref key) = req.idempotency_key {
+        validate_idempotency_key(key, state.strict_idempotency_keys)?;
+        // Retry of an earlier creation: return the original agent
+        if let Some(existing) =
+            find_agent_by_idempotency_key(state.db_pool.as_ref(), req.user_id, key).await?
+        {
+            return Ok(HttpResponse::Ok().json(existing));
+        }
+    }
+
     tracing::info!(
         user_id = req.user_id,
         agent_name = %agent_name,
@@ -106,6 +197,7 @@ pub async fn create_agent(
     // Verify user exists and is KYC approved
     let user = sqlx::query!("SELECT kyc_status FROM users WHERE id = $1", req.user_id)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "agents"))?;
 
@@ -114,12 +206,15 @@ pub async fn create_agent(
         None => return Err(ApiError::NotFound("User not found".to_string())),
     };
 
-    if user.kyc_status != "APPROVED" {
+    if ComplianceStatus::from_db_str(&user.kyc_status) != Some(ComplianceStatus::Approved) {
         return Err(ApiError::Forbidden(
             "KYC approval required to create agent wallets".to_string(),
         ));
     }
 
+    // Cap active agent wallets per user to prevent resource exhaustion
+    ensure_agent_quota(state.db_pool.as_ref(), req.user_id, max_agents_per_user()).await?;
+
     // BACKEND-CRIT-002: Validate spending limits
     let daily_limit = Decimal::from_str(&req.spending_limit_daily)
         .map_err(|_| ApiError::BadRequest("Invalid daily spending limit format".to_string()))?;
@@ -149,6 +244,9 @@ pub async fn create_agent(
         ));
     }
 
+    validate_spend_window(state.db_pool.as_ref(), &req.spend_window).await?;
+    let (spend_window, spend_window_tz) = req.spend_window.to_db_columns();
+
     // Generate agent ID and API key
     let agent_id = format!("agent_{}", Uuid::new_v4().to_string().replace("-", ""));
     let api_key = generate_api_key();
@@ -160,30 +258,46 @@ pub async fn create_agent(
         ApiError::InternalError(format!("Wallet generation failed: {}", e))
     })?;
 
-    // Insert agent wallet
-    let _agent = sqlx::query!(
+    // Insert agent wallet (runtime query: idempotency_key added in 20260601000001)
+    let inserted = sqlx::query(
         r#"
         INSERT INTO agent_wallets (
             user_id, agent_id, agent_name, wallet_address, api_key_hash,
-            spending_limit_daily, spending_limit_transaction
+            spending_limit_daily, spending_limit_transaction, idempotency_key,
+            spend_window, spend_window_tz
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7)
-        RETURNING id, created_at
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
         "#,
-        req.user_id,
-        agent_id,
-        agent_name,  // Use validated/trimmed agent_name
-        wallet_address,
-        api_key_hash,
-        req.spending_limit_daily,
-        req.spending_limit_transaction
     )
-    .fetch_one(state.db_pool.as_ref())
-    .await
-    .map_err(|e| {
+    .bind(req.user_id)
+    .bind(&agent_id)
+    .bind(agent_name) // Use validated/trimmed agent_name
+    .bind(&wallet_address)
+    .bind(&api_key_hash)
+    .bind(&req.spending_limit_daily)
+    .bind(&req.spending_limit_transaction)
+    .bind(&req.idempotency_key)
+    .bind(spend_window)
+    .bind(spend_window_tz)
+    .execute(state.db_pool.as_ref())
+    .tracked()
+    .await;
+
+    if let Err(e) = inserted {
+        // Concurrent retry with the same key won the race: replay its agent
+        let is_unique_violation = e
+            .as_database_error()
+            .is_some_and(|db| db.is_unique_violation());
+        if let (true, Some(key)) = (is_unique_violation, req.idempotency_key.as_deref()) {
+            if let Some(existing) =
+                find_agent_by_idempotency_key(state.db_pool.as_ref(), req.user_id, key).await?
+            {
+                return Ok(HttpResponse::Ok().json(existing));
+            }
+        }
         tracing::error!("Failed to create agent: {}", e);
-        ApiError::InternalError("Failed to create agent wallet".to_string())
-    })?;
+        return Err(ApiError::InternalError("Failed to create agent wallet".to_string()));
+    }
 
     // HIGH-027: Mask wallet address in logs (show first 6 and last 4 chars)
     tracing::info!(
@@ -194,10 +308,12 @@ pub async fn create_agent(
 
     Ok(HttpResponse::Created().json(CreateAgentResponse {
         agent_id,
-        api_key, // Only returned once!
+        api_key: Some(api_key), // Only returned once!
         wallet_address,
         spending_limit_daily: req.spending_limit_daily.clone(),
         spending_limit_transaction: req.spending_limit_transaction.clone(),
+        spend_window: req.spend_window.clone(),
+        idempotent_replay: false,
     }))
 }
 
@@ -234,82 +350,48 @@ pub async fn agent_pay(
         return Err(ApiError::Forbidden("You do not own this agent".to_string()));
     }
 
-    if !agent.is_active {
-        return Err(ApiError::Forbidden("Agent wallet is inactive".to_string()));
+    // Active, transaction limit, daily limit, and recipient checks
+    let daily_spent = get_daily_spent(state.db_pool.as_ref(), &req.agent_id).await?;
+    if let Some(violation) = payment_violations(&agent, &req.amount, &req.recipient, daily_spent)
+        .into_iter()
+        .next()
+    {
+        return Err(violation);
     }
+    verify_recipient_onchain(&state, &req.recipient).await?;
 
-    // Parse amount
     let amount_decimal = Decimal::from_str(&req.amount)
         .map_err(|_| ApiError::BadRequest("Invalid amount format".to_string()))?;
 
-    // Check transaction limit
-    let tx_limit = Decimal::from_str(&agent.spending_limit_transaction)
-        .map_err(|_| ApiError::InternalError("Invalid spending limit".to_string()))?;
+    // BE-CRIT-003: Sanitize memo before it goes anywhere near storage
+    let _validated_memo = sanitize_memo(req.memo.as_deref(), max_memo_length())?;
 
-    if amount_decimal > tx_limit {
-        return Err(ApiError::Forbidden(format!(
-            "Amount exceeds transaction limit: {} > {}",
-            amount_decimal, tx_limit
-        )));
-    }
-
-    // Check daily limit
-    let daily_spent = get_daily_spent(state.db_pool.as_ref(), &req.agent_id).await?;
-    let daily_limit = Decimal::from_str(&agent.spending_limit_daily)
-        .map_err(|_| ApiError::InternalError("Invalid daily limit".to_string()))?;
-
-    if daily_spent + amount_decimal > daily_limit {
-        return Err(ApiError::Forbidden(format!(
-            "Daily spending limit exceeded: {} + {} > {}",
-            daily_spent, amount_decimal, daily_limit
-        )));
-    }
-
-    // Validate recipient address
-    if !is_valid_ethereum_address(&req.recipient) {
-        return Err(ApiError::BadRequest("Invalid recipient address".to_string()));
-    }
-
-    // BE-CRIT-003: Validate memo field if present
-    // - Max 500 characters to prevent storage attacks
-    // - Sanitize to prevent XSS (only allow printable ASCII)
-    let _validated_memo: Option<String> = match &req.memo {
-        Some(memo) => {
-            let trimmed = memo.trim();
-            if trimmed.len() > 500 {
-                return Err(ApiError::BadRequest("Memo cannot exceed 500 characters".to_string()));
-            }
-            // Filter to printable ASCII only (32-126)
-            let sanitized: String = trimmed.chars()
-                .filter(|c| *c >= ' ' && *c <= '~')
-                .collect();
-            if sanitized.is_empty() {
-                None
-            } else {
-                Some(sanitized)
-            }
-        },
-        None => None,
-    };
-
-    // Insert transaction
+    // Insert transaction, re-checking the key and active flag so a payment
+    // racing a key rotation or deactivation fails rather than going through
     let transaction = sqlx::query!(
         r#"
         INSERT INTO agent_transactions (agent_id, currency, amount, recipient, status)
-        VALUES ($1, $2, $3, $4, 'PENDING')
+        SELECT agent_id, $2, $3, $4, 'PENDING'
+        FROM agent_wallets
+        WHERE agent_id = $1 AND api_key_hash = $5 AND is_active
         RETURNING id, status, created_at
         "#,
         req.agent_id,
         req.currency,
-        req.amount,
-        req.recipient
+        amount_decimal,
+        req.recipient,
+        hash_api_key(&req.api_key)
     )
-    .fetch_one(state.db_pool.as_ref())
+    .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create agent transaction: {}", e);
         ApiError::InternalError("Failed to create transaction".to_string())
     })?;
+    let Some(transaction) = transaction else {
+        return Err(revoked_agent_error(state.db_pool.as_ref(), &req.agent_id, &req.api_key).await);
+    };
 
     // BACKEND-CRIT-001 FIX: Fail-safe environment detection
     // Only allow mock transactions when EXPLICITLY in development mode
@@ -339,34 +421,37 @@ pub async fn agent_pay(
         ));
     }
 
-    // In production/staging, require real blockchain execution (not implemented yet)
-    if !mock_mode_requested || !is_safe_environment {
+    let tx_hash = if mock_mode_requested {
+        // MOCK MODE: Only reachable in dev/test with explicit opt-in
+        // WARNING: This does NOT execute real blockchain transactions!
         tracing::warn!(
             transaction_id = transaction.id,
             environment = %environment,
-            "Real blockchain execution not implemented. Set ENVIRONMENT=development and ALLOW_MOCK_TRANSACTIONS=true for testing."
+            "MOCK MODE (dev/test only): Generating simulated transaction hash"
         );
-        return Err(ApiError::InternalError(
-            "Blockchain execution not available. Contact support.".to_string()
-        ));
-    }
-
-    // MOCK MODE: Only reachable in dev/test with explicit opt-in
-    // WARNING: This does NOT execute real blockchain transactions!
-    tracing::warn!(
-        transaction_id = transaction.id,
-        environment = %environment,
-        "MOCK MODE (dev/test only): Generating simulated transaction hash"
-    );
-    let tx_hash = format!("0xMOCK_{}", Uuid::new_v4().to_string().replace("-", ""));
+        format!("0xMOCK_{}", Uuid::new_v4().to_string().replace("-", ""))
+    } else {
+        let Some(executor) = state.agent_payment_executor.as_deref() else {
+            tracing::warn!(
+                transaction_id = transaction.id,
+                environment = %environment,
+                "No agent payment executor configured. Set AGENT_PAYMENT_PRIVATE_KEY, or ENVIRONMENT=development and ALLOW_MOCK_TRANSACTIONS=true for testing."
+            );
+            return Err(ApiError::InternalError(
+                "Blockchain execution not available. Contact support.".to_string()
+            ));
+        };
+        execute_onchain_payment(&state, executor, transaction.id, &agent.wallet_address, &req, amount_decimal).await?
+    };
 
-    // Update transaction status
+    // Only reached once the transfer is confirmed (or simulated)
     sqlx::query!(
         "UPDATE agent_transactions SET status = 'COMPLETED', transaction_hash = $1 WHERE id = $2",
         tx_hash,
         transaction.id
     )
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update transaction status: {}", e);
@@ -376,6 +461,7 @@ pub async fn agent_pay(
     tracing::info!(
         transaction_id = transaction.id,
         tx_hash = %tx_hash,
+        amount = %Money::new(amount_decimal, &req.currency),
         "Agent payment executed successfully"
     );
 
@@ -391,6 +477,122 @@ pub async fn agent_pay(
     }))
 }
 
+/// Sends an agent payment through the on-chain executor
+///
+/// Returns the transaction hash once the transfer has a confirmation. A
+/// reverted or unsent transfer marks the row `FAILED` so it stops counting
+/// against the daily limit; a timed-out one stays `PENDING` because it may
+/// still be mined.
+async fn execute_onchain_payment(
+    state: &AppState,
+    executor: &dyn BlockchainExecutor,
+    transaction_id: i32,
+    from_wallet: &str,
+    req: &AgentPaymentRequest,
+    amount: Decimal,
+) -> Result<String, ApiError> {
+    let from = Address::from_str(from_wallet).map_err(|_| {
+        tracing::error!(transaction_id, "Agent wallet address is not a valid EVM address");
+        ApiError::InternalError("Agent wallet is misconfigured".to_string())
+    })?;
+    let to = Address::from_str(&req.recipient)
+        .map_err(|_| ApiError::BadRequest("Invalid recipient address".to_string()))?;
+
+    let result = executor
+        .execute_transfer(state.agent_payment_chain, from, to, amount, &req.currency)
+        .await;
+
+    match result {
+        Ok(tx_hash) => Ok(format!("{:?}", tx_hash)),
+        Err(ExecutionError::Timeout) => {
+            tracing::warn!(transaction_id, "Agent payment unconfirmed — left PENDING");
+            Err(ApiError::InternalError(
+                "Payment submitted but not yet confirmed. Do not retry; contact support.".to_string(),
+            ))
+        }
+        Err(e) => {
+            tracing::error!(transaction_id, error = %e, "Agent payment failed on-chain");
+            sqlx::query!(
+                "UPDATE agent_transactions SET status = 'FAILED' WHERE id = $1",
+                transaction_id
+            )
+            .execute(state.db_pool.as_ref())
+            .tracked()
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to mark transaction failed: {}", e);
+                ApiError::InternalError("Failed to update transaction".to_string())
+            })?;
+
+            let message = match e {
+                ExecutionError::Reverted(_) => "Payment reverted on-chain",
+                _ => "Payment could not be executed",
+            };
+            Err(ApiError::InternalError(message.to_string()))
+        }
+    }
+}
+
+/// POST /api/v1/agents/validate-payment
+///
+/// Dry run of `agent_pay`: runs the same ownership, active, limit,
+/// recipient, and memo checks and reports every failure. Nothing is persisted.
+pub async fn validate_agent_payment(
+    state: web::Data<Arc<AppState>>,
+    http_req: HttpRequest,
+    req: web::Json<AgentPaymentRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth_user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
+    let agent = verify_agent_api_key(state.db_pool.as_ref(), &req.agent_id, &req.api_key).await?;
+
+    // Don't reveal another user's limits or spend
+    if agent.user_id != auth_user_id {
+        return Ok(HttpResponse::Ok().json(ValidatePaymentResponse {
+            agent_id: req.agent_id.clone(),
+            valid: false,
+            reasons: vec!["You do not own this agent".to_string()],
+            daily_spent: "0".to_string(),
+            daily_remaining: "0".to_string(),
+        }));
+    }
+
+    let daily_spent = get_daily_spent(state.db_pool.as_ref(), &req.agent_id).await?;
+    let daily_remaining = Decimal::from_str(&agent.spending_limit_daily)
+        .map(|limit| (limit - daily_spent).max(Decimal::ZERO))
+        .unwrap_or(Decimal::ZERO);
+
+    let mut violations = payment_violations(&agent, &req.amount, &req.recipient, daily_spent);
+    if let Err(violation) = sanitize_memo(req.memo.as_deref(), max_memo_length()) {
+        violations.push(violation);
+    }
+    if let Err(violation) = verify_recipient_onchain(&state, &req.recipient).await {
+        violations.push(violation);
+    }
+
+    let reasons: Vec<String> = violations
+        .into_iter()
+        .map(|violation| match violation {
+            ApiError::BadRequest(msg) | ApiError::Forbidden(msg) | ApiError::InternalError(msg) => msg,
+            other => other.to_string(),
+        })
+        .collect();
+
+    tracing::info!(
+        agent_id = %req.agent_id,
+        valid = reasons.is_empty(),
+        failures = reasons.len(),
+        "Agent payment validated (dry run)"
+    );
+
+    Ok(HttpResponse::Ok().json(ValidatePaymentResponse {
+        agent_id: req.agent_id.clone(),
+        valid: reasons.is_empty(),
+        reasons,
+        daily_spent: daily_spent.to_string(),
+        daily_remaining: daily_remaining.to_string(),
+    }))
+}
+
 /// GET /api/v1/agents/list/{user_id}
 pub async fn list_agents(
     state: web::Data<Arc<AppState>>,
@@ -405,9 +607,9 @@ pub async fn list_agents(
         return Err(ApiError::Forbidden("Cannot access other user's agents".to_string()));
     }
 
-    // HIGH-001 FIX: Single query with LEFT JOIN to avoid N+1 queries
-    // Previously: 1 query for agents + N queries for daily_spent = N+1 queries
-    // Now: 1 query total (99% reduction for 100 agents)
+    // HIGH-001 FIX: One query for all agents. The daily spend is a correlated
+    // subquery that applies each agent's spend window, rather than a
+    // get_daily_spent call per agent (N+1 queries)
     let agents = sqlx::query!(
         r#"
         SELECT
@@ -416,16 +618,22 @@ pub async fn list_agents(
             aw.wallet_address,
             aw.spending_limit_daily,
             aw.spending_limit_transaction,
+            aw.spend_window,
+            aw.spend_window_tz,
             aw.is_active,
             aw.created_at,
             COALESCE(
-                (SELECT SUM(CAST(amount AS DECIMAL))::TEXT
-                 FROM agent_transactions
-                 WHERE agent_id = aw.agent_id
-                 AND created_at > NOW() - INTERVAL '24 hours'
-                 AND status IN ('PENDING', 'COMPLETED')),
-                '0'
-            ) AS daily_spent
+                (SELECT SUM(t.amount)
+                 FROM agent_transactions t
+                 WHERE t.agent_id = aw.agent_id
+                 AND t.created_at >= CASE
+                     WHEN aw.spend_window = 'CALENDAR_DAY' THEN
+                         date_trunc('day', NOW() AT TIME ZONE aw.spend_window_tz) AT TIME ZONE aw.spend_window_tz
+                     ELSE NOW() - INTERVAL '24 hours'
+                 END
+                 AND t.status IN ('PENDING', 'COMPLETED')),
+                0
+            ) AS "daily_spent!"
         FROM agent_wallets aw
         WHERE aw.user_id = $1
         ORDER BY aw.created_at DESC
@@ -433,18 +641,20 @@ pub async fn list_agents(
         user_id
     )
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "agents"))?;
 
     let responses: Vec<AgentWalletResponse> = agents
         .into_iter()
         .map(|agent| AgentWalletResponse {
-            agent_id: agent.agent_id.clone(),
+            agent_id: agent.agent_id,
             agent_name: agent.agent_name.unwrap_or_else(|| "Unnamed Agent".to_string()),
             wallet_address: agent.wallet_address,
             spending_limit_daily: agent.spending_limit_daily,
             spending_limit_transaction: agent.spending_limit_transaction,
-            daily_spent: agent.daily_spent.unwrap_or_else(|| "0".to_string()),
+            spend_window: SpendWindow::from_db_columns(&agent.spend_window, agent.spend_window_tz),
+            daily_spent: agent.daily_spent.to_string(),
             is_active: agent.is_active,
             created_at: agent.created_at.to_rfc3339(),
         })
@@ -472,6 +682,7 @@ pub async fn get_agent_transactions(
         agent_id
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "agents"))?;
 
@@ -492,6 +703,7 @@ pub async fn get_agent_transactions(
         agent_id
     )
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "agents"))?;
 
@@ -516,7 +728,155 @@ pub async fn get_agent_transactions(
     })))
 }
 
+/// POST /api/v1/agents/{agent_id}/rotate-key
+///
+/// Replaces a leaked or expiring API key without losing the agent's history.
+/// The old key stops working as soon as this commits, including for payments
+/// already in flight; the new key is returned exactly once.
+pub async fn rotate_agent_key(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    agent_id: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let agent_id = agent_id.into_inner();
+    let auth_user_id = get_authenticated_user_id(state.db_pool.as_ref(), &req).await?;
+    ensure_owns_agent(state.db_pool.as_ref(), &agent_id, auth_user_id).await?;
+
+    let api_key = generate_api_key();
+    let rotated = sqlx::query!(
+        r#"
+        UPDATE agent_wallets
+        SET api_key_hash = $1, updated_at = NOW()
+        WHERE agent_id = $2 AND user_id = $3
+        RETURNING updated_at
+        "#,
+        hash_api_key(&api_key),
+        agent_id,
+        auth_user_id
+    )
+    .fetch_optional(state.db_pool.as_ref())
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "agents"))?
+    .ok_or_else(|| ApiError::NotFound("Agent not found".to_string()))?;
+
+    tracing::info!(agent_id = %agent_id, user_id = auth_user_id, "Agent API key rotated");
+
+    Ok(HttpResponse::Ok().json(RotateAgentKeyResponse {
+        agent_id,
+        api_key,
+        rotated_at: rotated.updated_at.to_rfc3339(),
+    }))
+}
+
+/// POST /api/v1/agents/{agent_id}/deactivate
+///
+/// Blocks the agent from paying, including payments already in flight that
+/// have not yet been recorded, until it is reactivated.
+pub async fn deactivate_agent(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    agent_id: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    set_agent_active(&state, &req, &agent_id.into_inner(), false).await
+}
+
+/// POST /api/v1/agents/{agent_id}/activate
+pub async fn activate_agent(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    agent_id: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    set_agent_active(&state, &req, &agent_id.into_inner(), true).await
+}
+
+async fn set_agent_active(
+    state: &AppState,
+    req: &HttpRequest,
+    agent_id: &str,
+    active: bool,
+) -> Result<HttpResponse, ApiError> {
+    let auth_user_id = get_authenticated_user_id(state.db_pool.as_ref(), req).await?;
+    ensure_owns_agent(state.db_pool.as_ref(), agent_id, auth_user_id).await?;
+
+    let agent = sqlx::query!(
+        r#"
+        UPDATE agent_wallets
+        SET is_active = $1, updated_at = NOW()
+        WHERE agent_id = $2 AND user_id = $3
+        RETURNING agent_id, agent_name, wallet_address, spending_limit_daily,
+                  spending_limit_transaction, spend_window, spend_window_tz,
+                  is_active, created_at
+        "#,
+        active,
+        agent_id,
+        auth_user_id
+    )
+    .fetch_optional(state.db_pool.as_ref())
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "agents"))?
+    .ok_or_else(|| ApiError::NotFound("Agent not found".to_string()))?;
+
+    let daily_spent = get_daily_spent(state.db_pool.as_ref(), agent_id).await?;
+
+    tracing::info!(
+        agent_id = %agent_id,
+        user_id = auth_user_id,
+        is_active = active,
+        "Agent wallet active state changed"
+    );
+
+    Ok(HttpResponse::Ok().json(AgentWalletResponse {
+        agent_id: agent.agent_id,
+        agent_name: agent.agent_name.unwrap_or_else(|| "Unnamed Agent".to_string()),
+        wallet_address: agent.wallet_address,
+        spending_limit_daily: agent.spending_limit_daily,
+        spending_limit_transaction: agent.spending_limit_transaction,
+        spend_window: SpendWindow::from_db_columns(&agent.spend_window, agent.spend_window_tz),
+        daily_spent: daily_spent.to_string(),
+        is_active: agent.is_active,
+        created_at: agent.created_at.to_rfc3339(),
+    }))
+}
+
 // Helper functions
+
+/// NotFound for unknown agents, Forbidden for another user's agent
+async fn ensure_owns_agent(pool: &PgPool, agent_id: &str, user_id: i32) -> Result<(), ApiError> {
+    let agent_owner = sqlx::query!(
+        "SELECT user_id FROM agent_wallets WHERE agent_id = $1",
+        agent_id
+    )
+    .fetch_optional(pool)
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "agents"))?;
+
+    match agent_owner {
+        Some(owner) if owner.user_id == user_id => Ok(()),
+        Some(_) => {
+            tracing::warn!(
+                auth_user_id = user_id,
+                agent_id = %agent_id,
+                "Agent management rejected: user does not own agent"
+            );
+            Err(ApiError::Forbidden("You do not own this agent".to_string()))
+        }
+        None => Err(ApiError::NotFound("Agent not found".to_string())),
+    }
+}
+
+/// Error for a payment whose agent passed verification but could not record
+/// the transaction: it was deactivated or its key rotated in the meantime
+async fn revoked_agent_error(pool: &PgPool, agent_id: &str, api_key: &str) -> ApiError {
+    match verify_agent_api_key(pool, agent_id, api_key).await {
+        Ok(agent) if !agent.is_active => ApiError::Forbidden("Agent wallet is inactive".to_string()),
+        Ok(_) => ApiError::Unauthorized("Invalid agent credentials".to_string()),
+        Err(e) => e,
+    }
+}
+
 async fn verify_agent_api_key(
     pool: &PgPool,
     agent_id: &str,
@@ -535,6 +895,7 @@ async fn verify_agent_api_key(
         api_key_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "agents"))?;
 
@@ -551,25 +912,241 @@ async fn verify_agent_api_key(
     }
 }
 
+/// Active, spending-limit, and recipient checks shared by `agent_pay` and
+/// `validate_agent_payment`, in the order `agent_pay` enforces them.
+/// Ownership is checked by the callers.
+fn payment_violations(
+    agent: &AgentWallet,
+    amount: &str,
+    recipient: &str,
+    daily_spent: Decimal,
+) -> Vec<ApiError> {
+    let mut violations = Vec::new();
+
+    if !agent.is_active {
+        violations.push(ApiError::Forbidden("Agent wallet is inactive".to_string()));
+    }
+
+    match Decimal::from_str(amount) {
+        Ok(amount_decimal) => {
+            // Check transaction limit
+            match Decimal::from_str(&agent.spending_limit_transaction) {
+                Ok(tx_limit) if amount_decimal > tx_limit => {
+                    violations.push(ApiError::Forbidden(format!(
+                        "Amount exceeds transaction limit: {} > {}",
+                        amount_decimal, tx_limit
+                    )));
+                }
+                Ok(_) => {}
+                Err(_) => violations.push(ApiError::InternalError("Invalid spending limit".to_string())),
+            }
+
+            // Check daily limit
+            match Decimal::from_str(&agent.spending_limit_daily) {
+                Ok(daily_limit) if daily_spent + amount_decimal > daily_limit => {
+                    violations.push(ApiError::Forbidden(format!(
+                        "Daily spending limit exceeded: {} + {} > {}",
+                        daily_spent, amount_decimal, daily_limit
+                    )));
+                }
+                Ok(_) => {}
+                Err(_) => violations.push(ApiError::InternalError("Invalid daily limit".to_string())),
+            }
+        }
+        Err(_) => violations.push(ApiError::BadRequest("Invalid amount format".to_string())),
+    }
+
+    // Validate recipient address
+    if !is_valid_ethereum_address(recipient) {
+        violations.push(ApiError::BadRequest("Invalid recipient address".to_string()));
+    }
+
+    violations
+}
+
+/// Opt-in on-chain recipient check (`VERIFY_AGENT_RECIPIENTS_ONCHAIN`).
+/// Accepts contracts (smart-contract wallets) and used accounts, rejects
+/// addresses with no code, balance, or history. Fails closed on RPC errors.
+async fn verify_recipient_onchain(state: &AppState, recipient: &str) -> Result<(), ApiError> {
+    let Some(ref verifier) = state.recipient_verifier else {
+        return Ok(());
+    };
+    // Malformed addresses are already reported by `payment_violations`
+    let Ok(address) = Address::from_str(recipient) else {
+        return Ok(());
+    };
+
+    match verifier.ensure_payable(address).await {
+        Ok(kind) => {
+            tracing::debug!(recipient = %mask_address(recipient), kind = ?kind, "Recipient verified on-chain");
+            Ok(())
+        }
+        Err(ChainError::EmptyRecipient(_)) => Err(ApiError::BadRequest(
+            "Recipient address has no code, balance, or transaction history".to_string(),
+        )),
+        Err(e) => {
+            tracing::error!(recipient = %mask_address(recipient), error = %e, "On-chain recipient check failed");
+            Err(ApiError::InternalError("Unable to verify recipient address".to_string()))
+        }
+    }
+}
+
+/// Spend counted against the daily limit, since the start of the agent's
+/// spend window: 24 hours ago, or local midnight for calendar-day windows
 async fn get_daily_spent(pool: &PgPool, agent_id: &str) -> Result<Decimal, ApiError> {
     // Use SQL SUM() to aggregate in the database for better performance
-    // COALESCE handles NULL (no transactions) case, returning '0'
+    // COALESCE handles NULL (no transactions) case, returning 0
     let result = sqlx::query_scalar!(
         r#"
-        SELECT COALESCE(SUM(amount::NUMERIC), 0)::TEXT as "total!"
-        FROM agent_transactions
-        WHERE agent_id = $1
-        AND created_at > NOW() - INTERVAL '24 hours'
-        AND status IN ('PENDING', 'COMPLETED')
+        SELECT COALESCE(SUM(t.amount), 0) as "total!"
+        FROM agent_transactions t
+        JOIN agent_wallets w ON w.agent_id = t.agent_id
+        WHERE t.agent_id = $1
+        AND t.created_at >= CASE
+            WHEN w.spend_window = 'CALENDAR_DAY' THEN
+                date_trunc('day', NOW() AT TIME ZONE w.spend_window_tz) AT TIME ZONE w.spend_window_tz
+            ELSE NOW() - INTERVAL '24 hours'
+        END
+        AND t.status IN ('PENDING', 'COMPLETED')
         "#,
         agent_id
     )
     .fetch_one(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "agents"))?;
 
-    Decimal::from_str(&result)
-        .map_err(|_| ApiError::InternalError("Invalid sum from database".to_string()))
+    Ok(result)
+}
+
+/// Look up an agent previously created by `user_id` with this idempotency key
+async fn find_agent_by_idempotency_key(
+    pool: &PgPool,
+    user_id: i32,
+    idempotency_key: &str,
+) -> Result<Option<CreateAgentResponse>, ApiError> {
+    let existing: Option<(String, String, String, String, String, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT agent_id, wallet_address, spending_limit_daily, spending_limit_transaction,
+               spend_window, spend_window_tz
+        FROM agent_wallets
+        WHERE user_id = $1 AND idempotency_key = $2
+        "#,
+    )
+    .bind(user_id)
+    .bind(idempotency_key)
+    .fetch_optional(pool)
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "agents"))?;
+
+    Ok(existing.map(
+        |(agent_id, wallet_address, spending_limit_daily, spending_limit_transaction, window, tz)| {
+            tracing::info!(
+                agent_id = %agent_id,
+                idempotency_key = idempotency_key,
+                "Returning existing agent for idempotent request"
+            );
+            CreateAgentResponse {
+                agent_id,
+                api_key: None,
+                wallet_address,
+                spending_limit_daily,
+                spending_limit_transaction,
+                spend_window: SpendWindow::from_db_columns(&window, tz),
+                idempotent_replay: true,
+            }
+        },
+    ))
+}
+
+/// Rejects calendar-day windows whose timezone Postgres does not know, so a
+/// typo cannot fail every later spend query for the agent
+async fn validate_spend_window(pool: &PgPool, window: &SpendWindow) -> Result<(), ApiError> {
+    let SpendWindow::CalendarDay(tz) = window else {
+        return Ok(());
+    };
+    if tz.is_empty() || tz.len() > 64 {
+        return Err(ApiError::BadRequest("Spend window timezone must be 1-64 characters".to_string()));
+    }
+
+    let known: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM pg_timezone_names WHERE name = $1)")
+        .bind(tz)
+        .fetch_one(pool)
+        .tracked()
+        .await
+        .map_err(|e| handle_db_error(e, "agents"))?;
+    if !known {
+        return Err(ApiError::BadRequest(format!("Unknown spend window timezone: {}", tz)));
+    }
+    Ok(())
+}
+
+/// Configured maximum number of active agents per user
+fn max_agents_per_user() -> i64 {
+    std::env::var("MAX_AGENTS_PER_USER")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|n: &i64| *n > 0)
+        .unwrap_or(DEFAULT_MAX_AGENTS_PER_USER)
+}
+
+/// Configured maximum payment memo length in characters
+fn max_memo_length() -> usize {
+    std::env::var("MAX_MEMO_LENGTH")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|n: &usize| *n > 0)
+        .unwrap_or(DEFAULT_MAX_MEMO_LENGTH)
+}
+
+/// Trims a payment memo, strips control characters (newlines, NULs, escape
+/// sequences, ...) and rejects it if still longer than `max_len` characters.
+/// A memo that is empty after sanitizing is treated as absent.
+fn sanitize_memo(memo: Option<&str>, max_len: usize) -> Result<Option<String>, ApiError> {
+    let Some(memo) = memo else {
+        return Ok(None);
+    };
+
+    let sanitized: String = memo.chars().filter(|c| !c.is_control()).collect();
+    let sanitized = sanitized.trim();
+
+    if sanitized.chars().count() > max_len {
+        return Err(ApiError::BadRequest(format!(
+            "Memo cannot exceed {} characters",
+            max_len
+        )));
+    }
+
+    Ok((!sanitized.is_empty()).then(|| sanitized.to_string()))
+}
+
+/// Reject with 409 if the user already has `max_agents` active agents.
+/// Deactivated agents do not count towards the cap.
+async fn ensure_agent_quota(pool: &PgPool, user_id: i32, max_agents: i64) -> Result<(), ApiError> {
+    let (active,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM agent_wallets WHERE user_id = $1 AND is_active",
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "agents"))?;
+
+    if active >= max_agents {
+        tracing::warn!(
+            user_id = user_id,
+            active_agents = active,
+            max_agents = max_agents,
+            "Agent creation rejected: per-user agent limit reached"
+        );
+        return Err(ApiError::Conflict(format!(
+            "Maximum of {} active agents per user reached",
+            max_agents
+        )));
+    }
+
+    Ok(())
 }
 
 fn generate_api_key() -> String {
@@ -762,6 +1339,7 @@ async fn get_authenticated_user_id(
         token_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "agents"))?;
 
@@ -866,6 +1444,79 @@ mod tests {
         assert_eq!(addr1, addr2);
     }
 
+    fn test_wallet(is_active: bool) -> AgentWallet {
+        AgentWallet {
+            user_id: 1,
+            agent_id: "agent_test".to_string(),
+            wallet_address: "0x0000000000000000000000000000000000000001".to_string(),
+            spending_limit_daily: "1000".to_string(),
+            spending_limit_transaction: "100".to_string(),
+            is_active,
+        }
+    }
+
+    const RECIPIENT: &str = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1";
+
+    #[test]
+    fn test_payment_violations_within_limits() {
+        assert!(payment_violations(&test_wallet(true), "50", RECIPIENT, Decimal::from(900)).is_empty());
+    }
+
+    #[test]
+    fn test_payment_violations_reports_every_failure() {
+        let violations = payment_violations(&test_wallet(false), "150", "0xnope", Decimal::from(900));
+        let messages: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+
+        assert_eq!(violations.len(), 4, "{:?}", messages);
+        assert!(messages[0].contains("inactive"));
+        assert!(messages[1].contains("transaction limit"));
+        assert!(messages[2].contains("Daily spending limit"));
+        assert!(messages[3].contains("recipient"));
+    }
+
+    #[test]
+    fn test_sanitize_memo_strips_control_characters() {
+        assert_eq!(
+            sanitize_memo(Some("  invoice\n#42\r\t\u{0}\u{1b}[31m paid "), 500).unwrap(),
+            Some("invoice#42[31m paid".to_string())
+        );
+        assert_eq!(sanitize_memo(Some("café ☕"), 500).unwrap(), Some("café ☕".to_string()));
+        assert_eq!(sanitize_memo(Some(" \n\u{7f} "), 500).unwrap(), None);
+        assert_eq!(sanitize_memo(None, 500).unwrap(), None);
+    }
+
+    #[test]
+    fn test_sanitize_memo_rejects_over_length() {
+        let at_limit = "x".repeat(10);
+        assert_eq!(sanitize_memo(Some(&at_limit), 10).unwrap(), Some(at_limit.clone()));
+
+        let err = sanitize_memo(Some(&"x".repeat(11)), 10).unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(ref msg) if msg.contains("10 characters")));
+
+        // Stripped control bytes don't count towards the limit, multibyte chars count once
+        assert!(sanitize_memo(Some(&format!("{}\n\n", at_limit)), 10).is_ok());
+        assert!(sanitize_memo(Some(&"é".repeat(10)), 10).is_ok());
+    }
+
+    #[test]
+    fn test_spend_window_round_trip() {
+        let windows = [
+            SpendWindow::Rolling24h,
+            SpendWindow::CalendarDayUtc,
+            SpendWindow::CalendarDay("Europe/Berlin".to_string()),
+        ];
+        for window in windows {
+            let (column, tz) = window.to_db_columns();
+            assert_eq!(SpendWindow::from_db_columns(column, tz.map(String::from)), window);
+        }
+        assert_eq!(SpendWindow::from_db_columns("BOGUS", None), SpendWindow::Rolling24h);
+
+        let json = serde_json::to_value(SpendWindow::CalendarDay("Asia/Tokyo".to_string())).unwrap();
+        assert_eq!(json, serde_json::json!({"type": "CALENDAR_DAY", "timezone": "Asia/Tokyo"}));
+        let rolling: SpendWindow = serde_json::from_str(r#"{"type": "ROLLING_24H"}"#).unwrap();
+        assert_eq!(rolling, SpendWindow::Rolling24h);
+    }
+
     #[test]
     fn test_generate_wallet_address_different_for_different_agents() {
         let addr1 = generate_wallet_address("agent-1").expect("should generate address");
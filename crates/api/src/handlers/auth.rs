@@ -1,9 +1,11 @@
 //! Authentication handlers
 
 use crate::error::{ApiError, handle_db_error};
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{cookie::{Cookie, SameSite}, web, HttpRequest, HttpResponse};
 use chrono::{Duration, Utc};
+use meridian_compliance::ComplianceStatus;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -63,6 +65,7 @@ pub async fn login(
         req.email
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Database error during login: {}", e);
@@ -117,6 +120,7 @@ pub async fn login(
         expires_at
     )
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create session: {}", e);
@@ -129,6 +133,7 @@ pub async fn login(
         user.id
     )
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     {
         tracing::warn!(user_id = user.id, error = %e, "Failed to update last_login_at");
@@ -182,6 +187,7 @@ pub async fn register(
     // Check if user already exists
     let existing = sqlx::query!("SELECT id FROM users WHERE email = $1", req.email)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "auth"))?;
 
@@ -201,15 +207,17 @@ pub async fn register(
     let user = sqlx::query!(
         r#"
         INSERT INTO users (email, password_hash, role, organization, kyc_status)
-        VALUES ($1, $2, $3, $4, 'NOT_STARTED')
+        VALUES ($1, $2, $3, $4, $5)
         RETURNING id, email, role, organization, kyc_status, wallet_address, created_at
         "#,
         req.email,
         password_hash,
         role,
-        req.organization
+        req.organization,
+        ComplianceStatus::NotStarted.to_db_str()
     )
     .fetch_one(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create user: {}", e);
@@ -237,6 +245,7 @@ pub async fn register(
         expires_at
     )
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| ApiError::InternalError(format!("Failed to create session: {}", e)))?;
 
@@ -302,6 +311,7 @@ pub async fn verify(
         token_hash
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "auth"))?;
 
@@ -349,6 +359,7 @@ pub async fn refresh_token(
         token_hash
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "auth"))?;
 
@@ -379,6 +390,7 @@ pub async fn refresh_token(
         session.id
     )
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update session: {}", e);
@@ -433,6 +445,7 @@ pub async fn logout(
             token_hash
         )
         .execute(state.db_pool.as_ref())
+        .tracked()
         .await;
 
         match result {
@@ -509,6 +522,7 @@ pub async fn logout_all(
         token_hash
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Database error during logout-all: {}", e);
@@ -526,6 +540,7 @@ pub async fn logout_all(
         session.user_id
     )
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to delete all sessions: {}", e);
@@ -7,6 +7,7 @@
 //! the scattered verify_admin / get_authenticated_user_id helpers.
 
 use crate::error::ApiError;
+use crate::query_metrics::TrackQuery;
 use actix_web::HttpRequest;
 use sha2::{Sha256, Digest};
 use std::sync::OnceLock;
@@ -92,6 +93,7 @@ async fn authenticate_session(
     )
     .bind(&token_hash)
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("DB error in session auth: {}", e);
@@ -133,6 +135,7 @@ async fn authenticate_api_key(
     )
     .bind(&key_hash)
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("DB error in API key auth: {}", e);
@@ -145,6 +148,7 @@ async fn authenticate_api_key(
             let _ = sqlx::query("UPDATE api_keys SET last_used_at = NOW() WHERE id = $1")
                 .bind(r.id)
                 .execute(pool)
+                .tracked()
                 .await;
 
             // Derive role from permissions: keys with "admin" permission get ADMIN,
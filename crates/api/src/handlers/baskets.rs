@@ -1,16 +1,25 @@
 //! Basket management handlers
 
+use crate::basket_cache::CachedBasketValue;
 use crate::error::{ApiError, handle_db_error};
+use crate::handlers::operations::SUPPORTED_CURRENCIES;
+use crate::locale::Locale;
 use crate::models::{
-    BasketResponse, BasketValueResponse, CreateCustomBasketRequest, CreateImfSdrBasketRequest,
+    BasketResponse, BasketValueQuery, BasketValueResponse, CreateCustomBasketRequest, CreateImfSdrBasketRequest,
     CreateSingleCurrencyBasketRequest, PaginatedResponse, PaginationQuery,
 };
-use crate::state::AppState;
+use crate::query_metrics::TrackQuery;
+use crate::resilience::{resilient_call_with_budget, ResilientError, RetryBudget, RetryConfig};
+use crate::state::{AppState, CircuitBreaker};
 use actix_web::{web, HttpRequest, HttpResponse};
 use chrono::Utc;
-use meridian_basket::{CurrencyBasket, CurrencyComponent};
-use meridian_db::{BasketRepository, DbError};
+use meridian_basket::{BasketType, CurrencyBasket, CurrencyComponent};
+use meridian_db::{AuditRepository, BasketRepository, CreateAuditLogRequest, DbError};
+use meridian_oracle::{mainnet_feeds, ChainlinkOracle, OracleError};
+use rust_decimal::Decimal;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -27,7 +36,8 @@ use uuid::Uuid;
     responses(
         (status = 201, description = "Basket created successfully", body = BasketResponse),
         (status = 400, description = "Invalid request"),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Organization basket limit reached")
     )
 )]
 pub async fn create_single_currency_basket(
@@ -36,7 +46,7 @@ pub async fn create_single_currency_basket(
     req: web::Json<CreateSingleCurrencyBasketRequest>,
 ) -> Result<HttpResponse, ApiError> {
     // MED-001: Verify user is authenticated before allowing basket creation
-    let _user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
+    let user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
 
     tracing::info!(
         name = %req.name,
@@ -44,18 +54,16 @@ pub async fn create_single_currency_basket(
         "Creating single-currency basket"
     );
 
+    let chainlink_feed = resolve_feed(&req.currency_code, req.chainlink_feed.as_deref())?;
     let basket = CurrencyBasket::new_single_currency(
         req.name.clone(),
         req.currency_code.clone(),
-        req.chainlink_feed.clone(),
+        chainlink_feed,
     )?;
+    let basket = apply_min_price_confidence(basket, req.min_price_confidence)?;
 
     // Persist basket to database
-    let basket_repo = BasketRepository::new((*state.db_pool).clone());
-    basket_repo.create(&basket).await.map_err(|e| {
-        tracing::error!("Failed to persist basket: {}", e);
-        ApiError::InternalError("Failed to persist basket".to_string())
-    })?;
+    persist_basket(&state, &basket, user_id).await?;
 
     tracing::info!(id = %basket.id, "Basket created and persisted to database");
 
@@ -75,7 +83,8 @@ pub async fn create_single_currency_basket(
     responses(
         (status = 201, description = "IMF SDR basket created", body = BasketResponse),
         (status = 400, description = "Invalid request"),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Organization basket limit reached")
     )
 )]
 pub async fn create_imf_sdr_basket(
@@ -84,18 +93,16 @@ pub async fn create_imf_sdr_basket(
     req: web::Json<CreateImfSdrBasketRequest>,
 ) -> Result<HttpResponse, ApiError> {
     // MED-001: Verify user is authenticated before allowing basket creation
-    let _user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
+    let user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
 
     tracing::info!(name = %req.name, "Creating IMF SDR basket");
 
-    let basket = CurrencyBasket::new_imf_sdr(req.name.clone(), req.chainlink_feeds.clone())?;
+    let feeds = resolve_sdr_feeds(&req.chainlink_feeds)?;
+    let basket = CurrencyBasket::new_imf_sdr(req.name.clone(), feeds)?;
+    let basket = apply_min_price_confidence(basket, req.min_price_confidence)?;
 
     // Persist basket to database
-    let basket_repo = BasketRepository::new((*state.db_pool).clone());
-    basket_repo.create(&basket).await.map_err(|e| {
-        tracing::error!("Failed to persist basket: {}", e);
-        ApiError::InternalError("Failed to persist basket".to_string())
-    })?;
+    persist_basket(&state, &basket, user_id).await?;
 
     tracing::info!(id = %basket.id, "IMF SDR basket created and persisted to database");
 
@@ -115,7 +122,8 @@ pub async fn create_imf_sdr_basket(
     responses(
         (status = 201, description = "Custom basket created", body = BasketResponse),
         (status = 400, description = "Invalid request"),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Organization basket limit reached")
     )
 )]
 pub async fn create_custom_basket(
@@ -124,7 +132,7 @@ pub async fn create_custom_basket(
     req: web::Json<CreateCustomBasketRequest>,
 ) -> Result<HttpResponse, ApiError> {
     // MED-001: Verify user is authenticated before allowing basket creation
-    let _user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
+    let user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
 
     tracing::info!(
         name = %req.name,
@@ -133,40 +141,134 @@ pub async fn create_custom_basket(
     );
 
     // Convert request components to basket components
-    let components: Result<Vec<CurrencyComponent>, _> = req
+    let components = req
         .components
         .iter()
         .map(|c| {
+            let chainlink_feed = resolve_feed(&c.currency_code, c.chainlink_feed.as_deref())?;
             CurrencyComponent::new(
                 c.currency_code.clone(),
                 c.target_weight,
                 c.min_weight,
                 c.max_weight,
-                c.chainlink_feed.clone(),
+                chainlink_feed,
             )
+            .map_err(ApiError::from)
         })
-        .collect();
-
-    let components = components?;
+        .collect::<Result<Vec<CurrencyComponent>, ApiError>>()?;
 
     let basket = CurrencyBasket::new_custom_basket(
         req.name.clone(),
         components,
         req.rebalance_strategy.clone().into(),
     )?;
+    let basket = apply_min_price_confidence(basket, req.min_price_confidence)?;
 
     // Persist basket to database
-    let basket_repo = BasketRepository::new((*state.db_pool).clone());
-    basket_repo.create(&basket).await.map_err(|e| {
-        tracing::error!("Failed to persist basket: {}", e);
-        ApiError::InternalError("Failed to persist basket".to_string())
-    })?;
+    persist_basket(&state, &basket, user_id).await?;
 
     tracing::info!(id = %basket.id, "Custom basket created and persisted to database");
 
     Ok(HttpResponse::Created().json(BasketResponse::from(basket)))
 }
 
+/// Persists a new basket under the creating user's organization
+///
+/// Returns `Conflict` (409) when the organization is already at its basket cap.
+async fn persist_basket(
+    state: &AppState,
+    basket: &CurrencyBasket,
+    user_id: i32,
+) -> Result<(), ApiError> {
+    let (organization,): (String,) = sqlx::query_as("SELECT organization FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_one(state.db_pool.as_ref())
+        .tracked()
+        .await
+        .map_err(|e| handle_db_error(e, "baskets"))?;
+
+    let max_live = state.max_baskets_per_organization;
+    let basket_repo = BasketRepository::new((*state.db_pool).clone());
+    let created = basket_repo
+        .create_for_organization(basket, &organization, user_id, max_live)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to persist basket: {}", e);
+            ApiError::InternalError("Failed to persist basket".to_string())
+        })?;
+
+    if created.is_none() {
+        tracing::warn!(organization = %organization, max_live, "Basket limit reached");
+        return Err(ApiError::Conflict(format!(
+            "Organization has reached its limit of {} baskets",
+            max_live
+        )));
+    }
+
+    record_basket_created(state, BasketCreated::new(basket, user_id)).await;
+
+    Ok(())
+}
+
+/// Audit operation name for basket creation
+pub const BASKET_CREATED_AUDIT_OPERATION: &str = "basket_created";
+
+/// Audit event written whenever a basket-creation handler succeeds
+#[derive(Debug, Clone, Serialize)]
+pub struct BasketCreated {
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub basket_type: &'static str,
+    pub creator_user_id: i32,
+    pub component_codes: Vec<String>,
+}
+
+impl BasketCreated {
+    fn new(basket: &CurrencyBasket, creator_user_id: i32) -> Self {
+        let basket_type = match basket.basket_type {
+            BasketType::SingleCurrency => "single_currency",
+            BasketType::ImfSdr => "imf_sdr",
+            BasketType::CustomBasket => "custom_basket",
+        };
+        Self {
+            id: basket.id,
+            basket_type,
+            creator_user_id,
+            component_codes: basket.components.iter().map(|c| c.currency_code.clone()).collect(),
+        }
+    }
+
+    fn into_audit_request(self) -> CreateAuditLogRequest {
+        CreateAuditLogRequest {
+            operation: BASKET_CREATED_AUDIT_OPERATION.to_string(),
+            actor: Some(self.creator_user_id.to_string()),
+            stablecoin_id: None,
+            basket_id: Some(self.id),
+            details: serde_json::to_value(&self).unwrap_or_default(),
+        }
+    }
+}
+
+/// Persists a `BasketCreated` audit entry.
+///
+/// The basket is already committed by the time this runs, so a failed audit
+/// write is logged at error level rather than failing the request.
+async fn record_basket_created(state: &AppState, event: BasketCreated) {
+    tracing::info!(
+        basket_id = %event.id,
+        basket_type = event.basket_type,
+        creator_user_id = event.creator_user_id,
+        components = ?event.component_codes,
+        "BasketCreated"
+    );
+
+    let basket_id = event.id;
+    let audit_repo = AuditRepository::new((*state.db_pool).clone());
+    if let Err(e) = audit_repo.log(event.into_audit_request()).await {
+        tracing::error!(basket_id = %basket_id, error = %e, "Failed to persist BasketCreated audit entry");
+    }
+}
+
 /// Get basket by ID
 ///
 /// GET /api/v1/baskets/{id}
@@ -269,32 +371,39 @@ pub async fn list_baskets(
 
 /// Calculate basket value
 ///
-/// GET /api/v1/baskets/{id}/value
+/// GET /api/v1/baskets/{id}/value?base=EUR
 /// CRIT-018 FIX: Requires authentication to prevent information disclosure
+///
+/// With `base`, the USD value is also converted into that currency using
+/// the oracle's USD price for it.
 #[utoipa::path(
     get,
     path = "/api/v1/baskets/{id}/value",
     tag = "baskets",
     security(("bearer_auth" = [])),
     params(
-        ("id" = Uuid, Path, description = "Basket UUID")
+        ("id" = Uuid, Path, description = "Basket UUID"),
+        BasketValueQuery
     ),
     responses(
         (status = 200, description = "Basket value calculation", body = BasketValueResponse),
+        (status = 400, description = "Unsupported base currency"),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Basket not found"),
-        (status = 503, description = "Oracle not configured")
+        (status = 503, description = "Oracle not configured, circuit breaker open, or price confidence below the basket's requirement")
     )
 )]
 pub async fn get_basket_value(
     state: web::Data<Arc<AppState>>,
     http_req: HttpRequest,
     path: web::Path<Uuid>,
+    query: web::Query<BasketValueQuery>,
 ) -> Result<HttpResponse, ApiError> {
     // CRIT-018: Verify user is authenticated before returning basket value with FX rates
     let _user_id = get_authenticated_user_id(state.db_pool.as_ref(), &http_req).await?;
 
     let basket_id = path.into_inner();
+    let base_currency = query.into_inner().base.map(|b| validate_base_currency(&b)).transpose()?;
 
     // HIGH-011: Use info level for significant API operations
     tracing::info!(id = %basket_id, "Calculating basket value");
@@ -311,32 +420,243 @@ pub async fn get_basket_value(
             }
         })?;
 
+    // CRIT-002: Fast-fail while the oracle circuit is open instead of
+    // attempting per-component fetches against a known-bad provider
+    ensure_oracle_circuit_allows(&state.oracle_circuit_breaker)?;
+
     // Get oracle
     let oracle_guard = state.oracle.read().await;
     let oracle = oracle_guard.as_ref().ok_or(ApiError::OracleNotConfigured)?;
+    let budget = RetryBudget::for_request(&http_req, state.oracle_retry_budget);
+
+    // Serve from cache until the basket definition or oracle prices change
+    let cached = state
+        .basket_value_cache
+        .get_or_try_insert_with(&basket, || oracle.price_epoch(), || async {
+            // Prefer prices already held by the oracle; refresh only if missing or stale
+            let mut prices = HashMap::new();
+            for component in &basket.components {
+                let price =
+                    current_price(&state.oracle_circuit_breaker, &budget, oracle, &component.currency_code).await?;
+                prices.insert(component.currency_code.clone(), price);
+            }
 
-    // Fetch prices for all components
-    let mut prices = HashMap::new();
-    for component in &basket.components {
-        let price = oracle.update_price(&component.currency_code).await?;
-        prices.insert(component.currency_code.clone(), price);
+            // Calculate value
+            let value_usd = basket.calculate_value(&prices)?;
+            let needs_rebalancing = basket.needs_rebalancing(&prices)?;
+
+            Ok::<_, ApiError>(CachedBasketValue {
+                value_usd,
+                prices_used: prices,
+                needs_rebalancing,
+                calculated_at: Utc::now(),
+            })
+        })
+        .await?;
+
+    // Confidence decays between refreshes, so check it on every read rather
+    // than caching it with the value
+    if basket.min_price_confidence.is_some() {
+        let mut confidences = HashMap::new();
+        for component in &basket.components {
+            if let Ok(confidence) = oracle.get_price_confidence(&component.currency_code).await {
+                confidences.insert(component.currency_code.clone(), confidence);
+            }
+        }
+        check_price_confidence(&basket, &confidences)?;
     }
 
-    // Calculate value
-    let value = basket.calculate_value(&prices)?;
-    let needs_rebalancing = basket.needs_rebalancing(&prices)?;
+    let base_rate = match &base_currency {
+        Some(base) => Some(usd_to_base_rate(&state.oracle_circuit_breaker, &budget, oracle, base).await?),
+        None => None,
+    };
+    let value_base = base_rate
+        .map(|rate| {
+            cached
+                .value_usd
+                .checked_mul(rate)
+                .ok_or_else(|| ApiError::InternalError("Overflow converting basket value".to_string()))
+        })
+        .transpose()?;
 
+    let locale = Locale::from_request(&http_req);
     let response = BasketValueResponse {
         basket_id: basket.id,
-        value_usd: value,
-        prices_used: prices,
-        needs_rebalancing,
-        calculated_at: Utc::now().to_rfc3339(),
+        value_usd: cached.value_usd,
+        value_usd_formatted: locale.map(|l| l.format_amount(cached.value_usd)),
+        locale: locale.map(|l| l.tag().to_string()),
+        prices_used: cached.prices_used,
+        needs_rebalancing: cached.needs_rebalancing,
+        calculated_at: cached.calculated_at.to_rfc3339(),
+        base_currency,
+        value_base,
+        base_rate,
     };
 
     Ok(HttpResponse::Ok().json(response))
 }
 
+/// Prefer the price already held by the oracle; refresh only if missing or stale
+async fn current_price(
+    cb: &CircuitBreaker,
+    budget: &RetryBudget,
+    oracle: &ChainlinkOracle,
+    currency_code: &str,
+) -> Result<Decimal, ApiError> {
+    match oracle.get_price(currency_code).await {
+        Ok(price) => Ok(price),
+        Err(_) => refresh_price_guarded(cb, budget, || oracle.update_price(currency_code)).await,
+    }
+}
+
+/// Normalize `?base=` and check it is USD or a platform currency
+fn validate_base_currency(base: &str) -> Result<String, ApiError> {
+    let normalized = base.trim().to_uppercase();
+    if normalized == USD || SUPPORTED_CURRENCIES.contains(&normalized.as_str()) {
+        Ok(normalized)
+    } else {
+        Err(ApiError::BadRequest(format!(
+            "Unsupported base currency: {}. Supported: {}, {}",
+            base,
+            USD,
+            SUPPORTED_CURRENCIES.join(", ")
+        )))
+    }
+}
+
+/// Units of `base` per USD, from the oracle's USD/`base` cross rate
+///
+/// The `{base}/USD` leg is refreshed first if it is missing or stale.
+async fn usd_to_base_rate(
+    cb: &CircuitBreaker,
+    budget: &RetryBudget,
+    oracle: &ChainlinkOracle,
+    base: &str,
+) -> Result<Decimal, ApiError> {
+    if base != USD {
+        current_price(cb, budget, oracle, &format!("{}/{}", base, USD)).await?;
+    }
+    Ok(oracle.get_cross_price(USD, base).await?)
+}
+
+/// Reference currency of basket valuations
+const USD: &str = "USD";
+
+/// CRIT-002: Reject oracle reads while the circuit breaker is open
+fn ensure_oracle_circuit_allows(cb: &CircuitBreaker) -> Result<(), ApiError> {
+    if cb.allow_request() {
+        Ok(())
+    } else {
+        tracing::warn!("Oracle circuit breaker OPEN - refusing basket valuation");
+        Err(ApiError::OracleUnavailable)
+    }
+}
+
+/// Refresh a price from the provider behind the oracle circuit breaker, so
+/// basket reads both respect and feed the breaker's health state
+///
+/// Attempts are drawn from the request's `budget`, so a many-component
+/// basket cannot multiply the per-call retries against a failing provider.
+async fn refresh_price_guarded<F, Fut>(
+    cb: &CircuitBreaker,
+    budget: &RetryBudget,
+    fetch: F,
+) -> Result<Decimal, ApiError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Decimal, OracleError>>,
+{
+    resilient_call_with_budget(cb, &RetryConfig::default(), budget, fetch)
+        .await
+        .map_err(|e| match e {
+            ResilientError::CircuitOpen | ResilientError::BudgetExhausted => ApiError::OracleUnavailable,
+            ResilientError::Exhausted { last_error, .. } => ApiError::OracleError(last_error),
+        })
+}
+
+/// Set the basket's required price confidence, if the client asked for one
+fn apply_min_price_confidence(
+    basket: CurrencyBasket,
+    min: Option<Decimal>,
+) -> Result<CurrencyBasket, ApiError> {
+    match min {
+        Some(min) => basket
+            .with_min_price_confidence(min)
+            .map_err(|e| ApiError::BadRequest(e.to_string())),
+        None => Ok(basket),
+    }
+}
+
+/// Reject valuation when any component is below the basket's required confidence
+fn check_price_confidence(
+    basket: &CurrencyBasket,
+    confidences: &HashMap<String, Decimal>,
+) -> Result<(), ApiError> {
+    match basket.low_confidence_component(confidences) {
+        Some((currency, confidence)) => {
+            let required = basket.min_price_confidence.unwrap_or_default();
+            tracing::warn!(
+                basket_id = %basket.id,
+                currency = %currency,
+                confidence = %confidence,
+                required = %required,
+                "Basket valuation refused: price confidence below requirement"
+            );
+            Err(ApiError::PriceConfidenceTooLow(format!(
+                "{} price confidence {} is below required {}",
+                currency,
+                confidence.round_dp(4),
+                required
+            )))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Currencies in the IMF SDR basket
+const SDR_CURRENCIES: &[&str] = &["USD", "EUR", "CNY", "JPY", "GBP"];
+
+/// Use the caller's feed address if given, otherwise the known mainnet feed
+fn resolve_feed(currency_code: &str, provided: Option<&str>) -> Result<String, ApiError> {
+    if let Some(feed) = provided.map(str::trim).filter(|f| !f.is_empty()) {
+        return Ok(feed.to_string());
+    }
+    mainnet_feeds::feed_for_currency(currency_code)
+        .map(|address| format!("{:?}", address))
+        .ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "No known Chainlink feed for {}; provide chainlink_feed",
+                currency_code.to_uppercase()
+            ))
+        })
+}
+
+/// Fill SDR feeds missing from the request from the catalog, reporting every
+/// currency that still has no feed
+fn resolve_sdr_feeds(
+    provided: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, ApiError> {
+    let mut feeds = provided.clone();
+    let mut missing = Vec::new();
+
+    for code in SDR_CURRENCIES {
+        match resolve_feed(code, provided.get(*code).map(String::as_str)) {
+            Ok(feed) => {
+                feeds.insert(code.to_string(), feed);
+            }
+            Err(_) => missing.push(*code),
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(ApiError::BadRequest(format!(
+            "No known Chainlink feed for {}; provide chainlink_feeds for these currencies",
+            missing.join(", ")
+        )));
+    }
+    Ok(feeds)
+}
+
 /// Extract authenticated user ID from request token
 /// MED-001: Helper function for authentication checks
 async fn get_authenticated_user_id(
@@ -362,6 +682,7 @@ async fn get_authenticated_user_id(
         token_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "baskets"))?;
 
@@ -373,3 +694,191 @@ async fn get_authenticated_user_id(
 
 // HIGH-003: Use centralized token hashing from auth_utils
 use super::auth_utils::hash_token_for_lookup;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn open_breaker() -> CircuitBreaker {
+        let cb = CircuitBreaker::new();
+        for _ in 0..5 {
+            cb.record_failure();
+        }
+        cb
+    }
+
+    #[test]
+    fn test_open_circuit_rejects_basket_valuation() {
+        assert!(ensure_oracle_circuit_allows(&CircuitBreaker::new()).is_ok());
+
+        let err = ensure_oracle_circuit_allows(&open_breaker()).unwrap_err();
+        assert!(matches!(err, ApiError::OracleUnavailable));
+        assert_eq!(
+            actix_web::ResponseError::status_code(&err),
+            actix_web::http::StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_open_circuit_skips_provider() {
+        let cb = open_breaker();
+        let calls = AtomicU32::new(0);
+
+        let result = refresh_price_guarded(&cb, &RetryBudget::unlimited(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Decimal::ONE)
+        })
+        .await;
+
+        assert!(matches!(result, Err(ApiError::OracleUnavailable)));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_closed_circuit_fetches_price() {
+        let cb = CircuitBreaker::new();
+        let price = refresh_price_guarded(&cb, &RetryBudget::unlimited(), || async { Ok(Decimal::new(108, 2)) })
+            .await
+            .unwrap();
+        assert_eq!(price, Decimal::new(108, 2));
+    }
+
+    #[actix_web::test]
+    async fn test_spent_budget_skips_provider() {
+        let cb = CircuitBreaker::new();
+        let budget = RetryBudget::new(0);
+        let calls = AtomicU32::new(0);
+
+        let result = refresh_price_guarded(&cb, &budget, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Decimal::ONE)
+        })
+        .await;
+
+        assert!(matches!(result, Err(ApiError::OracleUnavailable)));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    fn borderline_stale_eur_confidence() -> HashMap<String, Decimal> {
+        // EUR/USD last updated 54 of 60 minutes ago: confidence 0.1
+        let now = Utc::now();
+        let feed = meridian_oracle::PriceFeed {
+            pair: "EUR".to_string(),
+            address: mainnet_feeds::eur_usd(),
+            decimals: 8,
+            expected_decimals: None,
+            latest_price: Decimal::new(108, 2),
+            latest_round: Default::default(),
+            updated_at: now - chrono::Duration::minutes(54),
+            is_stale: false,
+            description: "EUR / USD".to_string(),
+            stale_threshold_secs: None,
+        };
+        let mut confidences = HashMap::new();
+        confidences.insert("EUR".to_string(), feed.confidence_at(now, 3600));
+        confidences
+    }
+
+    fn eur_basket(min_confidence: &str) -> CurrencyBasket {
+        let basket = CurrencyBasket::new_single_currency(
+            "EUR".to_string(),
+            "EUR".to_string(),
+            resolve_feed("EUR", None).unwrap(),
+        )
+        .unwrap();
+        apply_min_price_confidence(basket, Some(min_confidence.parse().unwrap())).unwrap()
+    }
+
+    #[test]
+    fn test_borderline_stale_price_fails_high_confidence_basket() {
+        let err = check_price_confidence(&eur_basket("0.9"), &borderline_stale_eur_confidence())
+            .unwrap_err();
+        match &err {
+            ApiError::PriceConfidenceTooLow(msg) => assert!(msg.starts_with("EUR ")),
+            other => panic!("expected PriceConfidenceTooLow, got {:?}", other),
+        }
+        assert_eq!(
+            actix_web::ResponseError::status_code(&err),
+            actix_web::http::StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+
+    #[test]
+    fn test_borderline_stale_price_passes_low_confidence_basket() {
+        assert!(check_price_confidence(&eur_basket("0.05"), &borderline_stale_eur_confidence()).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_min_confidence_is_bad_request() {
+        let basket = CurrencyBasket::new_single_currency(
+            "EUR".to_string(),
+            "EUR".to_string(),
+            resolve_feed("EUR", None).unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(
+            apply_min_price_confidence(basket, Some(Decimal::from(2))),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_feed_from_catalog() {
+        let feed = resolve_feed("eur", None).unwrap();
+        assert_eq!(feed, format!("{:?}", mainnet_feeds::eur_usd()));
+
+        // A blank address is treated as omitted
+        let feed = resolve_feed("GBP", Some("  ")).unwrap();
+        assert_eq!(feed, format!("{:?}", mainnet_feeds::gbp_usd()));
+    }
+
+    #[test]
+    fn test_resolve_feed_prefers_caller_address() {
+        let custom = "0x1a81afB8146aeFfCFc5E50e8479e826E7D55b910";
+        assert_eq!(resolve_feed("EUR", Some(custom)).unwrap(), custom);
+    }
+
+    #[test]
+    fn test_resolve_feed_unknown_currency() {
+        assert!(matches!(resolve_feed("XYZ", None), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_single_currency_basket_by_code_alone() {
+        let feed = resolve_feed("JPY", None).unwrap();
+        let basket =
+            CurrencyBasket::new_single_currency("Yen".to_string(), "JPY".to_string(), feed)
+                .unwrap();
+        assert_eq!(
+            basket.components[0].chainlink_feed,
+            format!("{:?}", mainnet_feeds::jpy_usd())
+        );
+    }
+
+    #[test]
+    fn test_resolve_sdr_feeds_reports_missing_coverage() {
+        // USD has no catalog feed, so it must be supplied
+        match resolve_sdr_feeds(&HashMap::new()) {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("USD") && !msg.contains("EUR")),
+            other => panic!("expected BadRequest, got {:?}", other),
+        }
+
+        let mut provided = HashMap::new();
+        provided.insert(
+            "USD".to_string(),
+            "0x0000000000000000000000000000000000000001".to_string(),
+        );
+        let feeds = resolve_sdr_feeds(&provided).unwrap();
+        assert_eq!(feeds.len(), SDR_CURRENCIES.len());
+        assert!(CurrencyBasket::new_imf_sdr("SDR".to_string(), feeds).is_ok());
+    }
+
+    #[test]
+    fn test_validate_base_currency() {
+        assert_eq!(validate_base_currency("eur").unwrap(), "EUR");
+        assert_eq!(validate_base_currency("USD").unwrap(), "USD");
+        assert!(matches!(validate_base_currency("XYZ"), Err(ApiError::BadRequest(_))));
+        assert!(matches!(validate_base_currency(""), Err(ApiError::BadRequest(_))));
+    }
+}
@@ -0,0 +1,129 @@
+//! Customer compliance handlers
+
+use crate::error::ApiError;
+use crate::handlers::auth_utils::authenticate_request;
+use crate::handlers::operations::build_customer_compliance;
+use crate::models::CustomerComplianceResponse;
+use crate::state::AppState;
+use actix_web::{web, HttpRequest, HttpResponse};
+use meridian_compliance::{ComplianceService, CustomerCompliance};
+use std::sync::Arc;
+
+/// Get the consolidated compliance state for a customer
+///
+/// GET /api/v1/compliance/customers/{id}
+///
+/// Customers may read their own record; COMPLIANCE (or higher) may read any.
+#[utoipa::path(
+    get,
+    path = "/api/v1/compliance/customers/{id}",
+    tag = "compliance",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Consolidated compliance state", body = CustomerComplianceResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn get_customer_compliance(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    path: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let user_id = path.into_inner();
+
+    let ctx = authenticate_request(state.db_pool.as_ref(), &req).await?;
+    if ctx.user_id != Some(user_id) && !ctx.has_role("COMPLIANCE") {
+        return Err(ApiError::Forbidden(
+            "Cannot access other user's compliance record".to_string(),
+        ));
+    }
+
+    let record = build_customer_compliance(state.db_pool.as_ref(), user_id).await?;
+    let record = resolve_jurisdiction(&state.compliance, record);
+
+    Ok(HttpResponse::Ok().json(CustomerComplianceResponse::new(user_id, record)))
+}
+
+/// Fill in the jurisdiction-derived fields the database does not store
+fn resolve_jurisdiction(
+    service: &ComplianceService,
+    mut record: CustomerCompliance,
+) -> CustomerCompliance {
+    record.frameworks = service.get_frameworks(&record.country_code);
+    record.edd_required = service.requires_edd(&record.country_code);
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use meridian_compliance::{ComplianceConfig, ComplianceStatus, RegulatoryFramework};
+    use uuid::Uuid;
+
+    fn service() -> ComplianceService {
+        ComplianceService::new(ComplianceConfig {
+            high_risk_countries: vec!["IR".to_string()],
+            ..ComplianceConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_resolves_frameworks_and_edd_from_country() {
+        let record = resolve_jurisdiction(
+            &service(),
+            CustomerCompliance::new(Uuid::new_v4(), "DE".to_string()),
+        );
+        assert_eq!(record.frameworks, vec![RegulatoryFramework::MiCA]);
+        assert!(!record.edd_required);
+
+        let record = resolve_jurisdiction(
+            &service(),
+            CustomerCompliance::new(Uuid::new_v4(), "IR".to_string()),
+        );
+        assert!(record.frameworks.is_empty());
+        assert!(record.edd_required);
+    }
+
+    #[test]
+    fn test_response_flags_match_record() {
+        let mut approved = CustomerCompliance::new(Uuid::new_v4(), "US".to_string());
+        approved.status = ComplianceStatus::Approved;
+        approved.kyc_expires_at = Some(Utc::now() + Duration::days(30));
+
+        let mut overdue = CustomerCompliance::new(Uuid::new_v4(), "GB".to_string());
+        overdue.status = ComplianceStatus::Approved;
+        overdue.kyc_expires_at = Some(Utc::now() - Duration::days(1));
+        overdue.next_review_at = Utc::now() - Duration::days(1);
+
+        for record in [approved, overdue] {
+            let record = resolve_jurisdiction(&service(), record);
+            let response = CustomerComplianceResponse::new(7, record.clone());
+            assert_eq!(response.user_id, 7);
+            assert_eq!(response.frameworks, record.frameworks);
+            assert_eq!(response.edd_required, record.edd_required);
+            assert_eq!(response.review_due, record.is_review_due());
+            assert_eq!(response.kyc_expired, record.is_kyc_expired());
+            assert_eq!(response.can_transact, record.can_transact());
+        }
+    }
+
+    #[test]
+    fn test_overdue_customer_cannot_transact() {
+        let mut record = CustomerCompliance::new(Uuid::new_v4(), "GB".to_string());
+        record.status = ComplianceStatus::Approved;
+        record.kyc_expires_at = Some(Utc::now() - Duration::days(1));
+        record.next_review_at = Utc::now() - Duration::days(1);
+
+        let response = CustomerComplianceResponse::new(1, resolve_jurisdiction(&service(), record));
+        assert!(response.review_due);
+        assert!(response.kyc_expired);
+        assert!(!response.can_transact);
+        assert_eq!(response.frameworks, vec![RegulatoryFramework::FcaUk]);
+    }
+}
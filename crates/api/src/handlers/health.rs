@@ -1,13 +1,45 @@
 //! Health check and metrics handlers
 
 use crate::error::ApiError;
-use crate::models::HealthResponse;
+use crate::models::{HealthResponse, VersionResponse};
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{web, HttpRequest, HttpResponse};
-use meridian_db::BasketRepository;
+use meridian_db::{pool_metrics, BasketRepository};
+use meridian_oracle::FeedStaleness;
 use std::sync::Arc;
 use std::time::Instant;
 
+/// Default maximum feed age in seconds before the freshness SLO is breached
+const DEFAULT_ORACLE_FEED_AGE_SLO_SECS: u64 = 3600;
+
+/// Feed age (seconds) above which the oracle freshness SLO is breached.
+/// Overridable via `ORACLE_FEED_AGE_SLO_SECS`.
+fn oracle_feed_age_slo_secs() -> u64 {
+    std::env::var("ORACLE_FEED_AGE_SLO_SECS")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_ORACLE_FEED_AGE_SLO_SECS)
+}
+
+/// Pairs whose age exceeds the SLO threshold
+fn feeds_breaching_slo(summary: &[FeedStaleness], slo_secs: u64) -> Vec<&str> {
+    summary
+        .iter()
+        .filter(|feed| feed.age_seconds > slo_secs)
+        .map(|feed| feed.pair.as_str())
+        .collect()
+}
+
+/// Current staleness summary, empty when the oracle is not configured
+async fn oracle_staleness_summary(state: &AppState) -> Vec<FeedStaleness> {
+    let oracle_guard = state.oracle.read().await;
+    match oracle_guard.as_ref() {
+        Some(oracle) => oracle.staleness_summary().await,
+        None => Vec::new(),
+    }
+}
+
 /// Health check endpoint with database verification
 ///
 /// GET /health
@@ -26,6 +58,7 @@ pub async fn health_check(state: web::Data<Arc<AppState>>) -> HttpResponse {
     // Verify database connectivity
     let db_healthy = sqlx::query("SELECT 1")
         .fetch_one(state.db_pool.as_ref())
+        .tracked()
         .await
         .is_ok();
 
@@ -41,6 +74,8 @@ pub async fn health_check(state: web::Data<Arc<AppState>>) -> HttpResponse {
         let oracle_guard = state.oracle.read().await;
         oracle_guard.is_some()
     };
+    let staleness = oracle_staleness_summary(&state).await;
+    let oracle_feeds_fresh = feeds_breaching_slo(&staleness, oracle_feed_age_slo_secs()).is_empty();
 
     let basket_repo = BasketRepository::new((*state.db_pool).clone());
     let baskets_count = basket_repo.count().await.unwrap_or(0) as usize;
@@ -51,6 +86,7 @@ pub async fn health_check(state: web::Data<Arc<AppState>>) -> HttpResponse {
         status: "ok".to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         oracle_enabled,
+        oracle_feeds_fresh,
         baskets_count,
     };
 
@@ -59,6 +95,37 @@ pub async fn health_check(state: web::Data<Arc<AppState>>) -> HttpResponse {
         .json(response)
 }
 
+/// Running build and database versions
+///
+/// GET /api/v1/health/version
+#[utoipa::path(
+    get,
+    path = "/api/v1/health/version",
+    tag = "health",
+    responses(
+        (status = 200, description = "Build and dependency versions", body = VersionResponse)
+    )
+)]
+pub async fn version_info(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let database_version: Option<String> = match sqlx::query_scalar("SELECT version()")
+        .fetch_one(state.db_pool.as_ref())
+        .tracked()
+        .await
+    {
+        Ok(version) => Some(version),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to read database version");
+            None
+        }
+    };
+
+    HttpResponse::Ok().json(VersionResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_sha: env!("MERIDIAN_GIT_SHA").to_string(),
+        database_version,
+    })
+}
+
 /// Prometheus-compatible metrics endpoint
 ///
 /// GET /metrics
@@ -82,6 +149,10 @@ pub async fn metrics(
     // BE-CRIT-006: Verify user is authenticated AND has admin role
     verify_admin(state.db_pool.as_ref(), &req).await?;
 
+    // Refresh feed ages so the registry output below is current
+    let staleness = oracle_staleness_summary(&state).await;
+    crate::metrics::record_oracle_feed_ages(&staleness);
+
     // CRIT-004: Include OpenTelemetry/Prometheus registry metrics
     use crate::telemetry;
     let mut output = telemetry::prometheus_metrics();
@@ -95,16 +166,23 @@ pub async fn metrics(
     ));
 
     // Database pool stats
-    let pool_size = state.db_pool.size();
-    let pool_idle = state.db_pool.num_idle();
+    let pool = pool_metrics(state.db_pool.as_ref());
 
     output.push_str("# HELP meridian_db_pool_size Database connection pool size\n");
     output.push_str("# TYPE meridian_db_pool_size gauge\n");
-    output.push_str(&format!("meridian_db_pool_size {}\n", pool_size));
+    output.push_str(&format!("meridian_db_pool_size {}\n", pool.size));
 
     output.push_str("# HELP meridian_db_pool_idle Idle database connections\n");
     output.push_str("# TYPE meridian_db_pool_idle gauge\n");
-    output.push_str(&format!("meridian_db_pool_idle {}\n", pool_idle));
+    output.push_str(&format!("meridian_db_pool_idle {}\n", pool.idle));
+
+    output.push_str("# HELP meridian_db_pool_in_use Database connections checked out\n");
+    output.push_str("# TYPE meridian_db_pool_in_use gauge\n");
+    output.push_str(&format!("meridian_db_pool_in_use {}\n", pool.in_use));
+
+    output.push_str("# HELP meridian_db_pool_max_connections Configured database pool limit\n");
+    output.push_str("# TYPE meridian_db_pool_max_connections gauge\n");
+    output.push_str(&format!("meridian_db_pool_max_connections {}\n", pool.max_connections));
 
     // Oracle status
     let oracle_enabled = {
@@ -115,6 +193,18 @@ pub async fn metrics(
     output.push_str("# TYPE meridian_oracle_enabled gauge\n");
     output.push_str(&format!("meridian_oracle_enabled {}\n", oracle_enabled));
 
+    // Oracle freshness SLO
+    let slo_secs = oracle_feed_age_slo_secs();
+    output.push_str("# HELP oracle_feed_age_slo_seconds Maximum feed age before the freshness SLO is breached\n");
+    output.push_str("# TYPE oracle_feed_age_slo_seconds gauge\n");
+    output.push_str(&format!("oracle_feed_age_slo_seconds {}\n", slo_secs));
+    output.push_str("# HELP oracle_feed_slo_breached Feeds currently older than the freshness SLO\n");
+    output.push_str("# TYPE oracle_feed_slo_breached gauge\n");
+    output.push_str(&format!(
+        "oracle_feed_slo_breached {}\n",
+        feeds_breaching_slo(&staleness, slo_secs).len()
+    ));
+
     // Basket count
     let basket_repo = BasketRepository::new((*state.db_pool).clone());
     let baskets_count = basket_repo.count().await.unwrap_or(0);
@@ -125,6 +215,7 @@ pub async fn metrics(
     // User count
     let user_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
         .fetch_one(state.db_pool.as_ref())
+        .tracked()
         .await
         .unwrap_or(0);
     output.push_str("# HELP meridian_users_total Total registered users\n");
@@ -136,6 +227,7 @@ pub async fn metrics(
         "SELECT operation_type, COUNT(*) as count FROM operations GROUP BY operation_type"
     )
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .unwrap_or_default();
 
@@ -180,6 +272,7 @@ async fn verify_admin(
         token_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Database error checking admin auth: {}", e);
@@ -204,3 +297,24 @@ async fn verify_admin(
 
 // HIGH-003: Use centralized token hashing from auth_utils
 use super::auth_utils::hash_token_for_lookup;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(pair: &str, age_seconds: u64) -> FeedStaleness {
+        FeedStaleness {
+            pair: pair.to_string(),
+            age_seconds,
+            is_stale: false,
+        }
+    }
+
+    #[test]
+    fn test_feeds_breaching_slo() {
+        let summary = vec![feed("EUR/USD", 7200), feed("GBP/USD", 60), feed("JPY/USD", 3600)];
+        assert_eq!(feeds_breaching_slo(&summary, 3600), vec!["EUR/USD"]);
+        assert!(feeds_breaching_slo(&summary, 7200).is_empty());
+        assert!(feeds_breaching_slo(&[], 0).is_empty());
+    }
+}
@@ -1,8 +1,10 @@
 //! KYC/AML handlers
 
 use crate::error::{ApiError, handle_db_error};
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{web, HttpRequest, HttpResponse};
+use meridian_compliance::ComplianceStatus;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
@@ -13,6 +15,31 @@ const MAX_JSON_SIZE_BYTES: usize = 100 * 1024;
 /// SECURITY: Maximum allowed depth for nested JSON objects
 const MAX_JSON_DEPTH: usize = 10;
 
+/// Most users a single bulk status update may touch
+const MAX_BULK_KYC_USERS: usize = 1000;
+
+/// Audit operation name for an admin KYC status change
+pub const KYC_STATUS_CHANGED_AUDIT_OPERATION: &str = "kyc_status_changed";
+
+/// Whether no container in `value` is nested more than `max` levels deep
+///
+/// Walks the document with an explicit stack so a hostile payload cannot
+/// exhaust the call stack here.
+fn json_depth_within(value: &JsonValue, max: usize) -> bool {
+    let mut pending = vec![(value, 0usize)];
+    while let Some((v, depth)) = pending.pop() {
+        if depth > max {
+            return false;
+        }
+        match v {
+            JsonValue::Object(map) => pending.extend(map.values().map(|child| (child, depth + 1))),
+            JsonValue::Array(arr) => pending.extend(arr.iter().map(|child| (child, depth + 1))),
+            _ => {}
+        }
+    }
+    true
+}
+
 /// Validate JSON value size and depth to prevent abuse
 fn validate_json_field(value: &JsonValue, field_name: &str) -> Result<(), ApiError> {
     // Check serialized size
@@ -28,19 +55,7 @@ fn validate_json_field(value: &JsonValue, field_name: &str) -> Result<(), ApiErr
         )));
     }
 
-    // Check depth
-    fn check_depth(v: &JsonValue, current: usize, max: usize) -> bool {
-        if current > max {
-            return false;
-        }
-        match v {
-            JsonValue::Object(map) => map.values().all(|v| check_depth(v, current + 1, max)),
-            JsonValue::Array(arr) => arr.iter().all(|v| check_depth(v, current + 1, max)),
-            _ => true,
-        }
-    }
-
-    if !check_depth(value, 0, MAX_JSON_DEPTH) {
+    if !json_depth_within(value, MAX_JSON_DEPTH) {
         return Err(ApiError::BadRequest(format!(
             "{} exceeds maximum nesting depth of {}",
             field_name, MAX_JSON_DEPTH
@@ -119,6 +134,7 @@ pub async fn submit_kyc(
         application_data
     )
     .fetch_one(&mut *tx)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create KYC application: {}", e);
@@ -127,10 +143,12 @@ pub async fn submit_kyc(
 
     // Update user KYC status within same transaction
     sqlx::query!(
-        "UPDATE users SET kyc_status = 'PENDING_REVIEW', updated_at = NOW() WHERE id = $1",
-        req.user_id
+        "UPDATE users SET kyc_status = $2, updated_at = NOW() WHERE id = $1",
+        req.user_id,
+        ComplianceStatus::Pending.to_db_str()
     )
     .execute(&mut *tx)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update user status: {}", e);
@@ -173,6 +191,7 @@ pub async fn get_kyc_status(
     // Get user's KYC status
     let user_status = sqlx::query!("SELECT kyc_status FROM users WHERE id = $1", user_id)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "kyc"))?;
 
@@ -193,6 +212,7 @@ pub async fn get_kyc_status(
         user_id
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "kyc"))?;
 
@@ -234,6 +254,7 @@ pub async fn approve_kyc(
     // Get application to find user_id
     let application = sqlx::query!("SELECT user_id FROM kyc_applications WHERE id = $1", app_id)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "kyc"))?;
 
@@ -258,6 +279,7 @@ pub async fn approve_kyc(
         app_id
     )
     .execute(&mut *tx)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update application: {}", e);
@@ -266,10 +288,12 @@ pub async fn approve_kyc(
 
     // Update user KYC status within same transaction
     sqlx::query!(
-        "UPDATE users SET kyc_status = 'APPROVED', updated_at = NOW() WHERE id = $1",
-        application.user_id
+        "UPDATE users SET kyc_status = $2, updated_at = NOW() WHERE id = $1",
+        application.user_id,
+        ComplianceStatus::Approved.to_db_str()
     )
     .execute(&mut *tx)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update user status: {}", e);
@@ -333,6 +357,7 @@ pub async fn reject_kyc(
     // Get application
     let application = sqlx::query!("SELECT user_id FROM kyc_applications WHERE id = $1", app_id)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "kyc"))?;
 
@@ -358,6 +383,7 @@ pub async fn reject_kyc(
         rejection_reason
     )
     .execute(&mut *tx)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update application: {}", e);
@@ -366,10 +392,12 @@ pub async fn reject_kyc(
 
     // Update user status within same transaction
     sqlx::query!(
-        "UPDATE users SET kyc_status = 'REJECTED', updated_at = NOW() WHERE id = $1",
-        application.user_id
+        "UPDATE users SET kyc_status = $2, updated_at = NOW() WHERE id = $1",
+        application.user_id,
+        ComplianceStatus::Rejected.to_db_str()
     )
     .execute(&mut *tx)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to update user status: {}", e);
@@ -389,6 +417,165 @@ pub async fn reject_kyc(
     })))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BulkKycStatusRequest {
+    pub user_ids: Vec<i32>,
+    /// Target `users.kyc_status` value, e.g. "REVIEW_REQUIRED"
+    pub status: String,
+    /// Recorded in each audit entry (max 500 characters)
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KycStatusChange {
+    pub user_id: i32,
+    pub previous_status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkKycStatusResponse {
+    pub status: String,
+    pub updated: Vec<KycStatusChange>,
+    /// Users already in the target status
+    pub unchanged: Vec<i32>,
+}
+
+/// POST /api/v1/admin/kyc/bulk-status
+///
+/// Moves many users to one KYC status, e.g. to `REVIEW_REQUIRED` after a
+/// jurisdiction changes its rules. All-or-nothing: unknown users or a
+/// disallowed transition for any user reject the whole request. Each change
+/// is audited in the same transaction.
+pub async fn bulk_update_kyc_status(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    body: web::Json<BulkKycStatusRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let admin = require_role(state.db_pool.as_ref(), &req, "ADMIN").await?;
+
+    let target = ComplianceStatus::from_db_str(&body.status)
+        .ok_or_else(|| ApiError::BadRequest(format!("Unknown KYC status: {}", body.status)))?;
+    if !target.is_admin_settable() {
+        return Err(ApiError::BadRequest(format!(
+            "{} is not a status admins can set",
+            target.to_db_str()
+        )));
+    }
+    if body.reason.as_ref().is_some_and(|r| r.len() > 500) {
+        return Err(ApiError::BadRequest("Reason must be 500 characters or less".to_string()));
+    }
+
+    let mut user_ids = body.user_ids.clone();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+    if user_ids.is_empty() || user_ids.len() > MAX_BULK_KYC_USERS {
+        return Err(ApiError::BadRequest(format!(
+            "user_ids must contain 1-{} users",
+            MAX_BULK_KYC_USERS
+        )));
+    }
+
+    let mut tx = state.db_pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {}", e);
+        ApiError::InternalError("Database transaction error".to_string())
+    })?;
+
+    // Lock the rows so the transitions validated below are the ones applied
+    let current = sqlx::query!(
+        "SELECT id, kyc_status FROM users WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+        &user_ids
+    )
+    .fetch_all(&mut *tx)
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "kyc"))?;
+
+    if current.len() != user_ids.len() {
+        let missing: Vec<String> = user_ids
+            .iter()
+            .filter(|id| !current.iter().any(|row| row.id == **id))
+            .map(|id| id.to_string())
+            .collect();
+        return Err(ApiError::NotFound(format!("Users not found: {}", missing.join(", "))));
+    }
+
+    let mut updated = Vec::new();
+    let mut unchanged = Vec::new();
+    let mut disallowed = Vec::new();
+    for row in current {
+        match ComplianceStatus::from_db_str(&row.kyc_status) {
+            Some(status) if status == target => unchanged.push(row.id),
+            Some(status) if status.admin_can_transition_to(&target) => updated.push(KycStatusChange {
+                user_id: row.id,
+                previous_status: row.kyc_status,
+            }),
+            _ => disallowed.push(format!("{} ({})", row.id, row.kyc_status)),
+        }
+    }
+    if !disallowed.is_empty() {
+        return Err(ApiError::BadRequest(format!(
+            "Cannot move users to {}: {}",
+            target.to_db_str(),
+            disallowed.join(", ")
+        )));
+    }
+
+    let changed_ids: Vec<i32> = updated.iter().map(|change| change.user_id).collect();
+    sqlx::query!(
+        "UPDATE users SET kyc_status = $2, updated_at = NOW() WHERE id = ANY($1)",
+        &changed_ids,
+        target.to_db_str()
+    )
+    .execute(&mut *tx)
+    .tracked()
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update user statuses: {}", e);
+        ApiError::InternalError("Failed to update user statuses".to_string())
+    })?;
+
+    let actor = admin.user_id.map(|id| id.to_string());
+    for change in &updated {
+        sqlx::query("INSERT INTO audit_logs (operation, actor, details) VALUES ($1, $2, $3)")
+            .bind(KYC_STATUS_CHANGED_AUDIT_OPERATION)
+            .bind(&actor)
+            .bind(serde_json::json!({
+                "user_id": change.user_id,
+                "previous_status": change.previous_status,
+                "new_status": target.to_db_str(),
+                "reason": body.reason,
+                "bulk": true,
+            }))
+            .execute(&mut *tx)
+            .tracked()
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to write KYC audit entry: {}", e);
+                ApiError::InternalError("Failed to write audit entry".to_string())
+            })?;
+    }
+
+    // Commit transaction - status changes and their audit entries together
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit transaction: {}", e);
+        ApiError::InternalError("Database commit error".to_string())
+    })?;
+
+    tracing::info!(
+        admin_id = ?admin.user_id,
+        status = target.to_db_str(),
+        updated = updated.len(),
+        unchanged = unchanged.len(),
+        "Bulk KYC status update"
+    );
+
+    Ok(HttpResponse::Ok().json(BulkKycStatusResponse {
+        status: target.to_db_str().to_string(),
+        updated,
+        unchanged,
+    }))
+}
+
 struct AuthenticatedUser {
     user_id: i32,
     role: String,
@@ -419,6 +606,7 @@ async fn get_authenticated_user(
         token_hash
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "kyc"))?;
 
@@ -432,7 +620,7 @@ async fn get_authenticated_user(
 }
 
 // HIGH-003: Use centralized token hashing from auth_utils
-use super::auth_utils::hash_token_for_lookup;
+use super::auth_utils::{hash_token_for_lookup, require_role};
 
 /// Verify the caller has ADMIN role
 async fn verify_admin(
@@ -461,6 +649,7 @@ async fn verify_admin(
         token_hash
     )
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "kyc"))?;
 
@@ -471,3 +660,37 @@ async fn verify_admin(
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// `{"a": {"a": ... {"a": 1}}}` with `levels` objects
+    fn nested(levels: usize) -> JsonValue {
+        (0..levels).fold(json!(1), |inner, _| json!({ "a": inner }))
+    }
+
+    #[test]
+    fn test_normal_kyc_document_accepted() {
+        let entity_info = json!({
+            "legal_name": "Acme Treasury GmbH",
+            "address": { "street": "Hauptstr. 1", "city": "Berlin", "country": "DE" },
+            "directors": [{ "name": "A. Example", "ids": [{ "type": "passport" }] }]
+        });
+        assert!(validate_json_field(&entity_info, "entity_info").is_ok());
+        assert!(validate_json_field(&nested(MAX_JSON_DEPTH), "entity_info").is_ok());
+    }
+
+    #[test]
+    fn test_deeply_nested_kyc_document_rejected() {
+        let too_deep = nested(MAX_JSON_DEPTH + 1);
+        match validate_json_field(&too_deep, "documents") {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("documents exceeds maximum nesting depth of 10")),
+            other => panic!("expected BadRequest, got {:?}", other),
+        }
+
+        // Arrays count as nesting too
+        let arrays = (0..50).fold(json!(null), |inner, _| json!([inner]));
+        assert!(!json_depth_within(&arrays, MAX_JSON_DEPTH));
+    }
+}
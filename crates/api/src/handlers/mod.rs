@@ -1,22 +1,28 @@
 //! Request handlers for API endpoints
 
+pub mod admin;
 pub mod agents;
 pub mod auth;
 pub mod auth_utils;
 pub mod baskets;
+pub mod compliance;
 pub mod health;
 pub mod kyc;
 pub mod operations;
 pub mod oracle;
 pub mod reserves;
+pub mod stablecoins;
 pub mod tenants;
 
+pub use admin::*;
 pub use agents::*;
 pub use auth::*;
 pub use baskets::*;
+pub use compliance::*;
 pub use health::*;
 pub use kyc::*;
 pub use operations::*;
 pub use oracle::*;
 pub use reserves::*;
+pub use stablecoins::*;
 pub use tenants::*;
@@ -1,41 +1,127 @@
 //! Mint/Burn operation handlers
 
-use crate::error::{ApiError, handle_db_error};
-use crate::state::AppState;
+use crate::error::{ApiError, handle_db_error, handle_tx_error, surface_tx_error};
+use crate::handlers::auth_utils::require_role;
+use crate::handlers::oracle::ORACLE_PRICE_SOURCE;
+use crate::locale::Locale;
+use crate::query_metrics::TrackQuery;
+use crate::resilience::{resilient_call_with_budget, ResilientError, RetryBudget, RetryConfig};
+use crate::state::{AppState, FeeConfig, FxSource, SystemDailyCaps};
 use actix_web::{web, HttpRequest, HttpResponse};
 use ethers::types::{Address, U256};
+use meridian_basket::currency::{currency_decimals, Money};
 use meridian_chains::execution::OnChainMintRequest;
+use meridian_chains::Chain;
 use meridian_compliance::{ComplianceStatus, CustomerCompliance};
+use meridian_db::{is_transient, with_retry, PriceHistoryRow, PriceRepository};
+use chrono::SubsecRound;
 use rust_decimal::prelude::ToPrimitive;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::time::sleep;
 use uuid::Uuid;
 
-/// CRIT-001: Retry configuration for oracle calls
-const MAX_RETRIES: u32 = 3;
-const INITIAL_BACKOFF_MS: u64 = 100;
-const MAX_BACKOFF_MS: u64 = 2000;
-
-/// CRIT-001: Generate random jitter (0.0 to 0.5) for backoff
-/// Uses simple time-based pseudo-randomness to avoid adding rand crate dependency
-fn rand_jitter() -> f64 {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let nanos = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.subsec_nanos())
-        .unwrap_or(0);
-    // Convert to 0.0-0.5 range
-    (nanos as f64 % 500.0) / 1000.0
-}
-
 /// CRIT-003: Idempotency key for preventing duplicate operations
 /// Client must provide a unique key for each distinct operation
 const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
 
+/// Longest idempotency key accepted
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
+
+/// Shortest non-UUID idempotency key accepted under strict validation
+const MIN_STRICT_IDEMPOTENCY_KEY_LEN: usize = 16;
+
+/// Distinct characters a strict non-UUID key needs, so padded keys like
+/// "0000000000000001" don't pass on length alone
+const MIN_STRICT_IDEMPOTENCY_KEY_DISTINCT_CHARS: usize = 8;
+
+/// Settlement progress of an operation, tracked separately from its
+/// processing `status`: a COMPLETED mint stays unsettled until T+1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SettlementStatus {
+    /// Settlement date not reached, or processing not yet complete
+    Unsettled,
+    /// Completed and past its settlement date
+    Settled,
+    /// Failed or cancelled; will never settle
+    Failed,
+}
+
+impl SettlementStatus {
+    /// Parses an `operations.settlement_status` value
+    pub fn from_db_str(s: &str) -> Option<Self> {
+        match s {
+            "UNSETTLED" => Some(SettlementStatus::Unsettled),
+            "SETTLED" => Some(SettlementStatus::Settled),
+            "FAILED" => Some(SettlementStatus::Failed),
+            _ => None,
+        }
+    }
+
+    /// The `operations.settlement_status` value stored for this status
+    pub fn to_db_str(&self) -> &'static str {
+        match self {
+            SettlementStatus::Unsettled => "UNSETTLED",
+            SettlementStatus::Settled => "SETTLED",
+            SettlementStatus::Failed => "FAILED",
+        }
+    }
+
+    /// Reads a stored value; anything unrecognised is reported as unsettled
+    fn from_stored(s: &str) -> Self {
+        Self::from_db_str(s).unwrap_or(SettlementStatus::Unsettled)
+    }
+}
+
+/// Operations moved by one `settle_due_operations` run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SettlementRun {
+    pub settled: u64,
+    pub failed: u64,
+}
+
+/// Advances unsettled operations as of `now`
+///
+/// COMPLETED operations whose settlement date has passed become SETTLED;
+/// FAILED and CANCELLED operations become settlement-FAILED. Anything still
+/// processing is left UNSETTLED regardless of its settlement date.
+pub async fn settle_due_operations(
+    pool: &sqlx::PgPool,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<SettlementRun, sqlx::Error> {
+    let settled = sqlx::query(
+        r#"
+        UPDATE operations
+        SET settlement_status = 'SETTLED', updated_at = NOW()
+        WHERE settlement_status = 'UNSETTLED'
+          AND status = 'COMPLETED'
+          AND settlement_date <= $1
+        "#,
+    )
+    .bind(now)
+    .execute(pool)
+    .tracked()
+    .await?
+    .rows_affected();
+
+    let failed = sqlx::query(
+        r#"
+        UPDATE operations
+        SET settlement_status = 'FAILED', updated_at = NOW()
+        WHERE settlement_status = 'UNSETTLED'
+          AND status IN ('FAILED', 'CANCELLED')
+        "#,
+    )
+    .execute(pool)
+    .tracked()
+    .await?
+    .rows_affected();
+
+    Ok(SettlementRun { settled, failed })
+}
+
 #[derive(Debug, Deserialize)]
 pub struct MintRequest {
     pub user_id: i32,
@@ -44,6 +130,9 @@ pub struct MintRequest {
     /// CRIT-003: Unique idempotency key to prevent duplicate operations
     /// Must be unique per user+operation. Recommended: UUID v4
     pub idempotency_key: Option<String>,
+    /// Chain to settle on (e.g. "base"); defaults to the stablecoin's chain,
+    /// then the environment default. Must be in `ENABLED_CHAINS`.
+    pub settlement_chain: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -51,11 +140,56 @@ pub struct MintResponse {
     pub transaction_id: i32,
     pub currency: String,
     pub amount: String,
+    pub original_amount: String,
     pub usd_value: String,
     pub bond_requirement: String,
     pub fees_charged: String,
     pub settlement_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement_chain: Option<String>,
+    pub status: String,
+    pub settlement_status: SettlementStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BurnResponse {
+    pub transaction_id: i32,
+    pub currency: String,
+    pub amount_burned: String,
+    /// Gross USD value before the redemption fee
+    pub usd_value: String,
+    pub fees_charged: String,
+    pub net_proceeds: String,
+    pub settlement_date: String,
     pub status: String,
+    pub settlement_status: SettlementStatus,
+}
+
+impl BurnResponse {
+    /// Burn rows store net proceeds in `usd_value`; the gross value is
+    /// derived from them here so fresh and replayed responses are identical
+    fn new(
+        transaction_id: i32,
+        currency: String,
+        amount_burned: String,
+        net_proceeds: Decimal,
+        fees: Decimal,
+        settlement_date: chrono::DateTime<chrono::Utc>,
+        status: String,
+    ) -> Self {
+        Self {
+            transaction_id,
+            currency,
+            amount_burned,
+            usd_value: (net_proceeds + fees).to_string(),
+            fees_charged: fees.to_string(),
+            net_proceeds: net_proceeds.to_string(),
+            settlement_date: settlement_date.to_rfc3339(),
+            status,
+            // A new burn always starts unsettled; replays override from the row
+            settlement_status: SettlementStatus::Unsettled,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -65,16 +199,25 @@ pub struct TransactionResponse {
     pub currency: String,
     pub amount: String,
     pub usd_value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_formatted: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usd_value_formatted: Option<String>,
     pub status: String,
     pub transaction_hash: Option<String>,
     pub created_at: String,
     pub settlement_date: Option<String>,
+    pub settlement_status: SettlementStatus,
 }
 
 const FEE_ISSUANCE_BPS: i64 = 25; // 25 basis points
 const FEE_REDEMPTION_BPS: i64 = 25;
 const RESERVE_BUFFER_PERCENT: i64 = 2; // 2% over-collateralization
 
+/// Extra attempts for a mint/burn transaction aborted by a serialization
+/// failure or deadlock before the request fails with 409
+const TX_MAX_RETRIES: u32 = 3;
+
 // SECURITY: Amount validation bounds
 // Max transaction: 10 billion units (prevents overflow and unrealistic requests)
 const MAX_TRANSACTION_AMOUNT: &str = "10000000000";
@@ -104,6 +247,51 @@ fn validate_amount(amount: &Decimal, context: &str) -> Result<(), ApiError> {
     Ok(())
 }
 
+/// Parse a request amount, rejecting more fractional digits than the
+/// currency's minor unit allows (e.g. sub-yen JPY amounts).
+///
+/// Trailing zeros are ignored, so "100.50" and "100.500" are both valid EUR.
+fn parse_amount(raw: &str, currency: &str) -> Result<Decimal, ApiError> {
+    let (amount, decimals) = parse_with_decimals(raw, currency)?;
+
+    if amount.normalize().scale() > decimals {
+        return Err(ApiError::BadRequest(format!(
+            "Amount {} has too many decimal places for {} (max {})",
+            raw.trim(),
+            currency.to_uppercase(),
+            decimals
+        )));
+    }
+
+    Ok(amount)
+}
+
+/// Parse a raw amount and look up the currency's minor-unit decimals
+fn parse_with_decimals(raw: &str, currency: &str) -> Result<(Decimal, u32), ApiError> {
+    let amount = Decimal::from_str(raw.trim())
+        .map_err(|_| ApiError::BadRequest("Invalid amount format".to_string()))?;
+
+    let decimals = currency_decimals(currency).ok_or_else(|| {
+        ApiError::BadRequest(format!("Unsupported currency: {}", currency))
+    })?;
+
+    Ok((amount, decimals))
+}
+
+/// Parse a mint amount and round it to exactly the currency's decimals.
+///
+/// Uses banker's rounding, matching how `Money` displays amounts. Returns
+/// `(original, rounded)`; the rounded value is what gets stored and
+/// converted to USD, so fees never see sub-minor-unit precision.
+fn parse_mint_amount(raw: &str, currency: &str) -> Result<(Decimal, Decimal), ApiError> {
+    let (original, decimals) = parse_with_decimals(raw, currency)?;
+
+    let mut rounded = original.round_dp_with_strategy(decimals, RoundingStrategy::MidpointNearestEven);
+    rounded.rescale(decimals);
+
+    Ok((original, rounded))
+}
+
 /// Validate FX rate is positive and reasonable
 fn validate_fx_rate(rate: &Decimal, currency: &str) -> Result<(), ApiError> {
     // BACKEND-CRIT-003: FX rate must be greater than zero to prevent division errors
@@ -123,12 +311,213 @@ fn validate_fx_rate(rate: &Decimal, currency: &str) -> Result<(), ApiError> {
     Ok(())
 }
 
+/// Default reserve-ratio floor in percent (100 = fully backed)
+const DEFAULT_MIN_RESERVE_RATIO: &str = "100";
+
+/// Reserve-ratio floor (percent) below which mints are refused.
+/// Overridable via `MIN_RESERVE_RATIO`, e.g. `105` for 105%.
+fn min_reserve_ratio() -> Decimal {
+    std::env::var("MIN_RESERVE_RATIO")
+        .ok()
+        .and_then(|v| Decimal::from_str(v.trim()).ok())
+        .unwrap_or_else(|| {
+            Decimal::from_str(DEFAULT_MIN_RESERVE_RATIO).expect("DEFAULT_MIN_RESERVE_RATIO is a valid constant")
+        })
+}
+
+/// Default decimal places fees are charged at (USD cents)
+const DEFAULT_FEE_CHARGE_SCALE: u32 = 2;
+
+/// Decimal places fees are charged, stored, and displayed at.
+/// Overridable via `FEE_CHARGE_SCALE`.
+fn fee_charge_scale() -> u32 {
+    std::env::var("FEE_CHARGE_SCALE")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|scale| *scale <= 18)
+        .unwrap_or(DEFAULT_FEE_CHARGE_SCALE)
+}
+
+/// Fee of `bps` basis points on `amount`, rounded half-up to `scale` places.
+///
+/// The result carries exactly `scale` decimal places, so the value stored in
+/// `fees_charged` is the same string returned to the client.
+pub(crate) fn compute_fee(amount: Decimal, bps: i64, scale: u32) -> Decimal {
+    let mut fee = (amount * Decimal::from(bps) / Decimal::from(10_000))
+        .round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero);
+    fee.rescale(scale);
+    fee
+}
+
+/// Default cap on a user's minted amount per currency over a rolling 24 hours
+const DEFAULT_DAILY_MINT_LIMIT_PER_USER: i64 = 10_000_000;
+
+/// Per-user rolling 24-hour mint limit, in units of the minted currency.
+/// Overridable via `DAILY_MINT_LIMIT_PER_USER`.
+fn daily_mint_limit() -> Decimal {
+    std::env::var("DAILY_MINT_LIMIT_PER_USER")
+        .ok()
+        .and_then(|v| Decimal::from_str(v.trim()).ok())
+        .filter(|limit| *limit > Decimal::ZERO)
+        .unwrap_or_else(|| Decimal::from(DEFAULT_DAILY_MINT_LIMIT_PER_USER))
+}
+
+/// Chain mints settle on when neither the request nor the stablecoin names one.
+/// Overridable via `DEFAULT_SETTLEMENT_CHAIN`; otherwise Ethereum in production
+/// and Sepolia everywhere else.
+fn default_settlement_chain() -> Chain {
+    std::env::var("DEFAULT_SETTLEMENT_CHAIN")
+        .ok()
+        .and_then(|v| Chain::from_str(v.trim()).ok())
+        .unwrap_or_else(|| {
+            let is_production = std::env::var("ENVIRONMENT")
+                .map(|e| e.to_lowercase() == "production")
+                .unwrap_or(false);
+            if is_production {
+                Chain::Ethereum
+            } else {
+                Chain::EthereumSepolia
+            }
+        })
+}
+
+/// Chains mints may settle on.
+/// Overridable via `ENABLED_CHAINS` (comma-separated, e.g. `ethereum,base`);
+/// defaults to just the default settlement chain. Unknown and placeholder
+/// chains are ignored.
+fn enabled_chains(default: Chain) -> Vec<Chain> {
+    let Ok(raw) = std::env::var("ENABLED_CHAINS") else {
+        return vec![default];
+    };
+    raw.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .filter_map(|name| match Chain::from_str(name) {
+            Ok(chain) if !chain.is_placeholder() => Some(chain),
+            _ => {
+                tracing::warn!(chain = name, "Ignoring unknown or unavailable chain in ENABLED_CHAINS");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Pick the chain a mint settles on and check that it is enabled.
+///
+/// The request wins, then the chain the currency's stablecoin is deployed
+/// on, then the environment default.
+fn resolve_settlement_chain(
+    requested: Option<&str>,
+    stablecoin_chain: Option<Chain>,
+    default: Chain,
+    enabled: &[Chain],
+) -> Result<Chain, ApiError> {
+    let chain = match requested.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => Chain::from_str(name).map_err(|e| ApiError::BadRequest(e.to_string()))?,
+        None => stablecoin_chain.unwrap_or(default),
+    };
+
+    if !enabled.contains(&chain) {
+        return Err(ApiError::BadRequest(format!(
+            "Settlement chain not enabled: {}. Enabled: {}",
+            chain.slug(),
+            enabled.iter().map(Chain::slug).collect::<Vec<_>>().join(", ")
+        )));
+    }
+    Ok(chain)
+}
+
+/// Reserve ratio (percent) after minting `mint_usd` backed by `bond_requirement`.
+///
+/// Supply and reserves are both in USD. With no supply the position is
+/// treated as fully backed, matching `get_reserves`.
+fn projected_reserve_ratio(
+    supply_usd: Decimal,
+    reserves_usd: Decimal,
+    mint_usd: Decimal,
+    bond_requirement: Decimal,
+) -> Decimal {
+    let supply = supply_usd + mint_usd;
+    if supply <= Decimal::ZERO {
+        return Decimal::ONE_HUNDRED;
+    }
+    (reserves_usd + bond_requirement) / supply * Decimal::ONE_HUNDRED
+}
+
+/// Peg protection: refuse a mint whose projected reserve ratio breaches the floor
+fn check_reserve_ratio(projected: Decimal, minimum: Decimal, currency: &str) -> Result<(), ApiError> {
+    if projected < minimum {
+        tracing::warn!(
+            currency = currency,
+            projected_ratio = %projected.round_dp(2),
+            min_ratio = %minimum,
+            "Mint rejected: reserve ratio would fall below floor"
+        );
+        return Err(ApiError::Conflict(format!(
+            "Mint would reduce the {} reserve ratio to {:.2}%, below the {}% minimum",
+            currency.to_uppercase(),
+            projected,
+            minimum
+        )));
+    }
+    Ok(())
+}
+
+/// Current (supply, reserves) of the active stablecoin pegged to `currency`.
+///
+/// Supply is in currency units and reserves in USD; a currency without an
+/// active stablecoin row has no position yet.
+async fn fetch_reserve_position(
+    pool: &sqlx::PgPool,
+    currency: &str,
+) -> Result<(Decimal, Decimal), ApiError> {
+    let row: Option<(Decimal, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT COALESCE(total_supply, 0), COALESCE(total_reserve_value, 0)
+        FROM stablecoins
+        WHERE peg_currency = UPPER($1) AND status = 'active'
+        ORDER BY updated_at DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(currency)
+    .fetch_optional(pool)
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "operations"))?;
+
+    Ok(row.unwrap_or((Decimal::ZERO, Decimal::ZERO)))
+}
+
+/// Chain the active stablecoin pegged to `currency` is deployed on, if any
+async fn fetch_stablecoin_chain(
+    pool: &sqlx::PgPool,
+    currency: &str,
+) -> Result<Option<Chain>, ApiError> {
+    let chain_id: Option<(i32,)> = sqlx::query_as(
+        r#"
+        SELECT chain_id
+        FROM stablecoins
+        WHERE peg_currency = UPPER($1) AND status = 'active'
+        ORDER BY updated_at DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(currency)
+    .fetch_optional(pool)
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "operations"))?;
+
+    Ok(chain_id.and_then(|(id,)| Chain::from_chain_id(id as u64).ok()))
+}
+
 /// Supported currency codes (ISO 4217)
 /// Only these currencies can be minted/burned on the platform
-const SUPPORTED_CURRENCIES: &[&str] = &["EUR", "GBP", "JPY", "MXN", "BRL", "ARS"];
+pub(crate) const SUPPORTED_CURRENCIES: &[&str] = &["EUR", "GBP", "JPY", "MXN", "BRL", "ARS"];
 
 /// Validate currency code against whitelist
-fn validate_currency(currency: &str) -> Result<(), ApiError> {
+pub(crate) fn validate_currency(currency: &str) -> Result<(), ApiError> {
     let normalized = currency.to_uppercase();
     if !SUPPORTED_CURRENCIES.contains(&normalized.as_str()) {
         return Err(ApiError::BadRequest(format!(
@@ -140,33 +529,76 @@ fn validate_currency(currency: &str) -> Result<(), ApiError> {
     Ok(())
 }
 
+/// CRIT-003: Rejects idempotency keys too weak to tell operations apart.
+///
+/// Keys are always 1-128 printable ASCII characters. Under `strict`
+/// validation a key must also be a non-nil UUID or at least 16 characters
+/// with 8 distinct ones, so keys like "1" or "test" that collide across
+/// retries of unrelated operations are refused.
+pub fn validate_idempotency_key(key: &str, strict: bool) -> Result<(), ApiError> {
+    if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+        return Err(ApiError::BadRequest(format!(
+            "Idempotency key must be 1-{} characters",
+            MAX_IDEMPOTENCY_KEY_LEN
+        )));
+    }
+    if !key.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(ApiError::BadRequest(
+            "Idempotency key must be printable ASCII without whitespace".to_string(),
+        ));
+    }
+    if !strict {
+        return Ok(());
+    }
+
+    if let Ok(uuid) = Uuid::parse_str(key) {
+        if uuid.is_nil() {
+            return Err(ApiError::BadRequest("Idempotency key cannot be the nil UUID".to_string()));
+        }
+        return Ok(());
+    }
+    let distinct = key.chars().collect::<std::collections::HashSet<_>>().len();
+    if key.len() < MIN_STRICT_IDEMPOTENCY_KEY_LEN || distinct < MIN_STRICT_IDEMPOTENCY_KEY_DISTINCT_CHARS {
+        return Err(ApiError::BadRequest(format!(
+            "Idempotency key is too weak: use a UUID or at least {} characters with {} distinct",
+            MIN_STRICT_IDEMPOTENCY_KEY_LEN, MIN_STRICT_IDEMPOTENCY_KEY_DISTINCT_CHARS
+        )));
+    }
+    Ok(())
+}
+
 /// CRIT-003: Idempotency key record for database row mapping
 #[derive(sqlx::FromRow)]
 struct IdempotencyRecord {
     id: i32,
     currency: String,
-    amount: String,
-    usd_value: String,
-    bond_requirement: Option<String>,
-    fees_charged: String,
+    amount: Decimal,
+    original_amount: Option<Decimal>,
+    usd_value: Decimal,
+    bond_requirement: Option<Decimal>,
+    fees_charged: Decimal,
     settlement_date: Option<chrono::DateTime<chrono::Utc>>,
+    settlement_chain: Option<String>,
     status: String,
+    settlement_status: String,
+    transaction_hash: Option<String>,
 }
 
 /// CRIT-003: Check for existing operation with same idempotency key
 /// Uses runtime query (query_as) to avoid compile-time DB dependency
-async fn check_idempotency(
+async fn find_idempotent_operation(
     pool: &sqlx::PgPool,
     user_id: i32,
     idempotency_key: &str,
     operation_type: &str,
-) -> Result<Option<MintResponse>, ApiError> {
+) -> Result<Option<IdempotencyRecord>, ApiError> {
     let cutoff = chrono::Utc::now() - chrono::Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS);
 
     let existing: Option<IdempotencyRecord> = sqlx::query_as(
         r#"
-        SELECT id, currency, amount, usd_value, bond_requirement, fees_charged,
-               settlement_date, status
+        SELECT id, currency, amount, original_amount, usd_value, bond_requirement,
+               fees_charged, settlement_date, settlement_chain, status, settlement_status,
+               transaction_hash
         FROM operations
         WHERE user_id = $1
           AND idempotency_key = $2
@@ -179,6 +611,7 @@ async fn check_idempotency(
     .bind(operation_type)
     .bind(cutoff)
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| {
         let err_str = e.to_string();
@@ -193,26 +626,72 @@ async fn check_idempotency(
         ApiError::InternalError("Database error".to_string())
     })?;
 
-    if let Some(op) = existing {
+    if let Some(ref op) = existing {
         tracing::info!(
             idempotency_key = idempotency_key,
             operation_id = op.id,
             "Returning cached result for idempotent request"
         );
-
-        return Ok(Some(MintResponse {
-            transaction_id: op.id,
-            currency: op.currency,
-            amount: op.amount,
-            usd_value: op.usd_value,
-            bond_requirement: op.bond_requirement.unwrap_or_default(),
-            fees_charged: op.fees_charged,
-            settlement_date: op.settlement_date.map(|d| d.to_rfc3339()).unwrap_or_default(),
-            status: op.status,
-        }));
     }
 
-    Ok(None)
+    Ok(existing)
+}
+
+/// Replays the original `MintResponse` for a repeated idempotency key
+async fn check_idempotency(
+    pool: &sqlx::PgPool,
+    user_id: i32,
+    idempotency_key: &str,
+    operation_type: &str,
+) -> Result<Option<MintResponse>, ApiError> {
+    let existing = find_idempotent_operation(pool, user_id, idempotency_key, operation_type).await?;
+
+    Ok(existing.map(|op| MintResponse {
+        transaction_id: op.id,
+        currency: op.currency,
+        original_amount: op.original_amount.unwrap_or(op.amount).to_string(),
+        amount: op.amount.to_string(),
+        usd_value: op.usd_value.to_string(),
+        bond_requirement: op.bond_requirement.map(|b| b.to_string()).unwrap_or_default(),
+        fees_charged: op.fees_charged.to_string(),
+        settlement_date: op.settlement_date.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        settlement_chain: op.settlement_chain,
+        status: op.status,
+        settlement_status: SettlementStatus::from_stored(&op.settlement_status),
+    }))
+}
+
+/// Replays the original `BurnResponse` for a repeated idempotency key,
+/// rebuilding net proceeds and fees from the stored operation row
+async fn check_burn_idempotency(
+    pool: &sqlx::PgPool,
+    user_id: i32,
+    idempotency_key: &str,
+) -> Result<Option<BurnResponse>, ApiError> {
+    let Some(op) = find_idempotent_operation(pool, user_id, idempotency_key, "BURN").await? else {
+        return Ok(None);
+    };
+
+    let settlement_date = op
+        .settlement_date
+        .ok_or_else(|| ApiError::InternalError("Stored burn has no settlement date".to_string()))?;
+    let status = match op.transaction_hash {
+        Some(_) => "SUBMITTED".to_string(),
+        None => op.status,
+    };
+
+    Ok(Some(BurnResponse {
+        settlement_status: SettlementStatus::from_stored(&op.settlement_status),
+        ..BurnResponse::new(
+            op.id,
+            op.currency,
+            op.amount.to_string(),
+            op.usd_value,
+            op.fees_charged,
+            settlement_date,
+            status,
+        )
+    }))
 }
 
 /// Row type for user compliance lookup (runtime query)
@@ -220,6 +699,8 @@ async fn check_idempotency(
 struct UserComplianceRow {
     country_code: Option<String>,
     kyc_status: String,
+    wallet_address: Option<String>,
+    legal_name: Option<String>,
 }
 
 /// Build a CustomerCompliance record from the database for a given user.
@@ -227,15 +708,25 @@ struct UserComplianceRow {
 /// ComplianceService.check_transaction(). Uses runtime query (query_as) so that
 /// this works before the compliance migration (20260101000001) has been applied —
 /// the country_code column is optional and defaults gracefully.
-async fn build_customer_compliance(
+pub(crate) async fn build_customer_compliance(
     pool: &sqlx::PgPool,
     user_id: i32,
 ) -> Result<CustomerCompliance, ApiError> {
     let user_row: Option<UserComplianceRow> = sqlx::query_as(
-        "SELECT country_code, kyc_status FROM users WHERE id = $1"
+        r#"
+        SELECT u.country_code, u.kyc_status, u.wallet_address,
+               (SELECT k.application_data->'entity_info'->>'legalName'
+                FROM kyc_applications k
+                WHERE k.user_id = u.id
+                ORDER BY k.created_at DESC
+                LIMIT 1) AS legal_name
+        FROM users u
+        WHERE u.id = $1
+        "#
     )
     .bind(user_id)
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| {
         tracing::warn!("Compliance user lookup failed: {} — using permissive defaults", e);
@@ -248,14 +739,13 @@ async fn build_customer_compliance(
     let country_code = user_row.country_code.unwrap_or_else(|| "XX".to_string());
 
     let mut record = CustomerCompliance::new(Uuid::new_v4(), country_code);
+    // Screened against the sanctions list by check_transaction
+    record.legal_name = user_row.legal_name.filter(|n| !n.trim().is_empty());
+    record.wallet_address = user_row.wallet_address.filter(|a| !a.trim().is_empty());
 
     // Mirror the KYC status into the compliance record
-    record.status = match user_row.kyc_status.as_str() {
-        "APPROVED" => ComplianceStatus::Approved,
-        "PENDING_REVIEW" | "IN_PROGRESS" => ComplianceStatus::Pending,
-        "REJECTED" => ComplianceStatus::Rejected,
-        _ => ComplianceStatus::NotStarted,
-    };
+    record.status = ComplianceStatus::from_db_str(&user_row.kyc_status)
+        .unwrap_or(ComplianceStatus::NotStarted);
 
     // Mark KYC as valid (non-expired) when approved — the API already enforces
     // kyc_status = APPROVED before we reach this point
@@ -302,11 +792,12 @@ async fn run_compliance_gate(
     // Full transaction check (limits, EDD, high-risk jurisdiction scoring)
     match state.compliance.check_transaction(&customer, amount_cents, transaction_id) {
         Ok(check) if check.approved => {
-            if !check.flags.is_empty() {
+            if !check.flags.is_empty() || check.triggers_edd {
                 tracing::info!(
                     user_id = user_id,
                     risk_score = check.risk_score,
                     flags = ?check.flags,
+                    triggers_edd = check.triggers_edd,
                     "Compliance: approved with flags — manual review queued"
                 );
                 // Persist compliance alert for review queue (runtime query —
@@ -326,6 +817,7 @@ async fn run_compliance_gate(
                 .bind(flags_json)
                 .bind(actions_json)
                 .execute(state.db_pool.as_ref())
+                .tracked()
                 .await;
             }
             Ok(())
@@ -352,6 +844,143 @@ async fn run_compliance_gate(
     }
 }
 
+/// Rejects the mint if the user's MINT operations in `currency` over the
+/// last 24 hours plus `new_amount` would exceed `daily_mint_limit()`.
+/// Failed and cancelled operations don't count towards the total.
+///
+/// Runs inside the mint's serializable transaction so two concurrent mints
+/// can't both pass against the same 24-hour total.
+async fn check_daily_mint_limit(
+    conn: &mut sqlx::PgConnection,
+    user_id: i32,
+    currency: &str,
+    new_amount: Decimal,
+) -> Result<(), ApiError> {
+    let (daily_minted,): (Decimal,) = sqlx::query_as(
+        r#"
+        SELECT COALESCE(SUM(amount), 0)
+        FROM operations
+        WHERE user_id = $1
+        AND operation_type = 'MINT'
+        AND UPPER(currency) = UPPER($2)
+        AND status NOT IN ('FAILED', 'CANCELLED')
+        AND created_at > NOW() - INTERVAL '24 hours'
+        "#,
+    )
+    .bind(user_id)
+    .bind(currency)
+    .fetch_one(&mut *conn)
+    .tracked()
+    .await
+    .map_err(|e| handle_tx_error(e, "operations"))?;
+
+    let daily_limit = daily_mint_limit();
+    if daily_minted + new_amount > daily_limit {
+        tracing::warn!(
+            user_id,
+            currency = %currency,
+            daily_minted = %daily_minted,
+            amount = %new_amount,
+            "Mint rejected: daily mint limit exceeded"
+        );
+        return Err(ApiError::Forbidden(format!(
+            "Daily mint limit exceeded: {} + {} > {}",
+            daily_minted, new_amount, daily_limit
+        )));
+    }
+    Ok(())
+}
+
+/// Credits the fee charged on an operation to the configured fee account.
+///
+/// Runs inside the transaction that inserts the operation, so an operation
+/// is never recorded without its fee or vice versa.
+async fn credit_fee(
+    conn: &mut sqlx::PgConnection,
+    fee_config: &FeeConfig,
+    operation_id: i32,
+    operation_type: &str,
+    currency: &str,
+    fee: Decimal,
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        INSERT INTO fee_ledger (operation_id, fee_account, operation_type, currency, amount)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(operation_id)
+    .bind(&fee_config.account)
+    .bind(operation_type)
+    .bind(currency.to_uppercase())
+    .bind(fee)
+    .execute(conn)
+    .tracked()
+    .await
+    .map_err(|e| handle_tx_error(e, "fee_ledger"))?;
+    Ok(())
+}
+
+/// Rejects with 429 if `amount` would take today's (UTC) system-wide total
+/// of `operation_type` in `currency` past its configured cap.
+///
+/// Must run inside the transaction that inserts the operation: the advisory
+/// lock serializes same-currency operations until that transaction ends.
+/// Failed and cancelled operations don't count towards the total.
+async fn enforce_system_daily_cap(
+    conn: &mut sqlx::PgConnection,
+    caps: &SystemDailyCaps,
+    operation_type: &str,
+    currency: &str,
+    amount: Decimal,
+) -> Result<(), ApiError> {
+    let Some(cap) = caps.cap_for(operation_type, currency) else {
+        return Ok(());
+    };
+    let currency = currency.to_uppercase();
+
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(format!("daily_cap:{}:{}", operation_type, currency))
+        .execute(&mut *conn)
+        .tracked()
+        .await
+        .map_err(|e| handle_tx_error(e, "operations"))?;
+
+    let (today,): (Decimal,) = sqlx::query_as(
+        r#"
+        SELECT COALESCE(SUM(amount), 0)
+        FROM operations
+        WHERE operation_type = $1
+        AND UPPER(currency) = $2
+        AND status NOT IN ('FAILED', 'CANCELLED')
+        AND created_at >= date_trunc('day', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'
+        "#,
+    )
+    .bind(operation_type)
+    .bind(&currency)
+    .fetch_one(&mut *conn)
+    .tracked()
+    .await
+    .map_err(|e| handle_tx_error(e, "operations"))?;
+
+    if today + amount > cap {
+        tracing::warn!(
+            operation_type,
+            currency = %currency,
+            today = %today,
+            amount = %amount,
+            cap = %cap,
+            "System daily cap reached"
+        );
+        return Err(ApiError::LimitExceeded(format!(
+            "System daily {} cap for {} reached; try again tomorrow",
+            operation_type.to_lowercase(),
+            currency
+        )));
+    }
+    Ok(())
+}
+
 /// POST /api/v1/operations/mint
 pub async fn mint(
     state: web::Data<Arc<AppState>>,
@@ -371,6 +1000,7 @@ pub async fn mint(
 
     // CRIT-003: Check idempotency key if provided
     if let Some(ref idem_key) = req.idempotency_key {
+        validate_idempotency_key(idem_key, state.strict_idempotency_keys)?;
         if let Some(cached_response) = check_idempotency(
             state.db_pool.as_ref(),
             req.user_id,
@@ -384,16 +1014,26 @@ pub async fn mint(
     // Validate currency is on the supported whitelist
     validate_currency(&req.currency)?;
 
+    let default_chain = default_settlement_chain();
+    let settlement_chain = resolve_settlement_chain(
+        req.settlement_chain.as_deref(),
+        fetch_stablecoin_chain(state.db_pool.as_ref(), &req.currency).await?,
+        default_chain,
+        &enabled_chains(default_chain),
+    )?;
+
     tracing::info!(
         user_id = req.user_id,
         currency = %req.currency,
         amount = %req.amount,
+        settlement_chain = settlement_chain.slug(),
         "Mint request received"
     );
 
     // Verify user is KYC approved
     let user = sqlx::query!("SELECT kyc_status FROM users WHERE id = $1", req.user_id)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "operations"))?;
 
@@ -402,15 +1042,24 @@ pub async fn mint(
         None => return Err(ApiError::NotFound("User not found".to_string())),
     };
 
-    if user.kyc_status != "APPROVED" {
+    if ComplianceStatus::from_db_str(&user.kyc_status) != Some(ComplianceStatus::Approved) {
         return Err(ApiError::Forbidden(
             "KYC approval required for mint operations".to_string(),
         ));
     }
 
-    // Parse amount early so we can pass cents to compliance gate
-    let amount_decimal = Decimal::from_str(&req.amount)
-        .map_err(|_| ApiError::BadRequest("Invalid amount format".to_string()))?;
+    // Parse amount early so we can pass cents to compliance gate.
+    // Everything downstream (USD conversion, fees, storage) uses the rounded value.
+    let (original_amount, amount_decimal) =
+        parse_mint_amount(&req.amount, &req.currency)?;
+    if amount_decimal != original_amount {
+        tracing::info!(
+            original = %original_amount,
+            rounded = %amount_decimal,
+            currency = %req.currency,
+            "Mint amount rounded to currency precision"
+        );
+    }
 
     // BACKEND-CRIT-001: Validate amount is positive and within bounds
     validate_amount(&amount_decimal, "mint")?;
@@ -424,7 +1073,8 @@ pub async fn mint(
     run_compliance_gate(&state, req.user_id, amount_cents, tx_id, "MINT").await?;
 
     // Get FX rate (from oracle or fallback)
-    let fx_rate = get_fx_rate(&state, &req.currency).await?;
+    let budget = RetryBudget::for_request(&http_req, state.oracle_retry_budget);
+    let fx_rate = get_fx_rate(&state, &budget, &req.currency).await?;
 
     // BACKEND-CRIT-003: Validate FX rate before division
     validate_fx_rate(&fx_rate, &req.currency)?;
@@ -432,9 +1082,18 @@ pub async fn mint(
     let usd_value = amount_decimal / fx_rate;
 
     // Calculate fees and requirements
-    let fees = (usd_value * Decimal::from(FEE_ISSUANCE_BPS)) / Decimal::from(10000);
+    let fees = compute_fee(usd_value, FEE_ISSUANCE_BPS, fee_charge_scale());
     let bond_requirement = usd_value * (Decimal::from(100 + RESERVE_BUFFER_PERCENT)) / Decimal::from(100);
 
+    // Peg protection: project the post-mint reserve ratio against the floor
+    let (supply, reserves_usd) = fetch_reserve_position(state.db_pool.as_ref(), &req.currency).await?;
+    let projected_ratio = projected_reserve_ratio(supply / fx_rate, reserves_usd, usd_value, bond_requirement);
+    check_reserve_ratio(projected_ratio, min_reserve_ratio(), &req.currency)?;
+
+    // Four-eyes control: large mints wait for a second approver before execution
+    let approval_threshold = state.mint_approval_threshold_usd;
+    let needs_approval = usd_value > approval_threshold;
+
     // Calculate settlement date (T+1)
     let settlement_date = chrono::Utc::now() + chrono::Duration::days(1);
 
@@ -445,100 +1104,256 @@ pub async fn mint(
         status: String,
     }
 
-    let operation: InsertResult = sqlx::query_as(
-        r#"
-        INSERT INTO operations (
-            user_id, operation_type, currency, amount, usd_value,
-            bond_requirement, fees_charged, status, settlement_date, idempotency_key
-        )
-        VALUES ($1, 'MINT', $2, $3, $4, $5, $6, 'PENDING', $7, $8)
-        RETURNING id, status
-        "#
-    )
-    .bind(req.user_id)
-    .bind(&req.currency)
-    .bind(&req.amount)
-    .bind(usd_value.to_string())
-    .bind(bond_requirement.to_string())
-    .bind(fees.to_string())
-    .bind(settlement_date)
-    .bind(&req.idempotency_key)
-    .fetch_one(state.db_pool.as_ref())
+    // The per-user and system cap checks, the insert and the fee credit
+    // share a serializable transaction so concurrent mints can't both pass
+    // against the same daily total; conflicts are retried transparently
+    let tx_state = Arc::clone(state.get_ref());
+    let operation: InsertResult = with_retry(state.db_pool.as_ref(), TX_MAX_RETRIES, |conn| {
+        let state = Arc::clone(&tx_state);
+        let user_id = req.user_id;
+        let currency = req.currency.clone();
+        let idempotency_key = req.idempotency_key.clone();
+        Box::pin(async move {
+            check_daily_mint_limit(&mut *conn, user_id, &currency, amount_decimal).await?;
+            enforce_system_daily_cap(&mut *conn, &state.system_daily_caps, "MINT", &currency, amount_decimal).await?;
+
+            let operation: InsertResult = sqlx::query_as(
+                r#"
+                INSERT INTO operations (
+                    user_id, operation_type, currency, amount, original_amount, usd_value,
+                    bond_requirement, fees_charged, status, settlement_date, idempotency_key,
+                    settlement_chain
+                )
+                VALUES ($1, 'MINT', $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                RETURNING id, status
+                "#
+            )
+            .bind(user_id)
+            .bind(&currency)
+            .bind(amount_decimal)
+            .bind(original_amount)
+            .bind(usd_value)
+            .bind(bond_requirement)
+            .bind(fees)
+            .bind(if needs_approval { "AWAITING_APPROVAL" } else { "PENDING" })
+            .bind(settlement_date)
+            .bind(&idempotency_key)
+            .bind(settlement_chain.slug())
+            .fetch_one(&mut *conn)
+            .tracked()
+            .await
+            .map_err(|e| {
+                if is_transient(&e) {
+                    return handle_tx_error(e, "operations");
+                }
+                let err_str = e.to_string();
+                // Graceful degradation if migration not applied
+                if err_str.contains("idempotency_key") {
+                    tracing::warn!("Insert failed due to missing column - migration 20251230000001 required");
+                }
+                tracing::error!("Failed to create mint operation: {}", e);
+                ApiError::InternalError("Failed to create mint operation".to_string())
+            })?;
+            credit_fee(&mut *conn, &state.fee_config, operation.id, "MINT", &currency, fees).await?;
+            Ok(operation)
+        })
+    })
     .await
-    .map_err(|e| {
-        let err_str = e.to_string();
-        // Graceful degradation if migration not applied
-        if err_str.contains("idempotency_key") {
-            tracing::warn!("Insert failed due to missing column - migration 20251230000001 required");
-        }
-        tracing::error!("Failed to create mint operation: {}", e);
-        ApiError::InternalError("Failed to create mint operation".to_string())
-    })?;
+    .map_err(|e| surface_tx_error(e, "operations"))?;
 
     tracing::info!(
         transaction_id = operation.id,
-        usd_value = %usd_value,
+        amount = %Money::new(amount_decimal, &req.currency),
+        usd_value = %Money::new(usd_value, "USD"),
         "Mint operation created"
     );
 
-    // Wire to on-chain executor if configured
-    let mut tx_hash: Option<String> = None;
-    if let Some(ref executor) = state.evm_executor {
-        // recipient defaults to signer address; real impl would use wallet_address from user record
-        let recipient = executor
-            .get_nonce(Address::zero())
-            .await
-            .ok()
-            .map(|_| Address::zero())
-            .unwrap_or(Address::zero());
-
-        // amount in 6-decimal units (like USDC)
-        let amount_units = (amount_decimal * Decimal::from(1_000_000))
-            .to_u128()
-            .unwrap_or(0);
-        let reserve_units = (bond_requirement * Decimal::from(100))
-            .to_u128()
-            .unwrap_or(0);
-        let deadline = (chrono::Utc::now() + chrono::Duration::minutes(30)).timestamp() as u128;
-
-        let mint_req = OnChainMintRequest {
-            recipient,
-            amount: U256::from(amount_units),
-            reserve_value: U256::from(reserve_units),
-            deadline: U256::from(deadline),
-        };
+    if needs_approval {
+        tracing::info!(
+            transaction_id = operation.id,
+            usd_value = %Money::new(usd_value, "USD"),
+            threshold = %Money::new(approval_threshold, "USD"),
+            "Mint held for second approval"
+        );
+    }
 
-        match executor.mint_on_chain(mint_req).await {
-            Ok(submitted) => {
-                let hash = format!("{:?}", submitted.tx_hash);
-                tracing::info!(tx_hash = %hash, "Mint submitted on-chain");
-                // Update operation row with tx_hash (best-effort)
-                let _ = sqlx::query(
-                    "UPDATE operations SET transaction_hash = $1 WHERE id = $2"
-                )
-                .bind(&hash)
-                .bind(operation.id)
-                .execute(state.db_pool.as_ref())
-                .await;
-                tx_hash = Some(hash);
-            }
-            Err(e) => {
-                tracing::warn!(error = %e, "On-chain mint submission failed — operation remains PENDING");
-            }
-        }
+    // Wire to on-chain executor if configured (approved later for large mints)
+    let tx_hash = if needs_approval {
+        None
     } else {
-        tracing::debug!("EVM executor not configured — mint stays PENDING until executor is wired");
-    }
+        submit_mint_on_chain(&state, operation.id, amount_decimal, bond_requirement).await
+    };
 
     Ok(HttpResponse::Created().json(MintResponse {
         transaction_id: operation.id,
         currency: req.currency.clone(),
-        amount: req.amount.clone(),
+        amount: amount_decimal.to_string(),
+        original_amount: req.amount.trim().to_string(),
         usd_value: usd_value.to_string(),
         bond_requirement: bond_requirement.to_string(),
         fees_charged: fees.to_string(),
         settlement_date: settlement_date.to_rfc3339(),
+        settlement_chain: Some(settlement_chain.slug().to_string()),
         status: tx_hash.map(|_| "SUBMITTED".to_string()).unwrap_or(operation.status),
+        settlement_status: SettlementStatus::Unsettled,
+    }))
+}
+
+/// Submit an approved mint to the on-chain executor, if configured.
+///
+/// Best-effort: on failure the operation stays PENDING and the hash is None.
+async fn submit_mint_on_chain(
+    state: &Arc<AppState>,
+    operation_id: i32,
+    amount_decimal: Decimal,
+    bond_requirement: Decimal,
+) -> Option<String> {
+    let Some(ref executor) = state.evm_executor else {
+        tracing::debug!("EVM executor not configured — mint stays PENDING until executor is wired");
+        return None;
+    };
+
+    // recipient defaults to signer address; real impl would use wallet_address from user record
+    let recipient = executor
+        .get_nonce(Address::zero())
+        .await
+        .ok()
+        .map(|_| Address::zero())
+        .unwrap_or(Address::zero());
+
+    // amount in 6-decimal units (like USDC)
+    let amount_units = (amount_decimal * Decimal::from(1_000_000))
+        .to_u128()
+        .unwrap_or(0);
+    let reserve_units = (bond_requirement * Decimal::from(100))
+        .to_u128()
+        .unwrap_or(0);
+    let deadline = (chrono::Utc::now() + chrono::Duration::minutes(30)).timestamp() as u128;
+
+    let mint_req = OnChainMintRequest {
+        recipient,
+        amount: U256::from(amount_units),
+        reserve_value: U256::from(reserve_units),
+        deadline: U256::from(deadline),
+    };
+
+    match executor.mint_on_chain(mint_req).await {
+        Ok(submitted) => {
+            let hash = format!("{:?}", submitted.tx_hash);
+            tracing::info!(tx_hash = %hash, "Mint submitted on-chain");
+            // Update operation row with tx_hash (best-effort)
+            let _ = sqlx::query(
+                "UPDATE operations SET transaction_hash = $1 WHERE id = $2"
+            )
+            .bind(&hash)
+            .bind(operation_id)
+            .execute(state.db_pool.as_ref())
+            .tracked()
+            .await;
+            Some(hash)
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "On-chain mint submission failed — operation remains PENDING");
+            None
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApproveMintResponse {
+    pub transaction_id: i32,
+    pub status: String,
+    pub approved_by: i32,
+    pub approved_at: String,
+    pub transaction_hash: Option<String>,
+}
+
+/// POST /api/v1/operations/{id}/approve
+///
+/// Second approval for a mint held in AWAITING_APPROVAL. Requires TREASURY
+/// (or higher) on a user session; the initiator cannot approve their own mint.
+pub async fn approve_mint(
+    state: web::Data<Arc<AppState>>,
+    http_req: HttpRequest,
+    path: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let operation_id = path.into_inner();
+    let ctx = require_role(state.db_pool.as_ref(), &http_req, "TREASURY").await?;
+    let approver_id = ctx.user_id.ok_or_else(|| {
+        ApiError::Forbidden("Mint approval requires a user session".to_string())
+    })?;
+
+    #[derive(sqlx::FromRow)]
+    struct PendingMint {
+        user_id: i32,
+        operation_type: String,
+        status: String,
+        amount: Decimal,
+        bond_requirement: Option<Decimal>,
+    }
+
+    let op: PendingMint = sqlx::query_as(
+        "SELECT user_id, operation_type, status, amount, bond_requirement FROM operations WHERE id = $1",
+    )
+    .bind(operation_id)
+    .fetch_optional(state.db_pool.as_ref())
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "operations"))?
+    .ok_or_else(|| ApiError::NotFound(format!("Operation {} not found", operation_id)))?;
+
+    if op.operation_type != "MINT" || op.status != "AWAITING_APPROVAL" {
+        return Err(ApiError::Conflict(format!(
+            "Operation {} is not awaiting approval (status: {})",
+            operation_id, op.status
+        )));
+    }
+
+    if op.user_id == approver_id {
+        tracing::warn!(
+            operation_id,
+            user_id = approver_id,
+            "Mint approval rejected: initiator cannot self-approve"
+        );
+        return Err(ApiError::Forbidden("Initiator cannot approve their own mint".to_string()));
+    }
+
+    // Guard on status and initiator again so concurrent approvals can't double-fire
+    let approved_at: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+        r#"
+        UPDATE operations
+        SET status = 'PENDING', approved_by = $1, approved_at = NOW(), updated_at = NOW()
+        WHERE id = $2 AND status = 'AWAITING_APPROVAL' AND user_id <> $1
+        RETURNING approved_at
+        "#,
+    )
+    .bind(approver_id)
+    .bind(operation_id)
+    .fetch_optional(state.db_pool.as_ref())
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "operations"))?;
+
+    let approved_at = approved_at.ok_or_else(|| {
+        ApiError::Conflict(format!("Operation {} is no longer awaiting approval", operation_id))
+    })?;
+
+    tracing::info!(
+        operation_id,
+        initiator = op.user_id,
+        approved_by = approver_id,
+        "Mint approved"
+    );
+
+    let bond_requirement = op.bond_requirement.unwrap_or(Decimal::ZERO);
+    let tx_hash = submit_mint_on_chain(&state, operation_id, op.amount, bond_requirement).await;
+
+    Ok(HttpResponse::Ok().json(ApproveMintResponse {
+        transaction_id: operation_id,
+        status: tx_hash.as_ref().map(|_| "SUBMITTED").unwrap_or("PENDING").to_string(),
+        approved_by: approver_id,
+        approved_at: approved_at.to_rfc3339(),
+        transaction_hash: tx_hash,
     }))
 }
 
@@ -561,12 +1376,10 @@ pub async fn burn(
 
     // CRIT-003: Check idempotency key if provided
     if let Some(ref idem_key) = req.idempotency_key {
-        if let Some(cached_response) = check_idempotency(
-            state.db_pool.as_ref(),
-            req.user_id,
-            idem_key,
-            "BURN",
-        ).await? {
+        validate_idempotency_key(idem_key, state.strict_idempotency_keys)?;
+        if let Some(cached_response) =
+            check_burn_idempotency(state.db_pool.as_ref(), req.user_id, idem_key).await?
+        {
             return Ok(HttpResponse::Ok().json(cached_response));
         }
     }
@@ -584,6 +1397,7 @@ pub async fn burn(
     // Verify KYC
     let user = sqlx::query!("SELECT kyc_status FROM users WHERE id = $1", req.user_id)
         .fetch_optional(state.db_pool.as_ref())
+        .tracked()
         .await
         .map_err(|e| handle_db_error(e, "operations"))?;
 
@@ -592,15 +1406,14 @@ pub async fn burn(
         None => return Err(ApiError::NotFound("User not found".to_string())),
     };
 
-    if user.kyc_status != "APPROVED" {
+    if ComplianceStatus::from_db_str(&user.kyc_status) != Some(ComplianceStatus::Approved) {
         return Err(ApiError::Forbidden(
             "KYC approval required for burn operations".to_string(),
         ));
     }
 
     // Parse amount early so we can pass cents to compliance gate
-    let amount_decimal = Decimal::from_str(&req.amount)
-        .map_err(|_| ApiError::BadRequest("Invalid amount format".to_string()))?;
+    let amount_decimal = parse_amount(&req.amount, &req.currency)?;
 
     // BACKEND-CRIT-001: Validate amount is positive and within bounds
     validate_amount(&amount_decimal, "burn")?;
@@ -613,7 +1426,8 @@ pub async fn burn(
     run_compliance_gate(&state, req.user_id, amount_cents, tx_id, "BURN").await?;
 
     // Get FX rate
-    let fx_rate = get_fx_rate(&state, &req.currency).await?;
+    let budget = RetryBudget::for_request(&http_req, state.oracle_retry_budget);
+    let fx_rate = get_fx_rate(&state, &budget, &req.currency).await?;
 
     // BACKEND-CRIT-003: Validate FX rate before division
     validate_fx_rate(&fx_rate, &req.currency)?;
@@ -621,50 +1435,72 @@ pub async fn burn(
     let usd_value = amount_decimal / fx_rate;
 
     // Calculate redemption fee
-    let fees = (usd_value * Decimal::from(FEE_REDEMPTION_BPS)) / Decimal::from(10000);
+    let fees = compute_fee(usd_value, FEE_REDEMPTION_BPS, fee_charge_scale());
     let net_proceeds = usd_value - fees;
 
-    // Settlement date
-    let settlement_date = chrono::Utc::now() + chrono::Duration::days(2); // T+2 for bond sales
+    // Settlement date, at the database's microsecond precision so idempotent
+    // replays render it identically
+    let settlement_date = (chrono::Utc::now() + chrono::Duration::days(2)).trunc_subsecs(6); // T+2 for bond sales
 
     // CRIT-003: Insert burn operation with idempotency key using runtime query
     #[derive(sqlx::FromRow)]
     struct BurnResult {
         id: i32,
+        currency: String,
         status: String,
     }
 
-    let operation: BurnResult = sqlx::query_as(
-        r#"
-        INSERT INTO operations (
-            user_id, operation_type, currency, amount, usd_value,
-            fees_charged, status, settlement_date, idempotency_key
-        )
-        VALUES ($1, 'BURN', $2, $3, $4, $5, 'PENDING', $6, $7)
-        RETURNING id, status
-        "#
-    )
-    .bind(req.user_id)
-    .bind(&req.currency)
-    .bind(&req.amount)
-    .bind(net_proceeds.to_string())
-    .bind(fees.to_string())
-    .bind(settlement_date)
-    .bind(&req.idempotency_key)
-    .fetch_one(state.db_pool.as_ref())
+    let tx_state = Arc::clone(state.get_ref());
+    let operation: BurnResult = with_retry(state.db_pool.as_ref(), TX_MAX_RETRIES, |conn| {
+        let state = Arc::clone(&tx_state);
+        let user_id = req.user_id;
+        let currency = req.currency.clone();
+        let idempotency_key = req.idempotency_key.clone();
+        Box::pin(async move {
+            enforce_system_daily_cap(&mut *conn, &state.system_daily_caps, "BURN", &currency, amount_decimal).await?;
+
+            let operation: BurnResult = sqlx::query_as(
+                r#"
+                INSERT INTO operations (
+                    user_id, operation_type, currency, amount, usd_value,
+                    fees_charged, status, settlement_date, idempotency_key
+                )
+                VALUES ($1, 'BURN', $2, $3, $4, $5, 'PENDING', $6, $7)
+                RETURNING id, currency, status
+                "#
+            )
+            .bind(user_id)
+            .bind(&currency)
+            .bind(amount_decimal)
+            .bind(net_proceeds)
+            .bind(fees)
+            .bind(settlement_date)
+            .bind(&idempotency_key)
+            .fetch_one(&mut *conn)
+            .tracked()
+            .await
+            .map_err(|e| {
+                if is_transient(&e) {
+                    return handle_tx_error(e, "operations");
+                }
+                let err_str = e.to_string();
+                if err_str.contains("idempotency_key") {
+                    tracing::warn!("Insert failed due to missing column - migration 20251230000001 required");
+                }
+                tracing::error!("Failed to create burn operation: {}", e);
+                ApiError::InternalError("Failed to create burn operation".to_string())
+            })?;
+            credit_fee(&mut *conn, &state.fee_config, operation.id, "BURN", &currency, fees).await?;
+            Ok(operation)
+        })
+    })
     .await
-    .map_err(|e| {
-        let err_str = e.to_string();
-        if err_str.contains("idempotency_key") {
-            tracing::warn!("Insert failed due to missing column - migration 20251230000001 required");
-        }
-        tracing::error!("Failed to create burn operation: {}", e);
-        ApiError::InternalError("Failed to create burn operation".to_string())
-    })?;
+    .map_err(|e| surface_tx_error(e, "operations"))?;
 
     tracing::info!(
         transaction_id = operation.id,
-        net_proceeds = %net_proceeds,
+        amount = %Money::new(amount_decimal, &req.currency),
+        net_proceeds = %Money::new(net_proceeds, "USD"),
         "Burn operation created"
     );
 
@@ -685,6 +1521,7 @@ pub async fn burn(
                 .bind(&hash)
                 .bind(operation.id)
                 .execute(state.db_pool.as_ref())
+                .tracked()
                 .await;
                 burn_tx_hash = Some(hash);
             }
@@ -695,16 +1532,15 @@ pub async fn burn(
     }
 
     let status = burn_tx_hash.map(|_| "SUBMITTED".to_string()).unwrap_or(operation.status);
-    Ok(HttpResponse::Created().json(serde_json::json!({
-        "transaction_id": operation.id,
-        "currency": req.currency,
-        "amount_burned": req.amount,
-        "usd_value": usd_value.to_string(),
-        "fees_charged": fees.to_string(),
-        "net_proceeds": net_proceeds.to_string(),
-        "settlement_date": settlement_date.to_rfc3339(),
-        "status": status
-    })))
+    Ok(HttpResponse::Created().json(BurnResponse::new(
+        operation.id,
+        operation.currency,
+        amount_decimal.to_string(),
+        net_proceeds,
+        fees,
+        settlement_date,
+        status,
+    )))
 }
 
 /// GET /api/v1/operations/transactions/{user_id}
@@ -724,7 +1560,7 @@ pub async fn get_transactions(
     let transactions = sqlx::query!(
         r#"
         SELECT id, operation_type, currency, amount, usd_value, status, 
-               transaction_hash, created_at, settlement_date
+               transaction_hash, created_at, settlement_date, settlement_status
         FROM operations
         WHERE user_id = $1
         ORDER BY created_at DESC
@@ -733,126 +1569,175 @@ pub async fn get_transactions(
         user_id
     )
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "operations"))?;
 
+    let locale = Locale::from_request(&req);
     let responses: Vec<TransactionResponse> = transactions
         .into_iter()
         .map(|tx| TransactionResponse {
             id: tx.id,
             operation_type: tx.operation_type,
             currency: tx.currency,
-            amount: tx.amount,
-            usd_value: tx.usd_value,
+            amount_formatted: locale.map(|l| l.format_amount(tx.amount)),
+            usd_value_formatted: locale.map(|l| l.format_amount(tx.usd_value)),
+            amount: tx.amount.to_string(),
+            usd_value: tx.usd_value.to_string(),
             status: tx.status,
             transaction_hash: tx.transaction_hash,
             created_at: tx.created_at.to_rfc3339(),
             settlement_date: tx.settlement_date.map(|dt| dt.to_rfc3339()),
+            settlement_status: SettlementStatus::from_stored(&tx.settlement_status),
         })
         .collect();
 
-    Ok(HttpResponse::Ok().json(serde_json::json!({
+    let mut body = serde_json::json!({
         "transactions": responses,
         "count": responses.len()
-    })))
+    });
+    if let Some(locale) = locale {
+        body["locale"] = serde_json::Value::from(locale.tag());
+    }
+
+    Ok(HttpResponse::Ok().json(body))
 }
 
 /// CRIT-001 + CRIT-002: Get FX rate with circuit breaker and exponential backoff retry
 /// Uses circuit breaker to fast-fail when oracle is unavailable
-/// Retries oracle calls before falling back to static rates
+/// Retries oracle calls, within the request's retry budget, before falling
+/// back to static rates
 async fn get_fx_rate(
     state: &Arc<AppState>,
+    budget: &RetryBudget,
     currency: &str,
 ) -> Result<Decimal, ApiError> {
-    use crate::state::CircuitState;
-
     let pair = format!("{}/USD", currency);
+    let pair = pair.as_str();
 
-    // CRIT-002: Check circuit breaker first
-    let circuit_state = state.oracle_circuit_breaker.state();
-    if circuit_state == CircuitState::Open {
-        tracing::warn!(
-            pair = %pair,
-            "Circuit breaker OPEN - skipping oracle, using fallback rates"
-        );
-        // Fast-fail to fallback - don't even try oracle
-        return get_fallback_rate(currency);
+    first_available_rate(&state.fx_sources, move |source| {
+        fetch_fx_rate(state, budget, source, currency, pair)
+    })
+    .await
+}
+
+/// Tries each source in order and returns the first rate that resolves.
+///
+/// When every source fails the last error is returned, so the default
+/// oracle-then-static order still surfaces the static fallback's error.
+async fn first_available_rate<F, Fut>(sources: &[FxSource], mut fetch: F) -> Result<Decimal, ApiError>
+where
+    F: FnMut(FxSource) -> Fut,
+    Fut: std::future::Future<Output = Result<Decimal, ApiError>>,
+{
+    let mut last_error = None;
+    for &source in sources {
+        match fetch(source).await {
+            Ok(rate) => {
+                tracing::debug!(source = ?source, rate = %rate, "FX rate resolved");
+                return Ok(rate);
+            }
+            Err(e) => {
+                tracing::warn!(source = ?source, error = %e, "FX rate source unavailable, trying next");
+                last_error = Some(e);
+            }
+        }
     }
 
-    // 1. Try to get authentic price from Oracle with retry logic
-    let oracle_guard = state.oracle.read().await;
+    Err(last_error.unwrap_or_else(|| {
+        ApiError::InternalError("No FX rate sources configured".to_string())
+    }))
+}
 
-    if let Some(oracle) = oracle_guard.as_ref() {
-        let mut last_error: Option<String> = None;
-
-        for attempt in 0..MAX_RETRIES {
-            match oracle.get_price(&pair).await {
-                Ok(price) => {
-                    // CRIT-002: Record success for circuit breaker
-                    state.oracle_circuit_breaker.record_success();
-
-                    if attempt > 0 {
-                        tracing::info!(
-                            pair = %pair,
-                            attempt = attempt + 1,
-                            "Oracle succeeded after retry"
-                        );
-                    }
-                    return Ok(price);
-                }
-                Err(e) => {
-                    last_error = Some(e.to_string());
-
-                    if attempt < MAX_RETRIES - 1 {
-                        // CRIT-001: Exponential backoff with jitter
-                        let backoff_ms = (INITIAL_BACKOFF_MS * 2u64.pow(attempt))
-                            .min(MAX_BACKOFF_MS);
-                        // Add 0-50% jitter to prevent thundering herd
-                        let jitter = (backoff_ms as f64 * rand_jitter()) as u64;
-                        let wait_time = Duration::from_millis(backoff_ms + jitter);
-
-                        tracing::warn!(
-                            pair = %pair,
-                            attempt = attempt + 1,
-                            backoff_ms = wait_time.as_millis(),
-                            error = %e,
-                            "Oracle call failed, retrying with backoff"
-                        );
-
-                        sleep(wait_time).await;
-                    } else {
-                        // CRIT-002: Record failure for circuit breaker after all retries exhausted
-                        state.oracle_circuit_breaker.record_failure();
-
-                        tracing::error!(
-                            pair = %pair,
-                            attempts = MAX_RETRIES,
-                            error = %e,
-                            circuit_state = ?state.oracle_circuit_breaker.state(),
-                            "Oracle failed after all retries, falling back to static rates"
-                        );
-                    }
-                }
-            }
+async fn fetch_fx_rate(
+    state: &Arc<AppState>,
+    budget: &RetryBudget,
+    source: FxSource,
+    currency: &str,
+    pair: &str,
+) -> Result<Decimal, ApiError> {
+    match source {
+        FxSource::PrimaryOracle => get_primary_oracle_rate(state, budget, pair).await,
+        FxSource::SecondaryOracle => get_secondary_oracle_rate(state, pair).await,
+        FxSource::CachedDb { max_age_secs } => {
+            get_cached_db_rate(state.db_pool.as_ref(), pair, max_age_secs).await
         }
+        FxSource::Static => get_fallback_rate(currency),
+    }
+}
 
-        // Log that we're falling back after exhausting retries
-        if let Some(err) = last_error {
-            tracing::warn!(
+/// Get the price from the primary oracle with retry logic
+async fn get_primary_oracle_rate(
+    state: &Arc<AppState>,
+    budget: &RetryBudget,
+    pair: &str,
+) -> Result<Decimal, ApiError> {
+    let oracle_guard = state.oracle.read().await;
+    let Some(oracle) = oracle_guard.as_ref() else {
+        return Err(ApiError::InternalError("Oracle not configured".to_string()));
+    };
+
+    let config = RetryConfig::default();
+    match resilient_call_with_budget(&state.oracle_circuit_breaker, &config, budget, || oracle.get_price(pair)).await {
+        Ok(price) => Ok(price),
+        Err(ResilientError::CircuitOpen) => {
+            tracing::warn!(pair = %pair, "Circuit breaker OPEN - skipping oracle");
+            Err(ApiError::InternalError("Oracle circuit breaker open".to_string()))
+        }
+        Err(ResilientError::BudgetExhausted) => {
+            tracing::warn!(pair = %pair, "Request retry budget spent - skipping oracle");
+            Err(ApiError::InternalError("Oracle retry budget exhausted".to_string()))
+        }
+        Err(ResilientError::Exhausted { attempts, last_error }) => {
+            tracing::error!(
                 pair = %pair,
-                last_error = %err,
-                "Oracle exhausted {} retries, using fallback rates",
-                MAX_RETRIES
+                attempts,
+                error = %last_error,
+                circuit_state = ?state.oracle_circuit_breaker.state(),
+                "Oracle failed after all retries"
             );
+            Err(ApiError::OracleError(last_error))
         }
-    } else {
-        tracing::debug!("Oracle not configured, using static rates for {}", currency);
     }
+}
 
-    // Drop the oracle guard before async operations
-    drop(oracle_guard);
+/// Read the price live from the secondary oracle, subject to its own staleness threshold
+async fn get_secondary_oracle_rate(state: &Arc<AppState>, pair: &str) -> Result<Decimal, ApiError> {
+    let oracle_guard = state.secondary_oracle.read().await;
+    let Some(oracle) = oracle_guard.as_ref() else {
+        return Err(ApiError::InternalError("Secondary oracle not configured".to_string()));
+    };
 
-    get_fallback_rate(currency)
+    oracle.update_price(pair).await?;
+    Ok(oracle.get_price(pair).await?)
+}
+
+/// Use the last-known primary oracle price persisted in `price_history`
+async fn get_cached_db_rate(
+    pool: &sqlx::PgPool,
+    pair: &str,
+    max_age_secs: u64,
+) -> Result<Decimal, ApiError> {
+    let row = PriceRepository::new(pool.clone())
+        .get_latest_from_source(pair, ORACLE_PRICE_SOURCE)
+        .await?;
+    usable_cached_price(&row, chrono::Utc::now(), max_age_secs)
+}
+
+/// A cached price is usable if it was not flagged stale and is at most `max_age_secs` old
+fn usable_cached_price(
+    row: &PriceHistoryRow,
+    now: chrono::DateTime<chrono::Utc>,
+    max_age_secs: u64,
+) -> Result<Decimal, ApiError> {
+    let age_secs = (now - row.timestamp).num_seconds().max(0) as u64;
+    if row.is_stale || age_secs > max_age_secs {
+        return Err(ApiError::InternalError(format!(
+            "Cached price for {} is stale ({}s old)",
+            row.currency_pair, age_secs
+        )));
+    }
+    Ok(row.price)
 }
 
 /// Get fallback FX rate (used when oracle is unavailable)
@@ -928,6 +1813,7 @@ async fn get_authenticated_user_id(
         token_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "operations"))?;
 
@@ -944,6 +1830,128 @@ use super::auth_utils::hash_token_for_lookup;
 mod tests {
     use super::*;
 
+    // ========================
+    // idempotency key tests
+    // ========================
+
+    #[test]
+    fn test_strict_idempotency_key_rejects_weak_keys() {
+        for key in ["1", "test", "0000000000000001", "retry-retry-retry", "00000000-0000-0000-0000-000000000000"] {
+            assert!(
+                matches!(validate_idempotency_key(key, true), Err(ApiError::BadRequest(_))),
+                "{key} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict_idempotency_key_accepts_uuid_and_long_keys() {
+        assert!(validate_idempotency_key("3f2b8c1e-9a4d-4e7f-b6a2-5c8d0e1f2a3b", true).is_ok());
+        assert!(validate_idempotency_key(&Uuid::new_v4().simple().to_string(), true).is_ok());
+        assert!(validate_idempotency_key("order-2026-10-17-4821", true).is_ok());
+    }
+
+    #[test]
+    fn test_relaxed_idempotency_key_only_checks_shape() {
+        assert!(validate_idempotency_key("1", false).is_ok());
+        assert!(validate_idempotency_key("", false).is_err());
+        assert!(validate_idempotency_key(&"a".repeat(129), false).is_err());
+        assert!(validate_idempotency_key("has space", false).is_err());
+    }
+
+    // ========================
+    // parse_amount tests
+    // ========================
+
+    #[test]
+    fn test_parse_amount_rejects_sub_yen() {
+        let result = parse_amount("100.123456", "JPY");
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        assert!(parse_amount("100.5", "JPY").is_err());
+    }
+
+    #[test]
+    fn test_parse_amount_accepts_whole_yen() {
+        assert_eq!(parse_amount("1000", "JPY").unwrap(), Decimal::from(1000));
+        // Trailing zeros carry no extra precision
+        assert_eq!(parse_amount("1000.00", "JPY").unwrap(), Decimal::from(1000));
+    }
+
+    #[test]
+    fn test_parse_amount_accepts_two_decimal_eur() {
+        assert_eq!(parse_amount("100.25", "EUR").unwrap(), Decimal::new(10025, 2));
+        assert_eq!(parse_amount("100.250", "eur").unwrap(), Decimal::new(100250, 3));
+    }
+
+    #[test]
+    fn test_parse_amount_rejects_three_decimal_eur() {
+        assert!(matches!(parse_amount("100.255", "EUR"), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_mint_amount_rounds_over_precise_eur() {
+        let (original, rounded) = parse_mint_amount("100.2549", "EUR").unwrap();
+        assert_eq!(original, Decimal::from_str("100.2549").unwrap());
+        assert_eq!(rounded.to_string(), "100.25");
+    }
+
+    #[test]
+    fn test_mint_amount_rounds_half_to_even() {
+        assert_eq!(parse_mint_amount("100.125", "EUR").unwrap().1.to_string(), "100.12");
+        assert_eq!(parse_mint_amount("100.135", "EUR").unwrap().1.to_string(), "100.14");
+        assert_eq!(parse_mint_amount("1000.5", "JPY").unwrap().1.to_string(), "1000");
+    }
+
+    #[test]
+    fn test_mint_amount_normalizes_scale() {
+        assert_eq!(parse_mint_amount("100", "EUR").unwrap().1.to_string(), "100.00");
+        assert_eq!(parse_mint_amount("100.500", "EUR").unwrap().1.to_string(), "100.50");
+    }
+
+    #[test]
+    fn test_parse_amount_invalid_format() {
+        assert!(matches!(parse_amount("abc", "EUR"), Err(ApiError::BadRequest(_))));
+    }
+
+    // ========================
+    // reserve ratio floor tests
+    // ========================
+
+    /// $1M supply backed by $1.1M reserves (110%), 105% floor
+    fn reserve_check(mint_usd: i64) -> Result<(), ApiError> {
+        let bond = Decimal::from(mint_usd) * Decimal::from(100 + RESERVE_BUFFER_PERCENT) / Decimal::from(100);
+        let projected = projected_reserve_ratio(
+            Decimal::from(1_000_000),
+            Decimal::from(1_100_000),
+            Decimal::from(mint_usd),
+            bond,
+        );
+        check_reserve_ratio(projected, Decimal::from(105), "EUR")
+    }
+
+    #[test]
+    fn test_reserve_ratio_small_mint_passes() {
+        assert!(reserve_check(10_000).is_ok());
+    }
+
+    #[test]
+    fn test_reserve_ratio_large_mint_breaching_floor_rejected() {
+        // (1.1M + 2.04M) / 3M = 104.67% < 105%
+        match reserve_check(2_000_000) {
+            Err(ApiError::Conflict(msg)) => assert!(msg.contains("105% minimum")),
+            other => panic!("expected Conflict, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_projected_reserve_ratio_without_supply() {
+        let ratio = projected_reserve_ratio(Decimal::ZERO, Decimal::ZERO, Decimal::ZERO, Decimal::ZERO);
+        assert_eq!(ratio, Decimal::ONE_HUNDRED);
+
+        let first_mint = projected_reserve_ratio(Decimal::ZERO, Decimal::ZERO, Decimal::from(100), Decimal::from(102));
+        assert_eq!(first_mint, Decimal::from(102));
+    }
+
     // ========================
     // validate_amount tests
     // ========================
@@ -1103,6 +2111,43 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ========================
+    // Settlement chain tests
+    // ========================
+
+    #[test]
+    fn test_settlement_chain_defaults_when_unspecified() {
+        let enabled = [Chain::EthereumSepolia, Chain::BaseSepolia];
+        let chain = resolve_settlement_chain(None, None, Chain::EthereumSepolia, &enabled).unwrap();
+        assert_eq!(chain, Chain::EthereumSepolia);
+
+        // Stablecoin deployment beats the default, the request beats both
+        let chain =
+            resolve_settlement_chain(None, Some(Chain::BaseSepolia), Chain::EthereumSepolia, &enabled).unwrap();
+        assert_eq!(chain, Chain::BaseSepolia);
+        let chain =
+            resolve_settlement_chain(Some("sepolia"), Some(Chain::BaseSepolia), Chain::EthereumSepolia, &enabled)
+                .unwrap();
+        assert_eq!(chain, Chain::EthereumSepolia);
+    }
+
+    #[test]
+    fn test_settlement_chain_rejects_disabled_chain() {
+        let enabled = [Chain::EthereumSepolia];
+        let err = resolve_settlement_chain(Some("base"), None, Chain::EthereumSepolia, &enabled).unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(ref msg) if msg.contains("not enabled: base")));
+
+        // A stablecoin deployed on a disabled chain cannot mint either
+        let err = resolve_settlement_chain(None, Some(Chain::Ethereum), Chain::EthereumSepolia, &enabled).unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(_)));
+    }
+
+    #[test]
+    fn test_settlement_chain_rejects_unknown_chain() {
+        let err = resolve_settlement_chain(Some("dogechain"), None, Chain::Ethereum, &[Chain::Ethereum]).unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(ref msg) if msg.contains("Unknown chain")));
+    }
+
     // ========================
     // Fee calculation tests
     // ========================
@@ -1115,6 +2160,36 @@ mod tests {
         assert_eq!(RESERVE_BUFFER_PERCENT, 2);
     }
 
+    #[test]
+    fn test_compute_fee_at_charge_scale() {
+        // 25 bps of 1234.5678 = 3.0864195 -> 3.09 at cents
+        let usd_value = Decimal::from_str("1234.5678").unwrap();
+        let fee = compute_fee(usd_value, FEE_ISSUANCE_BPS, 2);
+        assert_eq!(fee, Decimal::from_str("3.09").unwrap());
+        assert_eq!(fee.to_string(), "3.09");
+
+        // Same inputs always produce the same charged fee
+        assert_eq!(fee, compute_fee(usd_value, FEE_ISSUANCE_BPS, 2));
+    }
+
+    #[test]
+    fn test_compute_fee_rounds_midpoint_up() {
+        // 25 bps of 1002 = 2.505
+        let fee = compute_fee(Decimal::from(1002), 25, 2);
+        assert_eq!(fee.to_string(), "2.51");
+    }
+
+    #[test]
+    fn test_compute_fee_pads_to_scale() {
+        // Stored and displayed fee share the charge scale, even for round values
+        assert_eq!(compute_fee(Decimal::from(1000), 25, 2).to_string(), "2.50");
+        assert_eq!(compute_fee(Decimal::from(1000), 25, 0).to_string(), "3");
+        assert_eq!(
+            compute_fee(Decimal::from_str("1234.5678").unwrap(), 25, 6).to_string(),
+            "3.086420"
+        );
+    }
+
     // ========================
     // hash_token_for_lookup tests
     // ========================
@@ -1141,5 +2216,123 @@ mod tests {
         assert_eq!(hash.len(), 64);
         assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
     }
-}
 
+    // ========================
+    // transaction retry tests
+    // ========================
+
+    #[test]
+    fn test_exhausted_tx_conflict_is_conflict() {
+        use meridian_db::{DbError, RetryableError};
+
+        let conflict = ApiError::DatabaseError(DbError::TransactionConflict("40001".to_string()));
+        assert!(conflict.is_retryable());
+        assert!(matches!(surface_tx_error(conflict, "operations"), ApiError::Conflict(_)));
+
+        let other = ApiError::DatabaseError(DbError::ConnectionError("pool timed out".to_string()));
+        assert!(!other.is_retryable());
+        assert!(matches!(surface_tx_error(other, "operations"), ApiError::InternalError(_)));
+
+        assert!(matches!(
+            surface_tx_error(ApiError::LimitExceeded("cap".to_string()), "operations"),
+            ApiError::LimitExceeded(_)
+        ));
+    }
+
+    // ========================
+    // FX source ordering tests
+    // ========================
+
+    fn fake_source(source: FxSource) -> Result<Decimal, ApiError> {
+        match source {
+            FxSource::SecondaryOracle => Ok(Decimal::from_str("1.05").unwrap()),
+            FxSource::Static => Ok(Decimal::from_str("1.04").unwrap()),
+            _ => Err(ApiError::InternalError(format!("{:?} down", source))),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_fx_sources_tried_in_configured_order() {
+        let sources = [
+            FxSource::PrimaryOracle,
+            FxSource::CachedDb { max_age_secs: 60 },
+            FxSource::SecondaryOracle,
+            FxSource::Static,
+        ];
+        let mut tried = Vec::new();
+        let rate = first_available_rate(&sources, |source| {
+            tried.push(source);
+            std::future::ready(fake_source(source))
+        })
+        .await
+        .unwrap();
+
+        // First healthy source wins; later sources are never consulted
+        assert_eq!(rate, Decimal::from_str("1.05").unwrap());
+        assert_eq!(tried, sources[..3].to_vec());
+    }
+
+    #[actix_web::test]
+    async fn test_fx_source_order_changes_winner() {
+        let sources = [FxSource::Static, FxSource::SecondaryOracle];
+        let mut tried = Vec::new();
+        let rate = first_available_rate(&sources, |source| {
+            tried.push(source);
+            std::future::ready(fake_source(source))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(rate, Decimal::from_str("1.04").unwrap());
+        assert_eq!(tried, vec![FxSource::Static]);
+    }
+
+    #[actix_web::test]
+    async fn test_fx_sources_all_failing_returns_last_error() {
+        let sources = [FxSource::PrimaryOracle, FxSource::CachedDb { max_age_secs: 60 }];
+        let err = first_available_rate(&sources, |source| std::future::ready(fake_source(source)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::InternalError(ref m) if m.starts_with("CachedDb")));
+
+        let err = first_available_rate(&[], |source| std::future::ready(fake_source(source)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::InternalError(_)));
+    }
+
+    fn cached_row(price: &str, is_stale: bool, age_secs: i64) -> PriceHistoryRow {
+        PriceHistoryRow {
+            id: 1,
+            currency_pair: "EUR/USD".to_string(),
+            price: Decimal::from_str(price).unwrap(),
+            source: ORACLE_PRICE_SOURCE.to_string(),
+            is_stale,
+            round_id: None,
+            timestamp: chrono::Utc::now() - chrono::Duration::seconds(age_secs),
+        }
+    }
+
+    #[test]
+    fn test_cached_price_respects_age_limit_and_stale_flag() {
+        let now = chrono::Utc::now();
+        assert_eq!(
+            usable_cached_price(&cached_row("1.09", false, 60), now, 300).unwrap(),
+            Decimal::from_str("1.09").unwrap()
+        );
+        assert!(usable_cached_price(&cached_row("1.09", false, 600), now, 300).is_err());
+        assert!(usable_cached_price(&cached_row("1.09", true, 60), now, 300).is_err());
+    }
+
+    #[test]
+    fn test_settlement_status_db_round_trip() {
+        for status in [SettlementStatus::Unsettled, SettlementStatus::Settled, SettlementStatus::Failed] {
+            assert_eq!(SettlementStatus::from_db_str(status.to_db_str()), Some(status));
+        }
+        assert_eq!(SettlementStatus::from_db_str("COMPLETED"), None);
+        assert_eq!(
+            serde_json::to_value(SettlementStatus::Unsettled).unwrap(),
+            serde_json::json!("UNSETTLED")
+        );
+    }
+}
@@ -2,17 +2,60 @@
 
 use crate::error::{ApiError, handle_db_error};
 use crate::models::*;
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{web, HttpRequest, HttpResponse};
 use chrono::Utc;
 use ethers::types::Address;
 use meridian_db::{InsertPriceRequest, PriceRepository};
+use meridian_oracle::{ChainlinkOracle, PriceFeed};
 use rust_decimal::Decimal;
 use sha2::{Sha256, Digest};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::{Arc, OnceLock};
 
+/// `price_history.source` for prices read from the primary Chainlink oracle
+pub const ORACLE_PRICE_SOURCE: &str = "chainlink";
+
+/// Records a freshly read oracle price in `price_history`
+///
+/// These rows are the last-known oracle prices the FX fallback chain reads
+/// when the oracle itself is unavailable. Returns the feed the price came from.
+pub async fn persist_oracle_price(
+    pool: &sqlx::PgPool,
+    oracle: &ChainlinkOracle,
+    pair: &str,
+    price: Decimal,
+) -> Result<PriceFeed, ApiError> {
+    let feed = oracle.get_feed_info(pair).await?;
+
+    // Convert round_id safely - skip if conversion would overflow
+    let round_id = if feed.latest_round.bits() <= 64 {
+        Some(Decimal::from(feed.latest_round.as_u64()))
+    } else {
+        tracing::warn!(pair = %pair, "Round ID too large for Decimal, skipping");
+        None
+    };
+
+    let insert_request = InsertPriceRequest {
+        currency_pair: pair.to_string(),
+        price,
+        source: ORACLE_PRICE_SOURCE.to_string(),
+        is_stale: feed.is_stale,
+        round_id,
+    };
+    PriceRepository::new(pool.clone())
+        .insert(insert_request)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to persist price: {}", e);
+            ApiError::InternalError("Failed to persist price".to_string())
+        })?;
+
+    Ok(feed)
+}
+
 /// Get all current prices
 ///
 /// GET /api/v1/oracle/prices
@@ -136,30 +179,7 @@ pub async fn update_price(
 
     let price = oracle.update_price(&pair).await?;
 
-    let feed = oracle.get_feed_info(&pair).await?;
-
-    // Persist price to database
-    let price_repo = PriceRepository::new((*state.db_pool).clone());
-    
-    // Convert round_id safely - skip if conversion would overflow
-    let round_id = if feed.latest_round.bits() <= 64 {
-        Some(Decimal::from(feed.latest_round.as_u64()))
-    } else {
-        tracing::warn!(pair = %pair, "Round ID too large for Decimal, skipping");
-        None
-    };
-    
-    let insert_request = InsertPriceRequest {
-        currency_pair: pair.clone(),
-        price,
-        source: "chainlink".to_string(),
-        is_stale: feed.is_stale,
-        round_id,
-    };
-    price_repo.insert(insert_request).await.map_err(|e| {
-        tracing::error!("Failed to persist price: {}", e);
-        ApiError::InternalError("Failed to persist price".to_string())
-    })?;
+    let feed = persist_oracle_price(state.db_pool.as_ref(), oracle, &pair, price).await?;
 
     tracing::info!(pair = %pair, price = %price, "Price updated and persisted to database");
 
@@ -217,7 +237,9 @@ pub async fn register_price_feed(
     let address = Address::from_str(&req.chainlink_address)
         .map_err(|e| ApiError::BadRequest(format!("Invalid address: {}", e)))?;
 
-    oracle.register_price_feed(&req.pair, address).await?;
+    oracle
+        .register_price_feed_with_decimals(&req.pair, address, req.expected_decimals)
+        .await?;
 
     Ok(HttpResponse::Created().json(serde_json::json!({
         "success": true,
@@ -290,6 +312,7 @@ async fn get_authenticated_user_with_role(
         token_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "oracle"))?;
 
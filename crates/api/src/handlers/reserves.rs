@@ -1,14 +1,27 @@
 //! Reserves and Attestation handlers
 
+use crate::attestation::{Attestation, AttestedReserve};
 use crate::error::{ApiError, handle_db_error};
+use crate::handlers::auth_utils::authenticate_request;
+use crate::handlers::operations::validate_currency;
+use crate::locale::Locale;
+use crate::proof_of_reserves::{hash_user_id, SiblingPosition};
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{web, HttpRequest, HttpResponse};
 use chrono::{Duration, Utc};
+use meridian_basket::CurrencyBasket;
+use meridian_custody::mock::MockAdapter;
+use meridian_custody::CustodyAdapter;
+use meridian_db::{BasketRepository, DbError, ReserveRepository, StablecoinRepository};
 use rust_decimal::Decimal;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::Arc;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+/// Days of reserve history returned by `get_reserves`
+const RESERVE_HISTORY_DAYS: i64 = 30;
 
 /// Bond holding with financial values as strings to avoid floating-point precision issues
 /// SECURITY: Per CLAUDE.md - NO floating-point for money
@@ -75,6 +88,10 @@ pub struct ReserveData {
     /// Total reserve value (as string for precision)
     #[schema(example = "10042250.00")]
     pub total_value: String,
+    /// `total_value` formatted for the requested locale, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "10,042,250.00")]
+    pub total_value_formatted: Option<String>,
     /// Reserve-to-supply ratio percentage
     #[schema(example = "100.42")]
     pub reserve_ratio: String,
@@ -91,33 +108,122 @@ pub struct ReserveData {
     pub currencies: Vec<CurrencyBreakdown>,
     /// Indicates this is simulated demo data, not real reserve verification
     pub demo_mode: bool,
-    /// Source of reserve data (database, demo, or error)
+    /// Source of reserve data: `database` when history comes from recorded
+    /// snapshots, `custody` / `mock_custody` when only current values are
+    /// real, or `demo`
     #[schema(example = "database")]
     pub data_source: String,
 }
 
+/// Share of a basket-backed coin's shortfall attributed to one component
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ComponentShortfall {
+    /// Component currency code
+    #[schema(example = "EUR")]
+    pub currency: String,
+    /// Component target weight percentage (as string for precision)
+    #[schema(example = "60")]
+    pub target_weight: String,
+    /// Shortfall contribution, `shortfall * weight / total weight` (as string for precision)
+    #[schema(example = "30000")]
+    pub shortfall: String,
+}
+
+/// Reserve shortfall response
+///
+/// Amounts are exact (not rounded) so treasury can top up precisely.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReserveShortfall {
+    /// Stablecoin symbol
+    #[schema(example = "EURM")]
+    pub currency: String,
+    /// Outstanding supply (as string for precision)
+    #[schema(example = "1000000")]
+    pub total_supply: String,
+    /// Reserve value backing the supply (as string for precision)
+    #[schema(example = "950000")]
+    pub total_reserve_value: String,
+    /// `max(0, total_supply - total_reserve_value)` (as string for precision)
+    #[schema(example = "50000")]
+    pub shortfall: String,
+    /// Whether reserves cover the supply
+    pub fully_backed: bool,
+    /// Per-component contributions; empty unless the coin is basket-backed
+    pub components: Vec<ComponentShortfall>,
+}
+
 /// Database row for stablecoin reserves query
 #[derive(Debug, sqlx::FromRow)]
 #[allow(dead_code)]
 struct StablecoinReserves {
     symbol: String,
-    total_supply: String,
-    total_reserve_value: String,
+    total_supply: Decimal,
+    total_reserve_value: Decimal,
     status: String,
 }
 
-/// Attestation status response
+/// Query selecting the currency of a liability Merkle tree
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct MerkleTreeQuery {
+    /// Currency code (e.g., EUR)
+    pub currency: String,
+}
+
+/// Published root of a currency's liability Merkle tree
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MerkleRootResponse {
+    #[schema(example = "EUR")]
+    pub currency: String,
+    /// Hex-encoded SHA-256 root
+    #[schema(example = "5f1c0e0a2b4ad3a8b1e3c0b7d8f9a6e4c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7")]
+    pub root: String,
+    /// Sum of all user balances in the tree (as string for precision)
+    #[schema(example = "1000000.00")]
+    pub total_liabilities: String,
+    /// Number of users in the tree
+    pub leaf_count: usize,
+}
+
+/// The caller's leaf in a liability Merkle tree
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MerkleLeaf {
+    /// Hex-encoded salted hash of the user ID
+    pub user_hash: String,
+    /// Balance committed to in the leaf (as string for precision)
+    #[schema(example = "2500.00")]
+    pub balance: String,
+    /// Hex-encoded leaf hash
+    pub hash: String,
+}
+
+/// One step of an inclusion path, from the leaf upwards
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MerkleProofStep {
+    /// Hex-encoded sibling hash
+    pub hash: String,
+    /// Side the sibling is on when hashing the pair
+    pub position: SiblingPosition,
+}
+
+/// Inclusion proof for the caller's balance
 #[derive(Debug, Serialize, ToSchema)]
-pub struct AttestationStatus {
-    /// ISO 8601 timestamp of last attestation
-    #[schema(example = "2025-01-01T11:15:00Z")]
-    pub timestamp: String,
-    /// Attestation status
-    #[schema(example = "healthy")]
-    pub status: String,
-    /// ISO 8601 timestamp of next scheduled attestation
-    #[schema(example = "2025-01-01T17:15:00Z")]
-    pub next_attestation: String,
+pub struct MerkleProofResponse {
+    #[schema(example = "EUR")]
+    pub currency: String,
+    /// Hex-encoded root the path leads to
+    pub root: String,
+    pub leaf: MerkleLeaf,
+    pub path: Vec<MerkleProofStep>,
+}
+
+/// Result of checking a submitted attestation
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AttestationVerification {
+    /// Whether the signature is valid for the attestation's contents
+    pub valid: bool,
+    /// Hex-encoded public key the signature was checked against
+    #[schema(example = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")]
+    pub public_key: String,
 }
 
 /// GET /api/v1/reserves/{currency}
@@ -144,6 +250,7 @@ pub async fn get_reserves(
     verify_authenticated(&state.db_pool, &req).await?;
 
     let currency_code = currency.into_inner().to_uppercase();
+    let locale = Locale::from_request(&req);
 
     tracing::info!("Fetching reserves for {}", currency_code);
 
@@ -160,10 +267,8 @@ pub async fn get_reserves(
             );
 
             // SECURITY-001: Use Decimal for financial calculations (NO FLOATING POINT)
-            let supply = Decimal::from_str(&reserves.total_supply)
-                .unwrap_or(Decimal::ZERO);
-            let reserve_value = Decimal::from_str(&reserves.total_reserve_value)
-                .unwrap_or(Decimal::ZERO);
+            let supply = reserves.total_supply;
+            let reserve_value = reserves.total_reserve_value;
 
             // Calculate reserve ratio (reserves / supply * 100) using Decimal
             let hundred = Decimal::from(100);
@@ -174,36 +279,37 @@ pub async fn get_reserves(
             };
 
             // Fetch live bond holdings from custody adapter
-            let bond_holdings = match state.custody.get_bond_holdings().await {
-                Ok(holdings) => holdings
-                    .into_iter()
-                    .filter(|h| h.currency == currency_code)
-                    .map(|h| BondHolding {
-                        isin: h.isin,
-                        name: h.name,
-                        maturity: h.maturity_date.format("%Y-%m-%d").to_string(),
-                        quantity: format!("{:.2}", h.face_value),
-                        price: "100.00".to_string(), // Would come from oracle in production
-                        value: format!("{:.2}", h.market_value),
-                        r#yield: format!("{:.4}", h.yield_to_maturity),
-                        rating: "AAA".to_string(), // Would come from custody metadata
-                    })
-                    .collect::<Vec<_>>(),
+            let (holdings, demo_mode) = fetch_bond_holdings(state.custody.as_ref(), &currency_code).await;
+            let bond_holdings = holdings.iter().map(bond_holding).collect();
+
+            // Recorded snapshots when there are any; the placeholder only for
+            // coins with no history yet
+            let recorded = match ReserveRepository::new((*state.db_pool).clone())
+                .history(&reserves.symbol, RESERVE_HISTORY_DAYS)
+                .await
+            {
+                Ok(points) => points,
                 Err(e) => {
-                    tracing::warn!(error = %e, "Failed to fetch custody bond holdings");
-                    vec![]
+                    tracing::warn!(currency = %currency_code, error = %e, "Failed to fetch reserve history");
+                    Vec::new()
                 }
             };
+            let (history, data_source) = if recorded.is_empty() {
+                let source = if demo_mode { "mock_custody" } else { "custody" };
+                (generate_history_placeholder(reserve_value, ratio), source)
+            } else {
+                (recorded.iter().map(history_point).collect(), "database")
+            };
 
-            let demo_mode = state.custody.provider_name() == "MockAdapter";
-
+            let total_value = format!("{:.2}", reserve_value);
             let response = ReserveData {
-                total_value: format!("{:.2}", reserve_value),
+                total_value_formatted: locale.and_then(|l| l.format_str(&total_value)),
+                total_value,
                 reserve_ratio: format!("{:.2}", ratio),
                 trend: "0.00".to_string(), // Would need historical data
                 active_currencies: 1,
                 bond_holdings,
-                history: generate_history_placeholder(reserve_value, ratio),
+                history,
                 currencies: vec![
                     CurrencyBreakdown {
                         currency: currency_code.clone(),
@@ -212,7 +318,7 @@ pub async fn get_reserves(
                     }
                 ],
                 demo_mode,
-                data_source: if demo_mode { "mock_custody".to_string() } else { "custody".to_string() },
+                data_source: data_source.to_string(),
             };
 
             Ok(HttpResponse::Ok().json(response))
@@ -231,6 +337,7 @@ pub async fn get_reserves(
 
             let response = ReserveData {
                 total_value: format!("{:.2}", demo_value),
+                total_value_formatted: locale.and_then(|l| l.format_str(&format!("{:.2}", demo_value))),
                 reserve_ratio: format!("{:.2}", demo_ratio),
                 trend: "0.42".to_string(),
                 active_currencies: 4,
@@ -263,6 +370,97 @@ pub async fn get_reserves(
     }
 }
 
+/// GET /api/v1/reserves/{currency}/shortfall
+/// SECURITY: Requires authentication to view reserve data
+#[utoipa::path(
+    get,
+    path = "/api/v1/reserves/{currency}/shortfall",
+    tag = "reserves",
+    security(("bearer_auth" = [])),
+    params(
+        ("currency" = String, Path, description = "Stablecoin symbol (e.g., EURM)")
+    ),
+    responses(
+        (status = 200, description = "Reserve shortfall for the stablecoin", body = ReserveShortfall),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Stablecoin not found")
+    )
+)]
+pub async fn get_reserve_shortfall(
+    state: web::Data<Arc<AppState>>,
+    currency: web::Path<String>,
+    req: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    verify_authenticated(&state.db_pool, &req).await?;
+
+    let symbol = currency.into_inner().to_uppercase();
+    let coin = StablecoinRepository::new((*state.db_pool).clone())
+        .find_by_symbol(&symbol)
+        .await
+        .map_err(|e| match e {
+            DbError::NotFound(_) => ApiError::NotFound(format!("Stablecoin {} not found", symbol)),
+            e => handle_db_error(e, "reserves"),
+        })?;
+
+    let shortfall = calculate_shortfall(coin.total_supply, coin.total_reserve_value);
+    let components = match coin.basket_id {
+        Some(basket_id) => {
+            let basket = BasketRepository::new((*state.db_pool).clone())
+                .find_by_id(basket_id)
+                .await
+                .map_err(|e| handle_db_error(e, "reserves"))?;
+            component_shortfalls(&basket, shortfall)
+        }
+        None => Vec::new(),
+    };
+
+    tracing::info!(
+        currency = %symbol,
+        supply = %coin.total_supply,
+        reserve = %coin.total_reserve_value,
+        shortfall = %shortfall,
+        "Calculated reserve shortfall"
+    );
+
+    Ok(HttpResponse::Ok().json(ReserveShortfall {
+        currency: coin.symbol,
+        total_supply: coin.total_supply.normalize().to_string(),
+        total_reserve_value: coin.total_reserve_value.normalize().to_string(),
+        shortfall: shortfall.normalize().to_string(),
+        fully_backed: shortfall.is_zero(),
+        components,
+    }))
+}
+
+/// Amount by which reserves fall short of supply, or zero if fully backed
+fn calculate_shortfall(total_supply: Decimal, total_reserve_value: Decimal) -> Decimal {
+    (total_supply - total_reserve_value).max(Decimal::ZERO)
+}
+
+/// Splits a shortfall across a basket's components by target weight
+///
+/// Weights are normalised by their sum, since custom baskets may be within
+/// tolerance of 100% rather than exactly on it.
+fn component_shortfalls(basket: &CurrencyBasket, shortfall: Decimal) -> Vec<ComponentShortfall> {
+    let total_weight: Decimal = basket.components.iter().map(|c| c.target_weight).sum();
+    basket
+        .components
+        .iter()
+        .map(|component| {
+            let contribution = if total_weight > Decimal::ZERO {
+                shortfall * component.target_weight / total_weight
+            } else {
+                Decimal::ZERO
+            };
+            ComponentShortfall {
+                currency: component.currency_code.clone(),
+                target_weight: component.target_weight.normalize().to_string(),
+                shortfall: contribution.normalize().to_string(),
+            }
+        })
+        .collect()
+}
+
 /// Fetch real reserve data from the database
 async fn fetch_real_reserves(
     pool: &sqlx::PgPool,
@@ -280,12 +478,68 @@ async fn fetch_real_reserves(
     )
     .bind(currency_symbol)
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| format!("Database query failed: {}", e))?;
 
     result.ok_or_else(|| format!("No active stablecoin found for symbol: {}", currency_symbol))
 }
 
+/// Bond holdings in `currency` from the custodian, and whether they are demo data
+///
+/// Demo holdings are returned when custody is unconfigured (the mock
+/// adapter) or the custodian can't be reached.
+async fn fetch_bond_holdings(
+    custody: &dyn CustodyAdapter,
+    currency: &str,
+) -> (Vec<meridian_custody::BondHolding>, bool) {
+    let mock = MockAdapter::new();
+    let is_mock = custody.provider_name() == mock.provider_name();
+    match custody.list_holdings(currency).await {
+        Ok(holdings) => (holdings, is_mock),
+        Err(e) => {
+            tracing::warn!(
+                provider = custody.provider_name(),
+                currency = %currency,
+                error = %e,
+                "Failed to fetch custody bond holdings, using demo holdings"
+            );
+            (mock.list_holdings(currency).await.unwrap_or_default(), true)
+        }
+    }
+}
+
+/// Renders a custody bond holding for the API
+///
+/// Price is the market value as a percentage of face value; unrated
+/// holdings are shown as `NR`.
+fn bond_holding(holding: &meridian_custody::BondHolding) -> BondHolding {
+    let price = if holding.face_value > Decimal::ZERO {
+        holding.market_value / holding.face_value * Decimal::ONE_HUNDRED
+    } else {
+        Decimal::ONE_HUNDRED
+    };
+    BondHolding {
+        isin: holding.isin.clone(),
+        name: holding.name.clone(),
+        maturity: holding.maturity_date.format("%Y-%m-%d").to_string(),
+        quantity: format!("{:.2}", holding.face_value),
+        price: format!("{:.2}", price),
+        value: format!("{:.2}", holding.market_value),
+        r#yield: format!("{:.4}", holding.yield_to_maturity),
+        rating: holding.rating.clone().unwrap_or_else(|| "NR".to_string()),
+    }
+}
+
+/// Renders a recorded reserve snapshot for the API
+fn history_point(point: &meridian_db::HistoryPoint) -> HistoryPoint {
+    HistoryPoint {
+        timestamp: point.recorded_at.timestamp_millis(),
+        ratio: format!("{:.2}", point.reserve_ratio),
+        total_value: format!("{:.2}", point.total_value),
+    }
+}
+
 /// Generate placeholder history data (for when we have real current data but no history)
 /// SECURITY: Per CLAUDE.md - Uses Decimal throughout, no floating-point for financial values
 fn generate_history_placeholder(current_value: Decimal, current_ratio: Decimal) -> Vec<HistoryPoint> {
@@ -312,34 +566,170 @@ fn generate_history_placeholder(current_value: Decimal, current_ratio: Decimal)
 
 /// GET /api/v1/attestation/latest
 /// CRIT-018 FIX: Requires authentication to prevent information disclosure
+///
+/// Signs the current supply and reserve value of every active stablecoin.
 #[utoipa::path(
     get,
     path = "/api/v1/attestation/latest",
     tag = "reserves",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "Latest attestation status", body = AttestationStatus),
-        (status = 401, description = "Unauthorized")
+        (status = 200, description = "Signed reserve attestation", body = Attestation),
+        (status = 401, description = "Unauthorized"),
+        (status = 503, description = "Attestation signing not configured")
     )
 )]
-pub async fn get_attestation_status(
+pub async fn get_latest_attestation(
     state: web::Data<Arc<AppState>>,
     req: HttpRequest,
 ) -> Result<HttpResponse, ApiError> {
-    // CRIT-018: Verify authentication before returning attestation status
+    // CRIT-018: Verify authentication before returning attestation data
     verify_authenticated(&state.db_pool, &req).await?;
 
-    let now = Utc::now();
-    let last_attestation = now - Duration::minutes(45); // Attested 45 mins ago
-    let next_attestation = last_attestation + Duration::hours(6);
+    let signer = state
+        .attestation_signer
+        .as_ref()
+        .ok_or(ApiError::AttestationNotConfigured)?;
 
-    let response = AttestationStatus {
-        timestamp: last_attestation.to_rfc3339(),
-        status: "healthy".to_string(),
-        next_attestation: next_attestation.to_rfc3339(),
-    };
+    // Same symbol on several chains is attested once, summed
+    let reserves = sqlx::query_as::<_, AttestedReserve>(
+        r#"
+        SELECT UPPER(symbol) AS symbol,
+               SUM(total_supply) AS total_supply,
+               SUM(total_reserve_value) AS total_reserve_value
+        FROM stablecoins
+        WHERE status = 'active'
+        GROUP BY UPPER(symbol)
+        "#,
+    )
+    .fetch_all(state.db_pool.as_ref())
+    .tracked()
+    .await
+    .map_err(|e| handle_db_error(e, "attestation_reserves"))?;
+
+    Ok(HttpResponse::Ok().json(signer.sign(Utc::now(), reserves)))
+}
 
-    Ok(HttpResponse::Ok().json(response))
+/// POST /api/v1/attestation/verify
+///
+/// Checks an attestation's signature against this server's public key.
+/// Public, so third parties can confirm an attestation they were handed.
+#[utoipa::path(
+    post,
+    path = "/api/v1/attestation/verify",
+    tag = "reserves",
+    request_body = Attestation,
+    responses(
+        (status = 200, description = "Verification result", body = AttestationVerification),
+        (status = 400, description = "Malformed attestation"),
+        (status = 503, description = "Attestation signing not configured")
+    )
+)]
+pub async fn verify_attestation(
+    state: web::Data<Arc<AppState>>,
+    body: web::Json<Attestation>,
+) -> Result<HttpResponse, ApiError> {
+    let signer = state
+        .attestation_signer
+        .as_ref()
+        .ok_or(ApiError::AttestationNotConfigured)?;
+
+    Ok(HttpResponse::Ok().json(AttestationVerification {
+        valid: signer.verify(&body),
+        public_key: signer.public_key_hex(),
+    }))
+}
+
+/// GET /api/v1/reserves/merkle-root
+///
+/// Public, so anyone can compare liabilities against published reserves.
+#[utoipa::path(
+    get,
+    path = "/api/v1/reserves/merkle-root",
+    tag = "reserves",
+    params(MerkleTreeQuery),
+    responses(
+        (status = 200, description = "Liability Merkle root", body = MerkleRootResponse),
+        (status = 400, description = "Unsupported currency")
+    )
+)]
+pub async fn get_merkle_root(
+    state: web::Data<Arc<AppState>>,
+    query: web::Query<MerkleTreeQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let currency = query.currency.to_uppercase();
+    validate_currency(&currency)?;
+
+    let tree = state
+        .liability_trees
+        .get_or_build(&state.db_pool, &currency)
+        .await
+        .map_err(|e| handle_db_error(e, "operations"))?;
+
+    Ok(HttpResponse::Ok().json(MerkleRootResponse {
+        currency,
+        root: hex::encode(tree.root()),
+        total_liabilities: format!("{:.2}", tree.total_liabilities()),
+        leaf_count: tree.leaf_count(),
+    }))
+}
+
+/// GET /api/v1/reserves/merkle-proof
+///
+/// The caller's leaf and inclusion path in the current tree.
+#[utoipa::path(
+    get,
+    path = "/api/v1/reserves/merkle-proof",
+    tag = "reserves",
+    security(("bearer_auth" = [])),
+    params(MerkleTreeQuery),
+    responses(
+        (status = 200, description = "Inclusion proof for the caller", body = MerkleProofResponse),
+        (status = 400, description = "Unsupported currency"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a user session"),
+        (status = 404, description = "Caller has no balance in this currency")
+    )
+)]
+pub async fn get_merkle_proof(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    query: web::Query<MerkleTreeQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let ctx = authenticate_request(&state.db_pool, &req).await?;
+    let user_id = ctx
+        .user_id
+        .ok_or_else(|| ApiError::Forbidden("Merkle proofs are only available to user sessions".to_string()))?;
+
+    let currency = query.currency.to_uppercase();
+    validate_currency(&currency)?;
+
+    let tree = state
+        .liability_trees
+        .get_or_build(&state.db_pool, &currency)
+        .await
+        .map_err(|e| handle_db_error(e, "operations"))?;
+    let proof = tree
+        .proof(&hash_user_id(user_id))
+        .ok_or_else(|| ApiError::NotFound(format!("No {} balance to prove", currency)))?;
+
+    Ok(HttpResponse::Ok().json(MerkleProofResponse {
+        currency,
+        root: hex::encode(tree.root()),
+        leaf: MerkleLeaf {
+            user_hash: hex::encode(proof.leaf.user_hash),
+            balance: proof.leaf.balance.normalize().to_string(),
+            hash: hex::encode(proof.leaf.hash()),
+        },
+        path: proof
+            .path
+            .iter()
+            .map(|step| MerkleProofStep {
+                hash: hex::encode(step.sibling),
+                position: step.position,
+            })
+            .collect(),
+    }))
 }
 
 /// Verify that the request contains a valid authentication token.
@@ -367,6 +757,7 @@ async fn verify_authenticated(
         token_hash
     )
     .fetch_optional(pool)
+    .tracked()
     .await
     .map_err(|e| handle_db_error(e, "reserves"))?;
 
@@ -379,3 +770,105 @@ async fn verify_authenticated(
 
 // HIGH-003: Use centralized token hashing from auth_utils
 use super::auth_utils::hash_token_for_lookup;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use meridian_basket::{CurrencyComponent, RebalanceStrategy};
+
+    fn component(code: &str, weight: i64) -> CurrencyComponent {
+        CurrencyComponent::new(
+            code.to_string(),
+            Decimal::from(weight),
+            Decimal::from(weight - 10),
+            Decimal::from(weight + 10),
+            format!("{:#x}", ethers::types::Address::from_low_u64_be(weight as u64)),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bond_holdings_fall_back_to_demo_when_custody_fails() {
+        let (holdings, demo_mode) = fetch_bond_holdings(&MockAdapter::new(), "eur").await;
+        assert!(demo_mode);
+        assert_eq!(holdings.len(), 3);
+
+        // Nothing listens on port 1
+        let unreachable = meridian_custody::http::HttpCustodyAdapter::new("http://127.0.0.1:1".to_string(), None);
+        let (holdings, demo_mode) = fetch_bond_holdings(&unreachable, "EUR").await;
+        assert!(demo_mode);
+        assert!(holdings.iter().all(|h| h.currency == "EUR"));
+        assert!(!holdings.is_empty());
+    }
+
+    #[test]
+    fn test_bond_holding_renders_custody_values() {
+        let now = Utc::now();
+        let mut holding = meridian_custody::BondHolding {
+            id: uuid::Uuid::new_v4(),
+            isin: "FR0013508470".to_string(),
+            name: "OAT France 0.75% 2028".to_string(),
+            currency: "EUR".to_string(),
+            face_value: Decimal::from(3_000_000),
+            market_value: Decimal::from(2_820_000),
+            yield_to_maturity: Decimal::new(310, 4),
+            maturity_date: chrono::DateTime::parse_from_rfc3339("2028-05-25T00:00:00Z").unwrap().into(),
+            custodian_account_id: "vault-1".to_string(),
+            rating: Some("AA".to_string()),
+            valued_at: now,
+        };
+        let rendered = bond_holding(&holding);
+        assert_eq!(rendered.maturity, "2028-05-25");
+        assert_eq!(rendered.quantity, "3000000.00");
+        assert_eq!(rendered.price, "94.00");
+        assert_eq!(rendered.value, "2820000.00");
+        assert_eq!(rendered.r#yield, "0.0310");
+        assert_eq!(rendered.rating, "AA");
+
+        holding.rating = None;
+        holding.face_value = Decimal::ZERO;
+        let rendered = bond_holding(&holding);
+        assert_eq!(rendered.rating, "NR");
+        assert_eq!(rendered.price, "100.00");
+    }
+
+    #[test]
+    fn test_history_point_renders_snapshot() {
+        let recorded_at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let point = history_point(&meridian_db::HistoryPoint {
+            total_value: Decimal::new(95_000_000_123, 5),
+            reserve_ratio: Decimal::new(9_512_345, 5),
+            recorded_at,
+        });
+        assert_eq!(point.timestamp, 1_700_000_000_000);
+        assert_eq!(point.total_value, "950000.00");
+        assert_eq!(point.ratio, "95.12");
+    }
+
+    #[test]
+    fn test_shortfall_is_never_negative() {
+        assert_eq!(calculate_shortfall(Decimal::from(1_000_000), Decimal::from(1_000_000)), Decimal::ZERO);
+        assert_eq!(calculate_shortfall(Decimal::from(1_000_000), Decimal::from(1_200_000)), Decimal::ZERO);
+        assert_eq!(
+            calculate_shortfall(Decimal::new(100_000_050, 2), Decimal::from(950_000)),
+            Decimal::new(5_000_050, 2)
+        );
+    }
+
+    #[test]
+    fn test_component_shortfalls_sum_to_total() {
+        let basket = CurrencyBasket::new_custom_basket(
+            "EUR/GBP/JPY".to_string(),
+            vec![component("EUR", 50), component("GBP", 30), component("JPY", 20)],
+            RebalanceStrategy::None,
+        )
+        .unwrap();
+
+        let parts = component_shortfalls(&basket, Decimal::from(50_000));
+        let shortfalls: Vec<&str> = parts.iter().map(|p| p.shortfall.as_str()).collect();
+        assert_eq!(shortfalls, vec!["25000", "15000", "10000"]);
+
+        let zero = component_shortfalls(&basket, Decimal::ZERO);
+        assert!(zero.iter().all(|p| p.shortfall == "0"));
+    }
+}
@@ -0,0 +1,44 @@
+//! Stablecoin metadata handlers
+
+use crate::error::{handle_db_error, ApiError};
+use crate::handlers::auth_utils::require_role;
+use crate::models::Stablecoin;
+use crate::state::AppState;
+use actix_web::{web, HttpRequest, HttpResponse};
+use meridian_db::{DbError, StablecoinRepository};
+use std::sync::Arc;
+
+/// Get a stablecoin by symbol
+///
+/// GET /api/v1/stablecoins/{symbol}
+#[utoipa::path(
+    get,
+    path = "/api/v1/stablecoins/{symbol}",
+    tag = "stablecoins",
+    security(("bearer_auth" = [])),
+    params(
+        ("symbol" = String, Path, description = "Token symbol (case-insensitive)")
+    ),
+    responses(
+        (status = 200, description = "Stablecoin metadata", body = Stablecoin),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Stablecoin not found")
+    )
+)]
+pub async fn get_stablecoin(
+    state: web::Data<Arc<AppState>>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    require_role(state.db_pool.as_ref(), &req, "VIEWER").await?;
+
+    let symbol = path.into_inner();
+
+    let repo = StablecoinRepository::new((*state.db_pool).clone());
+    let row = repo.find_by_symbol(&symbol).await.map_err(|e| match e {
+        DbError::NotFound(_) => ApiError::NotFound(format!("Stablecoin {} not found", symbol)),
+        e => handle_db_error(e, "stablecoins"),
+    })?;
+
+    Ok(HttpResponse::Ok().json(Stablecoin::from(row)))
+}
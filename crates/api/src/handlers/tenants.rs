@@ -7,8 +7,10 @@
 
 use crate::error::ApiError;
 use crate::handlers::auth_utils::{hash_api_key, require_role};
+use crate::query_metrics::TrackQuery;
 use crate::state::AppState;
 use actix_web::{web, HttpRequest, HttpResponse};
+use meridian_chains::Chain;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -38,6 +40,38 @@ pub struct TenantResponse {
     pub created_at: String,
 }
 
+/// Validate the chains selected in a tenant's `chain_config`.
+///
+/// Accepts `{"chain": "base"}` or `{"chains": ["base", "ethereum"]}`. Unknown
+/// chains and placeholder chains (Arc/Tempo until their IDs are published)
+/// are rejected so tenants never get configured against `chain_id: 0`.
+fn validate_chain_config(chain_config: &serde_json::Value) -> Result<(), ApiError> {
+    let mut selected: Vec<&serde_json::Value> = Vec::new();
+    if let Some(chain) = chain_config.get("chain") {
+        selected.push(chain);
+    }
+    if let Some(chains) = chain_config.get("chains") {
+        let chains = chains
+            .as_array()
+            .ok_or_else(|| ApiError::BadRequest("chain_config.chains must be an array".to_string()))?;
+        selected.extend(chains);
+    }
+
+    for value in selected {
+        let name = value
+            .as_str()
+            .ok_or_else(|| ApiError::BadRequest("Chain names must be strings".to_string()))?;
+        let chain: Chain = name
+            .parse()
+            .map_err(|e: meridian_chains::ChainError| ApiError::BadRequest(e.to_string()))?;
+        chain
+            .ensure_available()
+            .map_err(|_| ApiError::BadRequest(format!("Chain not yet available: {}", chain.name())))?;
+    }
+
+    Ok(())
+}
+
 /// POST /api/v1/tenants
 pub async fn create_tenant(
     state: web::Data<Arc<AppState>>,
@@ -45,6 +79,7 @@ pub async fn create_tenant(
     body: web::Json<CreateTenantRequest>,
 ) -> Result<HttpResponse, ApiError> {
     require_role(state.db_pool.as_ref(), &req, "ADMIN").await?;
+    validate_chain_config(&body.chain_config)?;
 
     #[derive(sqlx::FromRow)]
     struct Row {
@@ -69,6 +104,7 @@ pub async fn create_tenant(
     .bind(&body.custody_config)
     .bind(&body.chain_config)
     .fetch_one(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create tenant: {}", e);
@@ -108,6 +144,7 @@ pub async fn list_tenants(
         "SELECT id, name, legal_entity, jurisdiction, status, created_at FROM tenants ORDER BY created_at DESC"
     )
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to list tenants: {}", e);
@@ -148,6 +185,7 @@ pub async fn get_tenant(
     )
     .bind(tenant_id)
     .fetch_optional(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to get tenant: {}", e);
@@ -207,6 +245,7 @@ pub async fn create_api_key(
     .bind(body.expires_at)
     .bind(ctx.user_id)
     .fetch_one(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create API key: {}", e);
@@ -264,6 +303,7 @@ pub async fn list_api_keys(
     )
     .bind(tenant_id)
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to list API keys: {}", e);
@@ -287,6 +327,7 @@ pub async fn revoke_api_key(
     )
     .bind(key_id)
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to revoke API key: {}", e);
@@ -367,6 +408,7 @@ pub async fn create_webhook(
     .bind(&secret_hash)
     .bind(body.timeout_secs.unwrap_or(10))
     .fetch_one(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to create webhook: {}", e);
@@ -416,6 +458,7 @@ pub async fn list_webhooks(
     )
     .bind(tenant_id)
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to list webhooks: {}", e);
@@ -439,6 +482,7 @@ pub async fn delete_webhook(
     )
     .bind(webhook_id)
     .execute(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to deactivate webhook: {}", e);
@@ -474,6 +518,7 @@ pub async fn test_webhook(
     )
     .bind(tenant_id)
     .fetch_all(state.db_pool.as_ref())
+    .tracked()
     .await
     .map_err(|e| {
         tracing::error!("Failed to fetch webhooks: {}", e);
@@ -497,6 +542,7 @@ pub async fn test_webhook(
         .bind(webhook_id)
         .bind(&test_payload)
         .execute(state.db_pool.as_ref())
+        .tracked()
         .await;
     }
 
@@ -506,3 +552,34 @@ pub async fn test_webhook(
         "note": "Deliveries queued — check webhook_deliveries table for status"
     })))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_chain_config_accepts_live_chains() {
+        assert!(validate_chain_config(&json!({})).is_ok());
+        assert!(validate_chain_config(&json!({"chain": "base"})).is_ok());
+        assert!(validate_chain_config(&json!({"chains": ["ethereum", "base-sepolia"]})).is_ok());
+    }
+
+    #[test]
+    fn test_chain_config_rejects_placeholder_chains() {
+        for config in [json!({"chain": "arc"}), json!({"chains": ["base", "tempo-testnet"]})] {
+            match validate_chain_config(&config) {
+                Err(ApiError::BadRequest(msg)) => assert!(msg.contains("not yet available")),
+                other => panic!("expected BadRequest, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_chain_config_rejects_unknown_chain() {
+        assert!(matches!(
+            validate_chain_config(&json!({"chain": "dogechain"})),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+}
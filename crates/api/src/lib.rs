@@ -2,15 +2,26 @@
 //!
 //! HTTP API service for stablecoin management and oracle integration
 
+pub mod attestation;
+pub mod basket_cache;
+pub mod config;
 pub mod error;
 pub mod handlers;
+pub mod locale;
 pub mod metrics;
 pub mod middleware;
 pub mod models;
+pub mod proof_of_reserves;
+pub mod query_metrics;
+pub mod reconciliation;
+pub mod resilience;
 pub mod routes;
 pub mod state;
 pub mod telemetry;
 
 pub use error::ApiError;
-pub use middleware::{CorrelationId, CorrelationIdMiddleware, RateLimitHeadersMiddleware};
+pub use middleware::{
+    CorrelationId, CorrelationIdMiddleware, ProblemJsonMiddleware, QueryMetricsMiddleware,
+    RateLimitHeadersMiddleware,
+};
 pub use state::AppState;
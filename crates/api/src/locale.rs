@@ -0,0 +1,201 @@
+//! Locale-aware formatting for money fields
+//!
+//! Read endpoints always return raw decimal strings. When a client asks for a
+//! locale via `?locale=` or `Accept-Language`, responses also carry a
+//! `*_formatted` companion with grouped thousands and the locale's decimal
+//! separator. The formatted value is display-only and never parsed back.
+
+use actix_web::{http::header, HttpRequest};
+use rust_decimal::Decimal;
+
+/// Supported display locales
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    EnUs,
+    EnGb,
+    DeDe,
+    FrFr,
+    EsEs,
+    JaJp,
+}
+
+impl Locale {
+    /// Parses a BCP 47 tag such as `de-DE`, `de_DE` or a bare language `de`
+    pub fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.trim().replace('_', "-").to_ascii_lowercase();
+        match tag.as_str() {
+            "en-us" | "en" => Some(Locale::EnUs),
+            "en-gb" => Some(Locale::EnGb),
+            "de-de" | "de-at" | "de" => Some(Locale::DeDe),
+            "fr-fr" | "fr" => Some(Locale::FrFr),
+            "es-es" | "es" => Some(Locale::EsEs),
+            "ja-jp" | "ja" => Some(Locale::JaJp),
+            _ => {
+                // Fall back to the language subtag (e.g. "de-CH" -> "de")
+                let (lang, region) = tag.split_once('-')?;
+                if region.is_empty() {
+                    return None;
+                }
+                Self::parse(lang)
+            }
+        }
+    }
+
+    /// Resolves the requested locale, preferring `?locale=` over `Accept-Language`
+    ///
+    /// Returns `None` when the client asked for nothing we support, in which
+    /// case only raw values are returned.
+    pub fn from_request(req: &HttpRequest) -> Option<Self> {
+        let from_query = req.query_string().split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            (key == "locale").then(|| Self::parse(value)).flatten()
+        });
+        if from_query.is_some() {
+            return from_query;
+        }
+
+        req.headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Self::from_accept_language)
+    }
+
+    /// Picks the highest-weighted supported tag from an `Accept-Language` value
+    pub fn from_accept_language(value: &str) -> Option<Self> {
+        let mut candidates: Vec<(Decimal, Locale)> = value
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let locale = Self::parse(parts.next()?)?;
+                let weight = parts
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .map(|q| q.trim().parse::<Decimal>().ok())
+                    .unwrap_or(Some(Decimal::ONE))?;
+                (weight > Decimal::ZERO).then_some((weight, locale))
+            })
+            .collect();
+        // Stable sort keeps header order for equal weights
+        candidates.sort_by_key(|(weight, _)| std::cmp::Reverse(*weight));
+        candidates.first().map(|(_, locale)| *locale)
+    }
+
+    /// Canonical tag for this locale
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "en-US",
+            Locale::EnGb => "en-GB",
+            Locale::DeDe => "de-DE",
+            Locale::FrFr => "fr-FR",
+            Locale::EsEs => "es-ES",
+            Locale::JaJp => "ja-JP",
+        }
+    }
+
+    fn separators(&self) -> (&'static str, char) {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::JaJp => (",", '.'),
+            Locale::DeDe | Locale::EsEs => (".", ','),
+            // CLDR uses a narrow no-break space for French grouping
+            Locale::FrFr => ("\u{202F}", ','),
+        }
+    }
+
+    /// Formats an amount with grouped thousands, keeping its decimal scale
+    pub fn format_amount(&self, amount: Decimal) -> String {
+        let (group, decimal) = self.separators();
+        let raw = amount.abs().to_string();
+        let (int_part, frac_part) = match raw.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (raw.as_str(), None),
+        };
+
+        let mut out = String::with_capacity(raw.len() + int_part.len() / 3 * group.len() + 1);
+        if amount.is_sign_negative() && !amount.is_zero() {
+            out.push('-');
+        }
+        for (i, digit) in int_part.chars().enumerate() {
+            if i > 0 && (int_part.len() - i) % 3 == 0 {
+                out.push_str(group);
+            }
+            out.push(digit);
+        }
+        if let Some(frac) = frac_part {
+            out.push(decimal);
+            out.push_str(frac);
+        }
+        out
+    }
+
+    /// Formats a stored decimal string, returning `None` if it does not parse
+    pub fn format_str(&self, amount: &str) -> Option<String> {
+        amount.parse::<Decimal>().ok().map(|d| self.format_amount(d))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_format_en_us() {
+        let amount = Decimal::from_str("1234567.89").unwrap();
+        assert_eq!(Locale::EnUs.format_amount(amount), "1,234,567.89");
+    }
+
+    #[test]
+    fn test_format_de_de() {
+        let amount = Decimal::from_str("1234567.89").unwrap();
+        assert_eq!(Locale::DeDe.format_amount(amount), "1.234.567,89");
+    }
+
+    #[test]
+    fn test_format_small_negative_and_integral_amounts() {
+        assert_eq!(Locale::EnUs.format_amount(Decimal::from_str("999.5").unwrap()), "999.5");
+        assert_eq!(Locale::EnUs.format_amount(Decimal::from_str("-1000").unwrap()), "-1,000");
+        assert_eq!(Locale::DeDe.format_amount(Decimal::from_str("-0.00").unwrap()), "0,00");
+        assert_eq!(
+            Locale::FrFr.format_amount(Decimal::from_str("1234.5").unwrap()),
+            "1\u{202F}234,5"
+        );
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(Locale::parse("de-DE"), Some(Locale::DeDe));
+        assert_eq!(Locale::parse("de_de"), Some(Locale::DeDe));
+        assert_eq!(Locale::parse("de-CH"), Some(Locale::DeDe));
+        assert_eq!(Locale::parse("en"), Some(Locale::EnUs));
+        assert_eq!(Locale::parse("xx-YY"), None);
+        assert_eq!(Locale::parse(""), None);
+    }
+
+    #[test]
+    fn test_accept_language_respects_weights() {
+        assert_eq!(
+            Locale::from_accept_language("en-US;q=0.5, de-DE;q=0.9"),
+            Some(Locale::DeDe)
+        );
+        assert_eq!(Locale::from_accept_language("xx, fr;q=0.8"), Some(Locale::FrFr));
+        assert_eq!(Locale::from_accept_language("de;q=0"), None);
+        assert_eq!(Locale::from_accept_language("*"), None);
+    }
+
+    #[test]
+    fn test_query_overrides_header() {
+        let req = TestRequest::default()
+            .uri("/value?locale=de-DE")
+            .insert_header((header::ACCEPT_LANGUAGE, "en-US"))
+            .to_http_request();
+        assert_eq!(Locale::from_request(&req), Some(Locale::DeDe));
+
+        let req = TestRequest::default()
+            .insert_header((header::ACCEPT_LANGUAGE, "en-GB,en;q=0.8"))
+            .to_http_request();
+        assert_eq!(Locale::from_request(&req), Some(Locale::EnGb));
+
+        let req = TestRequest::default().to_http_request();
+        assert_eq!(Locale::from_request(&req), None);
+    }
+}
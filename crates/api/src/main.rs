@@ -8,9 +8,16 @@ use actix_cors::Cors;
 use actix_governor::{Governor, GovernorConfigBuilder};
 use actix_web::{middleware::{DefaultHeaders, Logger}, web, App, HttpServer};
 use ethers::types::U256;
-use meridian_api::{metrics, routes, state::AppState, telemetry, CorrelationIdMiddleware, RateLimitHeadersMiddleware};
+use meridian_api::config::RuntimeConfig;
+use meridian_api::reconciliation::{SupplyEvent, SupplyReconciler};
+use meridian_api::handlers::settle_due_operations;
+use meridian_api::{
+    metrics, routes, state::AppState, telemetry, CorrelationIdMiddleware, ProblemJsonMiddleware,
+    QueryMetricsMiddleware, RateLimitHeadersMiddleware,
+};
 use meridian_chains::execution::spawn_confirmation_worker;
-use meridian_db::{create_pool, run_migrations};
+use meridian_compliance::sanctions::spawn_sanctions_list_reloader;
+use meridian_db::{create_pool, run_migrations, ReserveRepository, SessionRepository};
 use openapi::ApiDoc;
 use rust_decimal::Decimal;
 use std::sync::Arc;
@@ -31,14 +38,12 @@ async fn main() -> std::io::Result<()> {
     tracing::info!("Starting Meridian API server...");
 
     // Get configuration from environment
-    let host = std::env::var("MERIDIAN_API_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
-    let port = std::env::var("MERIDIAN_API_PORT")
-        .unwrap_or_else(|_| "8080".to_string())
-        .parse::<u16>()
-        .expect("Invalid port number");
+    let config = RuntimeConfig::from_env();
+    let host = config.host.clone();
+    let port = config.port;
 
     // Get database URL from environment
-    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let database_url = config.database_url.clone().expect("DATABASE_URL must be set");
 
     // Initialize database connection pool
     tracing::info!("Connecting to database...");
@@ -59,6 +64,24 @@ async fn main() -> std::io::Result<()> {
 
     tracing::info!("Application state initialized");
 
+    // Refuse traffic until every catalog feed is registered and every
+    // live-required currency has a working feed
+    {
+        let oracle = app_state.oracle.read().await;
+        if let Some(oracle) = oracle.as_deref() {
+            if let Err(e) = AppState::check_required_feeds(oracle).await {
+                tracing::error!(error = %e, "Oracle startup health check failed");
+                return Err(std::io::Error::other(e.to_string()));
+            }
+        }
+        if let Err(e) =
+            AppState::validate_live_oracle_currencies(oracle.as_deref(), &config.live_oracle_currencies).await
+        {
+            tracing::error!(error = %e, "Startup validation failed");
+            return Err(std::io::Error::other(e.to_string()));
+        }
+    }
+
     // A.4 + H.4: Spawn background services — store JoinHandles for graceful shutdown
     let mut background_tasks: Vec<JoinHandle<()>> = Vec::new();
 
@@ -75,10 +98,11 @@ async fn main() -> std::io::Result<()> {
         tracing::info!("Confirmation worker skipped — EVM executor not configured");
     }
 
-    // 2. Proof of Reserves attestation (every 6h)
+    // 2. Proof of Reserves attestation (every 6h), snapshotting reserve history
     {
         let custody = app_state.custody.clone();
         let executor = app_state.evm_executor.clone();
+        let reserves = ReserveRepository::new(app_state.db_pool.as_ref().clone());
         let handle = tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(6 * 3600));
             loop {
@@ -88,6 +112,9 @@ async fn main() -> std::io::Result<()> {
                         tracing::info!(total_usd = %total_usd, "PoR attestation: custody total retrieved");
                         // H.3: Update custody balance metric
                         metrics::set_custody_balance("total", total_usd.to_string().parse::<f64>().unwrap_or(0.0));
+                        if let Err(e) = reserves.record_active().await {
+                            tracing::warn!(error = %e, "PoR attestation: failed to record reserve snapshots");
+                        }
                         if let Some(ref exec) = executor {
                             let value_units = (total_usd * Decimal::from(100))
                                 .to_string()
@@ -107,42 +134,132 @@ async fn main() -> std::io::Result<()> {
         tracing::info!("PoR attestation worker spawned (interval: 6h)");
     }
 
-    // 3. Session cleanup (every 1h — remove expired sessions)
+    // 3. Session retention purge (SESSION_PURGE_INTERVAL_SECS, default 1h)
+    {
+        let sessions = SessionRepository::new(app_state.db_pool.as_ref().clone());
+        let purge_interval = Duration::from_secs(config.session_purge_interval_secs);
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(purge_interval);
+            loop {
+                interval.tick().await;
+                match sessions.delete_expired_sessions().await {
+                    Ok(purged) => {
+                        if purged > 0 {
+                            tracing::info!(purged, "Session purge: expired sessions removed");
+                        }
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Session purge failed"),
+                }
+            }
+        });
+        background_tasks.push(handle);
+        tracing::info!(
+            interval_secs = config.session_purge_interval_secs,
+            "Session purge worker spawned"
+        );
+    }
+
+    // 4. Sanctions list hot reload (polls file mtime every 60s)
+    if let Some(ref path) = config.sanctions_list_path {
+        let handle = spawn_sanctions_list_reloader(
+            app_state.compliance.clone(),
+            path.into(),
+            Duration::from_secs(60),
+        );
+        background_tasks.push(handle);
+        tracing::info!(path = %path, "Sanctions list reloader spawned (poll interval: 60s)");
+    }
+
+    // 5. Oracle price refresh (ORACLE_REFRESH_INTERVAL_SECS, default 60s) —
+    //    keeps the cached prices mint/burn read warm
+    if let Some(ref oracle) = *app_state.oracle.read().await {
+        let handle = Arc::clone(oracle)
+            .spawn_refresh_task(Duration::from_secs(config.oracle_refresh_interval_secs));
+        background_tasks.push(handle);
+        tracing::info!(
+            interval_secs = config.oracle_refresh_interval_secs,
+            "Oracle price refresh worker spawned"
+        );
+    }
+
+    // 6. Settlement (every 5 min) — marks completed operations SETTLED once
+    //    their settlement date passes, and failed ones settlement-FAILED
     {
-        let db_pool = app_state.db_pool.clone();
+        let pool = app_state.db_pool.clone();
         let handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(3600));
+            let mut interval = tokio::time::interval(Duration::from_secs(300));
             loop {
                 interval.tick().await;
-                match sqlx::query("DELETE FROM sessions WHERE expires_at < NOW()")
-                    .execute(db_pool.as_ref())
-                    .await
-                {
-                    Ok(r) => {
-                        if r.rows_affected() > 0 {
-                            tracing::info!(expired = r.rows_affected(), "Session cleanup: expired sessions removed");
+                match settle_due_operations(&pool, chrono::Utc::now()).await {
+                    Ok(run) => {
+                        if run.settled > 0 || run.failed > 0 {
+                            tracing::info!(settled = run.settled, failed = run.failed, "Settlement run completed");
                         }
                     }
-                    Err(e) => tracing::warn!(error = %e, "Session cleanup query failed"),
+                    Err(e) => tracing::warn!(error = %e, "Settlement run failed"),
                 }
             }
         });
         background_tasks.push(handle);
-        tracing::info!("Session cleanup worker spawned (interval: 1h)");
+        tracing::info!("Settlement worker spawned (interval: 5m)");
+    }
+
+    // 7. Supply reconciliation (SUPPLY_RECONCILIATION_INTERVAL_SECS, default 1h) —
+    //    compares DB total_supply with on-chain totalSupply() on the chains in
+    //    SUPPLY_RECONCILIATION_CHAINS and records any discrepancy
+    let reconciler = SupplyReconciler::from_env();
+    if !reconciler.is_empty() {
+        // Count mismatches so they can be alerted on
+        let mut events = reconciler.subscribe();
+        background_tasks.push(tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(SupplyEvent::SupplyMismatch { symbol, chain_id, .. }) => {
+                        metrics::record_supply_mismatch(&symbol, chain_id);
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(skipped, "Supply mismatch consumer lagged");
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }));
+
+        let pool = app_state.db_pool.clone();
+        let reconcile_interval = Duration::from_secs(config.supply_reconciliation_interval_secs);
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(reconcile_interval);
+            loop {
+                interval.tick().await;
+                match reconciler.reconcile(&pool).await {
+                    Ok(run) => tracing::info!(
+                        checked = run.checked,
+                        mismatched = run.mismatched,
+                        failed = run.failed,
+                        "Supply reconciliation completed"
+                    ),
+                    Err(e) => tracing::warn!(error = %e, "Supply reconciliation run failed"),
+                }
+            }
+        });
+        background_tasks.push(handle);
+        tracing::info!(
+            interval_secs = config.supply_reconciliation_interval_secs,
+            "Supply reconciliation worker spawned"
+        );
+    } else {
+        tracing::info!("Supply reconciliation skipped — SUPPLY_RECONCILIATION_CHAINS not configured");
     }
 
     tracing::info!("Server starting at http://{}:{}", host, port);
 
     // Get CORS allowed origins from environment
-    let cors_origins = std::env::var("CORS_ALLOWED_ORIGINS")
-        .unwrap_or_else(|_| "http://localhost:3000".to_string());
+    let cors_origins = config.cors_allowed_origins.clone();
 
     // Security: Validate CORS origins - reject wildcards in production
-    let is_production = std::env::var("ENVIRONMENT")
-        .map(|e| e.to_lowercase() == "production")
-        .unwrap_or(false);
+    let is_production = config.is_production();
 
-    if is_production && cors_origins.contains('*') {
+    if is_production && cors_origins.iter().any(|o| o.contains('*')) {
         panic!("SECURITY: Wildcard CORS origins (*) are not allowed in production");
     }
 
@@ -172,6 +289,18 @@ async fn main() -> std::io::Result<()> {
             Ok(_) => {}
         }
 
+        // Proof-of-reserves leaves hash user IDs with this salt; a short or
+        // missing one would let published leaves be mapped back to users
+        match std::env::var("PROOF_OF_RESERVES_SALT") {
+            Ok(salt) if salt.len() < 32 => {
+                panic!("SECURITY: PROOF_OF_RESERVES_SALT must be at least 32 bytes, got {} bytes", salt.len());
+            }
+            Err(_) => {
+                panic!("SECURITY: PROOF_OF_RESERVES_SALT must be set in production");
+            }
+            Ok(_) => {}
+        }
+
         if std::env::var("WALLET_SERVICE_URL").is_err() {
             tracing::warn!(
                 "WALLET_SERVICE_URL not set - agent wallet creation will fail in production"
@@ -190,31 +319,24 @@ async fn main() -> std::io::Result<()> {
         tracing::info!("Production security checks passed (API_KEY_SALT, SESSION_TOKEN_SALT, COMPLIANCE validated)");
     }
 
-    tracing::info!("CORS allowed origins: {}", cors_origins);
+    tracing::info!("CORS allowed origins: {}", cors_origins.join(","));
 
     // Configure rate limiting: ~100 requests per minute per IP
-    // per_second(2) = 2 tokens/sec = 120/min, burst_size(10) = max burst
     let governor_config = GovernorConfigBuilder::default()
-        .per_second(2)
-        .burst_size(10)
+        .per_second(config.rate_limit_per_second)
+        .burst_size(config.rate_limit_burst)
         .finish()
         .expect("Failed to build rate limiter config");
 
     tracing::info!("Rate limiting enabled: ~100 requests/minute per IP");
 
     // Configure request size limits
-    let json_limit = std::env::var("MAX_JSON_PAYLOAD_SIZE")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(256 * 1024); // Default 256KB
+    let json_limit = config.json_payload_limit;
 
     tracing::info!("JSON payload limit: {} bytes", json_limit);
 
     // HIGH-009: Configure HTTP request timeout to prevent hanging requests
-    let request_timeout_secs = std::env::var("HTTP_REQUEST_TIMEOUT_SECS")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(60u64); // Default 60 seconds
+    let request_timeout_secs = config.request_timeout_secs;
 
     tracing::info!("HTTP request timeout: {} seconds", request_timeout_secs);
 
@@ -240,11 +362,8 @@ async fn main() -> std::io::Result<()> {
             .max_age(3600);
 
         // Add allowed origins from environment
-        for origin in cors_origins.split(',') {
-            let origin = origin.trim();
-            if !origin.is_empty() {
-                cors = cors.allowed_origin(origin);
-            }
+        for origin in &cors_origins {
+            cors = cors.allowed_origin(origin);
         }
 
         // Configure JSON payload limit
@@ -273,6 +392,10 @@ async fn main() -> std::io::Result<()> {
             .app_data(web::Data::new(app_state.clone()))
             .app_data(json_cfg)
             .wrap(security_headers)
+            // RFC 7807 error bodies for `Accept: application/problem+json` (inside CorrelationId)
+            .wrap(ProblemJsonMiddleware::new())
+            // Per-request query count / DB time warnings (inside CorrelationId)
+            .wrap(QueryMetricsMiddleware::from_env())
             // HIGH-010: Add rate limit headers (X-RateLimit-Limit, X-RateLimit-Remaining, X-RateLimit-Reset)
             .wrap(RateLimitHeadersMiddleware::new())
             .wrap(CorrelationIdMiddleware::new())
@@ -295,8 +418,8 @@ async fn main() -> std::io::Result<()> {
     .bind((host.as_str(), port))?
     // HIGH-009: HTTP timeouts to prevent hanging requests
     .client_request_timeout(Duration::from_secs(request_timeout_secs))
-    .client_disconnect_timeout(Duration::from_secs(5))
-    .keep_alive(Duration::from_secs(75)) // Keep-alive slightly longer than client timeout
+    .client_disconnect_timeout(Duration::from_secs(config.client_disconnect_timeout_secs))
+    .keep_alive(Duration::from_secs(config.keep_alive_secs)) // Keep-alive slightly longer than client timeout
     .run();
 
     let server_handle = server.handle();
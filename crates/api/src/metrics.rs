@@ -7,8 +7,11 @@
 //!   meridian_reserve_ratio         — Gauge    {currency}
 //!   meridian_attestation_age_secs  — Gauge    (seconds since last on-chain attestation)
 //!   meridian_custody_balance       — Gauge    {asset}
+//!   oracle_feed_age_seconds        — Gauge    {pair} (seconds since the feed last updated)
+//!   meridian_supply_mismatches_total — Counter {symbol, chain_id} (DB vs on-chain supply)
 
 use crate::telemetry::prometheus_registry;
+use meridian_oracle::FeedStaleness;
 use prometheus::{Gauge, GaugeVec, IntCounterVec, Opts};
 use std::sync::OnceLock;
 
@@ -16,6 +19,8 @@ static OPERATIONS_TOTAL: OnceLock<IntCounterVec> = OnceLock::new();
 static RESERVE_RATIO: OnceLock<GaugeVec> = OnceLock::new();
 static ATTESTATION_AGE_SECS: OnceLock<Gauge> = OnceLock::new();
 static CUSTODY_BALANCE: OnceLock<GaugeVec> = OnceLock::new();
+static ORACLE_FEED_AGE_SECS: OnceLock<GaugeVec> = OnceLock::new();
+static SUPPLY_MISMATCHES_TOTAL: OnceLock<IntCounterVec> = OnceLock::new();
 
 /// Register all business metrics against the global Prometheus registry.
 /// Safe to call multiple times — subsequent calls are no-ops.
@@ -74,6 +79,34 @@ pub fn init_metrics() {
         registry.register(Box::new(gauge.clone())).ok();
         CUSTODY_BALANCE.set(gauge).ok();
     }
+
+    // oracle_feed_age_seconds{pair="EUR/USD|..."}  — age of each cached oracle price
+    if ORACLE_FEED_AGE_SECS.get().is_none() {
+        let gauge = GaugeVec::new(
+            Opts::new(
+                "oracle_feed_age_seconds",
+                "Seconds since each oracle price feed was last updated",
+            ),
+            &["pair"],
+        )
+        .expect("Failed to create oracle feed age gauge");
+        registry.register(Box::new(gauge.clone())).ok();
+        ORACLE_FEED_AGE_SECS.set(gauge).ok();
+    }
+
+    // meridian_supply_mismatches_total{symbol="EURM|...", chain_id="1|..."}
+    if SUPPLY_MISMATCHES_TOTAL.get().is_none() {
+        let counter = IntCounterVec::new(
+            Opts::new(
+                "meridian_supply_mismatches_total",
+                "Supply reconciliation mismatches between DB and on-chain supply",
+            ),
+            &["symbol", "chain_id"],
+        )
+        .expect("Failed to create supply mismatch counter");
+        registry.register(Box::new(counter.clone())).ok();
+        SUPPLY_MISMATCHES_TOTAL.set(counter).ok();
+    }
 }
 
 /// Increment the operations counter.
@@ -107,3 +140,56 @@ pub fn set_custody_balance(asset: &str, usd_value: f64) {
         gauge.with_label_values(&[asset]).set(usd_value);
     }
 }
+
+/// Set the age gauge for every feed in an oracle staleness summary.
+pub fn record_oracle_feed_ages(summary: &[FeedStaleness]) {
+    if let Some(gauge) = ORACLE_FEED_AGE_SECS.get() {
+        for feed in summary {
+            gauge.with_label_values(&[&feed.pair]).set(feed.age_seconds as f64);
+        }
+    }
+}
+
+/// Increment the supply mismatch counter for a stablecoin on a chain.
+pub fn record_supply_mismatch(symbol: &str, chain_id: i32) {
+    if let Some(counter) = SUPPLY_MISMATCHES_TOTAL.get() {
+        counter.with_label_values(&[symbol, &chain_id.to_string()]).inc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::prometheus_metrics;
+
+    #[test]
+    fn test_oracle_feed_age_metric_reflects_summary() {
+        init_metrics();
+        record_oracle_feed_ages(&[FeedStaleness {
+            pair: "TST/USD".to_string(),
+            age_seconds: 7200,
+            is_stale: true,
+        }]);
+
+        let output = prometheus_metrics();
+        assert!(
+            output.contains("oracle_feed_age_seconds{pair=\"TST/USD\"} 7200"),
+            "missing feed age in:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_supply_mismatch_metric_counts_events() {
+        init_metrics();
+        record_supply_mismatch("TSTM", 11155111);
+        record_supply_mismatch("TSTM", 11155111);
+
+        let output = prometheus_metrics();
+        assert!(
+            output.contains("meridian_supply_mismatches_total{chain_id=\"11155111\",symbol=\"TSTM\"} 2"),
+            "missing supply mismatches in:\n{}",
+            output
+        );
+    }
+}
@@ -1,14 +1,18 @@
 //! Middleware components for the Meridian API
 //!
-//! Includes correlation ID propagation for distributed tracing
-//! and rate limit headers for API responses.
+//! Includes correlation ID propagation for distributed tracing,
+//! rate limit headers for API responses, per-request query metrics, and
+//! opt-in RFC 7807 problem+json error bodies.
 
+use actix_web::body::EitherBody;
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
 use actix_web::http::header::{HeaderName, HeaderValue};
 use actix_web::{Error, HttpMessage};
+use crate::error::{accepts_problem_json, ApiError};
+use crate::query_metrics::{with_query_stats, QueryMetricsConfig, QueryStats};
 use std::future::{ready, Future, Ready};
 use std::pin::Pin;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
 use uuid::Uuid;
 
@@ -706,6 +710,186 @@ fn extract_origin_from_url(url: &str) -> Option<String> {
     }
 }
 
+// ============================================================================
+// Per-request query count / slow-query instrumentation
+// ============================================================================
+
+/// Middleware that counts `track_query` calls made while handling a request.
+///
+/// Logs a warning tagged with the correlation ID when the request exceeds the
+/// configured query count or total DB time. Must be registered *before*
+/// `CorrelationIdMiddleware` (i.e. wrapped inside it) so the ID is available.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QueryMetricsMiddleware {
+    config: QueryMetricsConfig,
+}
+
+impl QueryMetricsMiddleware {
+    /// Create with explicit thresholds
+    pub fn new(config: QueryMetricsConfig) -> Self {
+        Self { config }
+    }
+
+    /// Create with thresholds from the environment
+    pub fn from_env() -> Self {
+        Self::new(QueryMetricsConfig::from_env())
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for QueryMetricsMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = QueryMetricsService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(QueryMetricsService {
+            service,
+            config: self.config,
+        }))
+    }
+}
+
+/// The actual service that scopes query stats to each request
+pub struct QueryMetricsService<S> {
+    service: S,
+    config: QueryMetricsConfig,
+}
+
+impl<S, B> Service<ServiceRequest> for QueryMetricsService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let correlation_id = req
+            .extensions()
+            .get::<CorrelationId>()
+            .map(|c| c.as_str().to_string())
+            .unwrap_or_default();
+        let method = req.method().to_string();
+        let path = req.path().to_string();
+        let config = self.config;
+        let stats = Arc::new(QueryStats::default());
+
+        let fut = self.service.call(req);
+
+        Box::pin(async move {
+            let res = with_query_stats(stats.clone(), fut).await?;
+
+            if config.is_exceeded(&stats) {
+                tracing::warn!(
+                    correlation_id = %correlation_id,
+                    method = %method,
+                    path = %path,
+                    query_count = stats.count(),
+                    db_time_ms = stats.total_time().as_millis() as u64,
+                    max_queries = config.max_queries_per_request,
+                    max_db_time_ms = config.max_db_time_ms,
+                    "Request exceeded query thresholds (possible N+1)"
+                );
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+// ============================================================================
+// RFC 7807 problem+json error rendering
+// ============================================================================
+
+/// Middleware that re-renders `ApiError` responses as `application/problem+json`
+/// when the client sends a matching `Accept` header.
+///
+/// Handlers keep returning `ApiError` as usual; everyone else still gets the
+/// default `ErrorResponse` envelope. Must be registered *before*
+/// `CorrelationIdMiddleware` (i.e. wrapped inside it) so `instance` is set.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProblemJsonMiddleware;
+
+impl ProblemJsonMiddleware {
+    /// Create a new problem+json middleware instance
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ProblemJsonMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = ProblemJsonService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(ProblemJsonService { service }))
+    }
+}
+
+/// The actual service that swaps error bodies for problem details
+pub struct ProblemJsonService<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for ProblemJsonService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let wants_problem = accepts_problem_json(req.request());
+        let fut = self.service.call(req);
+
+        Box::pin(async move {
+            let res = fut.await?;
+            if !wants_problem {
+                return Ok(res.map_into_left_body());
+            }
+
+            let problem = res
+                .response()
+                .error()
+                .and_then(|e| e.as_error::<ApiError>())
+                .map(|e| e.to_problem_response(res.request()));
+
+            match problem {
+                Some(problem) => Ok(res.into_response(problem).map_into_right_body()),
+                None => Ok(res.map_into_left_body()),
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod csrf_tests {
     use super::*;
@@ -925,3 +1109,137 @@ mod rate_limit_tests {
         assert_eq!(limit, "1000");
     }
 }
+
+#[cfg(test)]
+mod problem_json_tests {
+    use super::*;
+    use crate::error::PROBLEM_JSON;
+    use actix_web::{test, web, App};
+
+    async fn bad_request_handler() -> Result<&'static str, ApiError> {
+        Err(ApiError::BadRequest("Amount must be greater than zero".to_string()))
+    }
+
+    fn request(accept: Option<&str>) -> test::TestRequest {
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((CORRELATION_ID_HEADER, "corr-123"));
+        match accept {
+            Some(accept) => req.insert_header(("Accept", accept)),
+            None => req,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_renders_problem_json_for_400_when_accepted() {
+        let app = test::init_service(
+            App::new()
+                .wrap(ProblemJsonMiddleware::new())
+                .wrap(CorrelationIdMiddleware::new())
+                .route("/", web::get().to(bad_request_handler)),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            request(Some("application/problem+json, application/json;q=0.5")).to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 400);
+        assert_eq!(resp.headers().get("content-type").unwrap(), PROBLEM_JSON);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "type": "urn:meridian:error:bad_request",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "Bad request: Amount must be greater than zero",
+                "instance": "corr-123",
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_keeps_default_envelope_without_accept_header() {
+        let app = test::init_service(
+            App::new()
+                .wrap(ProblemJsonMiddleware::new())
+                .wrap(CorrelationIdMiddleware::new())
+                .route("/", web::get().to(bad_request_handler)),
+        )
+        .await;
+
+        let resp = test::call_service(&app, request(Some("application/json")).to_request()).await;
+        assert_eq!(resp.status(), 400);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "application/json");
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"], "bad_request");
+        assert!(body.get("type").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_to_response_honours_accept_header() {
+        let error = ApiError::BadRequest("bad".to_string());
+
+        let req = request(Some(PROBLEM_JSON)).to_http_request();
+        assert_eq!(
+            error.to_response(&req).headers().get("content-type").unwrap(),
+            PROBLEM_JSON
+        );
+
+        let req = request(None).to_http_request();
+        assert_eq!(
+            error.to_response(&req).headers().get("content-type").unwrap(),
+            "application/json"
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_metrics_tests {
+    use super::*;
+    use crate::query_metrics::{current_query_stats, track_query};
+    use actix_web::{test, web, App, HttpResponse};
+
+    /// Issues several "queries" and reports how many were counted
+    async fn multi_query_handler() -> HttpResponse {
+        for _ in 0..3 {
+            track_query(async {}).await;
+        }
+        let count = current_query_stats().map(|s| s.count()).unwrap_or_default();
+        HttpResponse::Ok().body(count.to_string())
+    }
+
+    #[actix_web::test]
+    async fn test_counts_queries_per_request() {
+        let app = test::init_service(
+            App::new()
+                .wrap(QueryMetricsMiddleware::new(QueryMetricsConfig::default()))
+                .wrap(CorrelationIdMiddleware::new())
+                .route("/", web::get().to(multi_query_handler)),
+        )
+        .await;
+
+        // Each request gets a fresh counter
+        for _ in 0..2 {
+            let req = test::TestRequest::get().uri("/").to_request();
+            let body = test::call_and_read_body(&app, req).await;
+            assert_eq!(body, "3");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_no_scope_without_middleware() {
+        let app = test::init_service(
+            App::new().route("/", web::get().to(multi_query_handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/").to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "0");
+    }
+}
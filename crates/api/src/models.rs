@@ -1,6 +1,7 @@
 //! Request and response models for the API
 
 use meridian_basket::{BasketType, CurrencyBasket, RebalanceStrategy};
+use meridian_compliance::{CustomerCompliance, RegulatoryFramework};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -18,9 +19,15 @@ pub struct CreateSingleCurrencyBasketRequest {
     /// ISO currency code (e.g., "EUR", "GBP", "JPY")
     #[schema(example = "EUR")]
     pub currency_code: String,
-    /// Chainlink price feed address on Ethereum
+    /// Chainlink price feed address on Ethereum; resolved from the known
+    /// feeds catalog when omitted
+    #[serde(default)]
     #[schema(example = "0x1a81afB8146aeFfCFc5E50e8479e826E7D55b910")]
-    pub chainlink_feed: String,
+    pub chainlink_feed: Option<String>,
+    /// Minimum oracle price confidence (0-1) required to value the basket
+    #[serde(default)]
+    #[schema(example = "0.9", value_type = Option<String>)]
+    pub min_price_confidence: Option<Decimal>,
 }
 
 /// Request to create an IMF SDR basket
@@ -29,8 +36,14 @@ pub struct CreateImfSdrBasketRequest {
     /// Name of the basket (e.g., "IMF SDR Basket")
     #[schema(example = "IMF SDR Basket")]
     pub name: String,
-    /// Map of currency codes to Chainlink feed addresses
+    /// Map of currency codes to Chainlink feed addresses; currencies left out
+    /// are resolved from the known feeds catalog
+    #[serde(default)]
     pub chainlink_feeds: HashMap<String, String>,
+    /// Minimum oracle price confidence (0-1) required to value the basket
+    #[serde(default)]
+    #[schema(example = "0.9", value_type = Option<String>)]
+    pub min_price_confidence: Option<Decimal>,
 }
 
 /// Request to create a custom basket
@@ -43,6 +56,10 @@ pub struct CreateCustomBasketRequest {
     pub components: Vec<ComponentRequest>,
     /// Rebalancing strategy for the basket
     pub rebalance_strategy: RebalanceStrategyRequest,
+    /// Minimum oracle price confidence (0-1) required to value the basket
+    #[serde(default)]
+    #[schema(example = "0.9", value_type = Option<String>)]
+    pub min_price_confidence: Option<Decimal>,
 }
 
 /// Currency component in a basket
@@ -60,9 +77,11 @@ pub struct ComponentRequest {
     /// Maximum allowed weight (0.0-1.0)
     #[schema(example = "0.60", value_type = String)]
     pub max_weight: Decimal,
-    /// Chainlink price feed address
+    /// Chainlink price feed address; resolved from the known feeds catalog
+    /// when omitted
+    #[serde(default)]
     #[schema(example = "0x1a81afB8146aeFfCFc5E50e8479e826E7D55b910")]
-    pub chainlink_feed: String,
+    pub chainlink_feed: Option<String>,
 }
 
 /// Rebalancing strategy
@@ -116,6 +135,9 @@ pub struct BasketResponse {
     /// Rebalancing strategy description
     #[schema(example = "none")]
     pub rebalance_strategy: String,
+    /// Minimum oracle price confidence (0-1) required for valuation
+    #[schema(value_type = Option<String>)]
+    pub min_price_confidence: Option<Decimal>,
     /// ISO 8601 creation timestamp
     #[schema(example = "2025-01-01T12:00:00Z")]
     pub created_at: String,
@@ -152,6 +174,7 @@ impl From<CurrencyBasket> for BasketResponse {
             basket_type,
             components,
             rebalance_strategy,
+            min_price_confidence: basket.min_price_confidence,
             created_at: basket.created_at.to_rfc3339(),
         }
     }
@@ -197,6 +220,14 @@ pub struct BasketValueResponse {
     /// Current basket value in USD
     #[schema(value_type = String)]
     pub value_usd: Decimal,
+    /// `value_usd` formatted for the requested locale, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "1,234,567.89")]
+    pub value_usd_formatted: Option<String>,
+    /// Locale used for `*_formatted` fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "en-US")]
+    pub locale: Option<String>,
     /// Prices used for calculation (currency -> price)
     #[schema(value_type = Object)]
     pub prices_used: HashMap<String, Decimal>,
@@ -205,6 +236,114 @@ pub struct BasketValueResponse {
     /// ISO 8601 calculation timestamp
     #[schema(example = "2025-01-01T12:00:00Z")]
     pub calculated_at: String,
+    /// Currency requested via `?base=`, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "EUR")]
+    pub base_currency: Option<String>,
+    /// Basket value in `base_currency`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub value_base: Option<Decimal>,
+    /// Units of `base_currency` per USD used for the conversion
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub base_rate: Option<Decimal>,
+}
+
+/// Query parameters for basket valuation
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct BasketValueQuery {
+    /// Also report the value in this currency (e.g. `EUR`); USD if omitted
+    pub base: Option<String>,
+}
+
+// ============ Stablecoin Models ============
+
+/// Stablecoin definition
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Stablecoin {
+    /// Unique stablecoin identifier
+    pub id: Uuid,
+    /// Stablecoin name
+    #[schema(example = "EUR Meridian")]
+    pub name: String,
+    /// Token symbol
+    #[schema(example = "EURM")]
+    pub symbol: String,
+    /// Token decimals
+    #[schema(example = 6)]
+    pub decimals: i16,
+    /// ISO 4217 code of the pegged currency (absent for legacy records)
+    #[schema(example = "EUR")]
+    pub peg_currency: Option<String>,
+    /// Backing basket, if linked
+    pub basket_id: Option<Uuid>,
+    /// EVM chain ID the token is deployed on
+    #[schema(example = 11155111)]
+    pub chain_id: i32,
+    /// Deployed contract address, once known
+    pub contract_address: Option<String>,
+    /// Lifecycle status (deploying, active, paused, deprecated)
+    #[schema(example = "active")]
+    pub status: String,
+    /// ISO 8601 creation timestamp
+    #[schema(example = "2025-01-01T12:00:00Z")]
+    pub created_at: String,
+}
+
+impl From<meridian_db::StablecoinRow> for Stablecoin {
+    fn from(row: meridian_db::StablecoinRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            symbol: row.symbol,
+            decimals: row.decimals,
+            peg_currency: row.peg_currency,
+            basket_id: row.basket_id,
+            chain_id: row.chain_id,
+            contract_address: row.contract_address,
+            status: row.status,
+            created_at: row.created_at.to_rfc3339(),
+        }
+    }
+}
+
+// ============ Compliance Models ============
+
+/// Consolidated compliance view of a customer
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerComplianceResponse {
+    /// User identifier
+    pub user_id: i32,
+    /// Underlying compliance record with frameworks and EDD resolved
+    #[schema(value_type = Object)]
+    pub record: CustomerCompliance,
+    /// Regulatory frameworks applicable to the customer's jurisdiction
+    #[schema(value_type = Vec<String>, example = json!(["MiCA"]))]
+    pub frameworks: Vec<RegulatoryFramework>,
+    /// Whether enhanced due diligence is required
+    pub edd_required: bool,
+    /// Whether the periodic review is overdue
+    pub review_due: bool,
+    /// Whether KYC has expired (or was never completed)
+    pub kyc_expired: bool,
+    /// Whether the customer may currently mint or burn
+    pub can_transact: bool,
+}
+
+impl CustomerComplianceResponse {
+    /// Compose the response, deriving every flag from the record itself
+    pub fn new(user_id: i32, record: CustomerCompliance) -> Self {
+        Self {
+            user_id,
+            frameworks: record.frameworks.clone(),
+            edd_required: record.edd_required,
+            review_due: record.is_review_due(),
+            kyc_expired: record.is_kyc_expired(),
+            can_transact: record.can_transact(),
+            record,
+        }
+    }
 }
 
 // ============ Oracle Models ============
@@ -255,6 +394,9 @@ pub struct RegisterFeedRequest {
     /// Chainlink price feed contract address
     #[schema(example = "0x1a81afB8146aeFfCFc5E50e8479e826E7D55b910")]
     pub chainlink_address: String,
+    /// Decimals the feed must report; updates are rejected on mismatch
+    #[schema(example = 8)]
+    pub expected_decimals: Option<u8>,
 }
 
 // ============ Health Check ============
@@ -270,10 +412,26 @@ pub struct HealthResponse {
     pub version: String,
     /// Whether the oracle is configured
     pub oracle_enabled: bool,
+    /// Whether every oracle feed is within the freshness SLO (true when no oracle)
+    pub oracle_feeds_fresh: bool,
     /// Number of active baskets
     pub baskets_count: usize,
 }
 
+/// Build and dependency versions
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VersionResponse {
+    /// API crate version
+    #[schema(example = "0.1.0")]
+    pub version: String,
+    /// Git commit the binary was built from ("unknown" if not recorded)
+    #[schema(example = "3f2c1a9b7d4e")]
+    pub git_sha: String,
+    /// `SELECT version()` of the connected database (null if unreachable)
+    #[schema(example = "PostgreSQL 16.2 on x86_64-pc-linux-gnu")]
+    pub database_version: Option<String>,
+}
+
 // ============ Pagination ============
 
 /// CRIT-013: Pagination query parameters with safe defaults
@@ -3,13 +3,15 @@
 
 use utoipa::OpenApi;
 
-use meridian_api::handlers::{baskets, health, oracle, reserves};
+use meridian_api::attestation::{Attestation, AttestedReserve};
+use meridian_api::handlers::{baskets, compliance, health, oracle, reserves, stablecoins};
 use meridian_api::models::{
     BasketResponse, BasketValueResponse, ComponentRequest, ComponentResponse,
     CreateCustomBasketRequest, CreateImfSdrBasketRequest, CreateSingleCurrencyBasketRequest,
-    HealthResponse, PaginationQuery, PriceData, PriceResponse, PricesResponse,
-    RebalanceStrategyRequest, RegisterFeedRequest,
+    CustomerComplianceResponse, HealthResponse, PaginationQuery, PriceData, PriceResponse, PricesResponse,
+    RebalanceStrategyRequest, RegisterFeedRequest, Stablecoin, VersionResponse,
 };
+use meridian_api::proof_of_reserves::SiblingPosition;
 
 /// Meridian API OpenAPI specification
 #[derive(OpenApi)]
@@ -35,16 +37,19 @@ use meridian_api::models::{
         (name = "health", description = "Health check and metrics endpoints"),
         (name = "auth", description = "Authentication and session management"),
         (name = "baskets", description = "Currency basket management"),
+        (name = "stablecoins", description = "Stablecoin metadata"),
         (name = "oracle", description = "Price feed and oracle operations"),
         (name = "reserves", description = "Reserve attestation and verification"),
         (name = "operations", description = "Mint and burn operations"),
         (name = "kyc", description = "KYC/AML compliance endpoints"),
+        (name = "compliance", description = "Customer compliance state"),
         (name = "agents", description = "AI agent (x402) wallet management")
     ),
     paths(
         // Health
         health::health_check,
         health::metrics,
+        health::version_info,
         // Baskets
         baskets::list_baskets,
         baskets::get_basket,
@@ -52,6 +57,10 @@ use meridian_api::models::{
         baskets::create_single_currency_basket,
         baskets::create_imf_sdr_basket,
         baskets::create_custom_basket,
+        // Stablecoins
+        stablecoins::get_stablecoin,
+        // Compliance
+        compliance::get_customer_compliance,
         // Oracle
         oracle::get_prices,
         oracle::get_price,
@@ -59,7 +68,11 @@ use meridian_api::models::{
         oracle::register_price_feed,
         // Reserves
         reserves::get_reserves,
-        reserves::get_attestation_status,
+        reserves::get_reserve_shortfall,
+        reserves::get_merkle_root,
+        reserves::get_merkle_proof,
+        reserves::get_latest_attestation,
+        reserves::verify_attestation,
     ),
     components(
         schemas(
@@ -72,6 +85,10 @@ use meridian_api::models::{
             BasketResponse,
             ComponentResponse,
             BasketValueResponse,
+            // Stablecoin models
+            Stablecoin,
+            // Compliance models
+            CustomerComplianceResponse,
             // Oracle models
             PriceResponse,
             PricesResponse,
@@ -79,6 +96,7 @@ use meridian_api::models::{
             RegisterFeedRequest,
             // Health models
             HealthResponse,
+            VersionResponse,
             // Pagination
             PaginationQuery,
             // Reserve models
@@ -86,7 +104,16 @@ use meridian_api::models::{
             reserves::BondHolding,
             reserves::CurrencyBreakdown,
             reserves::HistoryPoint,
-            reserves::AttestationStatus,
+            reserves::ReserveShortfall,
+            reserves::ComponentShortfall,
+            reserves::MerkleRootResponse,
+            reserves::MerkleLeaf,
+            reserves::MerkleProofStep,
+            reserves::MerkleProofResponse,
+            SiblingPosition,
+            reserves::AttestationVerification,
+            Attestation,
+            AttestedReserve,
             // Error response
             ErrorResponse,
         )
@@ -0,0 +1,376 @@
+//! Proof of reserves: Merkle tree over user liabilities
+//!
+//! Each user with a positive balance in a currency is a leaf of that
+//! currency's tree. Publishing the root and the total lets any user check,
+//! with the inclusion path from `MerkleTree::proof`, that their balance was
+//! counted in the liabilities the reserves are compared against.
+//!
+//! Leaves identify users by `SHA-256(user_id || PROOF_OF_RESERVES_SALT)`
+//! rather than the sequential user ID, so published leaves and paths can't
+//! be mapped back to accounts without the salt.
+//!
+//! Hashing (all SHA-256):
+//!
+//! ```text
+//! leaf = H(0x00 || user_hash || balance)   balance as a normalized decimal string
+//! node = H(0x01 || left || right)
+//! ```
+//!
+//! The prefixes keep a leaf from being passed off as an inner node. A node
+//! without a sibling is carried up to the next level unchanged.
+//!
+//! `LiabilityTreeCache` keeps the last tree per currency and rebuilds it only
+//! when that currency's completed operations change.
+
+use crate::query_metrics::TrackQuery;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+use utoipa::ToSchema;
+
+/// SHA-256 digest
+pub type Hash = [u8; 32];
+
+/// Domain prefix for leaf hashes
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Domain prefix for inner node hashes
+const NODE_PREFIX: u8 = 0x01;
+
+/// Root of a tree without leaves
+pub const EMPTY_ROOT: Hash = [0u8; 32];
+
+/// Salted identifier for `user_id` in the tree
+///
+/// Uses `PROOF_OF_RESERVES_SALT`, which production startup requires; other
+/// environments fall back to a fixed development salt.
+pub fn hash_user_id(user_id: i32) -> Hash {
+    static SALT: OnceLock<String> = OnceLock::new();
+    let salt = SALT.get_or_init(|| {
+        std::env::var("PROOF_OF_RESERVES_SALT").unwrap_or_else(|_| {
+            tracing::warn!("Using default proof of reserves salt - set PROOF_OF_RESERVES_SALT in production");
+            "dev-por-salt-not-for-production".to_string()
+        })
+    });
+    salted_user_hash(user_id, salt)
+}
+
+fn salted_user_hash(user_id: i32, salt: &str) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(user_id.to_string().as_bytes());
+    hasher.update(salt.as_bytes());
+    hasher.finalize().into()
+}
+
+/// One user's balance in the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiabilityLeaf {
+    pub user_hash: Hash,
+    pub balance: Decimal,
+}
+
+impl LiabilityLeaf {
+    /// Hash of this leaf as stored in the tree
+    pub fn hash(&self) -> Hash {
+        let mut hasher = Sha256::new();
+        hasher.update([LEAF_PREFIX]);
+        hasher.update(self.user_hash);
+        hasher.update(self.balance.normalize().to_string().as_bytes());
+        hasher.finalize().into()
+    }
+}
+
+fn hash_node(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Which side of the running hash a sibling sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SiblingPosition {
+    Left,
+    Right,
+}
+
+/// One step of an inclusion path, from the leaf upwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofStep {
+    pub sibling: Hash,
+    pub position: SiblingPosition,
+}
+
+/// Inclusion proof for one leaf
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerkleProof {
+    pub leaf: LiabilityLeaf,
+    pub path: Vec<ProofStep>,
+}
+
+impl MerkleProof {
+    /// Whether this proof leads from its leaf to `root`
+    pub fn verify(&self, root: &Hash) -> bool {
+        let computed = self.path.iter().fold(self.leaf.hash(), |acc, step| match step.position {
+            SiblingPosition::Left => hash_node(&step.sibling, &acc),
+            SiblingPosition::Right => hash_node(&acc, &step.sibling),
+        });
+        &computed == root
+    }
+}
+
+/// Merkle tree over one currency's liabilities
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    leaves: Vec<LiabilityLeaf>,
+    /// `levels[0]` holds the leaf hashes, the last level the root
+    levels: Vec<Vec<Hash>>,
+    total_liabilities: Decimal,
+}
+
+impl MerkleTree {
+    /// Builds the tree; leaves are ordered by `user_hash`
+    pub fn build(mut leaves: Vec<LiabilityLeaf>) -> Self {
+        leaves.sort_by_key(|leaf| leaf.user_hash);
+        let total_liabilities = leaves.iter().map(|l| l.balance).sum();
+
+        let mut levels = vec![leaves.iter().map(LiabilityLeaf::hash).collect::<Vec<_>>()];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks(2) yields one or two hashes"),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Self { leaves, levels, total_liabilities }
+    }
+
+    /// Root hash, or `EMPTY_ROOT` without leaves
+    pub fn root(&self) -> Hash {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .unwrap_or(EMPTY_ROOT)
+    }
+
+    /// Sum of all leaf balances
+    pub fn total_liabilities(&self) -> Decimal {
+        self.total_liabilities
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Inclusion proof for the leaf with `user_hash`, if present
+    pub fn proof(&self, user_hash: &Hash) -> Option<MerkleProof> {
+        let mut index = self
+            .leaves
+            .binary_search_by(|leaf| leaf.user_hash.cmp(user_hash))
+            .ok()?;
+        let leaf = self.leaves[index].clone();
+
+        let mut path = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if let Some(hash) = level.get(sibling) {
+                path.push(ProofStep {
+                    sibling: *hash,
+                    position: if sibling < index { SiblingPosition::Left } else { SiblingPosition::Right },
+                });
+            }
+            index /= 2;
+        }
+
+        Some(MerkleProof { leaf, path })
+    }
+}
+
+/// Builds the liability tree for `currency` from completed operations
+///
+/// A user's balance is their completed mints less their completed burns;
+/// users at zero (or, defensively, below) are left out.
+pub async fn build_liability_tree(pool: &PgPool, currency: &str) -> Result<MerkleTree, sqlx::Error> {
+    let balances: Vec<(i32, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT user_id,
+               SUM(CASE WHEN operation_type = 'MINT' THEN amount ELSE -amount END) AS balance
+        FROM operations
+        WHERE UPPER(currency) = UPPER($1)
+        AND status = 'COMPLETED'
+        GROUP BY user_id
+        HAVING SUM(CASE WHEN operation_type = 'MINT' THEN amount ELSE -amount END) > 0
+        "#,
+    )
+    .bind(currency)
+    .fetch_all(pool)
+    .tracked()
+    .await?;
+
+    Ok(MerkleTree::build(
+        balances
+            .into_iter()
+            .map(|(user_id, balance)| LiabilityLeaf {
+                user_hash: hash_user_id(user_id),
+                balance,
+            })
+            .collect(),
+    ))
+}
+
+/// What a currency's tree was built from; any change to its completed
+/// operations changes at least one of these
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+struct OperationsFingerprint {
+    count: i64,
+    max_id: Option<i32>,
+    last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    net_amount: Option<Decimal>,
+}
+
+async fn operations_fingerprint(pool: &PgPool, currency: &str) -> Result<OperationsFingerprint, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT COUNT(*) AS count,
+               MAX(id) AS max_id,
+               MAX(updated_at) AS last_updated,
+               SUM(CASE WHEN operation_type = 'MINT' THEN amount ELSE -amount END) AS net_amount
+        FROM operations
+        WHERE UPPER(currency) = UPPER($1)
+        AND status = 'COMPLETED'
+        "#,
+    )
+    .bind(currency)
+    .fetch_one(pool)
+    .tracked()
+    .await
+}
+
+/// Liability trees per currency, rebuilt when their operations change
+#[derive(Debug, Default)]
+pub struct LiabilityTreeCache {
+    trees: RwLock<HashMap<String, (OperationsFingerprint, Arc<MerkleTree>)>>,
+}
+
+impl LiabilityTreeCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The liability tree for `currency`, built only if its completed
+    /// operations changed since the cached tree was built
+    ///
+    /// The fingerprint is read before building, so a tree that raced a
+    /// change is at least as new as its fingerprint and is rebuilt next time.
+    pub async fn get_or_build(&self, pool: &PgPool, currency: &str) -> Result<Arc<MerkleTree>, sqlx::Error> {
+        let currency = currency.to_uppercase();
+        let fingerprint = operations_fingerprint(pool, &currency).await?;
+        {
+            let trees = self.trees.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Some((built_from, tree)) = trees.get(&currency) {
+                if *built_from == fingerprint {
+                    return Ok(Arc::clone(tree));
+                }
+            }
+        }
+
+        let tree = Arc::new(build_liability_tree(pool, &currency).await?);
+        self.trees
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(currency, (fingerprint, Arc::clone(&tree)));
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: i32) -> Vec<LiabilityLeaf> {
+        (1..=n)
+            .map(|user_id| LiabilityLeaf {
+                user_hash: salted_user_hash(user_id, "test-salt"),
+                balance: Decimal::new(user_id as i64 * 1000, 2),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_every_proof_verifies_against_root() {
+        for n in 1..=9 {
+            let tree = MerkleTree::build(leaves(n));
+            let root = tree.root();
+            assert_eq!(tree.leaf_count(), n as usize);
+
+            for leaf in leaves(n) {
+                let proof = tree.proof(&leaf.user_hash).expect("leaf is in the tree");
+                assert_eq!(proof.leaf, leaf);
+                assert!(proof.verify(&root), "proof for {} of {} leaves", leaf.balance, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_total_liabilities_and_empty_tree() {
+        let tree = MerkleTree::build(leaves(4));
+        assert_eq!(tree.total_liabilities(), Decimal::new(10000, 2));
+
+        let empty = MerkleTree::build(Vec::new());
+        assert_eq!(empty.root(), EMPTY_ROOT);
+        assert_eq!(empty.total_liabilities(), Decimal::ZERO);
+        assert!(empty.proof(&salted_user_hash(1, "test-salt")).is_none());
+    }
+
+    #[test]
+    fn test_tampered_proof_fails() {
+        let tree = MerkleTree::build(leaves(5));
+        let root = tree.root();
+        let proof = tree.proof(&salted_user_hash(3, "test-salt")).unwrap();
+
+        let mut inflated = proof.clone();
+        inflated.leaf.balance += Decimal::ONE;
+        assert!(!inflated.verify(&root));
+
+        let mut swapped = proof.clone();
+        swapped.path[0].position = match swapped.path[0].position {
+            SiblingPosition::Left => SiblingPosition::Right,
+            SiblingPosition::Right => SiblingPosition::Left,
+        };
+        assert!(!swapped.verify(&root));
+
+        // A leaf dropped from the tree changes the root
+        let smaller = MerkleTree::build(leaves(4));
+        assert!(!smaller.proof(&salted_user_hash(3, "test-salt")).unwrap().verify(&root));
+    }
+
+    #[test]
+    fn test_root_independent_of_input_order_and_balance_scale() {
+        let mut reversed = leaves(6);
+        reversed.reverse();
+        for leaf in &mut reversed {
+            leaf.balance.rescale(6);
+        }
+        assert_eq!(MerkleTree::build(reversed).root(), MerkleTree::build(leaves(6)).root());
+    }
+
+    #[test]
+    fn test_user_hash_depends_on_salt() {
+        assert_eq!(salted_user_hash(7, "a"), salted_user_hash(7, "a"));
+        assert_ne!(salted_user_hash(7, "a"), salted_user_hash(7, "b"));
+        assert_ne!(salted_user_hash(7, "a"), salted_user_hash(8, "a"));
+    }
+}
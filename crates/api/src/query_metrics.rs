@@ -0,0 +1,176 @@
+//! Per-request database query instrumentation
+//!
+//! `QueryMetricsMiddleware` opens a task-local `QueryStats` scope around each
+//! request. Database futures wrapped with `track_query` (or `.tracked()`)
+//! bump its counter and DB timer, so requests that issue too many queries or
+//! spend too long in the database are logged with their correlation ID.
+//! This is how N+1 patterns like a per-row `get_daily_spent` surface.
+//!
+//! Every query a handler issues must be wrapped, or the counts undercount;
+//! new handler queries end in `.tracked().await`. Single queries slower than
+//! `meridian_db::slow_query_threshold()` (`SLOW_QUERY_MS`) are logged too.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+tokio::task_local! {
+    static QUERY_STATS: Arc<QueryStats>;
+}
+
+/// Thresholds for query instrumentation warnings
+#[derive(Debug, Clone, Copy)]
+pub struct QueryMetricsConfig {
+    /// Warn when a request issues more queries than this
+    pub max_queries_per_request: u32,
+    /// Warn when a request spends longer than this in the database (total)
+    pub max_db_time_ms: u64,
+}
+
+impl Default for QueryMetricsConfig {
+    fn default() -> Self {
+        Self {
+            max_queries_per_request: 10,
+            max_db_time_ms: 500,
+        }
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default)
+}
+
+impl QueryMetricsConfig {
+    /// Load thresholds from environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            max_queries_per_request: env_parse(
+                "QUERY_COUNT_WARN_THRESHOLD",
+                defaults.max_queries_per_request,
+            ),
+            max_db_time_ms: env_parse("QUERY_TIME_WARN_MS", defaults.max_db_time_ms),
+        }
+    }
+
+    /// Whether the collected stats breach the per-request thresholds
+    pub fn is_exceeded(&self, stats: &QueryStats) -> bool {
+        stats.count() > self.max_queries_per_request
+            || stats.total_time() > Duration::from_millis(self.max_db_time_ms)
+    }
+}
+
+/// Query counters for a single request
+#[derive(Debug, Default)]
+pub struct QueryStats {
+    count: AtomicU32,
+    total_micros: AtomicU64,
+}
+
+impl QueryStats {
+    /// Number of queries recorded
+    pub fn count(&self) -> u32 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Total time spent awaiting recorded queries
+    pub fn total_time(&self) -> Duration {
+        Duration::from_micros(self.total_micros.load(Ordering::Relaxed))
+    }
+
+    fn record(&self, elapsed: Duration) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_micros
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+
+        let threshold = meridian_db::slow_query_threshold();
+        if elapsed > threshold {
+            tracing::warn!(
+                elapsed_ms = elapsed.as_millis() as u64,
+                threshold_ms = threshold.as_millis() as u64,
+                "Slow query"
+            );
+        }
+    }
+}
+
+/// Run `fut` with `stats` as the current request's query scope
+pub async fn with_query_stats<F: Future>(stats: Arc<QueryStats>, fut: F) -> F::Output {
+    QUERY_STATS.scope(stats, fut).await
+}
+
+/// Stats for the current request, if called inside a query scope
+pub fn current_query_stats() -> Option<Arc<QueryStats>> {
+    QUERY_STATS.try_with(|stats| stats.clone()).ok()
+}
+
+/// Await a database future, recording it against the current request.
+///
+/// Outside a request scope (e.g. background workers) this is a plain await.
+pub async fn track_query<F: Future>(fut: F) -> F::Output {
+    let start = Instant::now();
+    let output = fut.await;
+    if let Some(stats) = current_query_stats() {
+        stats.record(start.elapsed());
+    }
+    output
+}
+
+/// Method-call form of `track_query` for sqlx builder chains:
+/// `.fetch_one(pool).tracked().await`
+pub trait TrackQuery: Future + Sized {
+    fn tracked(self) -> impl Future<Output = Self::Output> {
+        track_query(self)
+    }
+}
+
+impl<F: Future> TrackQuery for F {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_track_query_counts_within_scope() {
+        let stats = Arc::new(QueryStats::default());
+
+        let sum = with_query_stats(stats.clone(), async {
+            let a = track_query(async { 1 }).await;
+            let b = async { 2 }.tracked().await;
+            a + b
+        })
+        .await;
+
+        assert_eq!(sum, 3);
+        assert_eq!(stats.count(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_track_query_outside_scope_is_passthrough() {
+        assert!(current_query_stats().is_none());
+        assert_eq!(track_query(async { 7 }).await, 7);
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let config = QueryMetricsConfig {
+            max_queries_per_request: 2,
+            max_db_time_ms: 50,
+        };
+        let stats = QueryStats::default();
+        stats.record(Duration::from_millis(1));
+        stats.record(Duration::from_millis(1));
+        assert!(!config.is_exceeded(&stats));
+
+        stats.record(Duration::from_millis(1));
+        assert!(config.is_exceeded(&stats));
+
+        let slow = QueryStats::default();
+        slow.record(Duration::from_millis(60));
+        assert!(config.is_exceeded(&slow));
+    }
+}
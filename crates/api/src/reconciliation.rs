@@ -0,0 +1,258 @@
+//! Supply reconciliation
+//!
+//! Mints and burns update `stablecoins.total_supply` alongside the on-chain
+//! transaction, so the two can drift apart (a confirmation that never
+//! landed, a manual contract call, a bad migration). `SupplyReconciler`
+//! periodically reads each active stablecoin's `totalSupply()` on its chain
+//! and compares it to the database. Differences beyond the tolerance are
+//! recorded in `supply_discrepancies` and published as
+//! `SupplyEvent::SupplyMismatch` to subscribers.
+
+use ethers::providers::{Http, Middleware, Provider};
+use ethers::types::Address;
+use meridian_chains::supply::SupplyReader;
+use meridian_chains::Chain;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Buffered events per subscriber before slow receivers start lagging
+pub const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// Default allowed difference between DB and on-chain supply, in tokens
+const DEFAULT_SUPPLY_TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+
+/// Events published by the reconciler
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum SupplyEvent {
+    /// On-chain supply differs from the DB-tracked supply beyond the tolerance
+    SupplyMismatch {
+        symbol: String,
+        chain_id: i32,
+        db_supply: Decimal,
+        on_chain_supply: Decimal,
+        /// `on_chain_supply - db_supply`
+        difference: Decimal,
+    },
+}
+
+/// Outcome of one reconciliation pass
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReconciliationRun {
+    /// Stablecoins whose supply was compared
+    pub checked: usize,
+    /// Stablecoins whose supplies differed beyond the tolerance
+    pub mismatched: usize,
+    /// Stablecoins that could not be read or recorded
+    pub failed: usize,
+}
+
+#[derive(sqlx::FromRow)]
+struct TrackedSupply {
+    id: Uuid,
+    symbol: String,
+    contract_address: String,
+    chain_id: i32,
+    decimals: i16,
+    total_supply: Decimal,
+}
+
+/// Compares DB-tracked stablecoin supply with on-chain `totalSupply()`
+pub struct SupplyReconciler<M> {
+    /// Readers keyed by chain ID; stablecoins on other chains are skipped
+    readers: HashMap<i32, SupplyReader<M>>,
+    tolerance: Decimal,
+    events: broadcast::Sender<SupplyEvent>,
+}
+
+impl<M: Middleware> SupplyReconciler<M> {
+    /// Create a reconciler with no chains and the given tolerance (in tokens)
+    pub fn new(tolerance: Decimal) -> Self {
+        Self {
+            readers: HashMap::new(),
+            tolerance: tolerance.abs(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+        }
+    }
+
+    /// Reconcile stablecoins on `chain_id` using `reader`
+    pub fn with_reader(mut self, chain_id: i32, reader: SupplyReader<M>) -> Self {
+        self.readers.insert(chain_id, reader);
+        self
+    }
+
+    /// Whether any chain is configured
+    pub fn is_empty(&self) -> bool {
+        self.readers.is_empty()
+    }
+
+    /// Subscribes to reconciliation events
+    pub fn subscribe(&self) -> broadcast::Receiver<SupplyEvent> {
+        self.events.subscribe()
+    }
+
+    /// Compares every active, deployed stablecoin on a configured chain
+    ///
+    /// A stablecoin whose supply can't be read or whose discrepancy can't be
+    /// recorded is counted as failed and does not stop the pass.
+    pub async fn reconcile(&self, pool: &PgPool) -> Result<ReconciliationRun, sqlx::Error> {
+        let chain_ids: Vec<i32> = self.readers.keys().copied().collect();
+        let coins: Vec<TrackedSupply> = sqlx::query_as(
+            r#"
+            SELECT id, symbol, contract_address, chain_id, decimals, total_supply
+            FROM stablecoins
+            WHERE chain_id = ANY($1)
+              AND status = 'active'
+              AND contract_address IS NOT NULL
+            ORDER BY symbol
+            "#,
+        )
+        .bind(&chain_ids)
+        .fetch_all(pool)
+        .await?;
+
+        let mut run = ReconciliationRun::default();
+        for coin in coins {
+            match self.reconcile_one(pool, &coin).await {
+                Ok(mismatched) => {
+                    run.checked += 1;
+                    if mismatched {
+                        run.mismatched += 1;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(symbol = %coin.symbol, chain_id = coin.chain_id, error = %e, "Supply reconciliation failed");
+                    run.failed += 1;
+                }
+            }
+        }
+        Ok(run)
+    }
+
+    /// Returns whether the stablecoin's supplies differed beyond the tolerance
+    async fn reconcile_one(&self, pool: &PgPool, coin: &TrackedSupply) -> Result<bool, String> {
+        let reader = self
+            .readers
+            .get(&coin.chain_id)
+            .ok_or_else(|| format!("no reader for chain {}", coin.chain_id))?;
+        let token = Address::from_str(&coin.contract_address)
+            .map_err(|e| format!("invalid contract address {}: {}", coin.contract_address, e))?;
+        let decimals = u32::try_from(coin.decimals).map_err(|_| format!("invalid decimals {}", coin.decimals))?;
+        let on_chain = reader.total_supply(token, decimals).await.map_err(|e| e.to_string())?;
+
+        let Some(difference) = supply_difference(coin.total_supply, on_chain, self.tolerance) else {
+            return Ok(false);
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO supply_discrepancies (stablecoin_id, chain_id, db_supply, on_chain_supply, difference)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(coin.id)
+        .bind(coin.chain_id)
+        .bind(coin.total_supply)
+        .bind(on_chain)
+        .bind(difference)
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tracing::error!(
+            symbol = %coin.symbol,
+            chain_id = coin.chain_id,
+            db_supply = %coin.total_supply,
+            on_chain_supply = %on_chain,
+            difference = %difference,
+            "Stablecoin supply mismatch between database and chain"
+        );
+        let _ = self.events.send(SupplyEvent::SupplyMismatch {
+            symbol: coin.symbol.clone(),
+            chain_id: coin.chain_id,
+            db_supply: coin.total_supply,
+            on_chain_supply: on_chain,
+            difference,
+        });
+        Ok(true)
+    }
+}
+
+impl SupplyReconciler<Provider<Http>> {
+    /// Builds a reconciler for the chains in `SUPPLY_RECONCILIATION_CHAINS`
+    /// (comma-separated, e.g. `ethereum,base`), tolerating differences up to
+    /// `SUPPLY_RECONCILIATION_TOLERANCE` tokens (default 0.01).
+    ///
+    /// Unknown chains and chains without a usable RPC URL are skipped, so
+    /// the result may be empty.
+    pub fn from_env() -> Self {
+        let tolerance = parse_tolerance(std::env::var("SUPPLY_RECONCILIATION_TOLERANCE").ok().as_deref());
+        let mut reconciler = Self::new(tolerance);
+        let chains = std::env::var("SUPPLY_RECONCILIATION_CHAINS").unwrap_or_default();
+        for name in chains.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let chain = match Chain::from_str(name) {
+                Ok(chain) => chain,
+                Err(e) => {
+                    tracing::warn!(chain = %name, error = %e, "Ignoring unknown supply reconciliation chain");
+                    continue;
+                }
+            };
+            match SupplyReader::for_chain(chain) {
+                Ok(reader) => {
+                    reconciler = reconciler.with_reader(chain.config().chain_id as i32, reader);
+                }
+                Err(e) => {
+                    tracing::warn!(chain = chain.slug(), error = %e, "Supply reconciliation unavailable for chain");
+                }
+            }
+        }
+        reconciler
+    }
+}
+
+fn parse_tolerance(value: Option<&str>) -> Decimal {
+    value
+        .and_then(|v| Decimal::from_str(v.trim()).ok())
+        .filter(|tolerance| *tolerance >= Decimal::ZERO)
+        .unwrap_or(DEFAULT_SUPPLY_TOLERANCE)
+}
+
+/// `on_chain - db` when it exceeds `tolerance` in either direction
+fn supply_difference(db_supply: Decimal, on_chain_supply: Decimal, tolerance: Decimal) -> Option<Decimal> {
+    let difference = on_chain_supply - db_supply;
+    (difference.abs() > tolerance).then_some(difference)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supply_difference_respects_tolerance() {
+        let tolerance = Decimal::new(1, 2);
+        assert_eq!(supply_difference(Decimal::new(1000, 0), Decimal::new(1000, 0), tolerance), None);
+        assert_eq!(supply_difference(Decimal::new(1000, 0), Decimal::new(100001, 2), tolerance), None);
+        assert_eq!(
+            supply_difference(Decimal::new(1000, 0), Decimal::new(1005, 0), tolerance),
+            Some(Decimal::new(5, 0))
+        );
+        assert_eq!(
+            supply_difference(Decimal::new(1000, 0), Decimal::new(990, 0), tolerance),
+            Some(Decimal::new(-10, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_tolerance() {
+        assert_eq!(parse_tolerance(None), Decimal::new(1, 2));
+        assert_eq!(parse_tolerance(Some(" 0.5 ")), Decimal::new(5, 1));
+        assert_eq!(parse_tolerance(Some("0")), Decimal::ZERO);
+        assert_eq!(parse_tolerance(Some("-1")), Decimal::new(1, 2));
+        assert_eq!(parse_tolerance(Some("abc")), Decimal::new(1, 2));
+    }
+}
@@ -0,0 +1,346 @@
+//! Retry and circuit-breaker helpers for outbound calls
+//!
+//! CRIT-001/CRIT-002: The exponential backoff + jitter retry loop and circuit
+//! breaker originally lived inside the mint handler's FX rate lookup. They are
+//! extracted here so webhooks, RPC calls, and any other fallible async
+//! operation can share the same failure semantics.
+
+use crate::state::{CircuitBreaker, CircuitState};
+use actix_web::{HttpMessage, HttpRequest};
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// CRIT-001: Retry configuration for outbound calls
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Total number of attempts (including the first)
+    pub max_retries: u32,
+    /// Backoff before the second attempt; doubles on each subsequent attempt
+    pub initial_backoff_ms: u64,
+    /// Upper bound on the backoff (before jitter)
+    pub max_backoff_ms: u64,
+}
+
+impl RetryConfig {
+    /// Backoff (without jitter) to wait after the given zero-based attempt
+    pub fn backoff_ms(&self, attempt: u32) -> u64 {
+        self.initial_backoff_ms
+            .saturating_mul(2u64.saturating_pow(attempt))
+            .min(self.max_backoff_ms)
+    }
+}
+
+impl Default for RetryConfig {
+    /// Defaults used for oracle calls:
+    /// - 3 attempts
+    /// - 100ms initial backoff
+    /// - 2s maximum backoff
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 2000,
+        }
+    }
+}
+
+/// Attempts shared by every resilient call made while serving one request
+///
+/// Each call's `RetryConfig` still applies, but a request reading several
+/// prices would otherwise multiply it (five currencies x 3 attempts = 15
+/// calls against a failing oracle). Clones share the same counter.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    remaining: Arc<AtomicU32>,
+}
+
+impl RetryBudget {
+    /// A budget of `attempts` total attempts
+    pub fn new(attempts: u32) -> Self {
+        Self { remaining: Arc::new(AtomicU32::new(attempts)) }
+    }
+
+    /// A budget that never runs out, for calls made outside a request
+    pub fn unlimited() -> Self {
+        Self::new(u32::MAX)
+    }
+
+    /// The request's budget, created with `attempts` on first use and kept in
+    /// the request extensions so later calls draw from the same pool
+    pub fn for_request(req: &HttpRequest, attempts: u32) -> Self {
+        if let Some(budget) = req.extensions().get::<RetryBudget>() {
+            return budget.clone();
+        }
+        let budget = Self::new(attempts);
+        req.extensions_mut().insert(budget.clone());
+        budget
+    }
+
+    /// Takes one attempt from the budget; false once it is spent
+    pub fn try_acquire(&self) -> bool {
+        self.remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    /// Attempts left
+    pub fn remaining(&self) -> u32 {
+        self.remaining.load(Ordering::SeqCst)
+    }
+}
+
+/// Outcome of a failed resilient call
+#[derive(Debug)]
+pub enum ResilientError<E> {
+    /// The circuit breaker was open, so the operation was never attempted
+    CircuitOpen,
+    /// The request's retry budget was already spent, so the operation was
+    /// never attempted
+    BudgetExhausted,
+    /// Every attempt failed; carries the error from the final attempt
+    Exhausted { attempts: u32, last_error: E },
+}
+
+/// CRIT-001: Generate random jitter (0.0 to 0.5) for backoff
+/// Uses simple time-based pseudo-randomness to avoid adding rand crate dependency
+fn rand_jitter() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    // Convert to 0.0-0.5 range
+    (nanos as f64 % 500.0) / 1000.0
+}
+
+/// Run an async fallible operation with retry/backoff behind a circuit breaker.
+///
+/// - Fast-fails with `CircuitOpen` when the breaker is open.
+/// - Retries with exponential backoff plus 0-50% jitter between attempts.
+/// - Records a success on the breaker as soon as an attempt succeeds, and a
+///   single failure once all attempts are exhausted.
+pub async fn resilient_call<T, E, F, Fut>(
+    cb: &CircuitBreaker,
+    config: &RetryConfig,
+    f: F,
+) -> Result<T, ResilientError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    resilient_call_with_budget(cb, config, &RetryBudget::unlimited(), f).await
+}
+
+/// `resilient_call`, with every attempt also drawn from a shared budget
+///
+/// Stops retrying once the budget is spent; if it was spent before the
+/// first attempt, fails with `BudgetExhausted` without calling `f`.
+pub async fn resilient_call_with_budget<T, E, F, Fut>(
+    cb: &CircuitBreaker,
+    config: &RetryConfig,
+    budget: &RetryBudget,
+    mut f: F,
+) -> Result<T, ResilientError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    // CRIT-002: Check circuit breaker first
+    if cb.state() == CircuitState::Open {
+        return Err(ResilientError::CircuitOpen);
+    }
+    if !budget.try_acquire() {
+        return Err(ResilientError::BudgetExhausted);
+    }
+
+    let attempts = config.max_retries.max(1);
+    let mut attempt = 0;
+
+    loop {
+        match f().await {
+            Ok(value) => {
+                cb.record_success();
+
+                if attempt > 0 {
+                    tracing::info!(attempt = attempt + 1, "Call succeeded after retry");
+                }
+                return Ok(value);
+            }
+            Err(e) if attempt + 1 < attempts && budget.try_acquire() => {
+                // CRIT-001: Exponential backoff with jitter
+                let backoff_ms = config.backoff_ms(attempt);
+                // Add 0-50% jitter to prevent thundering herd
+                let jitter = (backoff_ms as f64 * rand_jitter()) as u64;
+                let wait_time = Duration::from_millis(backoff_ms + jitter);
+
+                tracing::warn!(
+                    attempt = attempt + 1,
+                    backoff_ms = wait_time.as_millis(),
+                    error = %e,
+                    "Call failed, retrying with backoff"
+                );
+
+                sleep(wait_time).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                // CRIT-002: Record failure for circuit breaker after all retries exhausted
+                cb.record_failure();
+
+                if attempt + 1 < attempts {
+                    tracing::warn!(attempt = attempt + 1, error = %e, "Retry budget spent, not retrying");
+                }
+                return Err(ResilientError::Exhausted {
+                    attempts: attempt + 1,
+                    last_error: e,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_config() -> RetryConfig {
+        RetryConfig {
+            max_retries: 3,
+            initial_backoff_ms: 1,
+            max_backoff_ms: 2,
+        }
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let config = RetryConfig::default();
+        assert_eq!(config.backoff_ms(0), 100);
+        assert_eq!(config.backoff_ms(1), 200);
+        assert_eq!(config.backoff_ms(10), 2000);
+        assert_eq!(config.backoff_ms(64), 2000);
+    }
+
+    #[actix_web::test]
+    async fn test_success_after_retry() {
+        let cb = CircuitBreaker::new();
+        let calls = AtomicU32::new(0);
+
+        let result: Result<u32, ResilientError<String>> =
+            resilient_call(&cb, &fast_config(), || async {
+                let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                if n < 3 {
+                    Err(format!("transient failure {}", n))
+                } else {
+                    Ok(n)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(cb.metrics().failure_count, 0);
+    }
+
+    #[actix_web::test]
+    async fn test_exhausted_records_single_failure() {
+        let cb = CircuitBreaker::new();
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), ResilientError<String>> =
+            resilient_call(&cb, &fast_config(), || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err("down".to_string())
+            })
+            .await;
+
+        match result {
+            Err(ResilientError::Exhausted { attempts, last_error }) => {
+                assert_eq!(attempts, 3);
+                assert_eq!(last_error, "down");
+            }
+            other => panic!("Expected Exhausted, got {:?}", other),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(cb.metrics().failure_count, 1);
+    }
+
+    #[actix_web::test]
+    async fn test_fast_fail_when_open() {
+        let cb = CircuitBreaker::new();
+        for _ in 0..5 {
+            cb.record_failure();
+        }
+        assert_eq!(cb.state(), CircuitState::Open);
+
+        let calls = AtomicU32::new(0);
+        let result: Result<(), ResilientError<String>> =
+            resilient_call(&cb, &fast_config(), || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+
+        assert!(matches!(result, Err(ResilientError::CircuitOpen)));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_shared_budget_caps_attempts_across_calls() {
+        let cb = CircuitBreaker::new();
+        let budget = RetryBudget::new(4);
+        let calls = AtomicU32::new(0);
+
+        // Five currency fetches against a failing oracle: 3 + 1 attempts,
+        // then the remaining fetches are refused outright
+        let mut outcomes = Vec::new();
+        for _ in ["EUR", "GBP", "JPY", "MXN", "BRL"] {
+            let result: Result<(), ResilientError<String>> =
+                resilient_call_with_budget(&cb, &fast_config(), &budget, || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err("down".to_string())
+                })
+                .await;
+            outcomes.push(result);
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(budget.remaining(), 0);
+        assert!(matches!(outcomes[0], Err(ResilientError::Exhausted { attempts: 3, .. })));
+        assert!(matches!(outcomes[1], Err(ResilientError::Exhausted { attempts: 1, .. })));
+        for outcome in &outcomes[2..] {
+            assert!(matches!(outcome, Err(ResilientError::BudgetExhausted)));
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_budget_only_spends_attempts_made() {
+        let cb = CircuitBreaker::new();
+        let budget = RetryBudget::new(6);
+
+        let result: Result<u32, ResilientError<String>> =
+            resilient_call_with_budget(&cb, &fast_config(), &budget, || async { Ok(1) }).await;
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(budget.remaining(), 5);
+    }
+
+    #[test]
+    fn test_budget_is_shared_through_request_extensions() {
+        let req = actix_web::test::TestRequest::default().to_http_request();
+
+        let first = RetryBudget::for_request(&req, 2);
+        assert!(first.try_acquire());
+
+        // Later lookups reuse the stored budget and ignore the new size
+        let second = RetryBudget::for_request(&req, 10);
+        assert_eq!(second.remaining(), 1);
+        assert!(second.try_acquire());
+        assert!(!first.try_acquire());
+    }
+}
@@ -18,6 +18,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         // Health check and metrics
         .route("/health", web::get().to(handlers::health_check))
         .route("/metrics", web::get().to(handlers::metrics))
+        .route("/api/v1/health/version", web::get().to(handlers::version_info))
         // Authentication endpoints with stricter rate limiting
         .service(
             web::scope("/api/v1/auth")
@@ -38,11 +39,20 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                 .route("/approve/{application_id}", web::put().to(handlers::approve_kyc))
                 .route("/reject/{application_id}", web::put().to(handlers::reject_kyc)),
         )
+        // Compliance endpoints
+        .service(
+            web::scope("/api/v1/compliance")
+                .route(
+                    "/customers/{id}",
+                    web::get().to(handlers::get_customer_compliance),
+                ),
+        )
         // Operations endpoints
         .service(
             web::scope("/api/v1/operations")
                 .route("/mint", web::post().to(handlers::mint))
                 .route("/burn", web::post().to(handlers::burn))
+                .route("/{id}/approve", web::post().to(handlers::approve_mint))
                 .route(
                     "/transactions/{user_id}",
                     web::get().to(handlers::get_transactions),
@@ -53,11 +63,24 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             web::scope("/api/v1/agents")
                 .route("/create", web::post().to(handlers::create_agent))
                 .route("/pay", web::post().to(handlers::agent_pay))
+                .route(
+                    "/validate-payment",
+                    web::post().to(handlers::validate_agent_payment),
+                )
                 .route("/list/{user_id}", web::get().to(handlers::list_agents))
                 .route(
                     "/transactions/{agent_id}",
                     web::get().to(handlers::get_agent_transactions),
-                ),
+                )
+                .route(
+                    "/{agent_id}/rotate-key",
+                    web::post().to(handlers::rotate_agent_key),
+                )
+                .route(
+                    "/{agent_id}/deactivate",
+                    web::post().to(handlers::deactivate_agent),
+                )
+                .route("/{agent_id}/activate", web::post().to(handlers::activate_agent)),
         )
         // Basket endpoints
         .service(
@@ -72,15 +95,25 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                 .route("/{id}", web::get().to(handlers::get_basket))
                 .route("/{id}/value", web::get().to(handlers::get_basket_value)),
         )
+        // Stablecoin endpoints
+        .service(
+            web::scope("/api/v1/stablecoins")
+                .route("/{symbol}", web::get().to(handlers::get_stablecoin)),
+        )
         // Reserves endpoints
         .service(
             web::scope("/api/v1/reserves")
-                .route("/{currency}", web::get().to(handlers::get_reserves)),
+                // Registered before /{currency}, which would otherwise match them
+                .route("/merkle-root", web::get().to(handlers::get_merkle_root))
+                .route("/merkle-proof", web::get().to(handlers::get_merkle_proof))
+                .route("/{currency}", web::get().to(handlers::get_reserves))
+                .route("/{currency}/shortfall", web::get().to(handlers::get_reserve_shortfall)),
         )
         // Attestation endpoints
         .service(
             web::scope("/api/v1/attestation")
-                .route("/latest", web::get().to(handlers::get_attestation_status)),
+                .route("/latest", web::get().to(handlers::get_latest_attestation))
+                .route("/verify", web::post().to(handlers::verify_attestation)),
         )
         // Oracle endpoints
         .service(
@@ -114,5 +147,12 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                 .route("", web::get().to(handlers::list_webhooks))
                 .route("/test", web::post().to(handlers::test_webhook))
                 .route("/{id}", web::delete().to(handlers::delete_webhook)),
+        )
+        // Admin diagnostics (redacted effective config) and fee reporting
+        .service(
+            web::scope("/api/v1/admin")
+                .route("/diagnostics", web::get().to(handlers::get_diagnostics))
+                .route("/fees/summary", web::get().to(handlers::get_fee_summary))
+                .route("/kyc/bulk-status", web::post().to(handlers::bulk_update_kyc_status)),
         );
 }
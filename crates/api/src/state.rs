@@ -1,19 +1,42 @@
 //! Application state shared across all handlers
 
+use crate::attestation::AttestationSigner;
+use crate::basket_cache::BasketValueCache;
+use crate::handlers::operations::SUPPORTED_CURRENCIES;
+use crate::proof_of_reserves::LiabilityTreeCache;
 use ethers::types::Address;
+use ethers::providers::{Http, Provider};
 use meridian_chains::execution::EvmExecutor;
+use meridian_chains::recipient::RecipientVerifier;
+use meridian_chains::transfer::{BlockchainExecutor, Erc20TransferExecutor};
+use meridian_chains::Chain;
 use meridian_compliance::{ComplianceConfig, ComplianceService};
 use meridian_compliance::risk::RiskEngine;
-use meridian_compliance::sanctions::SanctionsService;
+use meridian_compliance::sanctions::{SanctionsList, SanctionsService};
 use meridian_custody::{build_adapter_from_env, CustodyAdapter};
-use meridian_oracle::ChainlinkOracle;
+use meridian_oracle::{mainnet_feeds, ChainlinkOracle, DeviationReference, OracleError};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 
+/// Startup validation failures that must prevent the server from serving traffic
+#[derive(Debug, thiserror::Error)]
+pub enum StartupValidationError {
+    #[error("Live oracle required for unsupported currency: {0}")]
+    UnsupportedCurrency(String),
+
+    #[error("Live oracle required for {} but no oracle is configured", .0.join(", "))]
+    OracleNotConfigured(Vec<String>),
+
+    #[error(transparent)]
+    Oracle(#[from] OracleError),
+}
+
 /// CRIT-002: Circuit breaker states
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CircuitState {
@@ -169,8 +192,9 @@ pub struct CircuitBreakerMetrics {
 pub struct AppState {
     /// Database connection pool
     pub db_pool: Arc<PgPool>,
-    /// Chainlink oracle client (optional, requires RPC URL)
-    pub oracle: Arc<RwLock<Option<ChainlinkOracle>>>,
+    /// Chainlink oracle client (optional, requires RPC URL); shared with
+    /// its background refresh task
+    pub oracle: Arc<RwLock<Option<Arc<ChainlinkOracle>>>>,
     /// CRIT-002: Circuit breaker for oracle calls
     pub oracle_circuit_breaker: CircuitBreaker,
     /// Compliance service for transaction pre-screening
@@ -183,17 +207,72 @@ pub struct AppState {
     pub evm_executor: Option<Arc<EvmExecutor>>,
     /// Custody adapter for Proof of Reserves (defaults to MockAdapter)
     pub custody: Arc<dyn CustodyAdapter>,
+    /// Basket valuations cached per basket version and oracle price epoch
+    pub basket_value_cache: Arc<BasketValueCache>,
+    /// Proof-of-reserves liability trees, rebuilt when operations change
+    pub liability_trees: Arc<LiabilityTreeCache>,
+    /// Independent Chainlink oracle used as an FX fallback (requires SECONDARY_ETHEREUM_RPC_URL)
+    pub secondary_oracle: Arc<RwLock<Option<ChainlinkOracle>>>,
+    /// FX rate sources, tried in order until one returns a usable rate
+    pub fx_sources: Vec<FxSource>,
+    /// Cap on live (non-deleted) baskets per organization
+    pub max_baskets_per_organization: i64,
+    /// Oracle attempts a single request may make across all its price reads
+    pub oracle_retry_budget: u32,
+    /// Require UUID-strength idempotency keys (always on in production)
+    pub strict_idempotency_keys: bool,
+    /// On-chain agent recipient check (None unless VERIFY_AGENT_RECIPIENTS_ONCHAIN=true)
+    pub recipient_verifier: Option<Arc<RecipientVerifier<Provider<Http>>>>,
+    /// Executes agent payments on-chain (None unless AGENT_PAYMENT_PRIVATE_KEY is set)
+    pub agent_payment_executor: Option<Arc<dyn BlockchainExecutor>>,
+    /// Chain agent payments are sent on
+    pub agent_payment_chain: Chain,
+    /// System-wide daily mint/burn caps per currency, across all users
+    pub system_daily_caps: SystemDailyCaps,
+    /// USD value above which a mint is held for a second approver
+    pub mint_approval_threshold_usd: Decimal,
+    /// Fee account credited with mint and burn fees
+    pub fee_config: FeeConfig,
+    /// Signs reserve attestations (None unless ATTESTATION_SIGNING_KEY is set)
+    pub attestation_signer: Option<Arc<AttestationSigner>>,
 }
 
 impl AppState {
     /// Creates new application state with database pool
     pub async fn new(db_pool: PgPool) -> Self {
-        // Try to initialize oracle if RPC URL is provided
-        let oracle = if let Ok(rpc_url) = std::env::var("ETHEREUM_RPC_URL") {
+        // Try to initialize oracle if RPC URL is provided, failing over to
+        // ETHEREUM_RPC_FALLBACKS when the primary endpoint is unreachable
+        let oracle = if std::env::var("ETHEREUM_RPC_URL").is_ok() {
             tracing::info!("Initializing Chainlink oracle with RPC URL");
-            match ChainlinkOracle::new(&rpc_url, Decimal::new(10, 0)).await {
-                Ok(oracle) => {
-                    tracing::info!("Chainlink oracle initialized");
+            match Self::connect_oracle(&Chain::Ethereum.rpc_urls()).await {
+                Ok(mut oracle) => {
+                    if let Some(limit) = std::env::var("ORACLE_RPC_CONCURRENCY")
+                        .ok()
+                        .and_then(|v| v.trim().parse::<usize>().ok())
+                    {
+                        oracle.set_rpc_concurrency(limit);
+                    }
+                    oracle.set_deviation_reference(oracle_deviation_reference());
+                    oracle.set_multicall_address(Chain::Ethereum.multicall3_address());
+                    if let Some(z_score) = std::env::var("ORACLE_ANOMALY_Z_SCORE")
+                        .ok()
+                        .and_then(|v| Decimal::from_str(v.trim()).ok())
+                    {
+                        oracle.set_anomaly_z_threshold(z_score);
+                    }
+                    if let Some(window) = std::env::var("ORACLE_ANOMALY_WINDOW")
+                        .ok()
+                        .and_then(|v| v.trim().parse::<usize>().ok())
+                    {
+                        oracle.set_anomaly_window(window);
+                    }
+                    Self::register_catalog_feeds(&oracle).await;
+                    tracing::info!(
+                        rpc_concurrency = oracle.rpc_concurrency(),
+                        deviation_reference = ?oracle.deviation_reference(),
+                        anomaly_z_threshold = %oracle.anomaly_z_threshold(),
+                        "Chainlink oracle initialized"
+                    );
                     Some(oracle)
                 }
                 Err(e) => {
@@ -206,6 +285,13 @@ impl AppState {
             None
         };
 
+        let secondary_oracle = Self::try_init_secondary_oracle().await;
+        let fx_sources = fx_rate_sources();
+        tracing::info!(fx_sources = ?fx_sources, "FX rate source order configured");
+        if fx_sources.contains(&FxSource::SecondaryOracle) && secondary_oracle.is_none() {
+            tracing::warn!("FX_RATE_SOURCES lists the secondary oracle but it is not available");
+        }
+
         // Initialize compliance services from environment
         let compliance_config = ComplianceConfig {
             enabled: std::env::var("COMPLIANCE_ENABLED")
@@ -213,6 +299,18 @@ impl AppState {
                 .unwrap_or(true),
             sanctions_api_url: std::env::var("SANCTIONS_API_URL").ok(),
             kyc_api_url: std::env::var("KYC_API_URL").ok(),
+            edd_amount_threshold: std::env::var("COMPLIANCE_EDD_AMOUNT_THRESHOLD_CENTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(ComplianceConfig::default().edd_amount_threshold),
+            auto_reject_threshold: std::env::var("COMPLIANCE_AUTO_REJECT_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(ComplianceConfig::default().auto_reject_threshold),
+            manual_review_threshold: std::env::var("COMPLIANCE_MANUAL_REVIEW_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(ComplianceConfig::default().manual_review_threshold),
             ..Default::default()
         };
 
@@ -224,24 +322,249 @@ impl AppState {
             tracing::warn!("COMPLIANCE_ENABLED=false — compliance checks are disabled (dev/test only)");
         }
 
+        let compliance = Arc::new(
+            ComplianceService::try_new(compliance_config).expect("Invalid compliance risk thresholds"),
+        );
+
+        // Initial load of the file-backed sanctions list; main.rs keeps it fresh
+        if let Ok(path) = std::env::var("SANCTIONS_LIST_PATH") {
+            match SanctionsList::from_path(&path) {
+                Ok(list) => {
+                    tracing::info!(
+                        path = %path,
+                        entries = list.len(),
+                        addresses = list.address_count(),
+                        "Sanctions list loaded"
+                    );
+                    compliance.replace_sanctions_list(Arc::new(list));
+                }
+                Err(e) => tracing::error!(path = %path, error = %e, "Failed to load sanctions list"),
+            }
+        }
+
         // Try to initialize EVM executor if keys are available
         let evm_executor = Self::try_init_executor().await;
 
+        let (agent_payment_chain, agent_payment_executor) = Self::try_init_agent_payment_executor();
+
         // Initialize custody adapter from environment (defaults to mock)
         let custody: Arc<dyn CustodyAdapter> = Arc::from(build_adapter_from_env());
 
         Self {
             db_pool: Arc::new(db_pool),
-            oracle: Arc::new(RwLock::new(oracle)),
+            oracle: Arc::new(RwLock::new(oracle.map(Arc::new))),
             oracle_circuit_breaker: CircuitBreaker::new(),
-            compliance: Arc::new(ComplianceService::new(compliance_config)),
+            compliance,
             risk_engine: Arc::new(RiskEngine::new()),
             sanctions: Arc::new(SanctionsService::new(sanctions_api_url)),
             evm_executor,
             custody,
+            basket_value_cache: Arc::new(BasketValueCache::new()),
+            liability_trees: Arc::new(LiabilityTreeCache::new()),
+            secondary_oracle: Arc::new(RwLock::new(secondary_oracle)),
+            fx_sources,
+            max_baskets_per_organization: max_baskets_per_organization(),
+            oracle_retry_budget: oracle_retry_budget(),
+            strict_idempotency_keys: strict_idempotency_keys(),
+            recipient_verifier: Self::try_init_recipient_verifier(),
+            agent_payment_executor,
+            agent_payment_chain,
+            system_daily_caps: SystemDailyCaps::from_env(),
+            mint_approval_threshold_usd: mint_approval_threshold_usd(),
+            fee_config: FeeConfig::from_env(),
+            attestation_signer: AttestationSigner::from_env().map(Arc::new),
+        }
+    }
+
+    /// Builds the agent recipient verifier when `VERIFY_AGENT_RECIPIENTS_ONCHAIN=true`.
+    ///
+    /// Checks run against `AGENT_RECIPIENT_CHAIN` (default Ethereum).
+    fn try_init_recipient_verifier() -> Option<Arc<RecipientVerifier<Provider<Http>>>> {
+        let enabled = std::env::var("VERIFY_AGENT_RECIPIENTS_ONCHAIN")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let chain = match std::env::var("AGENT_RECIPIENT_CHAIN") {
+            Ok(name) => match name.parse::<Chain>() {
+                Ok(chain) => chain,
+                Err(e) => {
+                    tracing::error!(error = %e, "Invalid AGENT_RECIPIENT_CHAIN — recipient verification disabled");
+                    return None;
+                }
+            },
+            Err(_) => Chain::Ethereum,
+        };
+
+        match RecipientVerifier::for_chain(chain) {
+            Ok(verifier) => {
+                tracing::info!(chain = chain.slug(), "On-chain agent recipient verification enabled");
+                Some(Arc::new(verifier))
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Recipient verifier unavailable — recipient verification disabled");
+                None
+            }
         }
     }
 
+    /// Builds the agent payment executor when `AGENT_PAYMENT_PRIVATE_KEY` is set.
+    ///
+    /// Payments go out on `AGENT_PAYMENT_CHAIN` (default Ethereum) using the
+    /// stablecoin contracts listed in `AGENT_PAYMENT_TOKENS`.
+    fn try_init_agent_payment_executor() -> (Chain, Option<Arc<dyn BlockchainExecutor>>) {
+        let chain = match std::env::var("AGENT_PAYMENT_CHAIN") {
+            Ok(name) => match name.parse::<Chain>() {
+                Ok(chain) => chain,
+                Err(e) => {
+                    tracing::error!(error = %e, "Invalid AGENT_PAYMENT_CHAIN — on-chain agent payments disabled");
+                    return (Chain::Ethereum, None);
+                }
+            },
+            Err(_) => Chain::Ethereum,
+        };
+
+        if std::env::var("AGENT_PAYMENT_PRIVATE_KEY").is_err() {
+            return (chain, None);
+        }
+
+        match Erc20TransferExecutor::from_env(chain) {
+            Ok(executor) => {
+                tracing::info!(
+                    chain = chain.slug(),
+                    wallet = ?executor.signer_address(),
+                    "On-chain agent payments enabled"
+                );
+                (chain, Some(Arc::new(executor)))
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Agent payment executor unavailable — on-chain agent payments disabled");
+                (chain, None)
+            }
+        }
+    }
+
+    /// Connects to the first reachable RPC endpoint, trying them in order
+    async fn connect_oracle(rpc_urls: &[String]) -> Result<ChainlinkOracle, OracleError> {
+        let mut last_error = OracleError::ProviderError("No RPC URLs configured".to_string());
+        for (index, rpc_url) in rpc_urls.iter().enumerate() {
+            // URLs are not logged: they commonly embed provider API keys
+            match ChainlinkOracle::new(rpc_url, Decimal::new(10, 0)).await {
+                Ok(oracle) => {
+                    if index > 0 {
+                        tracing::warn!(fallback = index, "Oracle connected via fallback RPC URL");
+                    }
+                    return Ok(oracle);
+                }
+                Err(e) => {
+                    tracing::warn!(endpoint = index, error = %e, "Oracle RPC endpoint unavailable");
+                    last_error = e;
+                }
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Initializes the secondary oracle from `SECONDARY_ETHEREUM_RPC_URL`.
+    ///
+    /// Registers the catalog feed for each supported currency. Its
+    /// staleness threshold is set independently via `SECONDARY_ORACLE_STALE_SECS`.
+    async fn try_init_secondary_oracle() -> Option<ChainlinkOracle> {
+        let rpc_url = std::env::var("SECONDARY_ETHEREUM_RPC_URL").ok()?;
+
+        let mut oracle = match ChainlinkOracle::new(&rpc_url, Decimal::new(10, 0)).await {
+            Ok(oracle) => oracle,
+            Err(e) => {
+                tracing::warn!("Failed to initialize secondary oracle: {}", e);
+                return None;
+            }
+        };
+        if let Some(seconds) = std::env::var("SECONDARY_ORACLE_STALE_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            oracle.set_stale_threshold(seconds);
+        }
+        oracle.set_multicall_address(Chain::Ethereum.multicall3_address());
+        Self::register_catalog_feeds(&oracle).await;
+
+        tracing::info!("Secondary Chainlink oracle initialized");
+        Some(oracle)
+    }
+
+    /// Registers the known mainnet `{CUR}/USD` feed for each supported currency.
+    ///
+    /// A feed that fails to register is logged and left out, so the startup
+    /// feed checks report it as missing.
+    pub async fn register_catalog_feeds(oracle: &ChainlinkOracle) {
+        for currency in SUPPORTED_CURRENCIES {
+            let Some(address) = mainnet_feeds::feed_for_currency(currency) else {
+                continue;
+            };
+            let pair = format!("{}/USD", currency);
+            if let Err(e) = oracle
+                .register_price_feed_with_decimals(&pair, address, Some(mainnet_feeds::FX_FEED_DECIMALS))
+                .await
+            {
+                tracing::warn!(pair = %pair, error = %e, "Oracle feed registration failed");
+            }
+        }
+    }
+
+    /// Startup health check: every supported currency with a catalog feed
+    /// must have its `{CUR}/USD` feed registered.
+    ///
+    /// main.rs refuses to start when it fails. Currencies without a Chainlink
+    /// feed (e.g. ARS) are priced from the FX fallbacks and are not required.
+    pub async fn check_required_feeds(oracle: &ChainlinkOracle) -> Result<(), OracleError> {
+        let pairs: Vec<String> = SUPPORTED_CURRENCIES
+            .iter()
+            .filter(|c| mainnet_feeds::feed_for_currency(c).is_some())
+            .map(|c| format!("{}/USD", c))
+            .collect();
+        let required: Vec<&str> = pairs.iter().map(String::as_str).collect();
+
+        oracle.verify_required_feeds(&required).await?;
+        tracing::info!(feeds = required.len(), "All required oracle feeds registered");
+        Ok(())
+    }
+
+    /// Verify every live-required currency has a registered, refreshable feed.
+    ///
+    /// Like `check_required_feeds`, this is a hard gate: main.rs refuses to
+    /// start when it fails, so a currency configured as live-only can never
+    /// silently fall back to static rates.
+    pub async fn validate_live_oracle_currencies(
+        oracle: Option<&ChainlinkOracle>,
+        currencies: &[String],
+    ) -> Result<(), StartupValidationError> {
+        if currencies.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(unsupported) = currencies
+            .iter()
+            .find(|c| !SUPPORTED_CURRENCIES.contains(&c.to_uppercase().as_str()))
+        {
+            return Err(StartupValidationError::UnsupportedCurrency(unsupported.clone()));
+        }
+
+        let oracle = oracle
+            .ok_or_else(|| StartupValidationError::OracleNotConfigured(currencies.to_vec()))?;
+
+        let pairs: Vec<String> = currencies
+            .iter()
+            .map(|c| format!("{}/USD", c.to_uppercase()))
+            .collect();
+        let required: Vec<&str> = pairs.iter().map(String::as_str).collect();
+        oracle.verify_live_feeds(&required).await?;
+
+        tracing::info!(currencies = ?currencies, "Live oracle feeds verified");
+        Ok(())
+    }
+
     async fn try_init_executor() -> Option<Arc<EvmExecutor>> {
         let rpc_url = std::env::var("SEPOLIA_RPC_URL")
             .or_else(|_| std::env::var("ETHEREUM_RPC_URL"))
@@ -268,10 +591,345 @@ impl AppState {
     }
 }
 
+/// Default TWAP window when deviation is measured against a TWAP baseline
+const DEFAULT_ORACLE_TWAP_WINDOW_SECS: u64 = 900;
+
+/// Oracle deviation baseline.
+/// `ORACLE_DEVIATION_REFERENCE=twap` compares refreshes against a TWAP over
+/// `ORACLE_TWAP_WINDOW_SECS` (default 15 minutes); anything else keeps the
+/// last cached price.
+fn oracle_deviation_reference() -> DeviationReference {
+    parse_deviation_reference(
+        std::env::var("ORACLE_DEVIATION_REFERENCE").ok().as_deref(),
+        std::env::var("ORACLE_TWAP_WINDOW_SECS").ok().as_deref(),
+    )
+}
+
+fn parse_deviation_reference(mode: Option<&str>, window_secs: Option<&str>) -> DeviationReference {
+    match mode.map(|m| m.trim().to_lowercase()).as_deref() {
+        Some("twap") => DeviationReference::Twap {
+            window_seconds: window_secs
+                .and_then(|w| w.trim().parse().ok())
+                .filter(|w| *w > 0)
+                .unwrap_or(DEFAULT_ORACLE_TWAP_WINDOW_SECS),
+        },
+        _ => DeviationReference::LastPrice,
+    }
+}
+
+/// Default cap on live (non-deleted) baskets per organization
+const DEFAULT_MAX_BASKETS_PER_ORGANIZATION: i64 = 50;
+
+/// Live baskets an organization may hold before creation is refused.
+/// Overridable via `MAX_BASKETS_PER_ORGANIZATION`.
+fn max_baskets_per_organization() -> i64 {
+    std::env::var("MAX_BASKETS_PER_ORGANIZATION")
+        .ok()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .filter(|max| *max > 0)
+        .unwrap_or(DEFAULT_MAX_BASKETS_PER_ORGANIZATION)
+}
+
+/// Default oracle attempts per request; two full `RetryConfig` rounds
+const DEFAULT_ORACLE_RETRY_BUDGET: u32 = 6;
+
+/// Oracle attempts shared by all price reads of one request.
+/// Overridable via `ORACLE_RETRY_BUDGET`.
+fn oracle_retry_budget() -> u32 {
+    std::env::var("ORACLE_RETRY_BUDGET")
+        .ok()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .filter(|budget| *budget > 0)
+        .unwrap_or(DEFAULT_ORACLE_RETRY_BUDGET)
+}
+
+/// Whether weak idempotency keys like "1" are refused.
+/// On by default; `STRICT_IDEMPOTENCY_KEYS=false` relaxes it outside
+/// production only.
+fn strict_idempotency_keys() -> bool {
+    let is_production = std::env::var("ENVIRONMENT")
+        .map(|e| e.to_lowercase() == "production")
+        .unwrap_or(false);
+    let requested = std::env::var("STRICT_IDEMPOTENCY_KEYS")
+        .map(|v| v.trim().to_lowercase() != "false")
+        .unwrap_or(true);
+    if is_production && !requested {
+        tracing::warn!("Ignoring STRICT_IDEMPOTENCY_KEYS=false in production");
+    }
+    is_production || requested
+}
+
+/// Default USD value above which a mint needs a second approver
+const DEFAULT_MINT_APPROVAL_THRESHOLD_USD: i64 = 1_000_000;
+
+/// USD value above which a mint is held in AWAITING_APPROVAL.
+/// Overridable via `MINT_APPROVAL_THRESHOLD_USD`.
+fn mint_approval_threshold_usd() -> Decimal {
+    std::env::var("MINT_APPROVAL_THRESHOLD_USD")
+        .ok()
+        .and_then(|v| Decimal::from_str(v.trim()).ok())
+        .unwrap_or_else(|| Decimal::from(DEFAULT_MINT_APPROVAL_THRESHOLD_USD))
+}
+
+/// System-wide daily mint and burn caps, in units of each currency
+///
+/// Currencies without an entry are uncapped. Configured via
+/// `SYSTEM_DAILY_MINT_CAPS` / `SYSTEM_DAILY_BURN_CAPS` as
+/// `EUR:5000000,GBP:2000000`.
+#[derive(Debug, Clone, Default)]
+pub struct SystemDailyCaps {
+    pub mint: HashMap<String, Decimal>,
+    pub burn: HashMap<String, Decimal>,
+}
+
+impl SystemDailyCaps {
+    fn from_env() -> Self {
+        let caps = Self {
+            mint: parse_currency_caps(std::env::var("SYSTEM_DAILY_MINT_CAPS").ok().as_deref()),
+            burn: parse_currency_caps(std::env::var("SYSTEM_DAILY_BURN_CAPS").ok().as_deref()),
+        };
+        if !caps.mint.is_empty() || !caps.burn.is_empty() {
+            tracing::info!(mint = ?caps.mint, burn = ?caps.burn, "System daily currency caps configured");
+        }
+        caps
+    }
+
+    /// Cap for `operation_type` (`MINT` or `BURN`) in `currency`, if any
+    pub fn cap_for(&self, operation_type: &str, currency: &str) -> Option<Decimal> {
+        let caps = match operation_type {
+            "MINT" => &self.mint,
+            "BURN" => &self.burn,
+            _ => return None,
+        };
+        caps.get(&currency.to_uppercase()).copied()
+    }
+}
+
+/// Default ledger account fees accrue to
+const DEFAULT_FEE_ACCOUNT: &str = "treasury";
+
+/// Where mint and burn fees accrue in `fee_ledger`
+///
+/// Configured via `FEE_ACCOUNT`: 1-64 characters of letters, digits, `-`,
+/// `_`, `.` or `:`. Anything else falls back to `treasury`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeConfig {
+    pub account: String,
+}
+
+impl FeeConfig {
+    fn from_env() -> Self {
+        Self::parse(std::env::var("FEE_ACCOUNT").ok().as_deref())
+    }
+
+    fn parse(account: Option<&str>) -> Self {
+        let account = match account.map(str::trim) {
+            None | Some("") => DEFAULT_FEE_ACCOUNT,
+            Some(account)
+                if account.len() <= 64
+                    && account.chars().all(|c| c.is_ascii_alphanumeric() || "-_.:".contains(c)) =>
+            {
+                account
+            }
+            Some(account) => {
+                tracing::warn!(account = %account, "Ignoring invalid FEE_ACCOUNT");
+                DEFAULT_FEE_ACCOUNT
+            }
+        };
+        Self { account: account.to_string() }
+    }
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self { account: DEFAULT_FEE_ACCOUNT.to_string() }
+    }
+}
+
+fn parse_currency_caps(list: Option<&str>) -> HashMap<String, Decimal> {
+    let mut caps = HashMap::new();
+    for entry in list.unwrap_or_default().split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let parsed = entry
+            .split_once(':')
+            .and_then(|(currency, cap)| Some((currency.trim(), Decimal::from_str(cap.trim()).ok()?)))
+            .filter(|(currency, cap)| !currency.is_empty() && *cap > Decimal::ZERO);
+        match parsed {
+            Some((currency, cap)) => {
+                caps.insert(currency.to_uppercase(), cap);
+            }
+            None => tracing::warn!(entry = %entry, "Ignoring malformed daily currency cap"),
+        }
+    }
+    caps
+}
+
+/// A source `get_fx_rate` can take a rate from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FxSource {
+    /// Primary Chainlink oracle, called through the circuit breaker
+    PrimaryOracle,
+    /// Secondary Chainlink oracle, read live with its own staleness threshold
+    SecondaryOracle,
+    /// Last-known primary oracle price from `price_history`, if not flagged
+    /// stale and no older than `max_age_secs`
+    CachedDb { max_age_secs: u64 },
+    /// Hardcoded fallback rates (refused in production unless STRICT_FX_RATES=false)
+    Static,
+}
+
+/// Default maximum age of a cached database price used as an FX source
+const DEFAULT_FX_DB_PRICE_MAX_AGE_SECS: u64 = 3600;
+
+/// FX rate source order.
+/// `FX_RATE_SOURCES` is a comma-separated list of `primary`, `secondary`,
+/// `db` and `static`; the `db` source accepts prices up to
+/// `FX_DB_PRICE_MAX_AGE_SECS` old (default 1 hour). Defaults to
+/// `primary,db,static`.
+fn fx_rate_sources() -> Vec<FxSource> {
+    parse_fx_sources(
+        std::env::var("FX_RATE_SOURCES").ok().as_deref(),
+        std::env::var("FX_DB_PRICE_MAX_AGE_SECS").ok().as_deref(),
+    )
+}
+
+fn parse_fx_sources(list: Option<&str>, db_max_age_secs: Option<&str>) -> Vec<FxSource> {
+    let max_age_secs = db_max_age_secs
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_FX_DB_PRICE_MAX_AGE_SECS);
+
+    let mut sources = Vec::new();
+    for name in list.unwrap_or_default().split(',').map(|n| n.trim().to_lowercase()) {
+        let source = match name.as_str() {
+            "" => continue,
+            "primary" | "oracle" => FxSource::PrimaryOracle,
+            "secondary" => FxSource::SecondaryOracle,
+            "db" | "cached" => FxSource::CachedDb { max_age_secs },
+            "static" => FxSource::Static,
+            other => {
+                tracing::warn!(source = %other, "Ignoring unknown FX rate source");
+                continue;
+            }
+        };
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    if sources.is_empty() {
+        // Default: live oracle, then its last-known price, then static rates
+        vec![
+            FxSource::PrimaryOracle,
+            FxSource::CachedDb { max_age_secs },
+            FxSource::Static,
+        ]
+    } else {
+        sources
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_deviation_reference() {
+        assert_eq!(parse_deviation_reference(None, None), DeviationReference::LastPrice);
+        assert_eq!(parse_deviation_reference(Some("last"), Some("60")), DeviationReference::LastPrice);
+        assert_eq!(
+            parse_deviation_reference(Some("TWAP"), None),
+            DeviationReference::Twap { window_seconds: DEFAULT_ORACLE_TWAP_WINDOW_SECS }
+        );
+        assert_eq!(
+            parse_deviation_reference(Some("twap"), Some("300")),
+            DeviationReference::Twap { window_seconds: 300 }
+        );
+        assert_eq!(
+            parse_deviation_reference(Some("twap"), Some("0")),
+            DeviationReference::Twap { window_seconds: DEFAULT_ORACLE_TWAP_WINDOW_SECS }
+        );
+    }
+
+    #[test]
+    fn test_parse_currency_caps() {
+        assert!(parse_currency_caps(None).is_empty());
+
+        let caps = parse_currency_caps(Some("eur:5000000, GBP : 250000.50,bogus,JPY:-1,CHF:0,:10,"));
+        assert_eq!(caps.len(), 2);
+        assert_eq!(caps["EUR"], Decimal::from(5_000_000));
+        assert_eq!(caps["GBP"], Decimal::from_str("250000.50").unwrap());
+    }
+
+    #[test]
+    fn test_fee_config_parse() {
+        assert_eq!(FeeConfig::parse(None), FeeConfig::default());
+        assert_eq!(FeeConfig::parse(Some("  ")).account, "treasury");
+        assert_eq!(FeeConfig::parse(Some(" fees:eu-1 ")).account, "fees:eu-1");
+        assert_eq!(FeeConfig::parse(Some("drop table")).account, "treasury");
+        assert_eq!(FeeConfig::parse(Some(&"a".repeat(65))).account, "treasury");
+    }
+
+    #[test]
+    fn test_system_daily_caps_lookup() {
+        let caps = SystemDailyCaps {
+            mint: parse_currency_caps(Some("EUR:1000")),
+            burn: parse_currency_caps(Some("GBP:500")),
+        };
+        assert_eq!(caps.cap_for("MINT", "eur"), Some(Decimal::from(1000)));
+        assert_eq!(caps.cap_for("BURN", "EUR"), None);
+        assert_eq!(caps.cap_for("BURN", "GBP"), Some(Decimal::from(500)));
+        assert_eq!(caps.cap_for("MINT", "GBP"), None);
+        assert_eq!(caps.cap_for("SWAP", "EUR"), None);
+    }
+
+    #[test]
+    fn test_parse_fx_sources() {
+        let default = vec![
+            FxSource::PrimaryOracle,
+            FxSource::CachedDb { max_age_secs: DEFAULT_FX_DB_PRICE_MAX_AGE_SECS },
+            FxSource::Static,
+        ];
+        assert_eq!(parse_fx_sources(None, None), default);
+        assert_eq!(parse_fx_sources(Some(" , bogus"), None), default);
+        assert_eq!(
+            parse_fx_sources(None, Some("300"))[1],
+            FxSource::CachedDb { max_age_secs: 300 }
+        );
+        assert_eq!(
+            parse_fx_sources(Some("primary, Secondary,db,static"), None),
+            vec![
+                FxSource::PrimaryOracle,
+                FxSource::SecondaryOracle,
+                FxSource::CachedDb { max_age_secs: DEFAULT_FX_DB_PRICE_MAX_AGE_SECS },
+                FxSource::Static,
+            ]
+        );
+        // Order is kept as written, duplicates and unknown names dropped
+        assert_eq!(
+            parse_fx_sources(Some("db,nope,secondary,db"), Some("120")),
+            vec![FxSource::CachedDb { max_age_secs: 120 }, FxSource::SecondaryOracle]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_live_oracle_validation() {
+        // Nothing required: passes even without an oracle
+        assert!(AppState::validate_live_oracle_currencies(None, &[]).await.is_ok());
+
+        // Required currency but no oracle to serve it
+        let required = vec!["EUR".to_string()];
+        assert!(matches!(
+            AppState::validate_live_oracle_currencies(None, &required).await,
+            Err(StartupValidationError::OracleNotConfigured(_))
+        ));
+
+        // Currency outside the supported list
+        let unsupported = vec!["XYZ".to_string()];
+        assert!(matches!(
+            AppState::validate_live_oracle_currencies(None, &unsupported).await,
+            Err(StartupValidationError::UnsupportedCurrency(_))
+        ));
+    }
+
     #[test]
     fn test_circuit_breaker_starts_closed() {
         let cb = CircuitBreaker::new();
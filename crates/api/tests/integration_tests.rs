@@ -1,25 +1,69 @@
 //! Integration tests for Meridian REST API
+//!
+//! Each test gets a migrated database from `TestDb`: `DATABASE_URL` when set,
+//! otherwise an ephemeral Postgres container.
 
+use actix_http::Request;
+use actix_web::dev::{Service, ServiceResponse};
 use actix_web::{test, web, App};
 use meridian_api::{routes, AppState};
-use meridian_db::{create_pool, run_migrations};
+use meridian_db::testing::TestDb;
 use serde_json::json;
+use sqlx::PgPool;
 use std::sync::Arc;
 
-/// Helper to get database URL from environment
-fn get_database_url() -> Option<String> {
-    std::env::var("DATABASE_URL").ok()
+/// Inserts a KYC-approved user with `role` (country `DE`) and an active session
+///
+/// Returns the user ID and the session's bearer token.
+async fn create_session_user(pool: &PgPool, role: &str) -> (i32, String) {
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (email, password_hash, role, organization, kyc_status, country_code)
+         VALUES ($1, 'x', $2, 'test', 'APPROVED', 'DE') RETURNING id",
+    )
+    .bind(format!("{}-{}@example.com", role.to_lowercase(), suffix))
+    .bind(role)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+
+    let token = format!("tok_{}", suffix);
+    sqlx::query(
+        "INSERT INTO sessions (user_id, access_token, refresh_token, expires_at)
+         VALUES ($1, $2, $3, NOW() + INTERVAL '1 hour')",
+    )
+    .bind(user_id)
+    .bind(meridian_api::handlers::auth_utils::hash_token_for_lookup(&token))
+    .bind(format!("refresh_{}", suffix))
+    .execute(pool)
+    .await
+    .unwrap();
+
+    (user_id, token)
+}
+
+/// Test service with every route, backed by `state`
+async fn init_app(
+    state: Arc<AppState>,
+) -> impl Service<Request, Response = ServiceResponse, Error = actix_web::Error> {
+    test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(routes::configure),
+    )
+    .await
 }
 
+/// Held by tests that register an active JPY stablecoin: mints use the
+/// stablecoin pegged to their currency, so these tests would see each other's rows
+static JPY_STABLECOIN: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
 #[actix_web::test]
 async fn test_health_check() {
-    let Some(db_url) = get_database_url() else {
-        println!("Skipping test: DATABASE_URL not set");
+    let Some(db) = TestDb::start().await else {
         return;
     };
-
-    let pool = create_pool(&db_url).await.expect("Failed to create pool");
-    run_migrations(&pool).await.expect("Failed to run migrations");
+    let pool = db.pool.clone();
 
     let state = Arc::new(AppState::new(pool).await);
 
@@ -41,14 +85,34 @@ async fn test_health_check() {
 }
 
 #[actix_web::test]
-async fn test_create_single_currency_basket() {
-    let Some(db_url) = get_database_url() else {
-        println!("Skipping test: DATABASE_URL not set");
+async fn test_version_endpoint() {
+    let Some(db) = TestDb::start().await else {
         return;
     };
+    let state = Arc::new(AppState::new(db.pool.clone()).await);
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(routes::configure),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/api/v1/health/version").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    assert!(!body["git_sha"].as_str().unwrap().is_empty());
+    assert!(body["database_version"].as_str().unwrap().starts_with("PostgreSQL"));
+}
 
-    let pool = create_pool(&db_url).await.expect("Failed to create pool");
-    run_migrations(&pool).await.expect("Failed to run migrations");
+#[actix_web::test]
+async fn test_create_single_currency_basket() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
 
     let state = Arc::new(AppState::new(pool).await);
 
@@ -81,14 +145,12 @@ async fn test_create_single_currency_basket() {
 }
 
 #[actix_web::test]
+#[allow(clippy::len_zero)]
 async fn test_list_baskets() {
-    let Some(db_url) = get_database_url() else {
-        println!("Skipping test: DATABASE_URL not set");
+    let Some(db) = TestDb::start().await else {
         return;
     };
-
-    let pool = create_pool(&db_url).await.expect("Failed to create pool");
-    run_migrations(&pool).await.expect("Failed to run migrations");
+    let pool = db.pool.clone();
 
     let state = Arc::new(AppState::new(pool).await);
 
@@ -128,13 +190,10 @@ async fn test_list_baskets() {
 
 #[actix_web::test]
 async fn test_get_nonexistent_basket() {
-    let Some(db_url) = get_database_url() else {
-        println!("Skipping test: DATABASE_URL not set");
+    let Some(db) = TestDb::start().await else {
         return;
     };
-
-    let pool = create_pool(&db_url).await.expect("Failed to create pool");
-    run_migrations(&pool).await.expect("Failed to run migrations");
+    let pool = db.pool.clone();
 
     let state = Arc::new(AppState::new(pool).await);
 
@@ -152,3 +211,2824 @@ async fn test_get_nonexistent_basket() {
 
     assert_eq!(resp.status(), 404);
 }
+
+#[actix_web::test]
+async fn test_diagnostics_requires_auth() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let state = Arc::new(AppState::new(pool).await);
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(routes::configure),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/admin/diagnostics")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 401);
+}
+
+#[actix_web::test]
+async fn test_create_agent_idempotent_retry() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    // KYC-approved user with an active session
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let body = json!({
+        "user_id": user_id,
+        "agent_name": "Retry Agent",
+        "spending_limit_daily": "1000",
+        "spending_limit_transaction": "100",
+        "idempotency_key": format!("idem-{}", suffix),
+    });
+    let create = || {
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&body)
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, create()).await;
+    assert_eq!(resp.status(), 201);
+    let first: serde_json::Value = test::read_body_json(resp).await;
+    assert!(first["api_key"].is_string());
+    assert_eq!(first["idempotent_replay"], false);
+
+    let resp = test::call_service(&app, create()).await;
+    assert_eq!(resp.status(), 200);
+    let retry: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(retry["agent_id"], first["agent_id"]);
+    assert_eq!(retry["wallet_address"], first["wallet_address"]);
+    assert!(retry["api_key"].is_null());
+    assert_eq!(retry["idempotent_replay"], true);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM agent_wallets WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_validate_payment_over_limit_is_dry_run() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    // KYC-approved user with an active session
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_id": user_id,
+                "agent_name": "Dry Run Agent",
+                "spending_limit_daily": "1000",
+                "spending_limit_transaction": "100",
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let agent: serde_json::Value = test::read_body_json(resp).await;
+
+    let validate = |amount: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/agents/validate-payment")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "agent_id": agent["agent_id"],
+                "api_key": agent["api_key"],
+                "recipient": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1",
+                "amount": amount,
+                "currency": "USD",
+            }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, validate("250")).await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(result["valid"], false);
+    assert!(result["reasons"][0]
+        .as_str()
+        .unwrap()
+        .contains("transaction limit"));
+
+    let resp = test::call_service(&app, validate("25")).await;
+    let result: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(result["valid"], true);
+    assert_eq!(result["reasons"].as_array().unwrap().len(), 0);
+
+    let (count,): (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM agent_transactions WHERE agent_id = $1")
+            .bind(agent["agent_id"].as_str().unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(count, 0);
+
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_rotate_agent_key_enforces_ownership() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    // Owner and another user, both KYC-approved with active sessions
+    let (owner_id, owner_token) = create_session_user(&pool, "TREASURY").await;
+    let (other_id, other_token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+            .set_json(json!({
+                "user_id": owner_id,
+                "agent_name": "Rotating Agent",
+                "spending_limit_daily": "1000",
+                "spending_limit_transaction": "100",
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let agent: serde_json::Value = test::read_body_json(resp).await;
+    let agent_id = agent["agent_id"].as_str().unwrap().to_string();
+    let old_key = agent["api_key"].as_str().unwrap().to_string();
+
+    let rotate = |token: &str, agent_id: &str| {
+        test::TestRequest::post()
+            .uri(&format!("/api/v1/agents/{}/rotate-key", agent_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+    let validate = |api_key: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/agents/validate-payment")
+            .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+            .set_json(json!({
+                "agent_id": agent_id,
+                "api_key": api_key,
+                "recipient": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1",
+                "amount": "25",
+                "currency": "USD",
+            }))
+            .to_request()
+    };
+
+    // Another user cannot rotate the key, and the original keeps working
+    let resp = test::call_service(&app, rotate(&other_token, &agent_id)).await;
+    assert_eq!(resp.status(), 403);
+    let resp = test::call_service(&app, validate(&old_key)).await;
+    assert_eq!(resp.status(), 200);
+
+    let resp = test::call_service(&app, rotate(&owner_token, "agent_does_not_exist")).await;
+    assert_eq!(resp.status(), 404);
+
+    let resp = test::call_service(&app, rotate(&owner_token, &agent_id)).await;
+    assert_eq!(resp.status(), 200);
+    let rotated: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(rotated["agent_id"], agent_id.as_str());
+    let new_key = rotated["api_key"].as_str().unwrap().to_string();
+    assert_ne!(new_key, old_key);
+
+    // The old key is revoked immediately; the new one is accepted
+    let resp = test::call_service(&app, validate(&old_key)).await;
+    assert_eq!(resp.status(), 401);
+    let resp = test::call_service(&app, validate(&new_key)).await;
+    assert_eq!(resp.status(), 200);
+
+    sqlx::query("DELETE FROM users WHERE id = ANY($1)")
+        .bind(vec![owner_id, other_id])
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_agent_deactivation_blocks_payments_until_reactivated() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    // Owner and another user, both KYC-approved with active sessions
+    let (owner_id, owner_token) = create_session_user(&pool, "TREASURY").await;
+    let (other_id, other_token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+            .set_json(json!({
+                "user_id": owner_id,
+                "agent_name": "Toggled Agent",
+                "spending_limit_daily": "1000",
+                "spending_limit_transaction": "100",
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let agent: serde_json::Value = test::read_body_json(resp).await;
+    let agent_id = agent["agent_id"].as_str().unwrap().to_string();
+
+    let toggle = |token: &str, action: &str| {
+        test::TestRequest::post()
+            .uri(&format!("/api/v1/agents/{}/{}", agent_id, action))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+    let payment = |path: &str| {
+        test::TestRequest::post()
+            .uri(path)
+            .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+            .set_json(json!({
+                "agent_id": agent["agent_id"],
+                "api_key": agent["api_key"],
+                "recipient": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1",
+                "amount": "25",
+                "currency": "USD",
+            }))
+            .to_request()
+    };
+
+    // Only the owner can toggle the agent
+    let resp = test::call_service(&app, toggle(&other_token, "deactivate")).await;
+    assert_eq!(resp.status(), 403);
+    let resp = test::call_service(&app, toggle(&other_token, "activate")).await;
+    assert_eq!(resp.status(), 403);
+
+    let resp = test::call_service(&app, toggle(&owner_token, "deactivate")).await;
+    assert_eq!(resp.status(), 200);
+    let wallet: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(wallet["agent_id"], agent_id.as_str());
+    assert_eq!(wallet["is_active"], false);
+    assert_eq!(wallet["agent_name"], "Toggled Agent");
+
+    let resp = test::call_service(&app, payment("/api/v1/agents/pay")).await;
+    assert_eq!(resp.status(), 403);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["message"].as_str().unwrap().contains("Agent wallet is inactive"));
+
+    let resp = test::call_service(&app, toggle(&owner_token, "activate")).await;
+    assert_eq!(resp.status(), 200);
+    let wallet: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(wallet["is_active"], true);
+
+    let resp = test::call_service(&app, payment("/api/v1/agents/validate-payment")).await;
+    let result: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(result["valid"], true);
+
+    let (count,): (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM agent_transactions WHERE agent_id = $1")
+            .bind(&agent_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(count, 0, "the inactive payment must not be recorded");
+
+    sqlx::query("DELETE FROM users WHERE id = ANY($1)")
+        .bind(vec![owner_id, other_id])
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_settlement_status_transitions_at_settlement_date() {
+    use chrono::SubsecRound;
+    use meridian_api::handlers::settle_due_operations;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (email, password_hash, role, organization, kyc_status)
+         VALUES ($1, 'x', 'TREASURY', 'test', 'APPROVED') RETURNING id",
+    )
+    .bind(format!("settlement-{}@example.com", suffix))
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // Postgres stores microseconds; keep the boundary exact
+    let settlement_date = (chrono::Utc::now() + chrono::Duration::days(1)).trunc_subsecs(6);
+    let mut ids = Vec::new();
+    for (status, due) in [
+        ("COMPLETED", settlement_date),
+        ("PENDING", settlement_date - chrono::Duration::days(2)),
+        ("FAILED", settlement_date),
+    ] {
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO operations (user_id, operation_type, currency, amount, usd_value, status, settlement_date)
+             VALUES ($1, 'MINT', 'EUR', '100', '108', $2, $3) RETURNING id",
+        )
+        .bind(user_id)
+        .bind(status)
+        .bind(due)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        ids.push(id);
+    }
+
+    let settlement_statuses = || async {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT settlement_status FROM operations WHERE id = ANY($1) ORDER BY id",
+        )
+        .bind(&ids)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        rows.into_iter().map(|(s,)| s).collect::<Vec<_>>()
+    };
+    assert_eq!(settlement_statuses().await, ["UNSETTLED", "UNSETTLED", "UNSETTLED"]);
+
+    // Just before the settlement date: the completed mint is not yet due
+    settle_due_operations(&pool, settlement_date - chrono::Duration::seconds(1))
+        .await
+        .unwrap();
+    assert_eq!(settlement_statuses().await, ["UNSETTLED", "UNSETTLED", "FAILED"]);
+
+    // At the settlement date it settles; the still-pending one never does
+    let run = settle_due_operations(&pool, settlement_date).await.unwrap();
+    assert!(run.settled >= 1);
+    assert_eq!(settlement_statuses().await, ["SETTLED", "UNSETTLED", "FAILED"]);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+/// Confirms transfers up to 30 and reverts anything larger
+struct FakeTransferExecutor;
+
+#[async_trait::async_trait]
+impl meridian_chains::transfer::BlockchainExecutor for FakeTransferExecutor {
+    async fn execute_transfer(
+        &self,
+        _chain: meridian_chains::Chain,
+        _from_wallet: ethers::types::Address,
+        _to: ethers::types::Address,
+        amount: rust_decimal::Decimal,
+        _currency: &str,
+    ) -> meridian_chains::execution::ExecutionResult<ethers::types::TxHash> {
+        if amount > rust_decimal::Decimal::new(30, 0) {
+            return Err(meridian_chains::execution::ExecutionError::Reverted("0xdead".to_string()));
+        }
+        Ok(ethers::types::TxHash::repeat_byte(0xab))
+    }
+}
+
+#[actix_web::test]
+async fn test_agent_pay_uses_blockchain_executor() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let mut state = AppState::new(pool.clone()).await;
+    state.agent_payment_executor = Some(Arc::new(FakeTransferExecutor));
+    let app = init_app(Arc::new(state)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_id": user_id,
+                "agent_name": "Paying Agent",
+                "spending_limit_daily": "1000",
+                "spending_limit_transaction": "100",
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let agent: serde_json::Value = test::read_body_json(resp).await;
+    let agent_id = agent["agent_id"].as_str().unwrap().to_string();
+
+    let pay = |amount: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/agents/pay")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "agent_id": agent["agent_id"],
+                "api_key": agent["api_key"],
+                "recipient": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1",
+                "amount": amount,
+                "currency": "USD",
+            }))
+            .to_request()
+    };
+
+    // Confirmed transfer: the real hash is persisted and the row completes
+    let resp = test::call_service(&app, pay("25")).await;
+    assert_eq!(resp.status(), 200);
+    let paid: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(paid["status"], "COMPLETED");
+    let expected_hash = format!("0x{}", "ab".repeat(32));
+    assert_eq!(paid["transaction_hash"], expected_hash.as_str());
+
+    // Reverted transfer: the row is rolled back to FAILED
+    let resp = test::call_service(&app, pay("40")).await;
+    assert_eq!(resp.status(), 500);
+
+    let rows: Vec<(String, Option<String>)> = sqlx::query_as(
+        "SELECT status, transaction_hash FROM agent_transactions WHERE agent_id = $1 ORDER BY id",
+    )
+    .bind(&agent_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            ("COMPLETED".to_string(), Some(expected_hash)),
+            ("FAILED".to_string(), None),
+        ]
+    );
+
+    sqlx::query("DELETE FROM agent_transactions WHERE agent_id = $1")
+        .bind(&agent_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_records_settlement_chain() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let mint = |body: serde_json::Value| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request()
+    };
+
+    // Outside production the default (and only enabled) chain is Sepolia
+    let resp = test::call_service(
+        &app,
+        mint(json!({ "user_id": user_id, "currency": "GBP", "amount": "10.00" })),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["settlement_chain"], "ethereum-sepolia");
+
+    let (settlement_chain,): (Option<String>,) =
+        sqlx::query_as("SELECT settlement_chain FROM operations WHERE id = $1")
+            .bind(body["transaction_id"].as_i64().unwrap() as i32)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(settlement_chain.as_deref(), Some("ethereum-sepolia"));
+
+    // A chain that is not enabled is refused before anything is recorded
+    let resp = test::call_service(
+        &app,
+        mint(json!({
+            "user_id": user_id,
+            "currency": "GBP",
+            "amount": "10.00",
+            "settlement_chain": "base",
+        })),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_defaults_to_stablecoin_chain() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+    let _jpy = JPY_STABLECOIN.lock().await;
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    // JPY's stablecoin lives on Base Sepolia, which isn't enabled in tests
+    let stablecoin_id = uuid::Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO stablecoins (id, name, symbol, peg_currency, chain_id, status)
+         VALUES ($1, 'JPY Meridian', $2, 'JPY', 84532, 'active')",
+    )
+    .bind(stablecoin_id)
+    .bind(format!("JPYM{}", &suffix[..8]).to_uppercase())
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let mint = |body: serde_json::Value| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request()
+    };
+
+    // Without a requested chain the stablecoin's chain is chosen over the default
+    let resp = test::call_service(
+        &app,
+        mint(json!({ "user_id": user_id, "currency": "JPY", "amount": "1000" })),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["message"].as_str().unwrap().contains("base-sepolia"));
+
+    // A requested chain still wins
+    let resp = test::call_service(
+        &app,
+        mint(json!({
+            "user_id": user_id,
+            "currency": "JPY",
+            "amount": "1000",
+            "settlement_chain": "ethereum-sepolia",
+        })),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["settlement_chain"], "ethereum-sepolia");
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+        .bind(stablecoin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_rounds_amount_before_usd_conversion() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let mint = |amount: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "user_id": user_id, "currency": "EUR", "amount": amount }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, mint("100.2549")).await;
+    assert_eq!(resp.status(), 201);
+    let rounded: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(rounded["amount"], "100.25");
+    assert_eq!(rounded["original_amount"], "100.2549");
+
+    // Converted and charged exactly like a mint of the rounded amount
+    let resp = test::call_service(&app, mint("100.25")).await;
+    assert_eq!(resp.status(), 201);
+    let exact: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(rounded["usd_value"], exact["usd_value"]);
+    assert_eq!(rounded["fees_charged"], exact["fees_charged"]);
+
+    let (amount, original_amount): (rust_decimal::Decimal, Option<rust_decimal::Decimal>) =
+        sqlx::query_as("SELECT amount, original_amount FROM operations WHERE id = $1")
+            .bind(rounded["transaction_id"].as_i64().unwrap() as i32)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(amount, rust_decimal::Decimal::new(10025, 2));
+    assert_eq!(original_amount, Some(rust_decimal::Decimal::new(1002549, 4)));
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_prefers_recent_oracle_price_over_static_fallback() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    // Last-known oracle price for BRL (static fallback is 0.16)
+    let (price_id,): (i64,) = sqlx::query_as(
+        "INSERT INTO price_history (currency_pair, price, source, is_stale)
+         VALUES ('BRL/USD', 0.20, 'chainlink', FALSE) RETURNING id",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // No ETHEREUM_RPC_URL in tests, so the live oracle tier always fails
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/operations/mint")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(json!({ "user_id": user_id, "currency": "BRL", "amount": "10.00" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 201);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let usd_value: rust_decimal::Decimal = body["usd_value"].as_str().unwrap().parse().unwrap();
+    assert_eq!(usd_value, rust_decimal::Decimal::new(50, 0));
+
+    sqlx::query("DELETE FROM price_history WHERE id = $1")
+        .bind(price_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_system_daily_mint_cap_blocks_across_users() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let mut users = Vec::new();
+    for _ in 0..2 {
+        users.push(create_session_user(&pool, "TREASURY").await);
+    }
+
+    // The cap is system-wide, so leave room for exactly one 100 MXN mint on
+    // top of whatever the shared database already holds for today
+    let (minted_today,): (rust_decimal::Decimal,) = sqlx::query_as(
+        "SELECT COALESCE(SUM(amount), 0) FROM operations
+         WHERE operation_type = 'MINT' AND UPPER(currency) = 'MXN'
+         AND status NOT IN ('FAILED', 'CANCELLED')
+         AND created_at >= date_trunc('day', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let cap = minted_today + rust_decimal::Decimal::from(150);
+
+    let mut state = AppState::new(pool.clone()).await;
+    state.system_daily_caps.mint.insert("MXN".to_string(), cap);
+    let app = init_app(Arc::new(state)).await;
+
+    let mint = |user_id: i32, token: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "user_id": user_id, "currency": "MXN", "amount": "100.00" }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, mint(users[0].0, &users[0].1)).await;
+    assert_eq!(resp.status(), 201);
+
+    // The second user has minted nothing today but the system total would hit 200
+    let resp = test::call_service(&app, mint(users[1].0, &users[1].1)).await;
+    assert_eq!(resp.status(), 429);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "limit_exceeded");
+
+    let (blocked_ops,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM operations WHERE user_id = $1")
+        .bind(users[1].0)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(blocked_ops, 0, "a capped mint must not be recorded");
+
+    for (user_id, _) in users {
+        sqlx::query("DELETE FROM operations WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
+
+#[actix_web::test]
+async fn test_daily_mint_limit_per_user() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    // 50 short of the default 10,000,000 limit; a failed mint and one older
+    // than 24 hours don't count
+    sqlx::query(
+        "INSERT INTO operations (user_id, operation_type, currency, amount, usd_value, status, created_at)
+         VALUES ($1, 'MINT', 'ARS', '9999950', '0', 'COMPLETED', NOW() - INTERVAL '1 hour'),
+                ($1, 'MINT', 'ARS', '500', '0', 'FAILED', NOW()),
+                ($1, 'MINT', 'ARS', '500', '0', 'COMPLETED', NOW() - INTERVAL '25 hours')",
+    )
+    .bind(user_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let mint = |amount: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "user_id": user_id, "currency": "ARS", "amount": amount }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, mint("100.00")).await;
+    assert_eq!(resp.status(), 403);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["message"].as_str().unwrap().contains("Daily mint limit exceeded"));
+
+    let resp = test::call_service(&app, mint("50.00")).await;
+    assert_eq!(resp.status(), 201);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_burn_idempotency_replays_identical_body() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let burn = |amount: &str, key: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/burn")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_id": user_id,
+                "currency": "GBP",
+                "amount": amount,
+                "idempotency_key": format!("{}-{}", key, suffix),
+            }))
+            .to_request()
+    };
+
+    // A whitespace-padded amount must echo back the parsed value, not the raw string
+    for (amount, key) in [("40.00", "burn"), (" 40.00", "burn-padded")] {
+        let resp = test::call_service(&app, burn(amount, key)).await;
+        assert_eq!(resp.status(), 201);
+        let first = test::read_body(resp).await;
+
+        let resp = test::call_service(&app, burn(amount, key)).await;
+        assert_eq!(resp.status(), 200);
+        let replay = test::read_body(resp).await;
+        assert_eq!(
+            std::str::from_utf8(&replay).unwrap(),
+            std::str::from_utf8(&first).unwrap()
+        );
+
+        let body: serde_json::Value = serde_json::from_slice(&first).unwrap();
+        assert_eq!(body["amount_burned"], "40.00");
+        assert!(body["net_proceeds"].is_string());
+        assert!(body["fees_charged"].is_string());
+    }
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_idempotency_key_replays_original_operation() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let idempotency_key = format!("mint-{}", suffix);
+    let mint = |amount: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_id": user_id,
+                "currency": "GBP",
+                "amount": amount,
+                "idempotency_key": idempotency_key,
+            }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, mint("25.00")).await;
+    assert_eq!(resp.status(), 201);
+    let first: serde_json::Value = test::read_body_json(resp).await;
+
+    // A retry, even with a different body, replays the stored operation
+    let resp = test::call_service(&app, mint("99.00")).await;
+    assert_eq!(resp.status(), 200);
+    let replay: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(replay["transaction_id"], first["transaction_id"]);
+    assert_eq!(replay["amount"], "25.00");
+    assert_eq!(replay["usd_value"], first["usd_value"]);
+    assert_eq!(replay["settlement_chain"], first["settlement_chain"]);
+
+    let (count,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM operations WHERE user_id = $1 AND idempotency_key = $2",
+    )
+    .bind(user_id)
+    .bind(&idempotency_key)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(count, 1);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_rejects_weak_idempotency_key() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let mut state = AppState::new(pool.clone()).await;
+    state.strict_idempotency_keys = true;
+    let app = init_app(Arc::new(state)).await;
+
+    let mint = |idempotency_key: String| {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_id": user_id,
+                "currency": "GBP",
+                "amount": "10.00",
+                "idempotency_key": idempotency_key,
+            }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, mint("1".to_string())).await;
+    assert_eq!(resp.status(), 400);
+
+    let resp = test::call_service(&app, mint(uuid::Uuid::new_v4().to_string())).await;
+    assert_eq!(resp.status(), 201);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_blocked_below_reserve_ratio_floor() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+    let _jpy = JPY_STABLECOIN.lock().await;
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    // The position is found by peg currency; the symbol deliberately differs
+    let stablecoin_id = uuid::Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO stablecoins (id, name, symbol, peg_currency, chain_id, total_supply, total_reserve_value, status)
+         VALUES ($1, 'JPY Meridian', $2, 'JPY', 11155111, 1000000, 0, 'active')",
+    )
+    .bind(stablecoin_id)
+    .bind(format!("JPYM{}", &suffix[..8]).to_uppercase())
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let mint = || {
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "user_id": user_id, "currency": "JPY", "amount": "1000" }))
+            .to_request()
+    };
+
+    // Unbacked supply: the post-mint ratio stays far below the 100% floor
+    let resp = test::call_service(&app, mint()).await;
+    assert_eq!(resp.status(), 409);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["message"].as_str().unwrap().contains("reserve ratio"));
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0, "a blocked mint must not be recorded");
+
+    // Once the reserves cover the supply the same mint goes through
+    sqlx::query("UPDATE stablecoins SET total_reserve_value = 1000000000 WHERE id = $1")
+        .bind(stablecoin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let resp = test::call_service(&app, mint()).await;
+    assert_eq!(resp.status(), 201);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+        .bind(stablecoin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_large_mint_requires_second_approver() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    // Initiator and approver: KYC-approved TREASURY users with sessions
+    let (initiator_id, initiator_token) = create_session_user(&pool, "TREASURY").await;
+    let (approver_id, approver_token) = create_session_user(&pool, "TREASURY").await;
+
+    // Anything over $100 needs a second approver in this test
+    let mut state = AppState::new(pool.clone()).await;
+    state.mint_approval_threshold_usd = rust_decimal::Decimal::from(100);
+    let app = init_app(Arc::new(state)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/operations/mint")
+            .insert_header(("Authorization", format!("Bearer {}", initiator_token)))
+            .set_json(json!({
+                "user_id": initiator_id,
+                "currency": "EUR",
+                "amount": "1000.00",
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let mint: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(mint["status"], "AWAITING_APPROVAL");
+    let approve_uri = format!("/api/v1/operations/{}/approve", mint["transaction_id"]);
+
+    // Initiator cannot self-approve
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri(&approve_uri)
+            .insert_header(("Authorization", format!("Bearer {}", initiator_token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 403);
+
+    // A different TREASURY user can
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri(&approve_uri)
+            .insert_header(("Authorization", format!("Bearer {}", approver_token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let approval: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(approval["approved_by"], approver_id);
+
+    let (status, approved_by): (String, Option<i32>) =
+        sqlx::query_as("SELECT status, approved_by FROM operations WHERE id = $1")
+            .bind(mint["transaction_id"].as_i64().unwrap() as i32)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(status, "PENDING");
+    assert_eq!(approved_by, Some(approver_id));
+
+    // Approving twice is a conflict
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri(&approve_uri)
+            .insert_header(("Authorization", format!("Bearer {}", approver_token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 409);
+
+    for user_id in [initiator_id, approver_id] {
+        sqlx::query("DELETE FROM operations WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
+
+#[actix_web::test]
+async fn test_create_basket_by_currency_code_alone() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    // No chainlink_feed: filled in from the mainnet catalog
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/baskets/single-currency")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "name": "Auto-feed EUR", "currency_code": "EUR" }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let basket: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        basket["components"][0]["chainlink_feed"],
+        format!("{:?}", meridian_oracle::mainnet_feeds::eur_usd())
+    );
+
+    // Currencies without a catalog feed must still be supplied explicitly
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/baskets/single-currency")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "name": "Unknown", "currency_code": "XYZ" }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    // Audited baskets can only be soft-deleted: audit_logs rows are immutable
+    if let Some(id) = basket["id"].as_str() {
+        sqlx::query("UPDATE baskets SET deleted_at = NOW() WHERE id = $1::uuid")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_basket_value_fails_fast_when_oracle_circuit_open() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    // Trip the oracle circuit breaker before serving any requests
+    let state = AppState::new(pool.clone()).await;
+    for _ in 0..5 {
+        state.oracle_circuit_breaker.record_failure();
+    }
+    let app = init_app(Arc::new(state)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/baskets/single-currency")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "name": "Circuit EUR", "currency_code": "EUR" }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let basket: serde_json::Value = test::read_body_json(resp).await;
+    let basket_id = basket["id"].as_str().unwrap().to_string();
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/baskets/{}/value", basket_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 503);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    // Rejected on circuit state, not on whether an oracle is configured
+    assert_eq!(body["error"], "oracle_unavailable");
+
+    sqlx::query("UPDATE baskets SET deleted_at = NOW() WHERE id = $1::uuid")
+        .bind(&basket_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+/// Serves a JSON-RPC endpoint whose Chainlink aggregators at `answers`
+/// report those 8-decimal answers, updated now; returns its URL
+async fn start_price_feed_rpc(answers: std::collections::HashMap<ethers::types::Address, i64>) -> String {
+    use ethers::abi::{encode, Token};
+    use ethers::types::{Address, I256, U256};
+
+    let answers = Arc::new(answers);
+    let server = actix_web::HttpServer::new(move || {
+        let answers = Arc::clone(&answers);
+        App::new().default_service(web::post().to(move |req: web::Json<serde_json::Value>| {
+            let answers = Arc::clone(&answers);
+            async move {
+                let call = &req["params"][0];
+                let to: Option<Address> = call["to"].as_str().and_then(|to| to.parse().ok());
+                let data = call["data"].as_str().or(call["input"].as_str()).unwrap_or_default();
+                let now = U256::from(chrono::Utc::now().timestamp());
+                let encoded = |tokens: &[Token]| Some(format!("0x{}", ethers::utils::hex::encode(encode(tokens))));
+                let result = match (req["method"].as_str(), data.get(..10)) {
+                    (Some("eth_chainId"), _) => Some("0x1".to_string()),
+                    // decimals()
+                    (Some("eth_call"), Some("0x313ce567")) => encoded(&[Token::Uint(8.into())]),
+                    // description()
+                    (Some("eth_call"), Some("0x7284e416")) => encoded(&[Token::String("TEST / USD".into())]),
+                    // latestRoundData()
+                    (Some("eth_call"), Some("0xfeaf968c")) => to.and_then(|to| answers.get(&to)).and_then(|answer| {
+                        encoded(&[
+                            Token::Uint(1.into()),
+                            Token::Int(I256::from(*answer).into_raw()),
+                            Token::Uint(now),
+                            Token::Uint(now),
+                            Token::Uint(1.into()),
+                        ])
+                    }),
+                    _ => None,
+                };
+                actix_web::HttpResponse::Ok().json(json!({ "jsonrpc": "2.0", "id": req["id"], "result": result }))
+            }
+        }))
+    })
+    .workers(1)
+    .bind(("127.0.0.1", 0))
+    .unwrap();
+    let url = format!("http://{}", server.addrs()[0]);
+    actix_web::rt::spawn(server.run());
+    url
+}
+
+#[actix_web::test]
+async fn test_basket_value_in_base_currency() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+    use meridian_basket::{CurrencyBasket, CurrencyComponent, RebalanceStrategy};
+    use meridian_oracle::{mainnet_feeds, ChainlinkOracle};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    // EUR at 1.08 USD, GBP at 1.25 USD
+    let rpc_url = start_price_feed_rpc(std::collections::HashMap::from([
+        (mainnet_feeds::eur_usd(), 108_000_000),
+        (mainnet_feeds::gbp_usd(), 125_000_000),
+    ]))
+    .await;
+    let oracle = ChainlinkOracle::new(&rpc_url, Decimal::from(10)).await.unwrap();
+    // Components are priced by currency code, cross rates by USD pair
+    for (pair, feed) in [
+        ("EUR", mainnet_feeds::eur_usd()),
+        ("GBP", mainnet_feeds::gbp_usd()),
+        ("EUR/USD", mainnet_feeds::eur_usd()),
+    ] {
+        oracle.register_price_feed(pair, feed).await.unwrap();
+    }
+    let state = AppState::new(pool.clone()).await;
+    *state.oracle.write().await = Some(Arc::new(oracle));
+    let app = init_app(Arc::new(state)).await;
+
+    let component = |code: &str, weight: i64, feed: ethers::types::Address| {
+        CurrencyComponent::new(
+            code.to_string(),
+            Decimal::from(weight),
+            Decimal::from(weight - 10),
+            Decimal::from(weight + 10),
+            format!("{:?}", feed),
+        )
+        .unwrap()
+    };
+    let basket = CurrencyBasket::new_custom_basket(
+        format!("Base {}", uuid::Uuid::new_v4().simple()),
+        vec![
+            component("EUR", 60, mainnet_feeds::eur_usd()),
+            component("GBP", 40, mainnet_feeds::gbp_usd()),
+        ],
+        RebalanceStrategy::None,
+    )
+    .unwrap();
+    let basket_id = meridian_db::BasketRepository::new(pool.clone()).create(&basket).await.unwrap();
+
+    let get = |query: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/baskets/{}/value{}", basket_id, query))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+    let decimal = |value: &serde_json::Value| Decimal::from_str(value.as_str().unwrap()).unwrap();
+
+    // 0.6 * 1.08 + 0.4 * 1.25 USD
+    let resp = test::call_service(&app, get("")).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(decimal(&body["value_usd"]), Decimal::new(1148, 3));
+    assert!(body.get("value_base").is_none());
+
+    // Same value in EUR at the USD/EUR cross rate
+    let resp = test::call_service(&app, get("?base=eur")).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(decimal(&body["value_usd"]), Decimal::new(1148, 3));
+    assert_eq!(body["base_currency"], "EUR");
+    assert_eq!(decimal(&body["base_rate"]).round_dp(6), Decimal::new(925_926, 6));
+    assert_eq!(decimal(&body["value_base"]).round_dp(6), Decimal::new(1_062_963, 6));
+
+    let resp = test::call_service(&app, get("?base=XYZ")).await;
+    assert_eq!(resp.status(), 400);
+
+    sqlx::query("UPDATE baskets SET deleted_at = NOW() WHERE id = $1")
+        .bind(basket_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_startup_feed_checks_pass_with_catalog_feeds() {
+    use meridian_api::state::StartupValidationError;
+    use meridian_oracle::{mainnet_feeds, ChainlinkOracle, OracleError};
+    use rust_decimal::Decimal;
+
+    // Every catalog feed but GBP/USD answers
+    let rpc_url = start_price_feed_rpc(std::collections::HashMap::from([
+        (mainnet_feeds::eur_usd(), 108_000_000),
+        (mainnet_feeds::jpy_usd(), 630_000),
+        (mainnet_feeds::mxn_usd(), 5_800_000),
+        (mainnet_feeds::brl_usd(), 18_000_000),
+    ]))
+    .await;
+    let oracle = ChainlinkOracle::new(&rpc_url, Decimal::from(10)).await.unwrap();
+    // Nothing registered yet: the required feed check names what is missing
+    match AppState::check_required_feeds(&oracle).await {
+        Err(OracleError::MissingRequiredFeeds(missing)) => assert!(missing.contains(&"EUR/USD".to_string())),
+        other => panic!("expected missing feeds, got {:?}", other),
+    }
+
+    AppState::register_catalog_feeds(&oracle).await;
+    AppState::check_required_feeds(&oracle)
+        .await
+        .expect("every catalog feed is registered");
+
+    let live = vec!["EUR".to_string(), "jpy".to_string()];
+    AppState::validate_live_oracle_currencies(Some(&oracle), &live)
+        .await
+        .expect("registered, refreshable feeds pass");
+
+    // Registered but not refreshable
+    let live = vec!["GBP".to_string()];
+    assert!(matches!(
+        AppState::validate_live_oracle_currencies(Some(&oracle), &live).await,
+        Err(StartupValidationError::Oracle(OracleError::FeedRefreshFailed(_)))
+    ));
+}
+
+#[actix_web::test]
+async fn test_get_stablecoin_by_symbol() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "VIEWER").await;
+
+    let symbol = format!("S{}", &suffix[..8]).to_uppercase();
+    let stablecoin_id = meridian_db::StablecoinRepository::new(pool.clone())
+        .create(meridian_db::CreateStablecoinRequest {
+            name: "JPY Meridian".to_string(),
+            symbol: symbol.clone(),
+            decimals: 6,
+            peg_currency: "JPY".to_string(),
+            basket_id: None,
+            chain_id: 11155111,
+        })
+        .await
+        .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/stablecoins/{}", symbol.to_lowercase()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["id"], stablecoin_id.to_string());
+    assert_eq!(body["symbol"], symbol);
+    assert_eq!(body["decimals"], 6);
+    assert_eq!(body["peg_currency"], "JPY");
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri("/api/v1/stablecoins/NOSUCHCOIN")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 404);
+
+    sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+        .bind(stablecoin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_reserve_shortfall() {
+    use meridian_basket::{CurrencyBasket, CurrencyComponent, RebalanceStrategy};
+    use rust_decimal::Decimal;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "VIEWER").await;
+
+    let component = |code: &str, weight: i64, feed: ethers::types::Address| {
+        CurrencyComponent::new(
+            code.to_string(),
+            Decimal::from(weight),
+            Decimal::from(weight - 10),
+            Decimal::from(weight + 10),
+            format!("{:?}", feed),
+        )
+        .unwrap()
+    };
+    let basket = CurrencyBasket::new_custom_basket(
+        format!("Shortfall {}", suffix),
+        vec![
+            component("EUR", 60, meridian_oracle::mainnet_feeds::eur_usd()),
+            component("GBP", 40, meridian_oracle::mainnet_feeds::gbp_usd()),
+        ],
+        RebalanceStrategy::None,
+    )
+    .unwrap();
+    let basket_id = meridian_db::BasketRepository::new(pool.clone()).create(&basket).await.unwrap();
+
+    let stablecoins = meridian_db::StablecoinRepository::new(pool.clone());
+    let mut coins = Vec::new();
+    for (label, basket_id, supply, reserve) in [
+        ("F", None, Decimal::from(1_000_000), Decimal::new(100_000_050, 2)),
+        ("U", Some(basket_id), Decimal::from(1_000_000), Decimal::from(950_000)),
+    ] {
+        let symbol = format!("{}{}", label, &suffix[..8]).to_uppercase();
+        let id = stablecoins
+            .create(meridian_db::CreateStablecoinRequest {
+                name: format!("Shortfall {}", label),
+                symbol: symbol.clone(),
+                decimals: 6,
+                peg_currency: "EUR".to_string(),
+                basket_id,
+                chain_id: 11155111,
+            })
+            .await
+            .unwrap();
+        stablecoins.update_balances(id, supply, reserve).await.unwrap();
+        coins.push((id, symbol));
+    }
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+    let get = |symbol: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/reserves/{}/shortfall", symbol))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    // Over-collateralised: no shortfall, no components
+    let resp = test::call_service(&app, get(&coins[0].1)).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["shortfall"], "0");
+    assert_eq!(body["fully_backed"], true);
+    assert_eq!(body["components"], json!([]));
+
+    // Under-backed basket coin: 50,000 short, split 60/40
+    let resp = test::call_service(&app, get(&coins[1].1.to_lowercase())).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["total_supply"], "1000000");
+    assert_eq!(body["total_reserve_value"], "950000");
+    assert_eq!(body["shortfall"], "50000");
+    assert_eq!(body["fully_backed"], false);
+    assert_eq!(body["components"][0]["currency"], "EUR");
+    assert_eq!(body["components"][0]["shortfall"], "30000");
+    assert_eq!(body["components"][1]["currency"], "GBP");
+    assert_eq!(body["components"][1]["shortfall"], "20000");
+
+    let resp = test::call_service(&app, get("NOSUCHCOIN")).await;
+    assert_eq!(resp.status(), 404);
+
+    for (id, _) in &coins {
+        sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    sqlx::query("DELETE FROM baskets WHERE id = $1")
+        .bind(basket_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_reserves_history_from_snapshots() {
+    use rust_decimal::Decimal;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "VIEWER").await;
+
+    let symbol = format!("R{}", &suffix[..8]).to_uppercase();
+    let stablecoins = meridian_db::StablecoinRepository::new(pool.clone());
+    let coin_id = stablecoins
+        .create(meridian_db::CreateStablecoinRequest {
+            name: "Reserve History".to_string(),
+            symbol: symbol.clone(),
+            decimals: 6,
+            // Not mintable, so concurrent mint tests never see this
+            // under-reserved coin as their currency's position
+            peg_currency: "CHF".to_string(),
+            basket_id: None,
+            chain_id: 11155111,
+        })
+        .await
+        .unwrap();
+    stablecoins
+        .update_balances(coin_id, Decimal::from(1_000_000), Decimal::from(950_000))
+        .await
+        .unwrap();
+    sqlx::query("UPDATE stablecoins SET status = 'active' WHERE id = $1")
+        .bind(coin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+    let get = || {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/reserves/{}", symbol.to_lowercase()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    // No snapshots yet: real current values, placeholder history
+    let resp = test::call_service(&app, get()).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["total_value"], "950000.00");
+    assert_eq!(body["reserve_ratio"], "95.00");
+    assert_ne!(body["data_source"], "database");
+    assert_eq!(body["history"].as_array().unwrap().len(), 30);
+
+    // One snapshot from two days ago, one from the attestation writer and
+    // one outside the 30-day window
+    for days_ago in [2, 45] {
+        sqlx::query(
+            "INSERT INTO reserve_snapshots (symbol, total_value, reserve_ratio, recorded_at)
+             VALUES ($1, 900000, 90, NOW() - make_interval(days => $2))",
+        )
+        .bind(&symbol)
+        .bind(days_ago)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    let reserves = meridian_db::ReserveRepository::new(pool.clone());
+    assert!(reserves.record_active().await.unwrap() >= 1);
+
+    let resp = test::call_service(&app, get()).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["data_source"], "database");
+    let history = body["history"].as_array().unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0]["total_value"], "900000.00");
+    assert_eq!(history[0]["ratio"], "90.00");
+    assert_eq!(history[1]["total_value"], "950000.00");
+    assert_eq!(history[1]["ratio"], "95.00");
+    assert!(history[0]["timestamp"].as_i64() < history[1]["timestamp"].as_i64());
+
+    sqlx::query("DELETE FROM reserve_snapshots WHERE symbol = $1")
+        .bind(&symbol)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+        .bind(coin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_get_customer_compliance() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "VIEWER").await;
+    let (other_id, _) = create_session_user(&pool, "VIEWER").await;
+    sqlx::query("UPDATE users SET kyc_status = 'PENDING_REVIEW' WHERE id = $1")
+        .bind(other_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/compliance/customers/{}", user_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["user_id"], user_id);
+    assert_eq!(body["frameworks"], serde_json::json!(["MiCA"]));
+    assert_eq!(body["record"]["frameworks"], body["frameworks"]);
+    assert_eq!(body["record"]["status"], "Approved");
+    assert_eq!(body["edd_required"], false);
+    assert_eq!(body["review_due"], false);
+    assert_eq!(body["kyc_expired"], false);
+    assert_eq!(body["can_transact"], true);
+
+    // A VIEWER cannot read someone else's record
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/compliance/customers/{}", other_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 403);
+
+    sqlx::query("DELETE FROM users WHERE id = ANY($1)")
+        .bind(vec![user_id, other_id])
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_basket_cap_per_organization() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+    sqlx::query("UPDATE users SET organization = $2 WHERE id = $1")
+        .bind(user_id)
+        .bind(format!("cap-org-{}", suffix))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let mut state = AppState::new(pool.clone()).await;
+    state.max_baskets_per_organization = 2;
+    let app = init_app(Arc::new(state)).await;
+
+    let create = |name: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/baskets/single-currency")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "name": name, "currency_code": "EUR" }))
+            .to_request()
+    };
+
+    let mut ids = Vec::new();
+    for name in ["Cap 1", "Cap 2"] {
+        let resp = test::call_service(&app, create(name)).await;
+        assert_eq!(resp.status(), 201);
+        let basket: serde_json::Value = test::read_body_json(resp).await;
+        ids.push(basket["id"].as_str().unwrap().parse::<uuid::Uuid>().unwrap());
+    }
+
+    // At the cap: refused with 409 and nothing is stored
+    let resp = test::call_service(&app, create("Cap 3")).await;
+    assert_eq!(resp.status(), 409);
+
+    // Soft-deleting a basket frees its slot
+    let repo = meridian_db::BasketRepository::new(pool.clone());
+    repo.soft_delete(ids[0]).await.unwrap();
+    let resp = test::call_service(&app, create("Cap 3")).await;
+    assert_eq!(resp.status(), 201);
+    let basket: serde_json::Value = test::read_body_json(resp).await;
+    ids.push(basket["id"].as_str().unwrap().parse::<uuid::Uuid>().unwrap());
+
+    let (stored,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM baskets WHERE created_by = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored, 3);
+
+    for id in &ids[1..] {
+        repo.soft_delete(*id).await.unwrap();
+    }
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_basket_creation_writes_audit_entry() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+    sqlx::query("UPDATE users SET organization = $2 WHERE id = $1")
+        .bind(user_id)
+        .bind(format!("audit-org-{}", suffix))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri("/api/v1/baskets/custom")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "name": "Audited Basket",
+                "components": [
+                    {
+                        "currency_code": "EUR",
+                        "target_weight": "60",
+                        "min_weight": "50",
+                        "max_weight": "70",
+                        "chainlink_feed": format!("{:?}", meridian_oracle::mainnet_feeds::eur_usd())
+                    },
+                    {
+                        "currency_code": "GBP",
+                        "target_weight": "40",
+                        "min_weight": "30",
+                        "max_weight": "50",
+                        "chainlink_feed": format!("{:?}", meridian_oracle::mainnet_feeds::gbp_usd())
+                    }
+                ],
+                "rebalance_strategy": { "type": "none" }
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 201);
+    let basket: serde_json::Value = test::read_body_json(resp).await;
+    let basket_id: uuid::Uuid = basket["id"].as_str().unwrap().parse().unwrap();
+
+    let logs = meridian_db::AuditRepository::new(pool.clone())
+        .get_basket_logs(basket_id, 10)
+        .await
+        .unwrap();
+    assert_eq!(logs.len(), 1);
+    let entry = &logs[0];
+    assert_eq!(entry.operation, meridian_api::handlers::baskets::BASKET_CREATED_AUDIT_OPERATION);
+    assert_eq!(entry.actor.as_deref(), Some(user_id.to_string().as_str()));
+    assert_eq!(entry.details["id"], basket_id.to_string());
+    assert_eq!(entry.details["type"], "custom_basket");
+    assert_eq!(entry.details["creator_user_id"], user_id);
+    assert_eq!(entry.details["component_codes"], json!(["EUR", "GBP"]));
+
+    sqlx::query("UPDATE baskets SET deleted_at = NOW() WHERE id = $1")
+        .bind(basket_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_create_agent_with_spend_window() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let create = |name: &str, spend_window: serde_json::Value| {
+        let mut body = json!({
+            "user_id": user_id,
+            "agent_name": name,
+            "spending_limit_daily": "1000",
+            "spending_limit_transaction": "100",
+        });
+        if !spend_window.is_null() {
+            body["spend_window"] = spend_window;
+        }
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request()
+    };
+
+    // Omitted: existing behaviour
+    let resp = test::call_service(&app, create("Rolling Agent", serde_json::Value::Null)).await;
+    assert_eq!(resp.status(), 201);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["spend_window"], json!({"type": "ROLLING_24H"}));
+
+    let berlin = json!({"type": "CALENDAR_DAY", "timezone": "Europe/Berlin"});
+    let resp = test::call_service(&app, create("Berlin Agent", berlin.clone())).await;
+    assert_eq!(resp.status(), 201);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["spend_window"], berlin);
+    let (window, tz): (String, Option<String>) =
+        sqlx::query_as("SELECT spend_window, spend_window_tz FROM agent_wallets WHERE agent_id = $1")
+            .bind(body["agent_id"].as_str().unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!((window.as_str(), tz.as_deref()), ("CALENDAR_DAY", Some("Europe/Berlin")));
+
+    let resp = test::call_service(
+        &app,
+        create("Typo Agent", json!({"type": "CALENDAR_DAY", "timezone": "Europe/Berlni"})),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_agent_quota_counts_active_only() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    // Fill the default quota of 10 directly
+    let mut agent_ids = Vec::new();
+    for _ in 0..10 {
+        let agent_id = format!("agent_{}", uuid::Uuid::new_v4().simple());
+        sqlx::query(
+            "INSERT INTO agent_wallets (user_id, agent_id, wallet_address, api_key_hash,
+                 spending_limit_daily, spending_limit_transaction)
+             VALUES ($1, $2, $3, 'hash', '1000', '100')",
+        )
+        .bind(user_id)
+        .bind(&agent_id)
+        .bind(format!("0x{}", &agent_id[6..]))
+        .execute(&pool)
+        .await
+        .unwrap();
+        agent_ids.push(agent_id);
+    }
+
+    let create = || {
+        test::TestRequest::post()
+            .uri("/api/v1/agents/create")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_id": user_id,
+                "agent_name": "Quota Agent",
+                "spending_limit_daily": "1000",
+                "spending_limit_transaction": "100",
+            }))
+            .to_request()
+    };
+    assert_eq!(test::call_service(&app, create()).await.status(), 409);
+
+    // Deactivating an agent frees a slot
+    sqlx::query("UPDATE agent_wallets SET is_active = FALSE WHERE agent_id = $1")
+        .bind(&agent_ids[0])
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert_eq!(test::call_service(&app, create()).await.status(), 201);
+
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_list_agents_daily_spent_matches_per_agent() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    // agent 0: none, agent 1: one tx, agent 2: several incl. FAILED and fractional
+    let payments: [&[(&str, &str)]; 3] = [
+        &[],
+        &[("10.50", "COMPLETED")],
+        &[("0.1", "PENDING"), ("0.2", "COMPLETED"), ("99", "FAILED"), ("3", "COMPLETED")],
+    ];
+    let mut agents = Vec::new();
+    for (i, txs) in payments.iter().enumerate() {
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/api/v1/agents/create")
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_json(json!({
+                    "user_id": user_id,
+                    "agent_name": format!("Spend Agent {}", i),
+                    "spending_limit_daily": "1000",
+                    "spending_limit_transaction": "100",
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 201);
+        let agent: serde_json::Value = test::read_body_json(resp).await;
+
+        for (amount, status) in txs.iter() {
+            sqlx::query(
+                "INSERT INTO agent_transactions (agent_id, currency, amount, recipient, status)
+                 VALUES ($1, 'USD', $2::NUMERIC, '0x0000000000000000000000000000000000000000', $3)",
+            )
+            .bind(agent["agent_id"].as_str().unwrap())
+            .bind(amount)
+            .bind(status)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        agents.push(agent);
+    }
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/agents/list/{}", user_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let listed: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(listed["count"], 3);
+
+    for (agent, expected) in agents.iter().zip(["0", "10.50", "3.3"]) {
+        let listed_spent = listed["agents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["agent_id"] == agent["agent_id"])
+            .map(|a| a["daily_spent"].as_str().unwrap().to_string())
+            .unwrap();
+        assert_eq!(listed_spent, expected, "list spend for {}", agent["agent_id"]);
+
+        // Same figure as the per-agent lookup used for payment validation
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/api/v1/agents/validate-payment")
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_json(json!({
+                    "agent_id": agent["agent_id"],
+                    "api_key": agent["api_key"],
+                    "recipient": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1",
+                    "amount": "1",
+                    "currency": "USD",
+                }))
+                .to_request(),
+        )
+        .await;
+        let validated: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(validated["daily_spent"], listed_spent.as_str(), "payment spend for {}", agent["agent_id"]);
+    }
+
+    let agent_ids: Vec<&str> = agents.iter().map(|a| a["agent_id"].as_str().unwrap()).collect();
+    sqlx::query("DELETE FROM agent_transactions WHERE agent_id = ANY($1)")
+        .bind(&agent_ids)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_daily_spent_respects_spend_window() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let windows = [
+        (json!({"type": "ROLLING_24H"}), "UTC"),
+        (json!({"type": "CALENDAR_DAY_UTC"}), "UTC"),
+        (json!({"type": "CALENDAR_DAY", "timezone": "Pacific/Kiritimati"}), "Pacific/Kiritimati"),
+    ];
+    let mut agents = Vec::new();
+    for (i, (spend_window, tz)) in windows.iter().enumerate() {
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/api/v1/agents/create")
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_json(json!({
+                    "user_id": user_id,
+                    "agent_name": format!("Window Agent {}", i),
+                    "spending_limit_daily": "1000",
+                    "spending_limit_transaction": "100",
+                    "spend_window": spend_window,
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 201);
+        let agent: serde_json::Value = test::read_body_json(resp).await;
+
+        // One payment a minute before local midnight (yesterday, but within
+        // 24 hours), one now
+        sqlx::query(
+            "INSERT INTO agent_transactions (agent_id, currency, amount, recipient, status, created_at)
+             VALUES
+                ($1, 'USD', '40', '0x0000000000000000000000000000000000000000', 'COMPLETED',
+                 (date_trunc('day', NOW() AT TIME ZONE $2) AT TIME ZONE $2) - INTERVAL '1 minute'),
+                ($1, 'USD', '2', '0x0000000000000000000000000000000000000000', 'COMPLETED', NOW())",
+        )
+        .bind(agent["agent_id"].as_str().unwrap())
+        .bind(tz)
+        .execute(&pool)
+        .await
+        .unwrap();
+        agents.push(agent);
+    }
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/agents/list/{}", user_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let listed: serde_json::Value = test::read_body_json(resp).await;
+
+    for (agent, expected) in agents.iter().zip(["42", "2", "2"]) {
+        let listed_spent = listed["agents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["agent_id"] == agent["agent_id"])
+            .map(|a| a["daily_spent"].clone())
+            .unwrap();
+        assert_eq!(listed_spent, expected, "list spend for {}", agent["agent_id"]);
+
+        // Payment validation reads the same window
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/api/v1/agents/validate-payment")
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_json(json!({
+                    "agent_id": agent["agent_id"],
+                    "api_key": agent["api_key"],
+                    "recipient": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1",
+                    "amount": "1",
+                    "currency": "USD",
+                }))
+                .to_request(),
+        )
+        .await;
+        let validated: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(validated["daily_spent"], expected, "payment spend for {}", agent["agent_id"]);
+    }
+
+    let agent_ids: Vec<&str> = agents.iter().map(|a| a["agent_id"].as_str().unwrap()).collect();
+    sqlx::query("DELETE FROM agent_transactions WHERE agent_id = ANY($1)")
+        .bind(&agent_ids)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_bulk_kyc_status_update() {
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (admin_id, token) = create_session_user(&pool, "ADMIN").await;
+    let mut user_ids = vec![admin_id];
+    for kyc_status in ["APPROVED", "APPROVED", "REVIEW_REQUIRED", "NOT_STARTED"] {
+        let (user_id, _) = create_session_user(&pool, "TREASURY").await;
+        sqlx::query("UPDATE users SET kyc_status = $2 WHERE id = $1")
+            .bind(user_id)
+            .bind(kyc_status)
+            .execute(&pool)
+            .await
+            .unwrap();
+        user_ids.push(user_id);
+    }
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    let bulk = |user_ids: &[i32], status: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/admin/kyc/bulk-status")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({
+                "user_ids": user_ids,
+                "status": status,
+                "reason": "Jurisdiction rule change",
+            }))
+            .to_request()
+    };
+    let kyc_status = |user_id: i32| {
+        let pool = pool.clone();
+        async move {
+            let (status,): (String,) = sqlx::query_as("SELECT kyc_status FROM users WHERE id = $1")
+                .bind(user_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            status
+        }
+    };
+
+    // Not an admin-settable status
+    let resp = test::call_service(&app, bulk(&user_ids[1..3], "REJECTED")).await;
+    assert_eq!(resp.status(), 400);
+
+    // One user cannot make the transition: nobody changes
+    let resp = test::call_service(&app, bulk(&user_ids[1..5], "REVIEW_REQUIRED")).await;
+    assert_eq!(resp.status(), 400);
+    assert_eq!(kyc_status(user_ids[1]).await, "APPROVED");
+
+    let resp = test::call_service(&app, bulk(&user_ids[1..4], "REVIEW_REQUIRED")).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], "REVIEW_REQUIRED");
+    assert_eq!(body["updated"].as_array().unwrap().len(), 2);
+    assert_eq!(body["updated"][0]["previous_status"], "APPROVED");
+    assert_eq!(body["unchanged"], json!([user_ids[3]]));
+    for user_id in &user_ids[1..4] {
+        assert_eq!(kyc_status(*user_id).await, "REVIEW_REQUIRED");
+    }
+
+    // One audit entry per change
+    let entries: Vec<_> = meridian_db::AuditRepository::new(pool.clone())
+        .get_by_operation(
+            meridian_api::handlers::kyc::KYC_STATUS_CHANGED_AUDIT_OPERATION,
+            chrono::Utc::now() - chrono::Duration::minutes(5),
+            1000,
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|entry| entry.actor.as_deref() == Some(admin_id.to_string().as_str()))
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|entry| entry.details["new_status"] == "REVIEW_REQUIRED"
+        && entry.details["reason"] == "Jurisdiction rule change"));
+
+    sqlx::query("DELETE FROM users WHERE id = ANY($1)")
+        .bind(&user_ids)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_supply_reconciliation_records_mismatch() {
+    use ethers::providers::Provider;
+    use ethers::types::{Address, Bytes, U256};
+    use meridian_api::reconciliation::{SupplyEvent, SupplyReconciler};
+    use meridian_chains::supply::SupplyReader;
+    use rust_decimal::Decimal;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    // Chain IDs of our own so other stablecoin rows are never picked up
+    let id = uuid::Uuid::new_v4();
+    let base_chain_id = 900_000_000 + (id.as_u128() % 1_000_000) as i32 * 2;
+    let suffix = id.simple().to_string();
+
+    let mut coins = Vec::new();
+    for (offset, label) in [(0, "MATCH"), (1, "DRIFT")] {
+        let chain_id = base_chain_id + offset;
+        let (coin_id,): (uuid::Uuid,) = sqlx::query_as(
+            "INSERT INTO stablecoins (id, name, symbol, contract_address, chain_id, decimals, total_supply, status)
+             VALUES (gen_random_uuid(), $1, $2, $3, $4, 6, 1000, 'active') RETURNING id",
+        )
+        .bind(format!("{} {}", label, suffix))
+        .bind(format!("{}{}", label, &suffix[..6]))
+        .bind(format!("{:?}", Address::random()))
+        .bind(chain_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        coins.push((coin_id, chain_id));
+    }
+
+    // totalSupply() answers in 6-decimal base units
+    let reconciler_for = |chain_id: i32, units: u64| {
+        let (provider, mock) = Provider::mocked();
+        let mut word = [0u8; 32];
+        U256::from(units).to_big_endian(&mut word);
+        mock.push::<Bytes, _>(Bytes::from(word.to_vec())).unwrap();
+        SupplyReconciler::new(Decimal::new(1, 2))
+            .with_reader(chain_id, SupplyReader::new(Arc::new(provider)))
+    };
+    let discrepancies = |coin_id: uuid::Uuid| {
+        let pool = pool.clone();
+        async move {
+            let rows: Vec<(Decimal, Decimal, Decimal)> = sqlx::query_as(
+                "SELECT db_supply, on_chain_supply, difference FROM supply_discrepancies WHERE stablecoin_id = $1",
+            )
+            .bind(coin_id)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+            rows
+        }
+    };
+
+    // Matching supply: nothing recorded, no event
+    let matching = reconciler_for(coins[0].1, 1_000_000_000);
+    let mut events = matching.subscribe();
+    let run = matching.reconcile(&pool).await.unwrap();
+    assert_eq!((run.checked, run.mismatched, run.failed), (1, 0, 0));
+    assert!(discrepancies(coins[0].0).await.is_empty());
+    assert!(events.try_recv().is_err());
+
+    // 5 more tokens on chain than the DB knows about
+    let drifting = reconciler_for(coins[1].1, 1_005_000_000);
+    let mut events = drifting.subscribe();
+    let run = drifting.reconcile(&pool).await.unwrap();
+    assert_eq!((run.checked, run.mismatched, run.failed), (1, 1, 0));
+    assert_eq!(
+        discrepancies(coins[1].0).await,
+        vec![(Decimal::from(1000), Decimal::from(1005), Decimal::from(5))]
+    );
+    match events.try_recv().unwrap() {
+        SupplyEvent::SupplyMismatch { chain_id, difference, .. } => {
+            assert_eq!(chain_id, coins[1].1);
+            assert_eq!(difference, Decimal::from(5));
+        }
+    }
+
+    for (coin_id, _) in coins {
+        sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+            .bind(coin_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
+
+#[actix_web::test]
+async fn test_fee_summary_accrues_operation_fees() {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (treasury_id, treasury_token) = create_session_user(&pool, "TREASURY").await;
+    let (admin_id, admin_token) = create_session_user(&pool, "ADMIN").await;
+
+    // A fee account of our own, so concurrent tests' fees don't show up
+    let mut state = AppState::new(pool.clone()).await;
+    state.fee_config.account = format!("fees-{}", &suffix[..12]);
+    let app = init_app(Arc::new(state)).await;
+
+    let mut expected: std::collections::BTreeMap<&str, Decimal> = Default::default();
+    for (op, currency, amount) in [
+        ("mint", "EUR", "1234.57"),
+        ("mint", "EUR", "0.03"),
+        ("mint", "GBP", "999.99"),
+        ("burn", "EUR", "10.01"),
+    ] {
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/operations/{}", op))
+            .insert_header(("Authorization", format!("Bearer {}", treasury_token)))
+            .set_json(json!({ "user_id": treasury_id, "currency": currency, "amount": amount }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201, "{} {} {}", op, currency, amount);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let fee = Decimal::from_str(body["fees_charged"].as_str().unwrap()).unwrap();
+        *expected.entry(currency).or_default() += fee;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/admin/fees/summary")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let summary: serde_json::Value = test::read_body_json(resp).await;
+
+    let currencies = summary["currencies"].as_array().unwrap();
+    assert_eq!(currencies.len(), 2);
+    for entry in currencies {
+        let currency = entry["currency"].as_str().unwrap();
+        let total = Decimal::from_str(entry["total_fees"].as_str().unwrap()).unwrap();
+        assert_eq!(total, expected[currency], "{}", currency);
+    }
+    assert_eq!(currencies[0]["operations"], 3);
+    let grand_total = Decimal::from_str(summary["total_fees"].as_str().unwrap()).unwrap();
+    assert_eq!(grand_total, expected.values().copied().sum::<Decimal>());
+    assert!(grand_total > Decimal::ZERO);
+
+    // Treasury users can't read the summary
+    let req = test::TestRequest::get()
+        .uri("/api/v1/admin/fees/summary")
+        .insert_header(("Authorization", format!("Bearer {}", treasury_token)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 403);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = $1")
+        .bind(treasury_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = ANY($1)")
+        .bind(vec![treasury_id, admin_id])
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_signed_attestation_roundtrip() {
+    use meridian_api::attestation::AttestationSigner;
+    use rust_decimal::Decimal;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let (user_id, token) = create_session_user(&pool, "VIEWER").await;
+
+    let symbol = format!("A{}", &suffix[..8]).to_uppercase();
+    let stablecoins = meridian_db::StablecoinRepository::new(pool.clone());
+    let coin_id = stablecoins
+        .create(meridian_db::CreateStablecoinRequest {
+            name: "Attested".to_string(),
+            symbol: symbol.clone(),
+            decimals: 6,
+            // Not mintable, so concurrent mint tests never pick this coin
+            peg_currency: "CHF".to_string(),
+            basket_id: None,
+            chain_id: 11155111,
+        })
+        .await
+        .unwrap();
+    stablecoins
+        .update_balances(coin_id, Decimal::from(500_000), Decimal::from(510_000))
+        .await
+        .unwrap();
+    sqlx::query("UPDATE stablecoins SET status = 'active' WHERE id = $1")
+        .bind(coin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Without a signing key both endpoints are unavailable
+    let unsigned = Arc::new(AppState::new(pool.clone()).await);
+    assert!(unsigned.attestation_signer.is_none());
+    let app = init_app(unsigned).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/attestation/latest")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 503);
+
+    let mut state = AppState::new(pool.clone()).await;
+    state.attestation_signer = Some(Arc::new(AttestationSigner::from_hex(&"42".repeat(32)).unwrap()));
+    let app = init_app(Arc::new(state)).await;
+
+    let req = test::TestRequest::get().uri("/api/v1/attestation/latest").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 401);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/attestation/latest")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let mut attestation: serde_json::Value = test::read_body_json(resp).await;
+    let reserve = attestation["reserves"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["symbol"] == symbol.as_str())
+        .cloned()
+        .expect("active stablecoin is attested");
+    assert_eq!(reserve["total_supply"], "500000");
+    assert_eq!(reserve["total_reserve_value"], "510000");
+    assert_eq!(attestation["signature"].as_str().unwrap().len(), 128);
+
+    // Verification needs no session
+    let verify = |body: serde_json::Value| {
+        test::TestRequest::post()
+            .uri("/api/v1/attestation/verify")
+            .set_json(body)
+            .to_request()
+    };
+    let resp = test::call_service(&app, verify(attestation.clone())).await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(result["valid"], true);
+    assert_eq!(result["public_key"], attestation["public_key"]);
+
+    // Any edit to the attested figures invalidates the signature
+    for r in attestation["reserves"].as_array_mut().unwrap() {
+        if r["symbol"] == symbol.as_str() {
+            r["total_reserve_value"] = serde_json::json!("990000");
+        }
+    }
+    let resp = test::call_service(&app, verify(attestation)).await;
+    let result: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(result["valid"], false);
+
+    sqlx::query("DELETE FROM stablecoins WHERE id = $1")
+        .bind(coin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_merkle_proof_validates_against_published_root() {
+    use meridian_api::proof_of_reserves::{
+        hash_user_id, LiabilityLeaf, MerkleProof, ProofStep, SiblingPosition,
+    };
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let (holder, token) = create_session_user(&pool, "VIEWER").await;
+    let (other, _) = create_session_user(&pool, "VIEWER").await;
+
+    // holder: 1000 minted, 250.50 burned, a failed mint ignored; other: 75
+    sqlx::query(
+        "INSERT INTO operations (user_id, operation_type, currency, amount, usd_value, status)
+         VALUES ($1, 'MINT', 'GBP', 1000, 0, 'COMPLETED'),
+                ($1, 'BURN', 'GBP', 250.50, 0, 'COMPLETED'),
+                ($1, 'MINT', 'GBP', 5000, 0, 'FAILED'),
+                ($2, 'MINT', 'GBP', 75, 0, 'COMPLETED')",
+    )
+    .bind(holder)
+    .bind(other)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = init_app(Arc::new(AppState::new(pool.clone()).await)).await;
+
+    // Root is public
+    let req = test::TestRequest::get().uri("/api/v1/reserves/merkle-root?currency=gbp").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let root: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(root["currency"], "GBP");
+    assert_eq!(root["root"].as_str().unwrap().len(), 64);
+    assert!(root["leaf_count"].as_u64().unwrap() >= 2);
+    let total = Decimal::from_str(root["total_liabilities"].as_str().unwrap()).unwrap();
+    assert!(total >= Decimal::new(82450, 2));
+
+    let req = test::TestRequest::get().uri("/api/v1/reserves/merkle-root?currency=XXX").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+
+    // Proofs need a session
+    let req = test::TestRequest::get().uri("/api/v1/reserves/merkle-proof?currency=GBP").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 401);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/reserves/merkle-proof?currency=GBP")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["leaf"]["balance"], "749.5");
+    assert_eq!(body["leaf"]["user_hash"], hex::encode(hash_user_id(holder)));
+
+    // Rebuild the proof from the response, as the user would, and check it
+    let to_hash = |v: &serde_json::Value| -> [u8; 32] {
+        hex::decode(v.as_str().unwrap()).unwrap().try_into().unwrap()
+    };
+    let proof = MerkleProof {
+        leaf: LiabilityLeaf {
+            user_hash: to_hash(&body["leaf"]["user_hash"]),
+            balance: Decimal::from_str(body["leaf"]["balance"].as_str().unwrap()).unwrap(),
+        },
+        path: body["path"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|step| ProofStep {
+                sibling: to_hash(&step["hash"]),
+                position: match step["position"].as_str().unwrap() {
+                    "left" => SiblingPosition::Left,
+                    _ => SiblingPosition::Right,
+                },
+            })
+            .collect(),
+    };
+    assert_eq!(hex::encode(proof.leaf.hash()), body["leaf"]["hash"]);
+    assert!(proof.verify(&to_hash(&body["root"])));
+
+    let mut inflated = proof.clone();
+    inflated.leaf.balance = Decimal::from(10_000);
+    assert!(!inflated.verify(&to_hash(&body["root"])));
+
+    // A newly completed operation rebuilds the cached tree
+    sqlx::query(
+        "INSERT INTO operations (user_id, operation_type, currency, amount, usd_value, status)
+         VALUES ($1, 'MINT', 'GBP', 100, 0, 'COMPLETED')",
+    )
+    .bind(holder)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let req = test::TestRequest::get()
+        .uri("/api/v1/reserves/merkle-proof?currency=GBP")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let body: serde_json::Value = test::read_body_json(test::call_service(&app, req).await).await;
+    assert_eq!(body["leaf"]["balance"], "849.5");
+
+    // No balance, no leaf
+    let req = test::TestRequest::get()
+        .uri("/api/v1/reserves/merkle-proof?currency=JPY")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 404);
+
+    sqlx::query("DELETE FROM operations WHERE user_id = ANY($1)")
+        .bind(vec![holder, other])
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM users WHERE id = ANY($1)")
+        .bind(vec![holder, other])
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_mint_blocked_for_sanctioned_wallet() {
+    use meridian_compliance::sanctions::{EntityType, SanctionListSource, SanctionsList, SanctionsListEntry};
+
+    let Some(db) = TestDb::start().await else {
+        return;
+    };
+    let pool = db.pool.clone();
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let wallet = format!("0x{}", &format!("{}{}", suffix, suffix)[..40]);
+    let (user_id, token) = create_session_user(&pool, "TREASURY").await;
+    sqlx::query("UPDATE users SET wallet_address = $2 WHERE id = $1")
+        .bind(user_id)
+        .bind(&wallet)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let state = AppState::new(pool.clone()).await;
+    state.compliance.replace_sanctions_list(Arc::new(SanctionsList::new(vec![SanctionsListEntry {
+        name: "Sanctioned Mixer".to_string(),
+        entity_type: EntityType::Entity,
+        list_id: format!("SDN-{}", suffix),
+        source: SanctionListSource::OfacSdn,
+        addresses: vec![wallet.to_uppercase().replacen("0X", "0x", 1)],
+    }])));
+    let app = init_app(Arc::new(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/operations/mint")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(json!({ "user_id": user_id, "currency": "EUR", "amount": "10.00" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 403);
+
+    let (operations,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM operations WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(operations, 0);
+
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,145 @@
+//! Currency metadata registry
+//!
+//! Static ISO 4217 metadata for the fiat currencies Meridian prices, mints,
+//! or holds in baskets. The `decimals` field is the ISO 4217 minor-unit
+//! exponent (EUR = 2 for cents, JPY = 0 since there is no sub-yen unit).
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::fmt;
+
+/// Display scale used for currencies missing from the registry
+const DEFAULT_DISPLAY_DECIMALS: u32 = 2;
+
+/// Metadata for a single fiat currency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CurrencyMetadata {
+    /// ISO 4217 alphabetic code
+    pub code: &'static str,
+    /// English currency name
+    pub name: &'static str,
+    /// Number of minor-unit decimal places
+    pub decimals: u32,
+}
+
+/// Registry of known currencies
+const CURRENCIES: &[CurrencyMetadata] = &[
+    CurrencyMetadata { code: "USD", name: "US Dollar", decimals: 2 },
+    CurrencyMetadata { code: "EUR", name: "Euro", decimals: 2 },
+    CurrencyMetadata { code: "GBP", name: "Pound Sterling", decimals: 2 },
+    CurrencyMetadata { code: "JPY", name: "Japanese Yen", decimals: 0 },
+    CurrencyMetadata { code: "CNY", name: "Chinese Yuan", decimals: 2 },
+    CurrencyMetadata { code: "CHF", name: "Swiss Franc", decimals: 2 },
+    CurrencyMetadata { code: "BRL", name: "Brazilian Real", decimals: 2 },
+    CurrencyMetadata { code: "MXN", name: "Mexican Peso", decimals: 2 },
+    CurrencyMetadata { code: "ARS", name: "Argentine Peso", decimals: 2 },
+    CurrencyMetadata { code: "INR", name: "Indian Rupee", decimals: 2 },
+];
+
+/// Looks up metadata for a currency code (case-insensitive)
+///
+/// # Example
+///
+/// ```rust
+/// use meridian_basket::currency::currency_metadata;
+///
+/// assert_eq!(currency_metadata("jpy").unwrap().decimals, 0);
+/// assert!(currency_metadata("XXX").is_none());
+/// ```
+pub fn currency_metadata(code: &str) -> Option<&'static CurrencyMetadata> {
+    CURRENCIES
+        .iter()
+        .find(|c| c.code.eq_ignore_ascii_case(code))
+}
+
+/// Number of minor-unit decimal places for a currency, if known
+pub fn currency_decimals(code: &str) -> Option<u32> {
+    currency_metadata(code).map(|c| c.decimals)
+}
+
+/// All registered currencies
+pub fn all_currencies() -> &'static [CurrencyMetadata] {
+    CURRENCIES
+}
+
+/// An amount tagged with its currency code
+///
+/// `Display` always renders the currency's minor-unit scale (banker's rounding,
+/// zero-padded) followed by the code, so logs read `1235 JPY` / `10.50 EUR`
+/// regardless of the scale the underlying `Decimal` happens to carry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Money {
+    pub amount: Decimal,
+    pub currency: String,
+}
+
+impl Money {
+    /// Creates a new amount in the given currency (code is upper-cased)
+    pub fn new(amount: Decimal, currency: impl AsRef<str>) -> Self {
+        Self {
+            amount,
+            currency: currency.as_ref().to_uppercase(),
+        }
+    }
+
+    /// Decimal places used when displaying this currency
+    pub fn display_scale(&self) -> u32 {
+        currency_decimals(&self.currency).unwrap_or(DEFAULT_DISPLAY_DECIMALS)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = self.display_scale();
+        let mut shown = self.amount.round_dp(scale);
+        shown.rescale(scale);
+        write!(f, "{} {}", shown, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_money_display_jpy_whole_units() {
+        let money = Money::new(Decimal::from_str("1234.56").unwrap(), "jpy");
+        assert_eq!(money.to_string(), "1235 JPY");
+        assert_eq!(Money::new(Decimal::from(500), "JPY").to_string(), "500 JPY");
+    }
+
+    #[test]
+    fn test_money_display_eur_two_decimals() {
+        assert_eq!(Money::new(Decimal::from_str("10.5").unwrap(), "EUR").to_string(), "10.50 EUR");
+        assert_eq!(Money::new(Decimal::from(7), "EUR").to_string(), "7.00 EUR");
+        assert_eq!(
+            Money::new(Decimal::from_str("0.123456789").unwrap(), "EUR").to_string(),
+            "0.12 EUR"
+        );
+    }
+
+    #[test]
+    fn test_money_display_unknown_currency_defaults_to_two_decimals() {
+        assert_eq!(Money::new(Decimal::from_str("1.005").unwrap(), "XYZ").to_string(), "1.00 XYZ");
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(currency_decimals("EUR"), Some(2));
+        assert_eq!(currency_decimals("USD"), Some(2));
+        assert_eq!(currency_decimals("JPY"), Some(0));
+        assert_eq!(currency_decimals("XYZ"), None);
+    }
+
+    #[test]
+    fn test_lookup_case_insensitive() {
+        assert_eq!(currency_metadata("gbp").unwrap().code, "GBP");
+    }
+
+    #[test]
+    fn test_codes_unique() {
+        let codes: std::collections::HashSet<_> = all_currencies().iter().map(|c| c.code).collect();
+        assert_eq!(codes.len(), all_currencies().len());
+    }
+}
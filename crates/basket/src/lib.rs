@@ -34,10 +34,17 @@
 //! let value = eur_basket.calculate_value(&prices).unwrap();
 //! ```
 
+pub mod currency;
+pub mod sdr;
+
+pub use sdr::SdrWeightSet;
+
 use chrono::{DateTime, Utc};
-use rust_decimal::Decimal;
+use ethers::types::Address;
+use rust_decimal::{Decimal, MathematicalOps};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -53,9 +60,18 @@ pub enum BasketError {
     #[error("Price not available for currency: {0}")]
     PriceNotAvailable(String),
 
+    #[error("Holding not available for currency: {0}")]
+    HoldingNotAvailable(String),
+
     #[error("Rebalancing not applicable for basket type: {0:?}")]
     RebalancingNotApplicable(BasketType),
 
+    #[error("Components cannot be edited for basket type: {0:?}")]
+    FixedComposition(BasketType),
+
+    #[error("Currency component already present: {0}")]
+    DuplicateComponent(String),
+
     #[error("Invalid weight range: min={min}, max={max}, target={target}")]
     InvalidWeightRange {
         min: Decimal,
@@ -63,16 +79,40 @@ pub enum BasketError {
         target: Decimal,
     },
 
+    #[error("No IMF SDR weight set in effect on {0}")]
+    NoSdrWeightSet(DateTime<Utc>),
+
     #[error("Empty basket: at least one currency component required")]
     EmptyBasket,
 
+    #[error("Invalid Chainlink feed address: {0}")]
+    InvalidFeedAddress(String),
+
     #[error("Invalid currency code: {0}")]
     InvalidCurrencyCode(String),
 
     #[error("Calculation error: {0}")]
     CalculationError(String),
+
+    #[error("Invalid price confidence: {0} (must be between 0 and 1)")]
+    InvalidConfidence(Decimal),
+
+    #[error("Invalid notional: {0} (must be positive)")]
+    InvalidNotional(Decimal),
+
+    #[error("Invalid basket config: {0}")]
+    InvalidConfig(String),
+
+    #[error("Invalid weight sum tolerance: {0} (must be between 0 and {max})", max = MAX_WEIGHT_SUM_TOLERANCE)]
+    InvalidTolerance(Decimal),
 }
 
+/// Default allowed deviation of target weights from 100%, in percentage points
+pub const DEFAULT_WEIGHT_SUM_TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+
+/// Largest tolerance accepted by `new_custom_basket_with_tolerance` (1 percentage point)
+pub const MAX_WEIGHT_SUM_TOLERANCE: Decimal = Decimal::ONE;
+
 /// Type of currency basket
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BasketType {
@@ -103,6 +143,62 @@ pub enum RebalanceStrategy {
     },
 }
 
+/// How far one component's market-driven weight sits from its target
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentDrift {
+    pub currency_code: String,
+    /// Target weight as a percentage
+    pub target_weight: Decimal,
+    /// Weight implied by current prices, as a percentage
+    pub current_weight: Decimal,
+    /// `|current - target|` in percentage points
+    pub absolute_deviation: Decimal,
+    /// Whether the current weight is inside the component's min/max band
+    pub within_bounds: bool,
+}
+
+/// Trade needed to bring one component back to its target weight
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RebalanceTrade {
+    /// ISO 4217 currency code
+    pub currency_code: String,
+    /// Current weight as a percentage at market prices
+    pub current_weight: Decimal,
+    /// Weight the trade brings the component to, as a percentage (the basket
+    /// target, or the nearest band edge with `RebalanceMode::ToNearestBand`)
+    pub target_weight: Decimal,
+    /// Signed USD amount: positive to buy, negative to sell
+    pub amount_usd: Decimal,
+}
+
+/// What a rebalance plan aims for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RebalanceMode {
+    /// Restore every component to its exact target weight
+    #[default]
+    ToTarget,
+    /// Minimal turnover: only bring components back inside their min/max band
+    ToNearestBand,
+}
+
+/// Rounding strategy for presenting basket values at a fixed scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundingStrategy {
+    /// Banker's rounding: midpoints round to the nearest even digit
+    HalfEven,
+    /// Midpoints round away from zero (conventional "round half up")
+    HalfUp,
+}
+
+impl From<RoundingStrategy> for rust_decimal::RoundingStrategy {
+    fn from(strategy: RoundingStrategy) -> Self {
+        match strategy {
+            RoundingStrategy::HalfEven => rust_decimal::RoundingStrategy::MidpointNearestEven,
+            RoundingStrategy::HalfUp => rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        }
+    }
+}
+
 /// Individual currency component within a basket
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CurrencyComponent {
@@ -135,7 +231,8 @@ impl CurrencyComponent {
     ///
     /// # Errors
     ///
-    /// Returns error if weights are invalid or currency code is malformed
+    /// Returns error if the currency code is malformed, weights are invalid,
+    /// or `chainlink_feed` is not an Ethereum address (checked in that order)
     pub fn new(
         currency_code: String,
         target_weight: Decimal,
@@ -157,6 +254,11 @@ impl CurrencyComponent {
             });
         }
 
+        // Catch typos here rather than when the oracle first reads the feed
+        if Address::from_str(&chainlink_feed).is_err() {
+            return Err(BasketError::InvalidFeedAddress(chainlink_feed));
+        }
+
         Ok(Self {
             id: Uuid::new_v4(),
             currency_code,
@@ -189,6 +291,10 @@ pub struct CurrencyBasket {
     pub rebalance_strategy: RebalanceStrategy,
     /// Last rebalance timestamp
     pub last_rebalanced: Option<DateTime<Utc>>,
+    /// Minimum oracle confidence (0-1) every component price must meet
+    /// before the basket is valued; `None` accepts any non-stale price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_price_confidence: Option<Decimal>,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 }
@@ -238,13 +344,14 @@ impl CurrencyBasket {
             components: vec![component],
             rebalance_strategy: RebalanceStrategy::None,
             last_rebalanced: None,
+            min_price_confidence: None,
             created_at: Utc::now(),
         })
     }
 
-    /// Creates a new IMF SDR basket with standard weights
+    /// Creates a new IMF SDR basket with the current weights
     ///
-    /// The IMF Special Drawing Rights basket consists of:
+    /// Uses the most recent [`SdrWeightSet`]; the 2022 review basket consists of:
     /// - USD: 43.38%
     /// - EUR: 29.31%
     /// - CNY: 12.28%
@@ -272,34 +379,40 @@ impl CurrencyBasket {
     /// let basket = CurrencyBasket::new_imf_sdr("IMF SDR".to_string(), feeds).unwrap();
     /// ```
     pub fn new_imf_sdr(name: String, feeds: HashMap<String, String>) -> Result<Self, BasketError> {
-        // IMF SDR weights as of 2024 (reviewed every 5 years)
-        let sdr_weights = [
-            ("USD", "43.38", "41.21", "45.55"),
-            ("EUR", "29.31", "27.84", "30.78"),
-            ("CNY", "12.28", "11.67", "12.89"),
-            ("JPY", "7.59", "7.21", "7.97"),
-            ("GBP", "7.44", "7.07", "7.81"),
-        ];
+        Self::from_sdr_weight_set(name, &feeds, &SdrWeightSet::latest())
+    }
+
+    /// Creates an IMF SDR basket with the weights in effect at `as_of`
+    ///
+    /// Lets SDR-pegged coins keep valuing against the right weights across an
+    /// IMF review transition.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoSdrWeightSet` if `as_of` precedes the earliest known set.
+    pub fn new_imf_sdr_for_date(
+        name: String,
+        feeds: HashMap<String, String>,
+        as_of: DateTime<Utc>,
+    ) -> Result<Self, BasketError> {
+        let weights = SdrWeightSet::for_date(as_of).ok_or(BasketError::NoSdrWeightSet(as_of))?;
+        Self::from_sdr_weight_set(name, &feeds, &weights)
+    }
 
+    fn from_sdr_weight_set(
+        name: String,
+        feeds: &HashMap<String, String>,
+        weights: &SdrWeightSet,
+    ) -> Result<Self, BasketError> {
         let mut components = Vec::new();
 
-        for (code, target, min, max) in sdr_weights {
+        for (code, target) in weights.weights() {
             let feed = feeds
                 .get(code)
                 .ok_or_else(|| BasketError::ComponentNotFound(code.to_string()))?;
+            let (min, max) = SdrWeightSet::band(target);
 
-            let component = CurrencyComponent::new(
-                code.to_string(),
-                Decimal::from_str_exact(target)
-                    .map_err(|e| BasketError::CalculationError(format!("Invalid weight: {}", e)))?,
-                Decimal::from_str_exact(min)
-                    .map_err(|e| BasketError::CalculationError(format!("Invalid weight: {}", e)))?,
-                Decimal::from_str_exact(max)
-                    .map_err(|e| BasketError::CalculationError(format!("Invalid weight: {}", e)))?,
-                feed.clone(),
-            )?;
-
-            components.push(component);
+            components.push(CurrencyComponent::new(code.to_string(), target, min, max, feed.clone())?);
         }
 
         Ok(Self {
@@ -311,6 +424,7 @@ impl CurrencyBasket {
                 max_deviation_percent: Decimal::new(5, 0), // 5% deviation
             },
             last_rebalanced: None,
+            min_price_confidence: None,
             created_at: Utc::now(),
         })
     }
@@ -364,20 +478,32 @@ impl CurrencyBasket {
         components: Vec<CurrencyComponent>,
         rebalance_strategy: RebalanceStrategy,
     ) -> Result<Self, BasketError> {
-        if components.is_empty() {
-            return Err(BasketError::EmptyBasket);
-        }
-
-        // Validate that weights sum to 100%
-        let total_weight: Decimal = components.iter().map(|c| c.target_weight).sum();
-        let hundred = Decimal::new(100, 0);
+        Self::new_custom_basket_with_tolerance(name, components, rebalance_strategy, DEFAULT_WEIGHT_SUM_TOLERANCE)
+    }
 
-        if (total_weight - hundred).abs() > Decimal::new(1, 2) {
-            // Allow 0.01% tolerance
-            return Err(BasketError::InvalidWeights {
-                actual: total_weight,
-            });
+    /// Create a custom basket, allowing target weights to sum to within
+    /// `tolerance` percentage points of 100%
+    ///
+    /// Useful when weights come from an external source with coarser
+    /// rounding than the 0.01 default. The tolerance only applies here;
+    /// later `add_component` / `remove_component` calls revalidate at the
+    /// default.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidTolerance` if `tolerance` is negative or above
+    ///   `MAX_WEIGHT_SUM_TOLERANCE`
+    /// - `EmptyBasket` / `InvalidWeights` if the weights are out of tolerance
+    pub fn new_custom_basket_with_tolerance(
+        name: String,
+        components: Vec<CurrencyComponent>,
+        rebalance_strategy: RebalanceStrategy,
+        tolerance: Decimal,
+    ) -> Result<Self, BasketError> {
+        if tolerance < Decimal::ZERO || tolerance > MAX_WEIGHT_SUM_TOLERANCE {
+            return Err(BasketError::InvalidTolerance(tolerance));
         }
+        validate_total_weight_within(&components, tolerance)?;
 
         Ok(Self {
             id: Uuid::new_v4(),
@@ -386,10 +512,219 @@ impl CurrencyBasket {
             components,
             rebalance_strategy,
             last_rebalanced: None,
+            min_price_confidence: None,
             created_at: Utc::now(),
         })
     }
 
+    /// Adds a component to a custom basket
+    ///
+    /// Weights are revalidated after the change; if they would no longer sum
+    /// to 100% the basket is left unchanged and the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - `FixedComposition` for single-currency and IMF SDR baskets
+    /// - `DuplicateComponent` if the currency is already in the basket
+    /// - `InvalidWeights` if the resulting weights do not sum to 100%
+    pub fn add_component(&mut self, component: CurrencyComponent) -> Result<(), BasketError> {
+        self.ensure_editable()?;
+
+        if self.get_component(&component.currency_code).is_some() {
+            return Err(BasketError::DuplicateComponent(component.currency_code));
+        }
+
+        let mut components = self.components.clone();
+        components.push(component);
+        validate_total_weight(&components)?;
+
+        self.components = components;
+        Ok(())
+    }
+
+    /// Removes a component from a custom basket, returning it
+    ///
+    /// Weights are revalidated after the change; if they would no longer sum
+    /// to 100% the basket is left unchanged and the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - `FixedComposition` for single-currency and IMF SDR baskets
+    /// - `ComponentNotFound` if the currency is not in the basket
+    /// - `EmptyBasket` / `InvalidWeights` if the remaining components are invalid
+    pub fn remove_component(&mut self, currency_code: &str) -> Result<CurrencyComponent, BasketError> {
+        self.ensure_editable()?;
+
+        let index = self
+            .components
+            .iter()
+            .position(|c| c.currency_code == currency_code)
+            .ok_or_else(|| BasketError::ComponentNotFound(currency_code.to_string()))?;
+
+        let mut components = self.components.clone();
+        let removed = components.remove(index);
+        validate_total_weight(&components)?;
+
+        self.components = components;
+        Ok(removed)
+    }
+
+    /// Checks that target weights sum to 100% at the default tolerance
+    ///
+    /// Components are public, so a basket edited directly (or deserialized)
+    /// may no longer satisfy the constructor checks. Call this before
+    /// persisting such a basket.
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyBasket` if there are no components
+    /// - `InvalidWeights` if the target weights do not sum to 100%
+    pub fn validate_weights(&self) -> Result<(), BasketError> {
+        validate_total_weight(&self.components)
+    }
+
+    /// Rescales every component so target weights sum to exactly 100%
+    ///
+    /// Mutates the basket in place. Each `target_weight`, `min_weight` and
+    /// `max_weight` is multiplied by `100 / sum(target_weight)`; any rounding
+    /// residue is absorbed by the largest component so the sum is exact.
+    /// Call this on raw inputs (e.g. market-cap or GDP shares) before the
+    /// 100% check performed by `new_custom_basket` and `add_component`.
+    /// On error the basket is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyBasket` if there are no components
+    /// - `CalculationError` if the current weights sum to zero
+    /// - `InvalidWeightRange` if a rescaled `max_weight` exceeds 100% or
+    ///   falls below its `min_weight`
+    pub fn normalize_weights(&mut self) -> Result<(), BasketError> {
+        if self.components.is_empty() {
+            return Err(BasketError::EmptyBasket);
+        }
+
+        let hundred = Decimal::new(100, 0);
+        let total: Decimal = self.components.iter().map(|c| c.target_weight).sum();
+        if total.is_zero() {
+            return Err(BasketError::CalculationError(
+                "Cannot normalize weights that sum to zero".to_string(),
+            ));
+        }
+
+        let factor = hundred / total;
+        let mut components = self.components.clone();
+        for component in &mut components {
+            component.target_weight *= factor;
+            component.min_weight *= factor;
+            component.max_weight *= factor;
+        }
+
+        // Division can leave a residue in the last digit; put it on the largest weight
+        let residue = hundred - components.iter().map(|c| c.target_weight).sum::<Decimal>();
+        if !residue.is_zero() {
+            if let Some(largest) = components.iter_mut().max_by_key(|c| c.target_weight) {
+                largest.target_weight += residue;
+                largest.min_weight = largest.min_weight.min(largest.target_weight);
+                largest.max_weight = largest.max_weight.max(largest.target_weight);
+            }
+        }
+
+        if let Some(invalid) = components
+            .iter()
+            .find(|c| c.max_weight > hundred || c.max_weight < c.min_weight)
+        {
+            return Err(BasketError::InvalidWeightRange {
+                min: invalid.min_weight,
+                max: invalid.max_weight,
+                target: invalid.target_weight,
+            });
+        }
+
+        self.components = components;
+        Ok(())
+    }
+
+    /// Only custom baskets may have their components edited
+    fn ensure_editable(&self) -> Result<(), BasketError> {
+        match self.basket_type {
+            BasketType::CustomBasket => Ok(()),
+            other => Err(BasketError::FixedComposition(other)),
+        }
+    }
+
+    /// Requires every component price to have at least `min` confidence (0-1)
+    pub fn with_min_price_confidence(mut self, min: Decimal) -> Result<Self, BasketError> {
+        if min < Decimal::ZERO || min > Decimal::ONE {
+            return Err(BasketError::InvalidConfidence(min));
+        }
+        self.min_price_confidence = Some(min);
+        Ok(self)
+    }
+
+    /// Serializes the basket, including its rebalance strategy, to TOML
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidConfig` if the basket cannot be represented as TOML
+    pub fn to_toml(&self) -> Result<String, BasketError> {
+        toml::to_string(self).map_err(|e| BasketError::InvalidConfig(e.to_string()))
+    }
+
+    /// Loads a basket from a TOML config file written by [`Self::to_toml`]
+    ///
+    /// The file is not trusted: every component is rechecked as in
+    /// [`CurrencyComponent::new`], and weights must sum to 100% as in the
+    /// basket constructors.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidConfig` if the TOML does not describe a basket
+    /// - `InvalidCurrencyCode`, `InvalidWeightRange` or `InvalidFeedAddress`
+    ///   for a malformed component
+    /// - `EmptyBasket` / `InvalidWeights` if the target weights are invalid
+    /// - `InvalidConfidence` if `min_price_confidence` is outside 0-1
+    pub fn from_toml(s: &str) -> Result<Self, BasketError> {
+        let basket: Self = toml::from_str(s).map_err(|e| BasketError::InvalidConfig(e.to_string()))?;
+
+        for component in &basket.components {
+            CurrencyComponent::new(
+                component.currency_code.clone(),
+                component.target_weight,
+                component.min_weight,
+                component.max_weight,
+                component.chainlink_feed.clone(),
+            )?;
+        }
+        validate_total_weight(&basket.components)?;
+
+        match basket.min_price_confidence {
+            Some(min) => {
+                let mut basket = basket;
+                basket.min_price_confidence = None;
+                basket.with_min_price_confidence(min)
+            }
+            None => Ok(basket),
+        }
+    }
+
+    /// First component whose price confidence is below the basket's minimum
+    ///
+    /// Components missing from `confidences` count as zero confidence.
+    /// Returns `None` when the basket has no minimum or every component meets it.
+    pub fn low_confidence_component(
+        &self,
+        confidences: &HashMap<String, Decimal>,
+    ) -> Option<(&str, Decimal)> {
+        let min = self.min_price_confidence?;
+        self.components.iter().find_map(|component| {
+            let confidence = confidences
+                .get(&component.currency_code)
+                .copied()
+                .unwrap_or(Decimal::ZERO);
+            (confidence < min).then_some((component.currency_code.as_str(), confidence))
+        })
+    }
+
     /// Calculates the current value of the basket in USD
     ///
     /// This method takes a map of currency prices (in USD) and computes
@@ -431,19 +766,130 @@ impl CurrencyBasket {
         prices: &HashMap<String, Decimal>,
     ) -> Result<Decimal, BasketError> {
         let mut total_value = Decimal::ZERO;
+
+        for component in &self.components {
+            let component_value = component_value(component, prices)?;
+
+            total_value = total_value.checked_add(component_value).ok_or_else(|| {
+                BasketError::CalculationError("Overflow in total value".to_string())
+            })?;
+        }
+
+        Ok(total_value)
+    }
+
+    /// USD contribution of each currency to the basket value
+    ///
+    /// Each contribution is `(target_weight / 100) * price`, computed exactly
+    /// as in [`calculate_value`](Self::calculate_value), so the values sum to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meridian_basket::CurrencyBasket;
+    /// use rust_decimal::Decimal;
+    /// use std::collections::HashMap;
+    ///
+    /// let basket = CurrencyBasket::new_single_currency(
+    ///     "EUR Basket".to_string(),
+    ///     "EUR".to_string(),
+    ///     "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+    /// ).unwrap();
+    ///
+    /// let mut prices = HashMap::new();
+    /// prices.insert("EUR".to_string(), Decimal::new(108, 2));
+    ///
+    /// let contributions = basket.value_contributions(&prices).unwrap();
+    /// assert_eq!(contributions["EUR"], Decimal::new(108, 2));
+    /// ```
+    pub fn value_contributions(
+        &self,
+        prices: &HashMap<String, Decimal>,
+    ) -> Result<HashMap<String, Decimal>, BasketError> {
+        self.components
+            .iter()
+            .map(|component| {
+                Ok((component.currency_code.clone(), component_value(component, prices)?))
+            })
+            .collect()
+    }
+
+    /// Each currency's contribution as a percentage of the total basket value
+    ///
+    /// # Errors
+    ///
+    /// Returns `PriceNotAvailable` for a missing price and `CalculationError`
+    /// if the basket value is zero.
+    pub fn value_contributions_percent(
+        &self,
+        prices: &HashMap<String, Decimal>,
+    ) -> Result<HashMap<String, Decimal>, BasketError> {
+        let contributions = self.value_contributions(prices)?;
+        let total: Decimal = contributions.values().sum();
+        if total.is_zero() {
+            return Err(BasketError::CalculationError(
+                "Basket value is zero; contributions are undefined".to_string(),
+            ));
+        }
+
         let hundred = Decimal::new(100, 0);
+        Ok(contributions
+            .into_iter()
+            .map(|(code, value)| (code, value / total * hundred))
+            .collect())
+    }
+
+    /// Calculates the USD value of the units actually held
+    ///
+    /// Unlike [`calculate_value`](Self::calculate_value), which assumes the
+    /// basket sits exactly at its target weights, this reflects drift between
+    /// rebalances: each component's held units are multiplied by its USD price.
+    ///
+    /// # Arguments
+    ///
+    /// * `holdings` - Units held per currency code
+    /// * `prices` - Current market prices in USD
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meridian_basket::CurrencyBasket;
+    /// use rust_decimal::Decimal;
+    /// use std::collections::HashMap;
+    ///
+    /// let basket = CurrencyBasket::new_single_currency(
+    ///     "EUR Basket".to_string(),
+    ///     "EUR".to_string(),
+    ///     "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+    /// ).unwrap();
+    ///
+    /// let mut holdings = HashMap::new();
+    /// holdings.insert("EUR".to_string(), Decimal::new(1000, 0));
+    ///
+    /// let mut prices = HashMap::new();
+    /// prices.insert("EUR".to_string(), Decimal::new(108, 2)); // 1.08
+    ///
+    /// let value = basket.calculate_value_from_holdings(&holdings, &prices).unwrap();
+    /// assert_eq!(value, Decimal::new(1080, 0));
+    /// ```
+    pub fn calculate_value_from_holdings(
+        &self,
+        holdings: &HashMap<String, Decimal>,
+        prices: &HashMap<String, Decimal>,
+    ) -> Result<Decimal, BasketError> {
+        let mut total_value = Decimal::ZERO;
 
         for component in &self.components {
+            let units = holdings
+                .get(&component.currency_code)
+                .ok_or_else(|| BasketError::HoldingNotAvailable(component.currency_code.clone()))?;
             let price = prices
                 .get(&component.currency_code)
                 .ok_or_else(|| BasketError::PriceNotAvailable(component.currency_code.clone()))?;
 
-            // Value = (weight / 100) * price
-            let component_value = (component.target_weight / hundred)
-                .checked_mul(*price)
-                .ok_or_else(|| {
-                    BasketError::CalculationError("Overflow in value calculation".to_string())
-                })?;
+            let component_value = units.checked_mul(*price).ok_or_else(|| {
+                BasketError::CalculationError("Overflow in holdings valuation".to_string())
+            })?;
 
             total_value = total_value.checked_add(component_value).ok_or_else(|| {
                 BasketError::CalculationError("Overflow in total value".to_string())
@@ -453,6 +899,42 @@ impl CurrencyBasket {
         Ok(total_value)
     }
 
+    /// Calculates the basket value in USD rounded to `scale` decimal places
+    ///
+    /// Internal calculation keeps full precision; rounding is applied once to
+    /// the final total so consumers can choose their own presentation rules.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meridian_basket::{CurrencyBasket, RoundingStrategy};
+    /// use rust_decimal::Decimal;
+    /// use std::collections::HashMap;
+    ///
+    /// let basket = CurrencyBasket::new_single_currency(
+    ///     "EUR Basket".to_string(),
+    ///     "EUR".to_string(),
+    ///     "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+    /// ).unwrap();
+    ///
+    /// let mut prices = HashMap::new();
+    /// prices.insert("EUR".to_string(), Decimal::new(108_456, 5)); // 1.08456
+    ///
+    /// let value = basket
+    ///     .calculate_value_rounded(&prices, 2, RoundingStrategy::HalfUp)
+    ///     .unwrap();
+    /// assert_eq!(value, Decimal::new(108, 2));
+    /// ```
+    pub fn calculate_value_rounded(
+        &self,
+        prices: &HashMap<String, Decimal>,
+        scale: u32,
+        strategy: RoundingStrategy,
+    ) -> Result<Decimal, BasketError> {
+        let value = self.calculate_value(prices)?;
+        Ok(value.round_dp_with_strategy(scale, strategy.into()))
+    }
+
     /// Determines if the basket needs rebalancing
     ///
     /// Checks current weights against target weights based on the
@@ -523,34 +1005,23 @@ impl CurrencyBasket {
             RebalanceStrategy::ThresholdBased {
                 max_deviation_percent,
             } => {
-                // Calculate current weights based on market prices
-                let current_weights = self.calculate_current_weights(prices)?;
-
                 // Check if any component is outside its bounds
-                for component in &self.components {
-                    let current_weight =
-                        current_weights
-                            .get(&component.currency_code)
-                            .ok_or_else(|| {
-                                BasketError::ComponentNotFound(component.currency_code.clone())
-                            })?;
-
-                    if !component.is_within_bounds(*current_weight) {
+                for drift in self.drift_report(prices)? {
+                    if !drift.within_bounds {
                         tracing::info!(
-                            currency = %component.currency_code,
-                            target = %component.target_weight,
-                            current = %current_weight,
+                            currency = %drift.currency_code,
+                            target = %drift.target_weight,
+                            current = %drift.current_weight,
                             "Component outside bounds, rebalancing needed"
                         );
                         return Ok(true);
                     }
 
                     // Also check absolute deviation from target
-                    let deviation = (*current_weight - component.target_weight).abs();
-                    if deviation > *max_deviation_percent {
+                    if drift.absolute_deviation > *max_deviation_percent {
                         tracing::info!(
-                            currency = %component.currency_code,
-                            deviation = %deviation,
+                            currency = %drift.currency_code,
+                            deviation = %drift.absolute_deviation,
                             threshold = %max_deviation_percent,
                             "Deviation threshold exceeded"
                         );
@@ -573,24 +1044,201 @@ impl CurrencyBasket {
         }
     }
 
-    /// Calculates current weights based on market prices
-    ///
-    /// This is used internally to determine if rebalancing is needed.
-    ///
-    /// # Arguments
+    /// Reports each component's drift from its target weight at current prices
     ///
-    /// * `prices` - Current market prices in USD
+    /// These are the figures `needs_rebalancing` checks for threshold-based
+    /// baskets, returned in component order without making a decision, so
+    /// they can be shown for any strategy.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Map of currency codes to their current weights as percentages
-    fn calculate_current_weights(
+    /// Returns error if any component price is missing
+    pub fn drift_report(
         &self,
         prices: &HashMap<String, Decimal>,
-    ) -> Result<HashMap<String, Decimal>, BasketError> {
-        let total_value = self.calculate_value(prices)?;
-        let mut current_weights = HashMap::new();
-        let hundred = Decimal::new(100, 0);
+    ) -> Result<Vec<ComponentDrift>, BasketError> {
+        let current_weights = self.calculate_current_weights(prices)?;
+
+        self.components
+            .iter()
+            .map(|component| {
+                let current_weight = *current_weights
+                    .get(&component.currency_code)
+                    .ok_or_else(|| BasketError::ComponentNotFound(component.currency_code.clone()))?;
+
+                Ok(ComponentDrift {
+                    currency_code: component.currency_code.clone(),
+                    target_weight: component.target_weight,
+                    current_weight,
+                    absolute_deviation: (current_weight - component.target_weight).abs(),
+                    within_bounds: component.is_within_bounds(current_weight),
+                })
+            })
+            .collect()
+    }
+
+    /// Computes the trades that restore every component to its target weight
+    ///
+    /// Each trade is `(target - current) / 100 * total_notional`, so buys and
+    /// sells net to zero. Components already on target produce no trade.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Current market prices in USD
+    /// * `total_notional` - USD value of the holdings being rebalanced
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meridian_basket::{CurrencyBasket, CurrencyComponent, RebalanceStrategy};
+    /// use rust_decimal::Decimal;
+    /// use std::collections::HashMap;
+    ///
+    /// let eur = CurrencyComponent::new(
+    ///     "EUR".to_string(),
+    ///     Decimal::new(50, 0),
+    ///     Decimal::new(45, 0),
+    ///     Decimal::new(55, 0),
+    ///     "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+    /// ).unwrap();
+    ///
+    /// let usd = CurrencyComponent::new(
+    ///     "USD".to_string(),
+    ///     Decimal::new(50, 0),
+    ///     Decimal::new(45, 0),
+    ///     Decimal::new(55, 0),
+    ///     "0x0000000000000000000000000000000000000001".to_string(),
+    /// ).unwrap();
+    ///
+    /// let basket = CurrencyBasket::new_custom_basket(
+    ///     "EUR-USD".to_string(),
+    ///     vec![eur, usd],
+    ///     RebalanceStrategy::None,
+    /// ).unwrap();
+    ///
+    /// let mut prices = HashMap::new();
+    /// prices.insert("EUR".to_string(), Decimal::new(15, 1)); // EUR drifts to 60%
+    /// prices.insert("USD".to_string(), Decimal::ONE);
+    ///
+    /// let plan = basket.rebalance_plan(&prices, Decimal::new(1000, 0)).unwrap();
+    /// assert_eq!(plan[0].amount_usd, Decimal::new(-100, 0)); // sell EUR
+    /// assert_eq!(plan[1].amount_usd, Decimal::new(100, 0)); // buy USD
+    /// ```
+    pub fn rebalance_plan(
+        &self,
+        prices: &HashMap<String, Decimal>,
+        total_notional: Decimal,
+    ) -> Result<Vec<RebalanceTrade>, BasketError> {
+        self.rebalance_plan_with_mode(prices, total_notional, RebalanceMode::ToTarget)
+    }
+
+    /// Like [`rebalance_plan`](Self::rebalance_plan), with a choice of goal
+    ///
+    /// With `RebalanceMode::ToNearestBand` each out-of-band component is moved
+    /// only to its nearest band edge. Whatever that leaves unbalanced is spread
+    /// across the other components in proportion to their remaining room
+    /// inside their bands, so trades still net to zero and nothing already in
+    /// band is pushed out of it.
+    pub fn rebalance_plan_with_mode(
+        &self,
+        prices: &HashMap<String, Decimal>,
+        total_notional: Decimal,
+        mode: RebalanceMode,
+    ) -> Result<Vec<RebalanceTrade>, BasketError> {
+        if total_notional <= Decimal::ZERO {
+            return Err(BasketError::InvalidNotional(total_notional));
+        }
+
+        let current_weights = self.calculate_current_weights(prices)?;
+        let mut current = Vec::with_capacity(self.components.len());
+        for component in &self.components {
+            let weight = *current_weights
+                .get(&component.currency_code)
+                .ok_or_else(|| BasketError::ComponentNotFound(component.currency_code.clone()))?;
+            current.push(weight);
+        }
+
+        let goals = match mode {
+            RebalanceMode::ToTarget => self.components.iter().map(|c| c.target_weight).collect(),
+            RebalanceMode::ToNearestBand => self.nearest_band_weights(&current),
+        };
+
+        let hundred = Decimal::new(100, 0);
+        let mut trades = Vec::new();
+
+        for ((component, current_weight), goal) in self.components.iter().zip(current).zip(goals) {
+            if current_weight == goal {
+                continue;
+            }
+
+            let amount_usd = ((goal - current_weight) / hundred)
+                .checked_mul(total_notional)
+                .ok_or_else(|| {
+                    BasketError::CalculationError("Overflow in rebalance trade".to_string())
+                })?;
+
+            trades.push(RebalanceTrade {
+                currency_code: component.currency_code.clone(),
+                current_weight,
+                target_weight: goal,
+                amount_usd,
+            });
+        }
+
+        Ok(trades)
+    }
+
+    /// Closest weights to `current` that are in band and sum to 100%
+    fn nearest_band_weights(&self, current: &[Decimal]) -> Vec<Decimal> {
+        let mut goals: Vec<Decimal> = self
+            .components
+            .iter()
+            .zip(current)
+            .map(|(c, w)| (*w).max(c.min_weight).min(c.max_weight))
+            .collect();
+
+        // Clamping sells overweights and buys underweights by different amounts;
+        // spread the difference over the room each component has left in band
+        let residual = Decimal::new(100, 0) - goals.iter().sum::<Decimal>();
+        if residual.is_zero() {
+            return goals;
+        }
+
+        let room: Vec<Decimal> = self
+            .components
+            .iter()
+            .zip(&goals)
+            .map(|(c, g)| if residual > Decimal::ZERO { c.max_weight - *g } else { *g - c.min_weight })
+            .collect();
+        let total_room: Decimal = room.iter().sum();
+        if total_room.is_zero() {
+            return goals;
+        }
+
+        for (goal, room) in goals.iter_mut().zip(room) {
+            *goal += residual * room / total_room;
+        }
+        goals
+    }
+
+    /// Calculates current weights based on market prices
+    ///
+    /// This is used internally to determine if rebalancing is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Current market prices in USD
+    ///
+    /// # Returns
+    ///
+    /// Map of currency codes to their current weights as percentages
+    fn calculate_current_weights(
+        &self,
+        prices: &HashMap<String, Decimal>,
+    ) -> Result<HashMap<String, Decimal>, BasketError> {
+        let total_value = self.calculate_value(prices)?;
+        let mut current_weights = HashMap::new();
+        let hundred = Decimal::new(100, 0);
 
         for component in &self.components {
             let price = prices
@@ -630,9 +1278,96 @@ impl CurrencyBasket {
     }
 }
 
+/// USD value of one component at target weight: `(target_weight / 100) * price`
+fn component_value(
+    component: &CurrencyComponent,
+    prices: &HashMap<String, Decimal>,
+) -> Result<Decimal, BasketError> {
+    let price = prices
+        .get(&component.currency_code)
+        .ok_or_else(|| BasketError::PriceNotAvailable(component.currency_code.clone()))?;
+
+    (component.target_weight / Decimal::new(100, 0))
+        .checked_mul(*price)
+        .ok_or_else(|| BasketError::CalculationError("Overflow in value calculation".to_string()))
+}
+
+/// Checks that component target weights sum to 100% (0.01% tolerance)
+fn validate_total_weight(components: &[CurrencyComponent]) -> Result<(), BasketError> {
+    validate_total_weight_within(components, DEFAULT_WEIGHT_SUM_TOLERANCE)
+}
+
+fn validate_total_weight_within(components: &[CurrencyComponent], tolerance: Decimal) -> Result<(), BasketError> {
+    if components.is_empty() {
+        return Err(BasketError::EmptyBasket);
+    }
+
+    let total_weight: Decimal = components.iter().map(|c| c.target_weight).sum();
+    let hundred = Decimal::new(100, 0);
+
+    if (total_weight - hundred).abs() > tolerance {
+        return Err(BasketError::InvalidWeights {
+            actual: total_weight,
+        });
+    }
+
+    Ok(())
+}
+
+/// Tracking error between two baskets over a series of price snapshots
+///
+/// Values both baskets at every snapshot and returns the (population)
+/// standard deviation of the per-period differences `a - b`. A constant gap
+/// between the baskets therefore has zero tracking error.
+///
+/// # Errors
+///
+/// Returns `CalculationError` if `price_series` is empty or either basket
+/// cannot be valued for some snapshot.
+pub fn tracking_error(
+    a: &CurrencyBasket,
+    b: &CurrencyBasket,
+    price_series: &[HashMap<String, Decimal>],
+) -> Result<Decimal, BasketError> {
+    if price_series.is_empty() {
+        return Err(BasketError::CalculationError(
+            "Tracking error needs at least one price snapshot".to_string(),
+        ));
+    }
+
+    let differences = price_series
+        .iter()
+        .enumerate()
+        .map(|(period, prices)| {
+            let value = |basket: &CurrencyBasket| {
+                basket.calculate_value(prices).map_err(|e| {
+                    BasketError::CalculationError(format!(
+                        "Cannot value {} for snapshot {}: {}",
+                        basket.name, period, e
+                    ))
+                })
+            };
+            Ok(value(a)? - value(b)?)
+        })
+        .collect::<Result<Vec<Decimal>, BasketError>>()?;
+
+    let n = Decimal::from(differences.len());
+    let mean = differences.iter().sum::<Decimal>() / n;
+    let variance = differences
+        .iter()
+        .map(|d| (*d - mean) * (*d - mean))
+        .sum::<Decimal>()
+        / n;
+
+    variance
+        .sqrt()
+        .ok_or_else(|| BasketError::CalculationError("Invalid tracking variance".to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     /// Helper function to create a standard price map for testing
     fn create_test_prices() -> HashMap<String, Decimal> {
@@ -846,6 +1581,84 @@ mod tests {
         }
     }
 
+    fn eur_gbp_components(eur_weight: Decimal, gbp_weight: Decimal) -> Vec<CurrencyComponent> {
+        vec![
+            CurrencyComponent::new(
+                "EUR".to_string(),
+                eur_weight,
+                Decimal::new(50, 0),
+                Decimal::new(70, 0),
+                "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+            )
+            .unwrap(),
+            CurrencyComponent::new(
+                "GBP".to_string(),
+                gbp_weight,
+                Decimal::new(30, 0),
+                Decimal::new(50, 0),
+                "0x5c0Ab2d9b5a7ed9f470386e82BB36A3613cDd4b5".to_string(),
+            )
+            .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_custom_basket_tolerance_edge() {
+        let tolerance = Decimal::new(5, 1); // 0.5 percentage points
+
+        // 60 + 40.5 = 100.5: exactly at the edge is accepted
+        let at_edge = CurrencyBasket::new_custom_basket_with_tolerance(
+            "Edge".to_string(),
+            eur_gbp_components(Decimal::new(60, 0), Decimal::new(405, 1)),
+            RebalanceStrategy::None,
+            tolerance,
+        );
+        assert!(at_edge.is_ok());
+
+        // 60 + 39.49 = 99.49: just outside is rejected
+        let beyond = CurrencyBasket::new_custom_basket_with_tolerance(
+            "Beyond".to_string(),
+            eur_gbp_components(Decimal::new(60, 0), Decimal::new(3949, 2)),
+            RebalanceStrategy::None,
+            tolerance,
+        );
+        assert!(matches!(
+            beyond,
+            Err(BasketError::InvalidWeights { actual }) if actual == Decimal::new(9949, 2)
+        ));
+
+        // The default constructor still rejects 100.5
+        let default = CurrencyBasket::new_custom_basket(
+            "Default".to_string(),
+            eur_gbp_components(Decimal::new(60, 0), Decimal::new(405, 1)),
+            RebalanceStrategy::None,
+        );
+        assert!(matches!(default, Err(BasketError::InvalidWeights { .. })));
+    }
+
+    #[test]
+    fn test_custom_basket_tolerance_bounds() {
+        let build = |tolerance: Decimal| {
+            CurrencyBasket::new_custom_basket_with_tolerance(
+                "Bounds".to_string(),
+                eur_gbp_components(Decimal::new(60, 0), Decimal::new(40, 0)),
+                RebalanceStrategy::None,
+                tolerance,
+            )
+        };
+
+        assert!(build(Decimal::ZERO).is_ok());
+        assert!(build(MAX_WEIGHT_SUM_TOLERANCE).is_ok());
+        assert!(matches!(
+            build(Decimal::new(-1, 2)),
+            Err(BasketError::InvalidTolerance(t)) if t == Decimal::new(-1, 2)
+        ));
+        assert!(matches!(
+            build(Decimal::new(101, 2)),
+            Err(BasketError::InvalidTolerance(_))
+        ));
+    }
+
     #[test]
     fn test_custom_basket_valuation() {
         let eur = CurrencyComponent::new(
@@ -1003,6 +1816,60 @@ mod tests {
         }
     }
 
+    fn component_with_feed(feed: &str) -> Result<CurrencyComponent, BasketError> {
+        CurrencyComponent::new(
+            "EUR".to_string(),
+            Decimal::new(100, 0),
+            Decimal::new(95, 0),
+            Decimal::new(105, 0),
+            feed.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_feed_address_checksummed() {
+        assert!(component_with_feed("0xb49f677943BC038e9857d61E7d053CaA2C1734C1").is_ok());
+    }
+
+    #[test]
+    fn test_feed_address_lowercase() {
+        assert!(component_with_feed("0xb49f677943bc038e9857d61e7d053caa2c1734c1").is_ok());
+    }
+
+    #[test]
+    fn test_feed_address_garbage_rejected() {
+        match component_with_feed("0xnot-a-feed").unwrap_err() {
+            BasketError::InvalidFeedAddress(feed) => assert_eq!(feed, "0xnot-a-feed"),
+            other => panic!("Expected InvalidFeedAddress error, got {:?}", other),
+        }
+        // Right shape, wrong length
+        assert!(matches!(
+            component_with_feed("0xb49f677943BC038e9857d61E7d053CaA2C1734").unwrap_err(),
+            BasketError::InvalidFeedAddress(_)
+        ));
+    }
+
+    #[test]
+    fn test_feed_address_checked_after_code_and_weights() {
+        let result = CurrencyComponent::new(
+            "EURO".to_string(),
+            Decimal::new(100, 0),
+            Decimal::new(95, 0),
+            Decimal::new(105, 0),
+            "garbage".to_string(),
+        );
+        assert!(matches!(result.unwrap_err(), BasketError::InvalidCurrencyCode(_)));
+
+        let result = CurrencyComponent::new(
+            "EUR".to_string(),
+            Decimal::new(50, 0),
+            Decimal::new(60, 0),
+            Decimal::new(70, 0),
+            "garbage".to_string(),
+        );
+        assert!(matches!(result.unwrap_err(), BasketError::InvalidWeightRange { .. }));
+    }
+
     #[test]
     fn test_empty_basket() {
         let result =
@@ -1087,6 +1954,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_calculate_value_rounded_half_even_vs_half_up() {
+        let basket = CurrencyBasket::new_single_currency(
+            "EUR Basket".to_string(),
+            "EUR".to_string(),
+            "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+        )
+        .unwrap();
+
+        // 1.005 sits exactly on the midpoint at 2dp
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::from_str_exact("1.005").unwrap());
+
+        let half_even = basket
+            .calculate_value_rounded(&prices, 2, RoundingStrategy::HalfEven)
+            .unwrap();
+        let half_up = basket
+            .calculate_value_rounded(&prices, 2, RoundingStrategy::HalfUp)
+            .unwrap();
+        assert_eq!(half_even, Decimal::from_str_exact("1.00").unwrap());
+        assert_eq!(half_up, Decimal::from_str_exact("1.01").unwrap());
+
+        // 1.015: both strategies round up (1 is odd, so half-even goes to 2)
+        prices.insert("EUR".to_string(), Decimal::from_str_exact("1.015").unwrap());
+        let half_even = basket
+            .calculate_value_rounded(&prices, 2, RoundingStrategy::HalfEven)
+            .unwrap();
+        let half_up = basket
+            .calculate_value_rounded(&prices, 2, RoundingStrategy::HalfUp)
+            .unwrap();
+        assert_eq!(half_even, Decimal::from_str_exact("1.02").unwrap());
+        assert_eq!(half_up, Decimal::from_str_exact("1.02").unwrap());
+    }
+
+    #[test]
+    fn test_calculate_value_rounded_missing_price() {
+        let basket = CurrencyBasket::new_single_currency(
+            "EUR Basket".to_string(),
+            "EUR".to_string(),
+            "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+        )
+        .unwrap();
+
+        let result = basket.calculate_value_rounded(&HashMap::new(), 2, RoundingStrategy::HalfUp);
+        assert!(matches!(result, Err(BasketError::PriceNotAvailable(_))));
+    }
+
     #[test]
     fn test_decimal_precision_no_floating_point() {
         // This test verifies we're using Decimal throughout, not f64
@@ -1131,4 +2045,715 @@ mod tests {
         // Value should be deterministic and precise
         assert!(value > Decimal::ZERO);
     }
+
+    #[test]
+    fn test_min_price_confidence() {
+        let basket = CurrencyBasket::new_single_currency(
+            "EUR Basket".to_string(),
+            "EUR".to_string(),
+            "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+        )
+        .unwrap();
+
+        let mut confidences = HashMap::new();
+        confidences.insert("EUR".to_string(), Decimal::new(4, 1)); // 0.4
+
+        // No minimum: any confidence is accepted
+        assert!(basket.low_confidence_component(&confidences).is_none());
+
+        let strict = basket.clone().with_min_price_confidence(Decimal::new(9, 1)).unwrap();
+        assert_eq!(
+            strict.low_confidence_component(&confidences),
+            Some(("EUR", Decimal::new(4, 1)))
+        );
+        assert_eq!(
+            strict.low_confidence_component(&HashMap::new()),
+            Some(("EUR", Decimal::ZERO))
+        );
+
+        let lenient = basket.clone().with_min_price_confidence(Decimal::new(2, 1)).unwrap();
+        assert!(lenient.low_confidence_component(&confidences).is_none());
+
+        assert!(matches!(
+            basket.with_min_price_confidence(Decimal::new(15, 1)),
+            Err(BasketError::InvalidConfidence(_))
+        ));
+    }
+
+    fn eur_usd_basket() -> CurrencyBasket {
+        let eur = CurrencyComponent::new(
+            "EUR".to_string(),
+            Decimal::new(50, 0),
+            Decimal::new(45, 0),
+            Decimal::new(55, 0),
+            "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+        )
+        .unwrap();
+        let usd = CurrencyComponent::new(
+            "USD".to_string(),
+            Decimal::new(50, 0),
+            Decimal::new(45, 0),
+            Decimal::new(55, 0),
+            "0x0000000000000000000000000000000000000001".to_string(),
+        )
+        .unwrap();
+        CurrencyBasket::new_custom_basket("EUR-USD".to_string(), vec![eur, usd], RebalanceStrategy::None)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_rebalance_plan_buys_and_sells_to_target() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(15, 1)); // 1.5 -> EUR at 60%
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let plan = basket.rebalance_plan(&prices, Decimal::new(1000, 0)).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].currency_code, "EUR");
+        assert_eq!(plan[0].current_weight, Decimal::new(60, 0));
+        assert_eq!(plan[0].target_weight, Decimal::new(50, 0));
+        assert_eq!(plan[0].amount_usd, Decimal::new(-100, 0));
+        assert_eq!(plan[1].currency_code, "USD");
+        assert_eq!(plan[1].amount_usd, Decimal::new(100, 0));
+    }
+
+    #[test]
+    fn test_rebalance_plan_nets_to_zero() {
+        let mut feeds = HashMap::new();
+        for code in ["USD", "EUR", "CNY", "JPY", "GBP"] {
+            feeds.insert(
+                code.to_string(),
+                "0x0000000000000000000000000000000000000001".to_string(),
+            );
+        }
+        let basket = CurrencyBasket::new_imf_sdr("SDR".to_string(), feeds).unwrap();
+
+        let plan = basket
+            .rebalance_plan(&create_test_prices(), Decimal::new(1_000_000, 0))
+            .unwrap();
+        assert!(!plan.is_empty());
+
+        let net: Decimal = plan.iter().map(|t| t.amount_usd).sum();
+        assert!(net.abs() < Decimal::new(1, 12), "trades net to {}", net);
+    }
+
+    #[test]
+    fn test_rebalance_plan_on_target_emits_no_trade() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::ONE);
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let plan = basket.rebalance_plan(&prices, Decimal::new(1000, 0)).unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn test_rebalance_plan_rejects_zero_notional() {
+        let basket = eur_usd_basket();
+        let result = basket.rebalance_plan(&create_test_prices(), Decimal::ZERO);
+        assert!(matches!(result, Err(BasketError::InvalidNotional(n)) if n.is_zero()));
+    }
+
+    #[test]
+    fn test_value_from_holdings_reflects_drift() {
+        let basket = eur_usd_basket();
+        let prices = create_test_prices();
+
+        // Drifted away from 50/50: more EUR units than target
+        let mut holdings = HashMap::new();
+        holdings.insert("EUR".to_string(), Decimal::new(600, 0));
+        holdings.insert("USD".to_string(), Decimal::new(400, 0));
+
+        let value = basket.calculate_value_from_holdings(&holdings, &prices).unwrap();
+        // 600 * 1.08 + 400 * 1.00 = 1048
+        assert_eq!(value, Decimal::new(1048, 0));
+    }
+
+    #[test]
+    fn test_value_from_holdings_missing_holding() {
+        let basket = eur_usd_basket();
+        let mut holdings = HashMap::new();
+        holdings.insert("EUR".to_string(), Decimal::new(600, 0));
+
+        let result = basket.calculate_value_from_holdings(&holdings, &create_test_prices());
+        assert!(matches!(result, Err(BasketError::HoldingNotAvailable(code)) if code == "USD"));
+    }
+
+    #[test]
+    fn test_value_from_holdings_missing_price() {
+        let basket = eur_usd_basket();
+        let mut holdings = HashMap::new();
+        holdings.insert("EUR".to_string(), Decimal::new(600, 0));
+        holdings.insert("USD".to_string(), Decimal::new(400, 0));
+        let mut prices = HashMap::new();
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let result = basket.calculate_value_from_holdings(&holdings, &prices);
+        assert!(matches!(result, Err(BasketError::PriceNotAvailable(code)) if code == "EUR"));
+    }
+
+    fn zero_weight_component(code: &str) -> CurrencyComponent {
+        CurrencyComponent::new(
+            code.to_string(),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::new(10, 0),
+            "0x0000000000000000000000000000000000000002".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_component_revalidates_weights() {
+        let mut basket = eur_usd_basket();
+
+        // A weighted component would push the total past 100%
+        let gbp = CurrencyComponent::new(
+            "GBP".to_string(),
+            Decimal::new(10, 0),
+            Decimal::new(5, 0),
+            Decimal::new(15, 0),
+            "0x5c0Ab2d9b5a7ed9f470386e82BB36A3613cDd4b5".to_string(),
+        )
+        .unwrap();
+        let result = basket.add_component(gbp);
+        assert!(matches!(result, Err(BasketError::InvalidWeights { actual }) if actual == Decimal::new(110, 0)));
+        assert_eq!(basket.components.len(), 2);
+
+        basket.add_component(zero_weight_component("GBP")).unwrap();
+        assert!(basket.get_component("GBP").is_some());
+
+        let result = basket.add_component(zero_weight_component("GBP"));
+        assert!(matches!(result, Err(BasketError::DuplicateComponent(code)) if code == "GBP"));
+        assert_eq!(basket.components.len(), 3);
+    }
+
+    #[test]
+    fn test_remove_component_revalidates_weights() {
+        let mut basket = eur_usd_basket();
+        basket.add_component(zero_weight_component("GBP")).unwrap();
+
+        // Removing a weighted component leaves only 50%
+        let result = basket.remove_component("EUR");
+        assert!(matches!(result, Err(BasketError::InvalidWeights { actual }) if actual == Decimal::new(50, 0)));
+        assert!(basket.get_component("EUR").is_some());
+
+        let removed = basket.remove_component("GBP").unwrap();
+        assert_eq!(removed.currency_code, "GBP");
+        assert_eq!(basket.components.len(), 2);
+
+        let result = basket.remove_component("CHF");
+        assert!(matches!(result, Err(BasketError::ComponentNotFound(code)) if code == "CHF"));
+    }
+
+    #[test]
+    fn test_validate_weights_after_direct_edit() {
+        let mut basket = eur_usd_basket();
+        assert!(basket.validate_weights().is_ok());
+
+        basket.components[0].target_weight = Decimal::new(40, 0);
+        assert!(matches!(
+            basket.validate_weights(),
+            Err(BasketError::InvalidWeights { actual }) if actual == Decimal::new(90, 0)
+        ));
+
+        basket.components.clear();
+        assert!(matches!(basket.validate_weights(), Err(BasketError::EmptyBasket)));
+    }
+
+    #[test]
+    fn test_component_edits_refused_for_fixed_baskets() {
+        let mut single = CurrencyBasket::new_single_currency(
+            "EUR Basket".to_string(),
+            "EUR".to_string(),
+            "0xb49f677943BC038e9857d61E7d053CaA2C1734C1".to_string(),
+        )
+        .unwrap();
+        assert!(matches!(
+            single.add_component(zero_weight_component("GBP")),
+            Err(BasketError::FixedComposition(BasketType::SingleCurrency))
+        ));
+        assert!(matches!(
+            single.remove_component("EUR"),
+            Err(BasketError::FixedComposition(BasketType::SingleCurrency))
+        ));
+
+        let mut feeds = HashMap::new();
+        for code in ["USD", "EUR", "CNY", "JPY", "GBP"] {
+            feeds.insert(
+                code.to_string(),
+                "0x0000000000000000000000000000000000000001".to_string(),
+            );
+        }
+        let mut sdr = CurrencyBasket::new_imf_sdr("SDR".to_string(), feeds).unwrap();
+        assert!(matches!(
+            sdr.remove_component("USD"),
+            Err(BasketError::FixedComposition(BasketType::ImfSdr))
+        ));
+        assert_eq!(sdr.components.len(), 5);
+    }
+
+    #[test]
+    fn test_normalize_weights_rescales_to_exactly_100() {
+        let mut basket = eur_usd_basket();
+        // Raw GDP-style shares that sum to 3
+        basket.components[0].target_weight = Decimal::ONE;
+        basket.components[0].min_weight = Decimal::new(5, 1);
+        basket.components[0].max_weight = Decimal::new(15, 1);
+        basket.components[1].target_weight = Decimal::new(2, 0);
+        basket.components[1].min_weight = Decimal::ONE;
+        basket.components[1].max_weight = Decimal::new(3, 0);
+
+        basket.normalize_weights().unwrap();
+
+        let total: Decimal = basket.components.iter().map(|c| c.target_weight).sum();
+        assert_eq!(total, Decimal::new(100, 0));
+
+        let eur = basket.get_component("EUR").unwrap();
+        assert_eq!(eur.target_weight.round_dp(10), Decimal::new(333333333333, 10));
+        assert_eq!(eur.min_weight.round_dp(10), Decimal::new(166666666667, 10));
+        assert_eq!(eur.max_weight, Decimal::new(50, 0));
+
+        let usd = basket.get_component("USD").unwrap();
+        assert_eq!(usd.min_weight.round_dp(10), Decimal::new(333333333333, 10));
+        assert_eq!(usd.max_weight, Decimal::new(100, 0));
+        for component in &basket.components {
+            assert!(component.min_weight <= component.target_weight);
+            assert!(component.target_weight <= component.max_weight);
+        }
+    }
+
+    #[test]
+    fn test_normalize_weights_is_noop_at_100() {
+        let mut basket = eur_usd_basket();
+        basket.normalize_weights().unwrap();
+        assert_eq!(basket.components[0].target_weight, Decimal::new(50, 0));
+        assert_eq!(basket.components[1].target_weight, Decimal::new(50, 0));
+    }
+
+    #[test]
+    fn test_normalize_weights_errors() {
+        let mut basket = eur_usd_basket();
+        for component in &mut basket.components {
+            component.target_weight = Decimal::ZERO;
+        }
+        assert!(matches!(basket.normalize_weights(), Err(BasketError::CalculationError(_))));
+
+        basket.components.clear();
+        assert!(matches!(basket.normalize_weights(), Err(BasketError::EmptyBasket)));
+    }
+
+    #[test]
+    fn test_normalize_weights_rejects_invalid_max_weight() {
+        // Shares sum to 2, so EUR's max of 60 would scale to 3000%
+        let mut basket = eur_usd_basket();
+        basket.components[0].target_weight = Decimal::ONE;
+        basket.components[0].min_weight = Decimal::ZERO;
+        basket.components[0].max_weight = Decimal::new(60, 0);
+        basket.components[1].target_weight = Decimal::ONE;
+        basket.components[1].min_weight = Decimal::ZERO;
+        basket.components[1].max_weight = Decimal::ONE;
+        let before = basket.components.clone();
+
+        assert!(matches!(
+            basket.normalize_weights(),
+            Err(BasketError::InvalidWeightRange { max, .. }) if max == Decimal::new(3000, 0)
+        ));
+        assert_eq!(basket.components, before, "basket is unchanged on error");
+
+        // max below min survives scaling and is rejected too
+        basket.components[0].max_weight = Decimal::ONE;
+        basket.components[1].min_weight = Decimal::new(15, 1);
+        basket.components[1].max_weight = Decimal::new(12, 1);
+        assert!(matches!(
+            basket.normalize_weights(),
+            Err(BasketError::InvalidWeightRange { .. })
+        ));
+    }
+
+    fn sdr_basket() -> CurrencyBasket {
+        let mut feeds = HashMap::new();
+        for code in ["USD", "EUR", "CNY", "JPY", "GBP"] {
+            feeds.insert(
+                code.to_string(),
+                "0x0000000000000000000000000000000000000001".to_string(),
+            );
+        }
+        CurrencyBasket::new_imf_sdr("SDR".to_string(), feeds).unwrap()
+    }
+
+    #[test]
+    fn test_value_contributions_sum_to_value() {
+        let basket = sdr_basket();
+        let prices = create_test_prices();
+
+        let contributions = basket.value_contributions(&prices).unwrap();
+        assert_eq!(contributions.len(), 5);
+        let usd = basket.get_component("USD").unwrap();
+        assert_eq!(contributions["USD"], usd.target_weight / Decimal::new(100, 0));
+
+        let total: Decimal = contributions.values().sum();
+        assert_eq!(total, basket.calculate_value(&prices).unwrap());
+    }
+
+    #[test]
+    fn test_value_contributions_percent() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(15, 1));
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let percent = basket.value_contributions_percent(&prices).unwrap();
+        assert_eq!(percent["EUR"], Decimal::new(60, 0));
+        assert_eq!(percent["USD"], Decimal::new(40, 0));
+
+        prices.insert("EUR".to_string(), Decimal::ZERO);
+        prices.insert("USD".to_string(), Decimal::ZERO);
+        assert!(matches!(
+            basket.value_contributions_percent(&prices),
+            Err(BasketError::CalculationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_value_contributions_missing_price() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let result = basket.value_contributions(&prices);
+        assert!(matches!(result, Err(BasketError::PriceNotAvailable(code)) if code == "EUR"));
+    }
+
+    fn three_currency_basket() -> CurrencyBasket {
+        let component = |code: &str, target: i64, min: i64, max: i64| {
+            CurrencyComponent::new(
+                code.to_string(),
+                Decimal::new(target, 0),
+                Decimal::new(min, 0),
+                Decimal::new(max, 0),
+                "0x0000000000000000000000000000000000000001".to_string(),
+            )
+            .unwrap()
+        };
+        CurrencyBasket::new_custom_basket(
+            "EUR-GBP-USD".to_string(),
+            vec![component("EUR", 40, 35, 45), component("GBP", 30, 25, 35), component("USD", 30, 20, 40)],
+            RebalanceStrategy::None,
+        )
+        .unwrap()
+    }
+
+    fn turnover(plan: &[RebalanceTrade]) -> Decimal {
+        plan.iter().map(|t| t.amount_usd.abs()).sum()
+    }
+
+    #[test]
+    fn test_rebalance_to_nearest_band_trades_less_than_to_target() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(15, 1)); // EUR drifts to 60%
+        prices.insert("USD".to_string(), Decimal::ONE);
+        let notional = Decimal::new(1000, 0);
+
+        let to_target = basket
+            .rebalance_plan_with_mode(&prices, notional, RebalanceMode::ToTarget)
+            .unwrap();
+        let to_band = basket
+            .rebalance_plan_with_mode(&prices, notional, RebalanceMode::ToNearestBand)
+            .unwrap();
+
+        assert_eq!(to_target, basket.rebalance_plan(&prices, notional).unwrap());
+        assert_eq!(to_band[0].target_weight, Decimal::new(55, 0));
+        assert_eq!(to_band[0].amount_usd, Decimal::new(-50, 0));
+        assert_eq!(to_band[1].target_weight, Decimal::new(45, 0));
+        assert_eq!(to_band[1].amount_usd, Decimal::new(50, 0));
+        assert!(turnover(&to_band) < turnover(&to_target));
+    }
+
+    #[test]
+    fn test_rebalance_to_nearest_band_spreads_residual() {
+        let basket = three_currency_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(15, 1)); // EUR 50%, GBP 25%, USD 25%
+        prices.insert("GBP".to_string(), Decimal::ONE);
+        prices.insert("USD".to_string(), Decimal::ONE);
+        let notional = Decimal::new(1000, 0);
+
+        let to_band = basket
+            .rebalance_plan_with_mode(&prices, notional, RebalanceMode::ToNearestBand)
+            .unwrap();
+        let amounts: Vec<Decimal> = to_band.iter().map(|t| t.amount_usd).collect();
+        // EUR sold to its 45% max; the 5% is bought in proportion to band room (10:15)
+        assert_eq!(amounts, vec![Decimal::new(-50, 0), Decimal::new(20, 0), Decimal::new(30, 0)]);
+        assert!(amounts.iter().sum::<Decimal>().is_zero());
+        for (trade, component) in to_band.iter().zip(&basket.components) {
+            assert!(component.is_within_bounds(trade.target_weight));
+        }
+
+        let to_target = basket.rebalance_plan(&prices, notional).unwrap();
+        assert!(turnover(&to_band) < turnover(&to_target));
+    }
+
+    #[test]
+    fn test_rebalance_to_nearest_band_in_band_emits_no_trade() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(105, 2)); // ~51.2%, inside 45-55
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let plan = basket
+            .rebalance_plan_with_mode(&prices, Decimal::new(1000, 0), RebalanceMode::ToNearestBand)
+            .unwrap();
+        assert!(plan.is_empty());
+    }
+
+    fn sdr_feeds() -> HashMap<String, String> {
+        ["USD", "EUR", "CNY", "JPY", "GBP"]
+            .iter()
+            .map(|code| (code.to_string(), "0x0000000000000000000000000000000000000001".to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_new_imf_sdr_for_date_uses_historical_weights() {
+        let as_of = Utc.with_ymd_and_hms(2020, 6, 30, 0, 0, 0).unwrap();
+        let basket = CurrencyBasket::new_imf_sdr_for_date("SDR".to_string(), sdr_feeds(), as_of).unwrap();
+
+        assert_eq!(basket.basket_type, BasketType::ImfSdr);
+        let usd = basket.get_component("USD").unwrap();
+        assert_eq!(usd.target_weight, Decimal::new(4173, 2));
+        assert_eq!(usd.min_weight, Decimal::new(3964, 2));
+        assert_eq!(usd.max_weight, Decimal::new(4382, 2));
+        assert_eq!(basket.get_component("CNY").unwrap().target_weight, Decimal::new(1092, 2));
+        let total: Decimal = basket.components.iter().map(|c| c.target_weight).sum();
+        assert_eq!(total, Decimal::new(100, 0));
+    }
+
+    #[test]
+    fn test_new_imf_sdr_for_date_after_review_matches_current() {
+        let as_of = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let dated = CurrencyBasket::new_imf_sdr_for_date("SDR".to_string(), sdr_feeds(), as_of).unwrap();
+        let current = CurrencyBasket::new_imf_sdr("SDR".to_string(), sdr_feeds()).unwrap();
+        let weights = |b: &CurrencyBasket| -> Vec<_> {
+            b.components
+                .iter()
+                .map(|c| (c.currency_code.clone(), c.target_weight, c.min_weight, c.max_weight))
+                .collect()
+        };
+        assert_eq!(weights(&dated), weights(&current));
+    }
+
+    #[test]
+    fn test_new_imf_sdr_for_date_before_earliest_set_errors() {
+        let as_of = Utc.with_ymd_and_hms(2015, 1, 1, 0, 0, 0).unwrap();
+        let err = CurrencyBasket::new_imf_sdr_for_date("SDR".to_string(), sdr_feeds(), as_of).unwrap_err();
+        assert!(matches!(err, BasketError::NoSdrWeightSet(date) if date == as_of));
+    }
+
+    #[test]
+    fn test_drift_report_at_target_prices() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::ONE);
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let report = basket.drift_report(&prices).unwrap();
+        assert_eq!(report.len(), 2);
+        for drift in report {
+            assert_eq!(drift.current_weight, Decimal::new(50, 0));
+            assert!(drift.absolute_deviation.is_zero());
+            assert!(drift.within_bounds);
+        }
+    }
+
+    #[test]
+    fn test_drift_report_flags_out_of_band_component() {
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(15, 1)); // EUR 60%, USD 40%
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        let report = basket.drift_report(&prices).unwrap();
+        assert_eq!(report[0].currency_code, "EUR");
+        assert_eq!(report[0].target_weight, Decimal::new(50, 0));
+        assert_eq!(report[0].current_weight, Decimal::new(60, 0));
+        assert_eq!(report[0].absolute_deviation, Decimal::new(10, 0));
+        assert!(!report[0].within_bounds);
+        assert_eq!(report[1].current_weight, Decimal::new(40, 0));
+        assert_eq!(report[1].absolute_deviation, Decimal::new(10, 0));
+        assert!(!report[1].within_bounds);
+    }
+
+    #[test]
+    fn test_drift_report_ignores_rebalance_strategy() {
+        // RebalanceStrategy::None never asks for a rebalance, but drift is still reported
+        let basket = eur_usd_basket();
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), Decimal::new(15, 1));
+        prices.insert("USD".to_string(), Decimal::ONE);
+
+        assert!(!basket.needs_rebalancing(&prices).unwrap());
+        assert!(basket.drift_report(&prices).unwrap().iter().any(|d| !d.within_bounds));
+
+        prices.remove("USD");
+        assert!(matches!(
+            basket.drift_report(&prices).unwrap_err(),
+            BasketError::PriceNotAvailable(_)
+        ));
+    }
+
+    fn eur_usd_prices(eur: Decimal) -> HashMap<String, Decimal> {
+        let mut prices = HashMap::new();
+        prices.insert("EUR".to_string(), eur);
+        prices.insert("USD".to_string(), Decimal::ONE);
+        prices
+    }
+
+    #[test]
+    fn test_tracking_error_of_identical_baskets_is_zero() {
+        let series: Vec<_> = [100, 120, 80].iter().map(|p| eur_usd_prices(Decimal::new(*p, 2))).collect();
+        let te = tracking_error(&eur_usd_basket(), &eur_usd_basket(), &series).unwrap();
+        assert!(te.is_zero());
+    }
+
+    #[test]
+    fn test_tracking_error_against_single_currency() {
+        let eur = CurrencyBasket::new_single_currency(
+            "EUR".to_string(),
+            "EUR".to_string(),
+            "0x0000000000000000000000000000000000000001".to_string(),
+        )
+        .unwrap();
+        // 50/50 minus pure EUR = 0.5 * (1 - EUR): differences 0, -0.1, 0.1
+        let series: Vec<_> = [100, 120, 80].iter().map(|p| eur_usd_prices(Decimal::new(*p, 2))).collect();
+
+        let te = tracking_error(&eur_usd_basket(), &eur, &series).unwrap();
+        assert_eq!(te.round_dp(5), Decimal::new(8165, 5));
+        // Symmetric in its arguments
+        assert_eq!(tracking_error(&eur, &eur_usd_basket(), &series).unwrap(), te);
+    }
+
+    #[test]
+    fn test_tracking_error_ignores_constant_gap() {
+        let usd = CurrencyBasket::new_single_currency(
+            "USD".to_string(),
+            "USD".to_string(),
+            "0x0000000000000000000000000000000000000001".to_string(),
+        )
+        .unwrap();
+        // 50/50 minus pure USD = 0.5 * (EUR - USD), held at 0.1 throughout
+        let series: Vec<_> = [(120, 100), (130, 110), (110, 90)]
+            .iter()
+            .map(|(eur, usd)| {
+                let mut prices = eur_usd_prices(Decimal::new(*eur, 2));
+                prices.insert("USD".to_string(), Decimal::new(*usd, 2));
+                prices
+            })
+            .collect();
+
+        assert!(tracking_error(&eur_usd_basket(), &usd, &series).unwrap().is_zero());
+    }
+
+    #[test]
+    fn test_tracking_error_errors() {
+        let err = tracking_error(&eur_usd_basket(), &eur_usd_basket(), &[]).unwrap_err();
+        assert!(matches!(err, BasketError::CalculationError(_)));
+
+        let mut missing_usd = HashMap::new();
+        missing_usd.insert("EUR".to_string(), Decimal::ONE);
+        let series = vec![eur_usd_prices(Decimal::ONE), missing_usd];
+        match tracking_error(&eur_usd_basket(), &eur_usd_basket(), &series).unwrap_err() {
+            BasketError::CalculationError(msg) => assert!(msg.contains("snapshot 1")),
+            other => panic!("Expected CalculationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_toml_round_trips_every_rebalance_strategy() {
+        let strategies = vec![
+            RebalanceStrategy::None,
+            RebalanceStrategy::Fixed { interval_days: 30 },
+            RebalanceStrategy::ThresholdBased {
+                max_deviation_percent: Decimal::new(25, 1),
+            },
+            RebalanceStrategy::Scheduled {
+                schedule: vec![
+                    Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap(),
+                ],
+            },
+        ];
+
+        for strategy in strategies {
+            let mut basket = three_currency_basket()
+                .with_min_price_confidence(Decimal::new(8, 1))
+                .unwrap();
+            basket.rebalance_strategy = strategy;
+            basket.last_rebalanced = Some(Utc.with_ymd_and_hms(2025, 12, 31, 12, 0, 0).unwrap());
+
+            let toml = basket.to_toml().unwrap();
+            assert_eq!(CurrencyBasket::from_toml(&toml).unwrap(), basket);
+        }
+    }
+
+    #[test]
+    fn test_toml_round_trips_sdr_basket() {
+        let basket = CurrencyBasket::new_imf_sdr("SDR".to_string(), sdr_feeds()).unwrap();
+        let restored = CurrencyBasket::from_toml(&basket.to_toml().unwrap()).unwrap();
+        assert_eq!(restored, basket);
+        assert_eq!(restored.basket_type, BasketType::ImfSdr);
+    }
+
+    #[test]
+    fn test_from_toml_revalidates_weights() {
+        let toml = three_currency_basket()
+            .to_toml()
+            .unwrap()
+            .replace("target_weight = \"40\"", "target_weight = \"45\"");
+        assert!(matches!(
+            CurrencyBasket::from_toml(&toml),
+            Err(BasketError::InvalidWeights { actual }) if actual == Decimal::new(105, 0)
+        ));
+    }
+
+    #[test]
+    fn test_from_toml_revalidates_components() {
+        let toml = three_currency_basket().to_toml().unwrap();
+
+        let bad_code = toml.replace("\"GBP\"", "\"gbp\"");
+        assert!(matches!(
+            CurrencyBasket::from_toml(&bad_code),
+            Err(BasketError::InvalidCurrencyCode(code)) if code == "gbp"
+        ));
+
+        let bad_feed = toml.replacen("0x0000000000000000000000000000000000000001", "0xnotanaddress", 1);
+        assert!(matches!(
+            CurrencyBasket::from_toml(&bad_feed),
+            Err(BasketError::InvalidFeedAddress(_))
+        ));
+
+        let bad_range = toml.replace("max_weight = \"45\"", "max_weight = \"39\"");
+        assert!(matches!(
+            CurrencyBasket::from_toml(&bad_range),
+            Err(BasketError::InvalidWeightRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_toml_rejects_malformed_config() {
+        assert!(matches!(
+            CurrencyBasket::from_toml("name = \"missing everything else\""),
+            Err(BasketError::InvalidConfig(_))
+        ));
+
+        let mut basket = three_currency_basket();
+        basket.min_price_confidence = Some(Decimal::new(2, 0));
+        assert!(matches!(
+            CurrencyBasket::from_toml(&basket.to_toml().unwrap()),
+            Err(BasketError::InvalidConfidence(_))
+        ));
+    }
 }
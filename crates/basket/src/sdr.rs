@@ -0,0 +1,134 @@
+//! IMF SDR weight history
+//!
+//! The IMF reviews the SDR valuation basket roughly every five years and
+//! publishes new currency weights that take effect on a fixed date. Each
+//! review is recorded here as an [`SdrWeightSet`] so a basket can be built
+//! with whichever weights were in force on a given day.
+
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Allowed drift around each SDR weight, relative to the weight (5%)
+const BAND_TOLERANCE: Decimal = Decimal::from_parts(5, 0, 0, false, 2);
+
+/// Currency weights published by one IMF SDR valuation review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SdrWeightSet {
+    /// Date the weights took effect
+    pub effective_date: DateTime<Utc>,
+    /// US Dollar weight as a percentage
+    pub usd: Decimal,
+    /// Euro weight as a percentage
+    pub eur: Decimal,
+    /// Chinese Yuan weight as a percentage
+    pub cny: Decimal,
+    /// Japanese Yen weight as a percentage
+    pub jpy: Decimal,
+    /// Pound Sterling weight as a percentage
+    pub gbp: Decimal,
+}
+
+impl SdrWeightSet {
+    /// All known weight sets, oldest first
+    pub fn known() -> Vec<SdrWeightSet> {
+        vec![
+            // 2015 review, effective 1 October 2016 (CNY added)
+            SdrWeightSet {
+                effective_date: Utc.with_ymd_and_hms(2016, 10, 1, 0, 0, 0).unwrap(),
+                usd: Decimal::new(4173, 2),
+                eur: Decimal::new(3093, 2),
+                cny: Decimal::new(1092, 2),
+                jpy: Decimal::new(833, 2),
+                gbp: Decimal::new(809, 2),
+            },
+            // 2022 review, effective 1 August 2022
+            SdrWeightSet {
+                effective_date: Utc.with_ymd_and_hms(2022, 8, 1, 0, 0, 0).unwrap(),
+                usd: Decimal::new(4338, 2),
+                eur: Decimal::new(2931, 2),
+                cny: Decimal::new(1228, 2),
+                jpy: Decimal::new(759, 2),
+                gbp: Decimal::new(744, 2),
+            },
+        ]
+    }
+
+    /// Weight set in effect at `as_of`, or `None` if it precedes every known set
+    pub fn for_date(as_of: DateTime<Utc>) -> Option<SdrWeightSet> {
+        Self::known()
+            .into_iter()
+            .rev()
+            .find(|set| set.effective_date <= as_of)
+    }
+
+    /// Most recent weight set
+    pub fn latest() -> SdrWeightSet {
+        Self::known()
+            .pop()
+            .expect("at least one SDR weight set is defined")
+    }
+
+    /// Weights keyed by currency code
+    pub fn weights(&self) -> [(&'static str, Decimal); 5] {
+        [
+            ("USD", self.usd),
+            ("EUR", self.eur),
+            ("CNY", self.cny),
+            ("JPY", self.jpy),
+            ("GBP", self.gbp),
+        ]
+    }
+
+    /// Min/max band for a weight: ±5% of the weight, to two decimal places
+    pub fn band(weight: Decimal) -> (Decimal, Decimal) {
+        (
+            (weight * (Decimal::ONE - BAND_TOLERANCE)).round_dp(2),
+            (weight * (Decimal::ONE + BAND_TOLERANCE)).round_dp(2),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_sets_sum_to_one_hundred() {
+        for set in SdrWeightSet::known() {
+            let total: Decimal = set.weights().iter().map(|(_, w)| *w).sum();
+            assert_eq!(total, Decimal::new(100, 0), "{}", set.effective_date);
+        }
+    }
+
+    #[test]
+    fn test_known_sets_are_ordered_by_effective_date() {
+        let known = SdrWeightSet::known();
+        assert!(known.windows(2).all(|w| w[0].effective_date < w[1].effective_date));
+        assert_eq!(SdrWeightSet::latest(), *known.last().unwrap());
+    }
+
+    #[test]
+    fn test_for_date_selects_set_in_effect() {
+        let before_2022 = Utc.with_ymd_and_hms(2022, 7, 31, 23, 59, 59).unwrap();
+        assert_eq!(SdrWeightSet::for_date(before_2022).unwrap().usd, Decimal::new(4173, 2));
+
+        let on_2022 = Utc.with_ymd_and_hms(2022, 8, 1, 0, 0, 0).unwrap();
+        assert_eq!(SdrWeightSet::for_date(on_2022).unwrap().usd, Decimal::new(4338, 2));
+
+        let too_early = Utc.with_ymd_and_hms(2016, 9, 30, 0, 0, 0).unwrap();
+        assert!(SdrWeightSet::for_date(too_early).is_none());
+    }
+
+    #[test]
+    fn test_band_is_five_percent_of_weight() {
+        assert_eq!(
+            SdrWeightSet::band(Decimal::new(4338, 2)),
+            (Decimal::new(4121, 2), Decimal::new(4555, 2))
+        );
+        assert_eq!(
+            SdrWeightSet::band(Decimal::new(744, 2)),
+            (Decimal::new(707, 2), Decimal::new(781, 2))
+        );
+    }
+}
@@ -17,6 +17,7 @@
 //! In production, swap for an HSM-backed signer (AWS KMS, Fireblocks MPC, etc.)
 //! by implementing the `Signer` trait from the `ethers-signers` crate.
 
+use crate::Chain;
 use ethers::abi::{Abi, Token};
 use ethers::contract::Contract;
 use ethers::middleware::SignerMiddleware;
@@ -53,6 +54,15 @@ pub enum ExecutionError {
 
     #[error("Serialization error: {0}")]
     Serialization(String),
+
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+
+    #[error("No token configured for currency: {0}")]
+    UnsupportedCurrency(String),
+
+    #[error("Executor not available for chain: {0}")]
+    UnsupportedChain(String),
 }
 
 pub type ExecutionResult<T> = Result<T, ExecutionError>;
@@ -216,16 +226,11 @@ impl EvmExecutor {
     }
 
     /// Number of confirmations to wait based on chain security properties.
-    /// L2s have fast finality; Ethereum mainnet needs more blocks.
+    /// Taken from `ChainConfig::confirmations_required`; unknown chains wait 1.
     fn default_confirmations(chain_id: u64) -> u64 {
-        match chain_id {
-            1 => 12,           // Ethereum mainnet — conservative
-            11155111 => 1,     // Sepolia — testnet
-            8453 | 84532 => 1, // Base / Base Sepolia
-            42161 | 421614 => 1, // Arbitrum / Arbitrum Sepolia
-            10 | 11155420 => 1,  // Optimism / Optimism Sepolia
-            _ => 1,
-        }
+        Chain::from_chain_id(chain_id)
+            .map(|chain| chain.config().confirmations_required)
+            .unwrap_or(1)
     }
 
     /// Get the current nonce for an address from the contract.
@@ -538,6 +543,8 @@ mod tests {
         assert_eq!(EvmExecutor::default_confirmations(8453), 1);
         // Arbitrum — L2, 1 block
         assert_eq!(EvmExecutor::default_confirmations(42161), 1);
+        // Optimism — L2, 1 block
+        assert_eq!(EvmExecutor::default_confirmations(10), 1);
         // Unknown chain — safe default of 1
         assert_eq!(EvmExecutor::default_confirmations(99999), 1);
     }
@@ -556,6 +563,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::inconsistent_digit_grouping)]
     fn test_mint_request_token_encoding() {
         // Verify Token::Tuple encoding matches Solidity struct layout
         let recipient = Address::zero();
@@ -0,0 +1,168 @@
+//! # Network Fee Estimation
+//!
+//! Rough per-chain cost of a stablecoin transfer, quoted in the chain's
+//! native token (ETH, SOL, ...) so clients can show an estimate before a
+//! deployment or transfer.
+//!
+//! ## Adding a New Estimator
+//!
+//! Implement `GasEstimator` for the chain family and return
+//! `ChainError::UnsupportedChain` for chains it cannot price.
+
+use crate::{Chain, ChainError};
+use ethers::providers::{Http, Middleware, Provider};
+use ethers::types::U256;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Gas used by a typical ERC-20 `transfer` call
+pub const TYPICAL_ERC20_TRANSFER_GAS: u64 = 65_000;
+
+/// Base fee of a single-signature Solana transaction, in lamports
+pub const SOLANA_BASE_FEE_LAMPORTS: u64 = 5_000;
+
+/// Lamports per SOL
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Estimates the network fee for a transfer on a chain
+#[async_trait::async_trait]
+pub trait GasEstimator: Send + Sync {
+    /// Estimated cost of one transfer, in the chain's native token
+    async fn estimate_transfer_cost(&self, chain: Chain) -> Result<Decimal, ChainError>;
+}
+
+/// EVM estimator: `eth_gasPrice` x `TYPICAL_ERC20_TRANSFER_GAS`
+pub struct EvmGasEstimator<M> {
+    provider: Arc<M>,
+    transfer_gas: u64,
+}
+
+impl<M: Middleware> EvmGasEstimator<M> {
+    /// Create an estimator backed by an existing provider
+    pub fn new(provider: Arc<M>) -> Self {
+        Self {
+            provider,
+            transfer_gas: TYPICAL_ERC20_TRANSFER_GAS,
+        }
+    }
+
+    /// Override the gas units assumed per transfer
+    pub fn with_transfer_gas(mut self, transfer_gas: u64) -> Self {
+        self.transfer_gas = transfer_gas;
+        self
+    }
+}
+
+impl EvmGasEstimator<Provider<Http>> {
+    /// Create an estimator using the chain's configured RPC URL
+    pub fn for_chain(chain: Chain) -> Result<Self, ChainError> {
+        chain.ensure_available()?;
+        if !chain.is_evm_chain() {
+            return Err(ChainError::UnsupportedChain(format!("{:?} is not an EVM chain", chain)));
+        }
+        let provider = Provider::<Http>::try_from(chain.config().rpc_url)
+            .map_err(|_| ChainError::RpcUrlNotConfigured(chain))?;
+        Ok(Self::new(Arc::new(provider)))
+    }
+}
+
+#[async_trait::async_trait]
+impl<M: Middleware + 'static> GasEstimator for EvmGasEstimator<M> {
+    async fn estimate_transfer_cost(&self, chain: Chain) -> Result<Decimal, ChainError> {
+        if !chain.is_evm_chain() {
+            return Err(ChainError::UnsupportedChain(format!("{:?} is not an EVM chain", chain)));
+        }
+
+        let gas_price = self
+            .provider
+            .get_gas_price()
+            .await
+            .map_err(|e| ChainError::RpcError(e.to_string()))?;
+
+        let cost_wei = gas_price
+            .checked_mul(U256::from(self.transfer_gas))
+            .ok_or_else(|| ChainError::RpcError(format!("Gas price overflow: {}", gas_price)))?;
+
+        wei_to_native(cost_wei)
+    }
+}
+
+/// Solana stub: fixed base fee per transaction, pending a real RPC client
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolanaGasEstimator;
+
+#[async_trait::async_trait]
+impl GasEstimator for SolanaGasEstimator {
+    async fn estimate_transfer_cost(&self, chain: Chain) -> Result<Decimal, ChainError> {
+        if !chain.is_solana_chain() {
+            return Err(ChainError::UnsupportedChain(format!("{:?} is not a Solana chain", chain)));
+        }
+        Ok(Decimal::from(SOLANA_BASE_FEE_LAMPORTS) / Decimal::from(LAMPORTS_PER_SOL))
+    }
+}
+
+/// Convert an amount in wei to whole native tokens (18 decimals)
+fn wei_to_native(wei: U256) -> Result<Decimal, ChainError> {
+    let formatted = ethers::utils::format_units(wei, 18)
+        .map_err(|e| ChainError::RpcError(e.to_string()))?;
+    Decimal::from_str(&formatted)
+        .map(|d| d.normalize())
+        .map_err(|e| ChainError::RpcError(format!("Unrepresentable fee {}: {}", formatted, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::providers::MockProvider;
+
+    fn mocked_estimator(gas_price_wei: u64) -> EvmGasEstimator<Provider<MockProvider>> {
+        let (provider, mock) = Provider::mocked();
+        mock.push(U256::from(gas_price_wei)).unwrap();
+        EvmGasEstimator::new(Arc::new(provider))
+    }
+
+    #[tokio::test]
+    async fn test_evm_estimate_is_gas_price_times_transfer_gas() {
+        // 20 gwei * 65,000 gas = 0.0013 ETH
+        let estimator = mocked_estimator(20_000_000_000);
+        let cost = estimator.estimate_transfer_cost(Chain::Ethereum).await.unwrap();
+        assert_eq!(cost, Decimal::from_str("0.0013").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_evm_estimate_with_custom_transfer_gas() {
+        // 0.1 gwei * 21,000 gas = 0.0000021 ETH
+        let estimator = mocked_estimator(100_000_000).with_transfer_gas(21_000);
+        let cost = estimator.estimate_transfer_cost(Chain::Base).await.unwrap();
+        assert_eq!(cost, Decimal::from_str("0.0000021").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_evm_estimator_rejects_solana() {
+        let estimator = mocked_estimator(1);
+        let result = estimator.estimate_transfer_cost(Chain::Solana).await;
+        assert!(matches!(result, Err(ChainError::UnsupportedChain(_))));
+    }
+
+    #[tokio::test]
+    async fn test_evm_estimate_surfaces_rpc_errors() {
+        // No queued response: the mock provider errors
+        let (provider, _mock) = Provider::mocked();
+        let estimator = EvmGasEstimator::new(Arc::new(provider));
+        let result = estimator.estimate_transfer_cost(Chain::Ethereum).await;
+        assert!(matches!(result, Err(ChainError::RpcError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_solana_stub_estimate() {
+        let cost = SolanaGasEstimator
+            .estimate_transfer_cost(Chain::SolanaDevnet)
+            .await
+            .unwrap();
+        assert_eq!(cost, Decimal::from_str("0.000005").unwrap());
+
+        let result = SolanaGasEstimator.estimate_transfer_cost(Chain::Ethereum).await;
+        assert!(matches!(result, Err(ChainError::UnsupportedChain(_))));
+    }
+}
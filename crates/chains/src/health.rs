@@ -0,0 +1,157 @@
+//! # RPC Health Checks
+//!
+//! Confirms a chain's RPC endpoint answers, and answers for the right
+//! network, before operations are routed to it. A misconfigured URL that
+//! points at another network is as dangerous as one that is down, so the
+//! returned chain ID is compared against the registry.
+
+use crate::{Chain, ChainConfig, ChainError};
+use ethers::providers::{Http, Middleware, Provider};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Marker left in the default RPC URLs until an operator supplies a key
+const RPC_KEY_PLACEHOLDER: &str = "YOUR_KEY";
+
+/// Outcome of probing a chain's RPC endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ChainHealth {
+    /// The endpoint answered `eth_chainId` within the timeout
+    pub reachable: bool,
+    /// The reported chain ID equals `config().chain_id`
+    pub chain_id_matches: bool,
+    /// Round-trip time of the probe (time until giving up if unreachable)
+    pub latency_ms: u64,
+}
+
+impl ChainHealth {
+    /// Reachable and on the expected network
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.chain_id_matches
+    }
+}
+
+impl Chain {
+    /// Probes the chain's primary RPC URL with `eth_chainId`
+    ///
+    /// An endpoint that errors or does not answer within `timeout` is
+    /// reported as unreachable rather than returned as an error.
+    ///
+    /// # Errors
+    ///
+    /// - `UnsupportedChain` for non-EVM chains
+    /// - `ChainNotAvailable` for placeholder chains
+    /// - `RpcUrlNotConfigured` if the URL still contains the `YOUR_KEY`
+    ///   default or cannot be parsed
+    pub async fn health_check(&self, timeout: Duration) -> Result<ChainHealth, ChainError> {
+        self.health_check_with(self.config(), timeout).await
+    }
+
+    async fn health_check_with(&self, config: ChainConfig, timeout: Duration) -> Result<ChainHealth, ChainError> {
+        if !self.is_evm_chain() {
+            return Err(ChainError::UnsupportedChain(format!("{:?} is not an EVM chain", self)));
+        }
+        self.ensure_available()?;
+
+        if config.rpc_url.contains(RPC_KEY_PLACEHOLDER) {
+            return Err(ChainError::RpcUrlNotConfigured(*self));
+        }
+        let provider = Provider::<Http>::try_from(config.rpc_url.as_str())
+            .map_err(|_| ChainError::RpcUrlNotConfigured(*self))?;
+
+        Ok(probe_chain_id(&provider, config.chain_id, timeout).await)
+    }
+}
+
+async fn probe_chain_id<M: Middleware>(provider: &M, expected: u64, timeout: Duration) -> ChainHealth {
+    let started = Instant::now();
+    let result = tokio::time::timeout(timeout, provider.get_chainid()).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(Ok(chain_id)) => ChainHealth {
+            reachable: true,
+            chain_id_matches: chain_id == expected.into(),
+            latency_ms,
+        },
+        Ok(Err(e)) => {
+            tracing::debug!(error = %e, "RPC health check failed");
+            ChainHealth { reachable: false, chain_id_matches: false, latency_ms }
+        }
+        Err(_) => {
+            tracing::debug!(timeout_ms = timeout.as_millis() as u64, "RPC health check timed out");
+            ChainHealth { reachable: false, chain_id_matches: false, latency_ms }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::U256;
+
+    #[tokio::test]
+    async fn test_probe_matching_chain_id() {
+        let (provider, mock) = Provider::mocked();
+        mock.push(U256::from(8453u64)).unwrap();
+
+        let health = probe_chain_id(&provider, 8453, Duration::from_secs(1)).await;
+        assert!(health.reachable);
+        assert!(health.chain_id_matches);
+        assert!(health.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_probe_detects_wrong_network() {
+        // Endpoint configured for Base actually serves Base Sepolia
+        let (provider, mock) = Provider::mocked();
+        mock.push(U256::from(84532u64)).unwrap();
+
+        let health = probe_chain_id(&provider, 8453, Duration::from_secs(1)).await;
+        assert!(health.reachable);
+        assert!(!health.chain_id_matches);
+        assert!(!health.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_probe_rpc_error_is_unreachable() {
+        // No queued response: the mock provider errors
+        let (provider, _mock) = Provider::mocked();
+        let health = probe_chain_id(&provider, 1, Duration::from_secs(1)).await;
+        assert!(!health.reachable);
+        assert!(!health.chain_id_matches);
+    }
+
+    #[tokio::test]
+    async fn test_probe_times_out_on_silent_endpoint() {
+        // Accepts the TCP connection (via the backlog) but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let provider = Provider::<Http>::try_from(url.as_str()).unwrap();
+
+        let started = Instant::now();
+        let health = probe_chain_id(&provider, 1, Duration::from_millis(200)).await;
+        assert!(!health.reachable);
+        assert!(started.elapsed() < Duration::from_secs(5), "probe should not hang");
+        drop(listener);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_rejects_placeholder_key() {
+        let mut config = Chain::EthereumSepolia.config();
+        config.rpc_url = "https://eth-sepolia.g.alchemy.com/v2/YOUR_KEY".to_string();
+        let result = Chain::EthereumSepolia
+            .health_check_with(config, Duration::from_millis(100))
+            .await;
+        assert!(matches!(result, Err(ChainError::RpcUrlNotConfigured(Chain::EthereumSepolia))));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_rejects_non_evm_and_placeholder_chains() {
+        let solana = Chain::Solana.health_check(Duration::from_millis(100)).await;
+        assert!(matches!(solana, Err(ChainError::UnsupportedChain(_))));
+
+        let tempo = Chain::Tempo.health_check(Duration::from_millis(100)).await;
+        assert!(matches!(tempo, Err(ChainError::ChainNotAvailable(Chain::Tempo))));
+    }
+}
@@ -1,16 +1,26 @@
 //! # Meridian Multi-Chain Configuration
 //!
 //! Chain registry and configuration for deploying stablecoins across
-//! Ethereum, Solana, Base, Arbitrum, Optimism, and other supported chains.
+//! Ethereum, Solana, Base, Arbitrum, Optimism, Polygon, Avalanche, BNB Chain,
+//! and other supported chains.
 
 pub mod execution;
+pub mod gas;
+pub mod health;
+pub mod recipient;
 pub mod signer;
+pub mod supply;
+pub mod transfer;
 
 use ethers::types::Address;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use thiserror::Error;
 
+/// Multicall3 address, identical on every chain it is deployed to via its
+/// keyless deployment transaction
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
 /// Supported blockchain networks
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Chain {
@@ -30,6 +40,18 @@ pub enum Chain {
     Optimism,
     OptimismSepolia,
 
+    // Polygon PoS
+    Polygon,
+    PolygonAmoy,
+
+    // Avalanche C-Chain
+    Avalanche,
+    AvalancheFuji,
+
+    // BNB Smart Chain
+    Bnb,
+    BnbTestnet,
+
     // Arc
     Arc,
     ArcTestnet,
@@ -53,16 +75,62 @@ pub struct ChainConfig {
     pub chain_id: u64,
     /// RPC endpoint URL
     pub rpc_url: String,
+    /// Backup RPC endpoints, in priority order, tried when `rpc_url` fails
+    #[serde(default)]
+    pub fallback_rpc_urls: Vec<String>,
     /// Block explorer base URL
     pub explorer_url: String,
     /// Native token symbol (ETH, SOL, etc.)
     pub native_token: String,
+    /// Block confirmations to wait for before treating a transaction as settled
+    pub confirmations_required: u64,
+    /// Average block (or slot) time, rounded up to whole seconds
+    pub avg_block_time_secs: u64,
     /// Deployed MeridianFactory contract address (EVM only)
     pub contract_address: Option<Address>,
     /// Deployed Solana program ID (Solana only)
     pub program_id: Option<SolanaPubkey>,
 }
 
+impl ChainConfig {
+    /// Block explorer link for a transaction hash (EVM) or signature (Solana)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meridian_chains::Chain;
+    ///
+    /// assert_eq!(Chain::Base.config().tx_url("0xabc"), "https://basescan.org/tx/0xabc");
+    /// assert_eq!(
+    ///     Chain::SolanaDevnet.config().tx_url("5sig"),
+    ///     "https://explorer.solana.com/tx/5sig?cluster=devnet"
+    /// );
+    /// ```
+    pub fn tx_url(&self, tx_hash: &str) -> String {
+        self.explorer_link("tx", tx_hash)
+    }
+
+    /// Block explorer link for an account or contract address
+    pub fn address_url(&self, address: &str) -> String {
+        self.explorer_link("address", address)
+    }
+
+    /// Joins `/{kind}/{id}` onto the explorer base, keeping any query string
+    /// (Solana devnet's `?cluster=devnet`) at the end where explorers expect it
+    fn explorer_link(&self, kind: &str, id: &str) -> String {
+        let (base, query) = match self.explorer_url.split_once('?') {
+            Some((base, query)) => (base, Some(query)),
+            None => (self.explorer_url.as_str(), None),
+        };
+        let mut url = format!("{}/{}/{}", base.trim_end_matches('/'), kind, id.trim());
+        if let Some(query) = query {
+            url.push('?');
+            url.push_str(query);
+        }
+        url
+    }
+}
+
 /// Errors for chain operations
 #[derive(Error, Debug)]
 pub enum ChainError {
@@ -77,6 +145,15 @@ pub enum ChainError {
 
     #[error("RPC URL not configured for {0:?}")]
     RpcUrlNotConfigured(Chain),
+
+    #[error("Chain not yet available: {0:?}")]
+    ChainNotAvailable(Chain),
+
+    #[error("RPC request failed: {0}")]
+    RpcError(String),
+
+    #[error("Recipient {0:?} has no code, balance, or transaction history")]
+    EmptyRecipient(Address),
 }
 
 impl Chain {
@@ -92,8 +169,11 @@ impl Chain {
                 rpc_url: std::env::var("ETHEREUM_RPC_URL").unwrap_or_else(|_| {
                     "https://eth-mainnet.g.alchemy.com/v2/YOUR_KEY".to_string()
                 }),
+                fallback_rpc_urls: fallback_rpc_urls("ETHEREUM_RPC_FALLBACKS"),
                 explorer_url: "https://etherscan.io".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 12,
+                avg_block_time_secs: 12,
                 contract_address: std::env::var("ETHEREUM_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -105,8 +185,11 @@ impl Chain {
                 rpc_url: std::env::var("SEPOLIA_RPC_URL").unwrap_or_else(|_| {
                     "https://eth-sepolia.g.alchemy.com/v2/YOUR_KEY".to_string()
                 }),
+                fallback_rpc_urls: fallback_rpc_urls("SEPOLIA_RPC_FALLBACKS"),
                 explorer_url: "https://sepolia.etherscan.io".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1, // testnet: reorg risk is irrelevant
+                avg_block_time_secs: 12,
                 contract_address: std::env::var("SEPOLIA_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -118,8 +201,11 @@ impl Chain {
                 chain_id: 8453,
                 rpc_url: std::env::var("BASE_RPC_URL")
                     .unwrap_or_else(|_| "https://mainnet.base.org".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("BASE_RPC_FALLBACKS"),
                 explorer_url: "https://basescan.org".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
                 contract_address: std::env::var("BASE_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -130,8 +216,11 @@ impl Chain {
                 chain_id: 84532,
                 rpc_url: std::env::var("BASE_SEPOLIA_RPC_URL")
                     .unwrap_or_else(|_| "https://sepolia.base.org".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("BASE_SEPOLIA_RPC_FALLBACKS"),
                 explorer_url: "https://sepolia.basescan.org".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
                 contract_address: std::env::var("BASE_SEPOLIA_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -143,8 +232,11 @@ impl Chain {
                 chain_id: 42161,
                 rpc_url: std::env::var("ARBITRUM_RPC_URL")
                     .unwrap_or_else(|_| "https://arb1.arbitrum.io/rpc".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("ARBITRUM_RPC_FALLBACKS"),
                 explorer_url: "https://arbiscan.io".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 1, // ~0.25s blocks, rounded up
                 contract_address: std::env::var("ARBITRUM_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -155,8 +247,11 @@ impl Chain {
                 chain_id: 421614,
                 rpc_url: std::env::var("ARBITRUM_SEPOLIA_RPC_URL")
                     .unwrap_or_else(|_| "https://sepolia-rollup.arbitrum.io/rpc".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("ARBITRUM_SEPOLIA_RPC_FALLBACKS"),
                 explorer_url: "https://sepolia.arbiscan.io".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 1, // ~0.25s blocks, rounded up
                 contract_address: std::env::var("ARBITRUM_SEPOLIA_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -168,8 +263,11 @@ impl Chain {
                 chain_id: 10,
                 rpc_url: std::env::var("OPTIMISM_RPC_URL")
                     .unwrap_or_else(|_| "https://mainnet.optimism.io".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("OPTIMISM_RPC_FALLBACKS"),
                 explorer_url: "https://optimistic.etherscan.io".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
                 contract_address: std::env::var("OPTIMISM_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -180,21 +278,120 @@ impl Chain {
                 chain_id: 11155420,
                 rpc_url: std::env::var("OPTIMISM_SEPOLIA_RPC_URL")
                     .unwrap_or_else(|_| "https://sepolia.optimism.io".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("OPTIMISM_SEPOLIA_RPC_FALLBACKS"),
                 explorer_url: "https://sepolia-optimism.etherscan.io".to_string(),
                 native_token: "ETH".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
                 contract_address: std::env::var("OPTIMISM_SEPOLIA_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
                 program_id: None,
             },
 
+            // ============ Polygon ============
+            Chain::Polygon => ChainConfig {
+                chain_id: 137,
+                rpc_url: std::env::var("POLYGON_RPC_URL")
+                    .unwrap_or_else(|_| "https://polygon-rpc.com".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("POLYGON_RPC_FALLBACKS"),
+                explorer_url: "https://polygonscan.com".to_string(),
+                native_token: "POL".to_string(),
+                confirmations_required: 32,
+                avg_block_time_secs: 2,
+                contract_address: std::env::var("POLYGON_FACTORY_ADDRESS")
+                    .ok()
+                    .and_then(|addr| Address::from_str(&addr).ok()),
+                program_id: None,
+            },
+
+            Chain::PolygonAmoy => ChainConfig {
+                chain_id: 80002,
+                rpc_url: std::env::var("POLYGON_AMOY_RPC_URL")
+                    .unwrap_or_else(|_| "https://rpc-amoy.polygon.technology".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("POLYGON_AMOY_RPC_FALLBACKS"),
+                explorer_url: "https://amoy.polygonscan.com".to_string(),
+                native_token: "POL".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
+                contract_address: std::env::var("POLYGON_AMOY_FACTORY_ADDRESS")
+                    .ok()
+                    .and_then(|addr| Address::from_str(&addr).ok()),
+                program_id: None,
+            },
+
+            // ============ Avalanche ============
+            Chain::Avalanche => ChainConfig {
+                chain_id: 43114,
+                rpc_url: std::env::var("AVALANCHE_RPC_URL")
+                    .unwrap_or_else(|_| "https://api.avax.network/ext/bc/C/rpc".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("AVALANCHE_RPC_FALLBACKS"),
+                explorer_url: "https://snowtrace.io".to_string(),
+                native_token: "AVAX".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
+                contract_address: std::env::var("AVALANCHE_FACTORY_ADDRESS")
+                    .ok()
+                    .and_then(|addr| Address::from_str(&addr).ok()),
+                program_id: None,
+            },
+
+            Chain::AvalancheFuji => ChainConfig {
+                chain_id: 43113,
+                rpc_url: std::env::var("AVALANCHE_FUJI_RPC_URL")
+                    .unwrap_or_else(|_| "https://api.avax-test.network/ext/bc/C/rpc".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("AVALANCHE_FUJI_RPC_FALLBACKS"),
+                explorer_url: "https://testnet.snowtrace.io".to_string(),
+                native_token: "AVAX".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 2,
+                contract_address: std::env::var("AVALANCHE_FUJI_FACTORY_ADDRESS")
+                    .ok()
+                    .and_then(|addr| Address::from_str(&addr).ok()),
+                program_id: None,
+            },
+
+            // ============ BNB Chain ============
+            Chain::Bnb => ChainConfig {
+                chain_id: 56,
+                rpc_url: std::env::var("BNB_RPC_URL")
+                    .unwrap_or_else(|_| "https://bsc-dataseed.bnbchain.org".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("BNB_RPC_FALLBACKS"),
+                explorer_url: "https://bscscan.com".to_string(),
+                native_token: "BNB".to_string(),
+                confirmations_required: 15,
+                avg_block_time_secs: 3,
+                contract_address: std::env::var("BNB_FACTORY_ADDRESS")
+                    .ok()
+                    .and_then(|addr| Address::from_str(&addr).ok()),
+                program_id: None,
+            },
+
+            Chain::BnbTestnet => ChainConfig {
+                chain_id: 97,
+                rpc_url: std::env::var("BNB_TESTNET_RPC_URL")
+                    .unwrap_or_else(|_| "https://data-seed-prebsc-1-s1.bnbchain.org:8545".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("BNB_TESTNET_RPC_FALLBACKS"),
+                explorer_url: "https://testnet.bscscan.com".to_string(),
+                native_token: "BNB".to_string(),
+                confirmations_required: 1,
+                avg_block_time_secs: 3,
+                contract_address: std::env::var("BNB_TESTNET_FACTORY_ADDRESS")
+                    .ok()
+                    .and_then(|addr| Address::from_str(&addr).ok()),
+                program_id: None,
+            },
+
             // ============ Arc ============
             Chain::Arc => ChainConfig {
                 chain_id: 0, // TODO: Update with actual Arc chain ID when available
                 rpc_url: std::env::var("ARC_RPC_URL")
                     .unwrap_or_else(|_| "https://arc-mainnet-rpc.example.com".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("ARC_RPC_FALLBACKS"),
                 explorer_url: "https://arc-explorer.example.com".to_string(),
                 native_token: "ARC".to_string(),
+                confirmations_required: 12, // TODO: placeholder until Arc launches
+                avg_block_time_secs: 12,
                 contract_address: std::env::var("ARC_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -205,8 +402,11 @@ impl Chain {
                 chain_id: 0, // TODO: Update with actual Arc testnet chain ID
                 rpc_url: std::env::var("ARC_TESTNET_RPC_URL")
                     .unwrap_or_else(|_| "https://arc-testnet-rpc.example.com".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("ARC_TESTNET_RPC_FALLBACKS"),
                 explorer_url: "https://arc-testnet-explorer.example.com".to_string(),
                 native_token: "ARC".to_string(),
+                confirmations_required: 12, // TODO: placeholder until Arc testnet launches
+                avg_block_time_secs: 12,
                 contract_address: None,
                 program_id: None,
             },
@@ -216,8 +416,11 @@ impl Chain {
                 chain_id: 0, // TODO: Update with actual Tempo chain ID when available
                 rpc_url: std::env::var("TEMPO_RPC_URL")
                     .unwrap_or_else(|_| "https://tempo-mainnet-rpc.example.com".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("TEMPO_RPC_FALLBACKS"),
                 explorer_url: "https://tempo-explorer.example.com".to_string(),
                 native_token: "TEMPO".to_string(),
+                confirmations_required: 12, // TODO: placeholder until Tempo launches
+                avg_block_time_secs: 12,
                 contract_address: std::env::var("TEMPO_FACTORY_ADDRESS")
                     .ok()
                     .and_then(|addr| Address::from_str(&addr).ok()),
@@ -228,8 +431,11 @@ impl Chain {
                 chain_id: 0, // TODO: Update with actual Tempo testnet chain ID
                 rpc_url: std::env::var("TEMPO_TESTNET_RPC_URL")
                     .unwrap_or_else(|_| "https://tempo-testnet-rpc.example.com".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("TEMPO_TESTNET_RPC_FALLBACKS"),
                 explorer_url: "https://tempo-testnet-explorer.example.com".to_string(),
                 native_token: "TEMPO".to_string(),
+                confirmations_required: 12, // TODO: placeholder until Tempo testnet launches
+                avg_block_time_secs: 12,
                 contract_address: None,
                 program_id: None,
             },
@@ -239,8 +445,11 @@ impl Chain {
                 chain_id: 0, // Solana doesn't use numeric chain IDs
                 rpc_url: std::env::var("SOLANA_RPC_URL")
                     .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string()),
+                fallback_rpc_urls: fallback_rpc_urls("SOLANA_RPC_FALLBACKS"),
                 explorer_url: "https://explorer.solana.com".to_string(),
                 native_token: "SOL".to_string(),
+                confirmations_required: 32, // finalized after 32 slots
+                avg_block_time_secs: 1, // ~0.4s slots, rounded up
                 contract_address: None,
                 program_id: Some(std::env::var("SOLANA_PROGRAM_ID").unwrap_or_else(|_| {
                     "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS".to_string()
@@ -252,8 +461,11 @@ impl Chain {
                     chain_id: 0,
                     rpc_url: std::env::var("SOLANA_DEVNET_RPC_URL")
                         .unwrap_or_else(|_| "https://api.devnet.solana.com".to_string()),
+                    fallback_rpc_urls: fallback_rpc_urls("SOLANA_DEVNET_RPC_FALLBACKS"),
                     explorer_url: "https://explorer.solana.com?cluster=devnet".to_string(),
                     native_token: "SOL".to_string(),
+                    confirmations_required: 32, // finalized after 32 slots
+                    avg_block_time_secs: 1, // ~0.4s slots, rounded up
                     contract_address: None,
                     program_id: Some(std::env::var("SOLANA_DEVNET_PROGRAM_ID").unwrap_or_else(
                         |_| "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS".to_string(),
@@ -263,6 +475,23 @@ impl Chain {
         }
     }
 
+    /// Rough time until a transaction on this chain is final, in seconds
+    ///
+    /// `confirmations_required * avg_block_time_secs`; meant for sizing
+    /// settlement windows, not for deciding whether a transaction is final.
+    pub fn estimated_finality_secs(&self) -> u64 {
+        let config = self.config();
+        config.confirmations_required * config.avg_block_time_secs
+    }
+
+    /// RPC endpoints in priority order: `rpc_url`, then the fallbacks
+    ///
+    /// Fallbacks come from a comma-separated `<CHAIN>_RPC_FALLBACKS` variable
+    /// (e.g. `ETHEREUM_RPC_FALLBACKS`). Duplicates of earlier entries are dropped.
+    pub fn rpc_urls(&self) -> Vec<String> {
+        ordered_rpc_urls(self.config())
+    }
+
     /// Returns true if this is an EVM-compatible chain
     pub fn is_evm_chain(&self) -> bool {
         !matches!(self, Chain::Solana | Chain::SolanaDevnet)
@@ -281,6 +510,9 @@ impl Chain {
                 | Chain::BaseSepolia
                 | Chain::ArbitrumSepolia
                 | Chain::OptimismSepolia
+                | Chain::PolygonAmoy
+                | Chain::AvalancheFuji
+                | Chain::BnbTestnet
                 | Chain::ArcTestnet
                 | Chain::TempoTestnet
                 | Chain::SolanaDevnet
@@ -292,6 +524,119 @@ impl Chain {
         !self.is_testnet()
     }
 
+    /// Returns true if the chain still uses placeholder values
+    ///
+    /// Arc and Tempo ship with `chain_id: 0` and example.com endpoints until
+    /// their networks launch. Anything keyed on chain ID breaks silently on
+    /// these, so callers should reject them via `ensure_available`. Solana
+    /// is excluded from the chain ID check since it has no numeric ID.
+    pub fn is_placeholder(&self) -> bool {
+        self.has_placeholder_values(&self.config())
+    }
+
+    fn has_placeholder_values(&self, config: &ChainConfig) -> bool {
+        (self.is_evm_chain() && config.chain_id == 0)
+            || config.rpc_url.contains("example.com")
+            || config.explorer_url.contains("example.com")
+    }
+
+    /// Errors with `ChainNotAvailable` if the chain is still a placeholder
+    pub fn ensure_available(&self) -> Result<(), ChainError> {
+        if self.is_placeholder() {
+            return Err(ChainError::ChainNotAvailable(*self));
+        }
+        Ok(())
+    }
+
+    /// Multicall3 contract for batching read calls into one `eth_call`
+    ///
+    /// None for Solana and for placeholder chains, where Multicall3 has not
+    /// been deployed.
+    pub fn multicall3_address(&self) -> Option<Address> {
+        if !self.is_evm_chain() || self.is_placeholder() {
+            return None;
+        }
+        Address::from_str(MULTICALL3_ADDRESS).ok()
+    }
+
+    /// Gets the chain configuration, rejecting it if it is unusable
+    ///
+    /// Unlike `config`, this surfaces operator mistakes (empty or malformed
+    /// RPC URLs from the environment) instead of handing them to a provider.
+    ///
+    /// # Errors
+    ///
+    /// - `ChainNotAvailable` if the chain still uses placeholder values
+    /// - `RpcUrlNotConfigured` if the primary RPC URL is empty
+    /// - `InvalidConfiguration` if an RPC URL is not http(s)/ws(s), or the
+    ///   confirmation count or block time is zero
+    pub fn config_checked(&self) -> Result<ChainConfig, ChainError> {
+        self.check_config(self.config())
+    }
+
+    fn check_config(&self, config: ChainConfig) -> Result<ChainConfig, ChainError> {
+        if self.has_placeholder_values(&config) {
+            return Err(ChainError::ChainNotAvailable(*self));
+        }
+        if config.rpc_url.trim().is_empty() {
+            return Err(ChainError::RpcUrlNotConfigured(*self));
+        }
+        let urls_valid = std::iter::once(&config.rpc_url)
+            .chain(&config.fallback_rpc_urls)
+            .all(|url| is_supported_rpc_scheme(url));
+        if !urls_valid || config.confirmations_required == 0 || config.avg_block_time_secs == 0 {
+            return Err(ChainError::InvalidConfiguration(*self));
+        }
+
+        Ok(config)
+    }
+
+    /// Canonical identifier, as accepted by `FromStr` and stored in the database
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Chain::Ethereum => "ethereum",
+            Chain::EthereumSepolia => "ethereum-sepolia",
+            Chain::Base => "base",
+            Chain::BaseSepolia => "base-sepolia",
+            Chain::Arbitrum => "arbitrum",
+            Chain::ArbitrumSepolia => "arbitrum-sepolia",
+            Chain::Optimism => "optimism",
+            Chain::OptimismSepolia => "optimism-sepolia",
+            Chain::Polygon => "polygon",
+            Chain::PolygonAmoy => "polygon-amoy",
+            Chain::Avalanche => "avalanche",
+            Chain::AvalancheFuji => "avalanche-fuji",
+            Chain::Bnb => "bnb",
+            Chain::BnbTestnet => "bnb-testnet",
+            Chain::Arc => "arc",
+            Chain::ArcTestnet => "arc-testnet",
+            Chain::Tempo => "tempo",
+            Chain::TempoTestnet => "tempo-testnet",
+            Chain::Solana => "solana",
+            Chain::SolanaDevnet => "solana-devnet",
+        }
+    }
+
+    /// Looks up an EVM chain by its numeric chain ID
+    ///
+    /// Solana and the placeholder Arc/Tempo entries all report `chain_id: 0`,
+    /// so `0` is rejected rather than resolved to an arbitrary one of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnsupportedChain` for `0` and for any unknown ID
+    pub fn from_chain_id(chain_id: u64) -> Result<Chain, ChainError> {
+        if chain_id == 0 {
+            return Err(ChainError::UnsupportedChain(
+                "chain ID 0 is ambiguous (non-EVM or unassigned)".to_string(),
+            ));
+        }
+        list_evm_chains()
+            .into_iter()
+            .find(|chain| chain.config().chain_id == chain_id)
+            .ok_or_else(|| ChainError::UnsupportedChain(format!("chain ID {}", chain_id)))
+    }
+
     /// Gets the chain name as a string
     pub fn name(&self) -> &'static str {
         match self {
@@ -303,6 +648,12 @@ impl Chain {
             Chain::ArbitrumSepolia => "Arbitrum Sepolia",
             Chain::Optimism => "Optimism",
             Chain::OptimismSepolia => "Optimism Sepolia",
+            Chain::Polygon => "Polygon",
+            Chain::PolygonAmoy => "Polygon Amoy",
+            Chain::Avalanche => "Avalanche",
+            Chain::AvalancheFuji => "Avalanche Fuji",
+            Chain::Bnb => "BNB Chain",
+            Chain::BnbTestnet => "BNB Chain Testnet",
             Chain::Arc => "Arc",
             Chain::ArcTestnet => "Arc Testnet",
             Chain::Tempo => "Tempo",
@@ -326,6 +677,12 @@ impl FromStr for Chain {
             "arbitrum-sepolia" => Ok(Chain::ArbitrumSepolia),
             "optimism" | "op" => Ok(Chain::Optimism),
             "optimism-sepolia" => Ok(Chain::OptimismSepolia),
+            "polygon" | "pol" => Ok(Chain::Polygon),
+            "polygon-amoy" | "amoy" => Ok(Chain::PolygonAmoy),
+            "avalanche" | "avax" => Ok(Chain::Avalanche),
+            "avalanche-fuji" | "fuji" => Ok(Chain::AvalancheFuji),
+            "bnb" | "bsc" => Ok(Chain::Bnb),
+            "bnb-testnet" | "bsc-testnet" => Ok(Chain::BnbTestnet),
             "arc" => Ok(Chain::Arc),
             "arc-testnet" => Ok(Chain::ArcTestnet),
             "tempo" => Ok(Chain::Tempo),
@@ -355,6 +712,36 @@ pub fn get_chain_config(chain: Chain) -> ChainConfig {
     chain.config()
 }
 
+/// Reads a comma-separated list of fallback RPC URLs from `var`
+fn fallback_rpc_urls(var: &str) -> Vec<String> {
+    parse_rpc_url_list(std::env::var(var).ok().as_deref())
+}
+
+fn ordered_rpc_urls(config: ChainConfig) -> Vec<String> {
+    let mut urls = vec![config.rpc_url];
+    for url in config.fallback_rpc_urls {
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+fn is_supported_rpc_scheme(url: &str) -> bool {
+    ["https://", "http://", "wss://", "ws://"]
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme))
+}
+
+fn parse_rpc_url_list(list: Option<&str>) -> Vec<String> {
+    list.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Checks if a chain is EVM-compatible
 ///
 /// # Example
@@ -384,6 +771,28 @@ pub fn is_solana_chain(chain: Chain) -> bool {
     chain.is_solana_chain()
 }
 
+/// Validates every chain in `chains`, in order
+///
+/// Collects each chain's `config_checked` result so deployment tooling can
+/// report every misconfigured chain in one pass rather than stopping at the
+/// first.
+///
+/// # Example
+///
+/// ```
+/// use meridian_chains::{Chain, validate_all};
+///
+/// let results = validate_all(&[Chain::Base, Chain::Arc]);
+/// assert!(results[0].1.is_ok());
+/// assert!(results[1].1.is_err());
+/// ```
+pub fn validate_all(chains: &[Chain]) -> Vec<(Chain, Result<ChainConfig, ChainError>)> {
+    chains
+        .iter()
+        .map(|chain| (*chain, chain.config_checked()))
+        .collect()
+}
+
 /// Lists all supported EVM chains
 pub fn list_evm_chains() -> Vec<Chain> {
     vec![
@@ -395,6 +804,12 @@ pub fn list_evm_chains() -> Vec<Chain> {
         Chain::ArbitrumSepolia,
         Chain::Optimism,
         Chain::OptimismSepolia,
+        Chain::Polygon,
+        Chain::PolygonAmoy,
+        Chain::Avalanche,
+        Chain::AvalancheFuji,
+        Chain::Bnb,
+        Chain::BnbTestnet,
         Chain::Arc,
         Chain::ArcTestnet,
         Chain::Tempo,
@@ -414,6 +829,9 @@ pub fn list_mainnet_chains() -> Vec<Chain> {
         Chain::Base,
         Chain::Arbitrum,
         Chain::Optimism,
+        Chain::Polygon,
+        Chain::Avalanche,
+        Chain::Bnb,
         Chain::Arc,
         Chain::Tempo,
         Chain::Solana,
@@ -427,6 +845,9 @@ pub fn list_testnet_chains() -> Vec<Chain> {
         Chain::BaseSepolia,
         Chain::ArbitrumSepolia,
         Chain::OptimismSepolia,
+        Chain::PolygonAmoy,
+        Chain::AvalancheFuji,
+        Chain::BnbTestnet,
         Chain::ArcTestnet,
         Chain::TempoTestnet,
         Chain::SolanaDevnet,
@@ -458,6 +879,12 @@ mod tests {
         assert!(Chain::EthereumSepolia.is_testnet());
         assert!(Chain::BaseSepolia.is_testnet());
         assert!(Chain::SolanaDevnet.is_testnet());
+        assert!(Chain::PolygonAmoy.is_testnet());
+        assert!(Chain::AvalancheFuji.is_testnet());
+        assert!(Chain::BnbTestnet.is_testnet());
+        assert!(!Chain::Polygon.is_testnet());
+        assert!(!Chain::Avalanche.is_testnet());
+        assert!(!Chain::Bnb.is_testnet());
         assert!(!Chain::Ethereum.is_testnet());
         assert!(!Chain::Solana.is_testnet());
     }
@@ -469,6 +896,22 @@ mod tests {
         assert_eq!(Chain::Base.config().chain_id, 8453);
         assert_eq!(Chain::Arbitrum.config().chain_id, 42161);
         assert_eq!(Chain::Optimism.config().chain_id, 10);
+        assert_eq!(Chain::Polygon.config().chain_id, 137);
+        assert_eq!(Chain::PolygonAmoy.config().chain_id, 80002);
+        assert_eq!(Chain::Avalanche.config().chain_id, 43114);
+        assert_eq!(Chain::AvalancheFuji.config().chain_id, 43113);
+        assert_eq!(Chain::Bnb.config().chain_id, 56);
+        assert_eq!(Chain::BnbTestnet.config().chain_id, 97);
+    }
+
+    #[test]
+    fn test_non_eth_native_tokens() {
+        assert_eq!(Chain::Polygon.config().native_token, "POL");
+        assert_eq!(Chain::PolygonAmoy.config().native_token, "POL");
+        assert_eq!(Chain::Avalanche.config().native_token, "AVAX");
+        assert_eq!(Chain::AvalancheFuji.config().native_token, "AVAX");
+        assert_eq!(Chain::Bnb.config().native_token, "BNB");
+        assert_eq!(Chain::BnbTestnet.config().native_token, "BNB");
     }
 
     #[test]
@@ -481,6 +924,166 @@ mod tests {
         assert!(Chain::from_str("invalid").is_err());
     }
 
+    #[test]
+    fn test_slug_round_trips_through_from_str() {
+        for chain in list_evm_chains().into_iter().chain(list_solana_chains()) {
+            assert_eq!(Chain::from_str(chain.slug()).unwrap(), chain);
+        }
+    }
+
+    #[test]
+    fn test_from_chain_id() {
+        assert_eq!(Chain::from_chain_id(1).unwrap(), Chain::Ethereum);
+        assert_eq!(Chain::from_chain_id(11155111).unwrap(), Chain::EthereumSepolia);
+        assert_eq!(Chain::from_chain_id(8453).unwrap(), Chain::Base);
+        assert_eq!(Chain::from_chain_id(42161).unwrap(), Chain::Arbitrum);
+        assert_eq!(Chain::from_chain_id(10).unwrap(), Chain::Optimism);
+        assert!(matches!(Chain::from_chain_id(0), Err(ChainError::UnsupportedChain(_))));
+        assert!(matches!(Chain::from_chain_id(999_999), Err(ChainError::UnsupportedChain(_))));
+    }
+
+    #[test]
+    fn test_from_chain_id_round_trips_every_evm_chain() {
+        for chain in list_evm_chains() {
+            let chain_id = chain.config().chain_id;
+            if chain_id != 0 {
+                assert_eq!(Chain::from_chain_id(chain_id).unwrap(), chain);
+            }
+        }
+    }
+
+    #[test]
+    fn test_estimated_finality() {
+        assert_eq!(Chain::Ethereum.estimated_finality_secs(), 144);
+        assert_eq!(Chain::Base.estimated_finality_secs(), 2);
+        assert_eq!(Chain::Optimism.estimated_finality_secs(), 2);
+        assert_eq!(Chain::Arbitrum.estimated_finality_secs(), 1);
+        assert_eq!(Chain::Solana.estimated_finality_secs(), 32);
+
+        // Every chain needs at least one confirmation and a non-zero block time
+        for chain in list_evm_chains().into_iter().chain(list_solana_chains()) {
+            assert!(chain.estimated_finality_secs() > 0, "{:?}", chain);
+        }
+    }
+
+    #[test]
+    fn test_parse_rpc_url_list() {
+        assert!(parse_rpc_url_list(None).is_empty());
+        assert!(parse_rpc_url_list(Some(" , ")).is_empty());
+        assert_eq!(
+            parse_rpc_url_list(Some("https://a.example, https://b.example,")),
+            vec!["https://a.example".to_string(), "https://b.example".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_rpc_urls_lists_primary_then_fallbacks() {
+        let mut config = Chain::TempoTestnet.config();
+        config.fallback_rpc_urls = parse_rpc_url_list(Some(
+            "https://backup-1.example, https://tempo-testnet-rpc.example.com, https://backup-2.example",
+        ));
+
+        assert_eq!(
+            ordered_rpc_urls(config),
+            vec![
+                "https://tempo-testnet-rpc.example.com".to_string(),
+                "https://backup-1.example".to_string(),
+                "https://backup-2.example".to_string(),
+            ]
+        );
+        assert_eq!(Chain::TempoTestnet.rpc_urls(), vec!["https://tempo-testnet-rpc.example.com".to_string()]);
+    }
+
+    #[test]
+    fn test_placeholder_chains() {
+        for chain in [Chain::Arc, Chain::ArcTestnet, Chain::Tempo, Chain::TempoTestnet] {
+            assert!(chain.is_placeholder(), "{:?} should be a placeholder", chain);
+            let err = chain.ensure_available().unwrap_err();
+            assert!(matches!(err, ChainError::ChainNotAvailable(c) if c == chain));
+            assert!(err.to_string().contains("not yet available"));
+        }
+
+        for chain in [Chain::Base, Chain::BaseSepolia, Chain::Ethereum, Chain::Solana] {
+            assert!(!chain.is_placeholder(), "{:?} should not be a placeholder", chain);
+            assert!(chain.ensure_available().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_multicall3_address() {
+        let expected = Address::from_str(MULTICALL3_ADDRESS).unwrap();
+        assert_eq!(Chain::Ethereum.multicall3_address(), Some(expected));
+        assert_eq!(Chain::BaseSepolia.multicall3_address(), Some(expected));
+        assert_eq!(Chain::Solana.multicall3_address(), None);
+        assert_eq!(Chain::Tempo.multicall3_address(), None);
+    }
+
+    #[test]
+    fn test_validate_all_reports_every_placeholder() {
+        let chains = [Chain::Ethereum, Chain::Arc, Chain::Base, Chain::Tempo, Chain::Solana];
+        let results = validate_all(&chains);
+
+        assert_eq!(results.len(), chains.len());
+        for ((chain, _), expected) in results.iter().zip(chains) {
+            assert_eq!(*chain, expected, "results should keep input order");
+        }
+        assert!(results[0].1.is_ok());
+        assert!(matches!(results[1].1, Err(ChainError::ChainNotAvailable(Chain::Arc))));
+        assert_eq!(results[2].1.as_ref().unwrap().chain_id, 8453);
+        assert!(matches!(results[3].1, Err(ChainError::ChainNotAvailable(Chain::Tempo))));
+        assert!(results[4].1.is_ok());
+    }
+
+    #[test]
+    fn test_config_checked_rejects_malformed_rpc_urls() {
+        let fuji = |rpc_url: &str, fallbacks: &str| {
+            let mut config = Chain::AvalancheFuji.config();
+            config.rpc_url = rpc_url.to_string();
+            config.fallback_rpc_urls = parse_rpc_url_list(Some(fallbacks));
+            Chain::AvalancheFuji.check_config(config)
+        };
+        let valid = "https://api.avax-test.network/ext/bc/C/rpc";
+
+        assert!(matches!(fuji("", ""), Err(ChainError::RpcUrlNotConfigured(Chain::AvalancheFuji))));
+        assert!(matches!(
+            fuji("api.avax-test.network/ext/bc/C/rpc", ""),
+            Err(ChainError::InvalidConfiguration(Chain::AvalancheFuji))
+        ));
+        assert!(matches!(
+            fuji(valid, "wss://ok.example.org, ftp://nope.example.org"),
+            Err(ChainError::InvalidConfiguration(Chain::AvalancheFuji))
+        ));
+        assert!(fuji(valid, "wss://ok.example.org").is_ok());
+        assert!(Chain::AvalancheFuji.config_checked().is_ok());
+    }
+
+    #[test]
+    fn test_explorer_links_evm() {
+        let config = Chain::Ethereum.config();
+        assert_eq!(config.tx_url("0xdeadbeef"), "https://etherscan.io/tx/0xdeadbeef");
+        assert_eq!(
+            config.address_url("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1"),
+            "https://etherscan.io/address/0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1"
+        );
+
+        let mut trailing_slash = Chain::Polygon.config();
+        trailing_slash.explorer_url.push('/');
+        assert_eq!(trailing_slash.tx_url("0x1"), "https://polygonscan.com/tx/0x1");
+    }
+
+    #[test]
+    fn test_explorer_links_solana_keep_cluster_suffix() {
+        assert_eq!(Chain::Solana.config().tx_url("5sig"), "https://explorer.solana.com/tx/5sig");
+        assert_eq!(
+            Chain::SolanaDevnet.config().tx_url("5sig"),
+            "https://explorer.solana.com/tx/5sig?cluster=devnet"
+        );
+        assert_eq!(
+            Chain::SolanaDevnet.config().address_url("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS"),
+            "https://explorer.solana.com/address/Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS?cluster=devnet"
+        );
+    }
+
     #[test]
     fn test_chain_names() {
         assert_eq!(Chain::Ethereum.name(), "Ethereum");
@@ -491,7 +1094,7 @@ mod tests {
     #[test]
     fn test_list_chains() {
         let evm_chains = list_evm_chains();
-        assert_eq!(evm_chains.len(), 12);
+        assert_eq!(evm_chains.len(), 18);
         assert!(evm_chains.contains(&Chain::Ethereum));
         assert!(evm_chains.contains(&Chain::Base));
 
@@ -500,11 +1103,11 @@ mod tests {
         assert!(solana_chains.contains(&Chain::Solana));
 
         let mainnet_chains = list_mainnet_chains();
-        assert_eq!(mainnet_chains.len(), 7);
+        assert_eq!(mainnet_chains.len(), 10);
         assert!(mainnet_chains.iter().all(|c| c.is_mainnet()));
 
         let testnet_chains = list_testnet_chains();
-        assert_eq!(testnet_chains.len(), 7);
+        assert_eq!(testnet_chains.len(), 10);
         assert!(testnet_chains.iter().all(|c| c.is_testnet()));
     }
 
@@ -521,6 +1124,12 @@ mod tests {
             Chain::ArbitrumSepolia,
             Chain::Optimism,
             Chain::OptimismSepolia,
+            Chain::Polygon,
+            Chain::PolygonAmoy,
+            Chain::Avalanche,
+            Chain::AvalancheFuji,
+            Chain::Bnb,
+            Chain::BnbTestnet,
             Chain::Arc,
             Chain::ArcTestnet,
             Chain::Tempo,
@@ -546,6 +1155,12 @@ mod tests {
             Chain::ArbitrumSepolia,
             Chain::Optimism,
             Chain::OptimismSepolia,
+            Chain::Polygon,
+            Chain::PolygonAmoy,
+            Chain::Avalanche,
+            Chain::AvalancheFuji,
+            Chain::Bnb,
+            Chain::BnbTestnet,
             Chain::Arc,
             Chain::ArcTestnet,
             Chain::Tempo,
@@ -568,6 +1183,12 @@ mod tests {
         assert_eq!(Chain::from_str("op").unwrap(), Chain::Optimism);
         assert_eq!(Chain::from_str("sol").unwrap(), Chain::Solana);
         assert_eq!(Chain::from_str("devnet").unwrap(), Chain::SolanaDevnet);
+        assert_eq!(Chain::from_str("pol").unwrap(), Chain::Polygon);
+        assert_eq!(Chain::from_str("amoy").unwrap(), Chain::PolygonAmoy);
+        assert_eq!(Chain::from_str("avax").unwrap(), Chain::Avalanche);
+        assert_eq!(Chain::from_str("fuji").unwrap(), Chain::AvalancheFuji);
+        assert_eq!(Chain::from_str("bsc").unwrap(), Chain::Bnb);
+        assert_eq!(Chain::from_str("bsc-testnet").unwrap(), Chain::BnbTestnet);
     }
 
     #[test]
@@ -599,6 +1220,12 @@ mod tests {
             Chain::ArbitrumSepolia,
             Chain::Optimism,
             Chain::OptimismSepolia,
+            Chain::Polygon,
+            Chain::PolygonAmoy,
+            Chain::Avalanche,
+            Chain::AvalancheFuji,
+            Chain::Bnb,
+            Chain::BnbTestnet,
             Chain::Arc,
             Chain::ArcTestnet,
             Chain::Tempo,
@@ -637,6 +1264,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_all_chain_names_unique() {
         let all_chains = list_evm_chains().into_iter()
             .chain(list_solana_chains().into_iter())
@@ -0,0 +1,176 @@
+//! # Recipient Verification
+//!
+//! Optional on-chain check of a payment recipient before funds are sent.
+//! Format validation alone accepts any 20-byte address; this confirms the
+//! address is either a deployed contract (e.g. an EIP-1271 smart-contract
+//! wallet) or an account that has been used, so payments are not sent to
+//! an address nobody controls.
+//!
+//! An unused EOA with no balance looks exactly like a typo'd address on
+//! chain, so both are rejected as `ChainError::EmptyRecipient`.
+
+use crate::{Chain, ChainError};
+use ethers::providers::{Http, Middleware, Provider};
+use ethers::types::Address;
+use std::sync::Arc;
+
+/// What the chain knows about a recipient address
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientKind {
+    /// Code is deployed at the address (smart-contract wallet)
+    Contract,
+    /// No code, but the account has sent transactions or holds a balance
+    ExternallyOwned,
+    /// No code, no nonce, no balance
+    Empty,
+}
+
+/// Classifies recipient addresses using an EVM provider
+pub struct RecipientVerifier<M> {
+    provider: Arc<M>,
+}
+
+impl<M: Middleware> RecipientVerifier<M> {
+    /// Create a verifier backed by an existing provider
+    pub fn new(provider: Arc<M>) -> Self {
+        Self { provider }
+    }
+
+    /// Looks up code, then nonce, then balance, stopping at the first that
+    /// identifies the address as in use
+    pub async fn classify(&self, address: Address) -> Result<RecipientKind, ChainError> {
+        let code = self
+            .provider
+            .get_code(address, None)
+            .await
+            .map_err(|e| ChainError::RpcError(e.to_string()))?;
+        if !code.is_empty() {
+            return Ok(RecipientKind::Contract);
+        }
+
+        let nonce = self
+            .provider
+            .get_transaction_count(address, None)
+            .await
+            .map_err(|e| ChainError::RpcError(e.to_string()))?;
+        if !nonce.is_zero() {
+            return Ok(RecipientKind::ExternallyOwned);
+        }
+
+        let balance = self
+            .provider
+            .get_balance(address, None)
+            .await
+            .map_err(|e| ChainError::RpcError(e.to_string()))?;
+        if !balance.is_zero() {
+            return Ok(RecipientKind::ExternallyOwned);
+        }
+
+        Ok(RecipientKind::Empty)
+    }
+
+    /// Errors with `EmptyRecipient` unless the address is a contract or a
+    /// used account
+    pub async fn ensure_payable(&self, address: Address) -> Result<RecipientKind, ChainError> {
+        match self.classify(address).await? {
+            RecipientKind::Empty => Err(ChainError::EmptyRecipient(address)),
+            kind => Ok(kind),
+        }
+    }
+}
+
+impl RecipientVerifier<Provider<Http>> {
+    /// Create a verifier using the chain's configured RPC URL
+    pub fn for_chain(chain: Chain) -> Result<Self, ChainError> {
+        chain.ensure_available()?;
+        if !chain.is_evm_chain() {
+            return Err(ChainError::UnsupportedChain(format!("{:?} is not an EVM chain", chain)));
+        }
+        let provider = Provider::<Http>::try_from(chain.config().rpc_url)
+            .map_err(|_| ChainError::RpcUrlNotConfigured(chain))?;
+        Ok(Self::new(Arc::new(provider)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::providers::MockProvider;
+    use ethers::types::{Bytes, U256};
+
+    const RECIPIENT: &str = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb1";
+
+    // MockProvider answers the most recently pushed response first, so the
+    // responses are queued in reverse call order: balance, nonce, code.
+    fn mocked_verifier(
+        code: &[u8],
+        nonce: Option<u64>,
+        balance: Option<u64>,
+    ) -> RecipientVerifier<Provider<MockProvider>> {
+        let (provider, mock) = Provider::mocked();
+        if let Some(balance) = balance {
+            mock.push(U256::from(balance)).unwrap();
+        }
+        if let Some(nonce) = nonce {
+            mock.push(U256::from(nonce)).unwrap();
+        }
+        mock.push::<Bytes, _>(Bytes::from(code.to_vec())).unwrap();
+        RecipientVerifier::new(Arc::new(provider))
+    }
+
+    fn recipient() -> Address {
+        RECIPIENT.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_contract_recipient() {
+        let verifier = mocked_verifier(&[0x60, 0x80, 0x60, 0x40], None, None);
+        assert_eq!(verifier.ensure_payable(recipient()).await.unwrap(), RecipientKind::Contract);
+    }
+
+    #[tokio::test]
+    async fn test_eoa_recipient() {
+        let with_history = mocked_verifier(&[], Some(7), None);
+        assert_eq!(
+            with_history.ensure_payable(recipient()).await.unwrap(),
+            RecipientKind::ExternallyOwned
+        );
+
+        let funded_only = mocked_verifier(&[], Some(0), Some(1_000));
+        assert_eq!(
+            funded_only.ensure_payable(recipient()).await.unwrap(),
+            RecipientKind::ExternallyOwned
+        );
+    }
+
+    #[tokio::test]
+    async fn test_nonexistent_recipient_rejected() {
+        let verifier = mocked_verifier(&[], Some(0), Some(0));
+        assert_eq!(verifier.classify(recipient()).await.unwrap(), RecipientKind::Empty);
+
+        let verifier = mocked_verifier(&[], Some(0), Some(0));
+        let err = verifier.ensure_payable(recipient()).await.unwrap_err();
+        assert!(matches!(err, ChainError::EmptyRecipient(addr) if addr == recipient()));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_errors_surface() {
+        // No queued response: the mock provider errors
+        let (provider, _mock) = Provider::mocked();
+        let verifier = RecipientVerifier::new(Arc::new(provider));
+        let result = verifier.ensure_payable(recipient()).await;
+        assert!(matches!(result, Err(ChainError::RpcError(_))));
+    }
+
+    #[test]
+    fn test_for_chain_rejects_non_evm_and_placeholders() {
+        assert!(matches!(
+            RecipientVerifier::for_chain(Chain::Solana),
+            Err(ChainError::UnsupportedChain(_))
+        ));
+        assert!(matches!(
+            RecipientVerifier::for_chain(Chain::Arc),
+            Err(ChainError::ChainNotAvailable(Chain::Arc))
+        ));
+    }
+}
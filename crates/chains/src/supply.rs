@@ -0,0 +1,110 @@
+//! # Supply Reader
+//!
+//! Reads a stablecoin's circulating supply straight from its ERC-20
+//! `totalSupply()`, so the database's tracked supply can be reconciled
+//! against what actually exists on chain.
+
+use crate::{Chain, ChainError};
+use ethers::providers::{Http, Middleware, Provider};
+use ethers::types::{Address, Bytes, TransactionRequest, U256};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// `totalSupply()` function selector
+const TOTAL_SUPPLY_SELECTOR: [u8; 4] = [0x18, 0x16, 0x0d, 0xdd];
+
+/// Reads ERC-20 total supply using an EVM provider
+pub struct SupplyReader<M> {
+    provider: Arc<M>,
+}
+
+impl<M: Middleware> SupplyReader<M> {
+    /// Create a reader backed by an existing provider
+    pub fn new(provider: Arc<M>) -> Self {
+        Self { provider }
+    }
+
+    /// Total supply of `token` in whole tokens, given its `decimals`
+    pub async fn total_supply(&self, token: Address, decimals: u32) -> Result<Decimal, ChainError> {
+        let call = TransactionRequest::new()
+            .to(token)
+            .data(Bytes::from(TOTAL_SUPPLY_SELECTOR.to_vec()));
+        let output = self
+            .provider
+            .call(&call.into(), None)
+            .await
+            .map_err(|e| ChainError::RpcError(e.to_string()))?;
+        if output.len() < 32 {
+            return Err(ChainError::RpcError(format!(
+                "totalSupply() on {:?} returned {} bytes",
+                token,
+                output.len()
+            )));
+        }
+        from_token_units(U256::from_big_endian(&output[..32]), decimals)
+    }
+}
+
+impl SupplyReader<Provider<Http>> {
+    /// Create a reader using the chain's configured RPC URL
+    pub fn for_chain(chain: Chain) -> Result<Self, ChainError> {
+        chain.ensure_available()?;
+        if !chain.is_evm_chain() {
+            return Err(ChainError::UnsupportedChain(format!("{:?} is not an EVM chain", chain)));
+        }
+        let provider = Provider::<Http>::try_from(chain.config().rpc_url)
+            .map_err(|_| ChainError::RpcUrlNotConfigured(chain))?;
+        Ok(Self::new(Arc::new(provider)))
+    }
+}
+
+/// Converts integer base units to a decimal token amount
+pub fn from_token_units(units: U256, decimals: u32) -> Result<Decimal, ChainError> {
+    if units > U256::from(i128::MAX as u128) {
+        return Err(ChainError::RpcError(format!("Token amount overflow: {}", units)));
+    }
+    Decimal::try_from_i128_with_scale(units.as_u128() as i128, decimals)
+        .map(|amount| amount.normalize())
+        .map_err(|e| ChainError::RpcError(format!("Unrepresentable token amount {}: {}", units, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::providers::MockProvider;
+
+    const TOKEN: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+
+    fn mocked_reader(output: Vec<u8>) -> SupplyReader<Provider<MockProvider>> {
+        let (provider, mock) = Provider::mocked();
+        mock.push::<Bytes, _>(Bytes::from(output)).unwrap();
+        SupplyReader::new(Arc::new(provider))
+    }
+
+    fn encoded(units: u128) -> Vec<u8> {
+        let mut word = [0u8; 32];
+        U256::from(units).to_big_endian(&mut word);
+        word.to_vec()
+    }
+
+    #[tokio::test]
+    async fn test_total_supply_scales_by_decimals() {
+        let reader = mocked_reader(encoded(1_250_500_000));
+        let supply = reader.total_supply(TOKEN.parse().unwrap(), 6).await.unwrap();
+        assert_eq!(supply, Decimal::new(12505, 1));
+    }
+
+    #[tokio::test]
+    async fn test_total_supply_rejects_short_output() {
+        let reader = mocked_reader(vec![0x01]);
+        let err = reader.total_supply(TOKEN.parse().unwrap(), 6).await.unwrap_err();
+        assert!(matches!(err, ChainError::RpcError(_)));
+    }
+
+    #[test]
+    fn test_from_token_units() {
+        assert_eq!(from_token_units(U256::zero(), 6).unwrap(), Decimal::ZERO);
+        assert_eq!(from_token_units(U256::from(1_000_000u64), 6).unwrap(), Decimal::ONE);
+        assert!(from_token_units(U256::MAX, 18).is_err());
+    }
+}
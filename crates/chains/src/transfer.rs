@@ -0,0 +1,324 @@
+//! # ERC-20 Transfer Execution
+//!
+//! Executes agent payments as plain ERC-20 `transfer` calls on the
+//! Meridian stablecoin for the payment currency.
+//!
+//! `BlockchainExecutor` is the seam the API depends on; `Erc20TransferExecutor`
+//! is the ethers-backed implementation. A transfer is only reported as
+//! successful once its receipt is mined with a success status, so callers can
+//! mark payments complete on `Ok` without polling themselves.
+//!
+//! ## Outcome on error
+//!
+//! - `Reverted`: the transaction was mined and failed; no funds moved
+//! - `Timeout`: the transaction was submitted but its outcome is unknown,
+//!   so the payment must not be treated as failed
+//! - anything else: no transfer was mined
+
+use crate::execution::{ExecutionError, ExecutionResult};
+use crate::Chain;
+use ethers::abi::Abi;
+use ethers::contract::Contract;
+use ethers::middleware::SignerMiddleware;
+use ethers::providers::{Http, Provider};
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::{Address, TransactionReceipt, TxHash, U256};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Decimals used by every Meridian stablecoin (6, like USDC)
+pub const TOKEN_DECIMALS: u32 = 6;
+
+/// Default time to wait for a submitted transfer to be mined
+const DEFAULT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Receipt polling interval while waiting for confirmation
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+const ERC20_TRANSFER_ABI_JSON: &str = r#"[
+  {
+    "type": "function",
+    "name": "transfer",
+    "inputs": [
+      { "name": "to",     "type": "address" },
+      { "name": "amount", "type": "uint256" }
+    ],
+    "outputs": [{ "name": "", "type": "bool" }],
+    "stateMutability": "nonpayable"
+  }
+]"#;
+
+/// Executes token transfers on behalf of agent wallets
+#[async_trait::async_trait]
+pub trait BlockchainExecutor: Send + Sync {
+    /// Transfers `amount` of the `currency` stablecoin from `from_wallet` to
+    /// `to` on `chain`, returning once the transaction has one confirmation
+    async fn execute_transfer(
+        &self,
+        chain: Chain,
+        from_wallet: Address,
+        to: Address,
+        amount: Decimal,
+        currency: &str,
+    ) -> ExecutionResult<TxHash>;
+}
+
+/// Ethers-backed `BlockchainExecutor` that signs with a local key
+///
+/// Holds one signing key, so it can only send from the wallet that key
+/// controls; requests for any other `from_wallet` are refused.
+pub struct Erc20TransferExecutor {
+    chain: Chain,
+    client: Arc<SignerMiddleware<Provider<Http>, LocalWallet>>,
+    /// Stablecoin contract per currency code on `chain`
+    tokens: HashMap<String, Address>,
+    abi: Abi,
+    confirmation_timeout: Duration,
+}
+
+impl Erc20TransferExecutor {
+    /// Create an executor for `chain` using its configured RPC URL
+    pub fn new(chain: Chain, private_key: &str, tokens: HashMap<String, Address>) -> ExecutionResult<Self> {
+        if !chain.is_evm_chain() {
+            return Err(ExecutionError::UnsupportedChain(chain.slug().to_string()));
+        }
+        chain
+            .ensure_available()
+            .map_err(|e| ExecutionError::UnsupportedChain(e.to_string()))?;
+
+        let config = chain.config();
+        let provider = Provider::<Http>::try_from(config.rpc_url.as_str())
+            .map_err(|e| ExecutionError::Provider(e.to_string()))?;
+        let wallet = private_key
+            .parse::<LocalWallet>()
+            .map_err(|e| ExecutionError::SignerConfig(e.to_string()))?
+            .with_chain_id(config.chain_id);
+        let abi: Abi = serde_json::from_str(ERC20_TRANSFER_ABI_JSON)
+            .map_err(|e| ExecutionError::Serialization(e.to_string()))?;
+
+        Ok(Self {
+            chain,
+            client: Arc::new(SignerMiddleware::new(provider, wallet)),
+            tokens,
+            abi,
+            confirmation_timeout: DEFAULT_CONFIRMATION_TIMEOUT,
+        })
+    }
+
+    /// Create an executor from environment variables
+    ///
+    /// Reads:
+    /// - `AGENT_PAYMENT_PRIVATE_KEY` — key of the paying wallet
+    /// - `AGENT_PAYMENT_TOKENS` — `EUR:0x...,GBP:0x...` stablecoin addresses
+    pub fn from_env(chain: Chain) -> ExecutionResult<Self> {
+        let private_key = std::env::var("AGENT_PAYMENT_PRIVATE_KEY")
+            .map_err(|_| ExecutionError::SignerConfig("AGENT_PAYMENT_PRIVATE_KEY not set".to_string()))?;
+        let tokens = parse_token_addresses(&std::env::var("AGENT_PAYMENT_TOKENS").unwrap_or_default())?;
+        Self::new(chain, &private_key, tokens)
+    }
+
+    /// Override how long to wait for a transfer to be mined
+    pub fn with_confirmation_timeout(mut self, timeout: Duration) -> Self {
+        self.confirmation_timeout = timeout;
+        self
+    }
+
+    /// Address of the wallet this executor signs for
+    pub fn signer_address(&self) -> Address {
+        self.client.signer().address()
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockchainExecutor for Erc20TransferExecutor {
+    async fn execute_transfer(
+        &self,
+        chain: Chain,
+        from_wallet: Address,
+        to: Address,
+        amount: Decimal,
+        currency: &str,
+    ) -> ExecutionResult<TxHash> {
+        if chain != self.chain {
+            return Err(ExecutionError::UnsupportedChain(chain.slug().to_string()));
+        }
+        if from_wallet != self.signer_address() {
+            return Err(ExecutionError::SignerConfig(format!("No signing key for wallet {:?}", from_wallet)));
+        }
+        let token = *self
+            .tokens
+            .get(&currency.to_uppercase())
+            .ok_or_else(|| ExecutionError::UnsupportedCurrency(currency.to_string()))?;
+        let units = to_token_units(amount, TOKEN_DECIMALS)?;
+
+        let contract = Contract::new(token, self.abi.clone(), self.client.clone());
+        let call = contract
+            .method::<(Address, U256), bool>("transfer", (to, units))
+            .map_err(|e| ExecutionError::Contract(format!("ABI encode error: {}", e)))?;
+
+        let pending_tx = call.send().await.map_err(|e| {
+            let msg = e.to_string();
+            if msg.contains("revert") {
+                ExecutionError::Reverted(msg)
+            } else {
+                ExecutionError::Contract(msg)
+            }
+        })?;
+        let tx_hash = pending_tx.tx_hash();
+
+        tracing::info!(
+            tx_hash = ?tx_hash,
+            currency = %currency,
+            chain = self.chain.slug(),
+            "Agent transfer submitted"
+        );
+
+        let receipt = tokio::time::timeout(
+            self.confirmation_timeout,
+            pending_tx.interval(RECEIPT_POLL_INTERVAL).confirmations(1),
+        )
+        .await;
+
+        match receipt {
+            Ok(Ok(Some(receipt))) => confirmed_hash(tx_hash, &receipt),
+            Ok(Ok(None)) => {
+                tracing::warn!(tx_hash = ?tx_hash, "Agent transfer dropped from mempool");
+                Err(ExecutionError::Provider(format!("Transaction {:?} dropped", tx_hash)))
+            }
+            Ok(Err(e)) => {
+                // The transaction is out; a polling failure says nothing about its outcome
+                tracing::warn!(tx_hash = ?tx_hash, error = %e, "Lost track of agent transfer");
+                Err(ExecutionError::Timeout)
+            }
+            Err(_) => Err(ExecutionError::Timeout),
+        }
+    }
+}
+
+fn confirmed_hash(tx_hash: TxHash, receipt: &TransactionReceipt) -> ExecutionResult<TxHash> {
+    if receipt.status.map(|s| s.as_u64() == 1).unwrap_or(false) {
+        Ok(tx_hash)
+    } else {
+        tracing::warn!(tx_hash = ?tx_hash, "Agent transfer reverted on-chain");
+        Err(ExecutionError::Reverted(format!("{:?}", tx_hash)))
+    }
+}
+
+/// Converts a decimal token amount to integer base units
+///
+/// Rejects non-positive amounts and amounts with more precision than the
+/// token supports rather than silently rounding.
+pub fn to_token_units(amount: Decimal, decimals: u32) -> ExecutionResult<U256> {
+    if amount <= Decimal::ZERO {
+        return Err(ExecutionError::InvalidAmount(format!("{} must be positive", amount)));
+    }
+    let scaled = Decimal::from(10u64.pow(decimals))
+        .checked_mul(amount)
+        .ok_or_else(|| ExecutionError::InvalidAmount(format!("{} is too large", amount)))?;
+    if !scaled.fract().is_zero() {
+        return Err(ExecutionError::InvalidAmount(format!(
+            "{} has more than {} decimal places",
+            amount, decimals
+        )));
+    }
+    scaled
+        .to_u128()
+        .map(U256::from)
+        .ok_or_else(|| ExecutionError::InvalidAmount(format!("{} is too large", amount)))
+}
+
+/// Parses `CODE:address` pairs, e.g. `EUR:0xabc...,GBP:0xdef...`
+pub fn parse_token_addresses(raw: &str) -> ExecutionResult<HashMap<String, Address>> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (code, address) = entry
+                .split_once(':')
+                .ok_or_else(|| ExecutionError::InvalidAddress(entry.to_string()))?;
+            let address = address
+                .trim()
+                .parse::<Address>()
+                .map_err(|_| ExecutionError::InvalidAddress(address.trim().to_string()))?;
+            Ok((code.trim().to_uppercase(), address))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::U64;
+
+    const TEST_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const EURM: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+
+    #[test]
+    fn test_to_token_units() {
+        assert_eq!(to_token_units(Decimal::new(1, 0), 6).unwrap(), U256::from(1_000_000u64));
+        assert_eq!(to_token_units(Decimal::new(12345, 2), 6).unwrap(), U256::from(123_450_000u64));
+        assert_eq!(to_token_units(Decimal::new(1, 6), 6).unwrap(), U256::from(1u64));
+    }
+
+    #[test]
+    fn test_to_token_units_rejects_bad_amounts() {
+        assert!(matches!(to_token_units(Decimal::ZERO, 6), Err(ExecutionError::InvalidAmount(_))));
+        assert!(matches!(to_token_units(Decimal::new(-5, 0), 6), Err(ExecutionError::InvalidAmount(_))));
+        // Seven decimal places cannot be represented in a 6-decimal token
+        assert!(matches!(to_token_units(Decimal::new(1, 7), 6), Err(ExecutionError::InvalidAmount(_))));
+    }
+
+    #[test]
+    fn test_parse_token_addresses() {
+        let tokens = parse_token_addresses(&format!(" eur:{} , ", EURM)).unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens["EUR"], EURM.parse::<Address>().unwrap());
+
+        assert!(parse_token_addresses("").unwrap().is_empty());
+        assert!(matches!(parse_token_addresses("EUR"), Err(ExecutionError::InvalidAddress(_))));
+        assert!(matches!(parse_token_addresses("EUR:0xnope"), Err(ExecutionError::InvalidAddress(_))));
+    }
+
+    #[test]
+    fn test_confirmed_hash_checks_receipt_status() {
+        let tx_hash = TxHash::repeat_byte(0xab);
+        let mined = |status: Option<u64>| TransactionReceipt {
+            transaction_hash: tx_hash,
+            status: status.map(U64::from),
+            ..Default::default()
+        };
+
+        assert_eq!(confirmed_hash(tx_hash, &mined(Some(1))).unwrap(), tx_hash);
+        assert!(matches!(confirmed_hash(tx_hash, &mined(Some(0))), Err(ExecutionError::Reverted(_))));
+        assert!(matches!(confirmed_hash(tx_hash, &mined(None)), Err(ExecutionError::Reverted(_))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_transfer_refuses_before_broadcast() {
+        let tokens = parse_token_addresses(&format!("EUR:{}", EURM)).unwrap();
+        let executor = Erc20TransferExecutor::new(Chain::BaseSepolia, TEST_KEY, tokens).unwrap();
+        let signer = executor.signer_address();
+        let to = Address::repeat_byte(0x11);
+        let amount = Decimal::new(10, 0);
+
+        let wrong_chain = executor.execute_transfer(Chain::Base, signer, to, amount, "EUR").await;
+        assert!(matches!(wrong_chain, Err(ExecutionError::UnsupportedChain(_))));
+
+        let foreign_wallet = executor
+            .execute_transfer(Chain::BaseSepolia, Address::repeat_byte(0x22), to, amount, "EUR")
+            .await;
+        assert!(matches!(foreign_wallet, Err(ExecutionError::SignerConfig(_))));
+
+        let unknown_currency = executor.execute_transfer(Chain::BaseSepolia, signer, to, amount, "JPY").await;
+        assert!(matches!(unknown_currency, Err(ExecutionError::UnsupportedCurrency(c)) if c == "JPY"));
+    }
+
+    #[test]
+    fn test_new_rejects_non_evm_chain() {
+        let result = Erc20TransferExecutor::new(Chain::Solana, TEST_KEY, HashMap::new());
+        assert!(matches!(result, Err(ExecutionError::UnsupportedChain(_))));
+    }
+}
@@ -9,7 +9,9 @@
 //! - Regulatory reporting
 
 use chrono::{DateTime, Utc};
+use sanctions::{SanctionHit, SanctionsList};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -43,6 +45,12 @@ pub enum ComplianceError {
 
     #[error("External service error: {0}")]
     ExternalServiceError(String),
+
+    #[error("Sanctions list load failed: {0}")]
+    SanctionsListLoadFailed(String),
+
+    #[error("Invalid compliance configuration: {0}")]
+    InvalidConfig(String),
 }
 
 /// Result type for compliance operations
@@ -82,6 +90,60 @@ pub enum ComplianceStatus {
     ReviewRequired,
 }
 
+impl ComplianceStatus {
+    /// Parses a `users.kyc_status` value
+    ///
+    /// `IN_PROGRESS` (application started but not submitted) reads as
+    /// `Pending`, like `PENDING_REVIEW`. Unknown values return `None`.
+    pub fn from_db_str(s: &str) -> Option<Self> {
+        match s {
+            "NOT_STARTED" => Some(ComplianceStatus::NotStarted),
+            "PENDING_REVIEW" | "IN_PROGRESS" => Some(ComplianceStatus::Pending),
+            "APPROVED" => Some(ComplianceStatus::Approved),
+            "REJECTED" => Some(ComplianceStatus::Rejected),
+            "SUSPENDED" => Some(ComplianceStatus::Suspended),
+            "REVIEW_REQUIRED" => Some(ComplianceStatus::ReviewRequired),
+            _ => None,
+        }
+    }
+
+    /// The `users.kyc_status` value stored for this status
+    pub fn to_db_str(&self) -> &'static str {
+        match self {
+            ComplianceStatus::NotStarted => "NOT_STARTED",
+            ComplianceStatus::Pending => "PENDING_REVIEW",
+            ComplianceStatus::Approved => "APPROVED",
+            ComplianceStatus::Rejected => "REJECTED",
+            ComplianceStatus::Suspended => "SUSPENDED",
+            ComplianceStatus::ReviewRequired => "REVIEW_REQUIRED",
+        }
+    }
+
+    /// Statuses an admin may set directly; the others follow from the
+    /// customer's own application and its review
+    pub fn is_admin_settable(&self) -> bool {
+        matches!(
+            self,
+            ComplianceStatus::Approved | ComplianceStatus::Suspended | ComplianceStatus::ReviewRequired
+        )
+    }
+
+    /// Whether an admin may move a customer from `self` to `target`
+    ///
+    /// Approved customers can be sent back for review; anyone approved or
+    /// under review can be suspended; reviewed or suspended customers can be
+    /// reinstated.
+    pub fn admin_can_transition_to(&self, target: &ComplianceStatus) -> bool {
+        use ComplianceStatus::*;
+        matches!(
+            (self, target),
+            (Approved, ReviewRequired)
+                | (Approved | Pending | ReviewRequired, Suspended)
+                | (ReviewRequired | Suspended, Approved)
+        )
+    }
+}
+
 /// Risk level classification per FATF guidelines
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum RiskLevel {
@@ -116,6 +178,12 @@ pub struct CustomerCompliance {
     pub last_review_at: DateTime<Utc>,
     /// Next scheduled review
     pub next_review_at: DateTime<Utc>,
+    /// Legal name from the KYC application; screened against sanctioned names
+    #[serde(default)]
+    pub legal_name: Option<String>,
+    /// Wallet the customer transacts from; screened against sanctioned addresses
+    #[serde(default)]
+    pub wallet_address: Option<String>,
 }
 
 impl CustomerCompliance {
@@ -134,6 +202,8 @@ impl CustomerCompliance {
             edd_required: false,
             last_review_at: now,
             next_review_at: now + chrono::Duration::days(365), // Annual review default
+            legal_name: None,
+            wallet_address: None,
         }
     }
 
@@ -171,6 +241,9 @@ pub struct TransactionCheck {
     pub checked_at: DateTime<Utc>,
     /// Required actions if any
     pub required_actions: Vec<String>,
+    /// Amount exceeded the EDD trigger; enhanced due diligence must be performed
+    #[serde(default)]
+    pub triggers_edd: bool,
 }
 
 /// Compliance flags that can be raised
@@ -211,6 +284,46 @@ pub struct ComplianceConfig {
     pub prohibited_countries: Vec<String>,
     /// Countries requiring enhanced due diligence
     pub high_risk_countries: Vec<String>,
+    /// Transactions above this amount (cents) trigger enhanced due diligence
+    #[serde(default = "default_edd_amount_threshold")]
+    pub edd_amount_threshold: u64,
+    /// Transactions scoring at or above this are rejected
+    #[serde(default = "default_auto_reject_threshold")]
+    pub auto_reject_threshold: u8,
+    /// Transactions scoring at or above this require manual review
+    #[serde(default = "default_manual_review_threshold")]
+    pub manual_review_threshold: u8,
+}
+
+fn default_edd_amount_threshold() -> u64 {
+    1_000_000 // $10,000.00 in cents
+}
+
+fn default_auto_reject_threshold() -> u8 {
+    80
+}
+
+fn default_manual_review_threshold() -> u8 {
+    60
+}
+
+impl ComplianceConfig {
+    /// Validate threshold ordering: manual review must trigger before auto-reject
+    pub fn validate(&self) -> ComplianceResult<()> {
+        if self.manual_review_threshold >= self.auto_reject_threshold {
+            return Err(ComplianceError::InvalidConfig(format!(
+                "manual_review_threshold ({}) must be below auto_reject_threshold ({})",
+                self.manual_review_threshold, self.auto_reject_threshold
+            )));
+        }
+        if self.auto_reject_threshold > 100 {
+            return Err(ComplianceError::InvalidConfig(format!(
+                "auto_reject_threshold ({}) must be at most 100",
+                self.auto_reject_threshold
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl Default for ComplianceConfig {
@@ -233,6 +346,9 @@ impl Default for ComplianceConfig {
                 "MM".to_string(), // Myanmar
                 "VE".to_string(), // Venezuela
             ],
+            edd_amount_threshold: default_edd_amount_threshold(),
+            auto_reject_threshold: default_auto_reject_threshold(),
+            manual_review_threshold: default_manual_review_threshold(),
         }
     }
 }
@@ -240,12 +356,23 @@ impl Default for ComplianceConfig {
 /// Main compliance service
 pub struct ComplianceService {
     config: ComplianceConfig,
+    /// File-loaded sanctions list; replaced wholesale on hot reload
+    sanctions_list: RwLock<Arc<SanctionsList>>,
 }
 
 impl ComplianceService {
     /// Create a new compliance service
     pub fn new(config: ComplianceConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            sanctions_list: RwLock::new(Arc::new(SanctionsList::default())),
+        }
+    }
+
+    /// Create a new compliance service, rejecting invalid thresholds
+    pub fn try_new(config: ComplianceConfig) -> ComplianceResult<Self> {
+        config.validate()?;
+        Ok(Self::new(config))
     }
 
     /// Create with default configuration
@@ -258,6 +385,43 @@ impl ComplianceService {
         self.config.enabled
     }
 
+    /// Current sanctions list snapshot
+    pub fn sanctions_list(&self) -> Arc<SanctionsList> {
+        self.sanctions_list
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Atomically swap in a new sanctions list
+    pub fn replace_sanctions_list(&self, list: Arc<SanctionsList>) {
+        *self
+            .sanctions_list
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = list;
+    }
+
+    /// Screen a name against the loaded sanctions list
+    pub fn screen_name(&self, name: &str) -> Vec<SanctionHit> {
+        self.sanctions_list().screen_name(name)
+    }
+
+    /// Whether `address` is a sanctioned crypto address on the loaded list
+    pub fn screen_address(&self, address: &str) -> bool {
+        match self.sanctions_list().screen_address(address) {
+            Some(entry) => {
+                tracing::warn!(
+                    address,
+                    list_id = %entry.list_id,
+                    name = %entry.name,
+                    "Sanctioned address matched"
+                );
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Check if a country is prohibited
     pub fn is_country_prohibited(&self, country_code: &str) -> bool {
         self.config.prohibited_countries.contains(&country_code.to_uppercase())
@@ -283,6 +447,7 @@ impl ComplianceService {
                 flags: vec![],
                 checked_at: Utc::now(),
                 required_actions: vec![],
+                triggers_edd: false,
             });
         }
 
@@ -304,6 +469,30 @@ impl ComplianceService {
             )));
         }
 
+        // Sanctioned names and wallets are blocked outright
+        let name_hits = customer
+            .legal_name
+            .as_deref()
+            .map(|name| self.screen_name(name))
+            .unwrap_or_default();
+        for hit in &name_hits {
+            tracing::warn!(
+                customer_id = %customer.customer_id,
+                list_id = %hit.list_id,
+                matched_name = %hit.matched_name,
+                score = hit.score,
+                "Sanctioned name matched"
+            );
+        }
+        let address_hit = customer
+            .wallet_address
+            .as_deref()
+            .is_some_and(|address| self.screen_address(address));
+        if !name_hits.is_empty() || address_hit {
+            flags.push(ComplianceFlag::SanctionMatch);
+            risk_score = 100;
+        }
+
         // Check transaction limits
         if amount_cents > self.config.default_single_limit {
             flags.push(ComplianceFlag::SingleTransactionLimitExceeded);
@@ -322,14 +511,20 @@ impl ComplianceService {
         }
 
         // Determine if transaction should be blocked
-        let approved = risk_score < 80 && flags.iter().all(|f| *f != ComplianceFlag::SanctionMatch);
+        let approved = risk_score < self.config.auto_reject_threshold && flags.iter().all(|f| *f != ComplianceFlag::SanctionMatch);
 
-        let required_actions = if risk_score >= 60 {
+        let mut required_actions = if risk_score >= self.config.manual_review_threshold {
             vec!["Manual review required".to_string()]
         } else {
             vec![]
         };
 
+        // Large amounts require EDD regardless of the customer's risk profile
+        let triggers_edd = amount_cents > self.config.edd_amount_threshold;
+        if triggers_edd {
+            required_actions.push("Enhanced due diligence required".to_string());
+        }
+
         Ok(TransactionCheck {
             transaction_id: transaction_id.to_string(),
             approved,
@@ -337,6 +532,7 @@ impl ComplianceService {
             flags,
             checked_at: Utc::now(),
             required_actions,
+            triggers_edd,
         })
     }
 
@@ -358,6 +554,7 @@ impl ComplianceService {
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
     use super::*;
 
@@ -430,6 +627,126 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn approved_customer(country: &str) -> CustomerCompliance {
+        let mut customer = CustomerCompliance::new(Uuid::new_v4(), country.to_string());
+        customer.status = ComplianceStatus::Approved;
+        customer.kyc_verified_at = Some(Utc::now());
+        customer.kyc_expires_at = Some(Utc::now() + chrono::Duration::days(365));
+        customer
+    }
+
+    #[test]
+    fn test_lower_reject_threshold_blocks_transaction() {
+        // High-risk jurisdiction scores 25: passes with defaults, blocked at 20
+        let customer = approved_customer("RU");
+
+        let default_check = ComplianceService::default_service()
+            .check_transaction(&customer, 10_000, "tx_1")
+            .unwrap();
+        assert!(default_check.approved);
+        assert_eq!(default_check.risk_score, 25);
+
+        let strict = ComplianceService::try_new(ComplianceConfig {
+            auto_reject_threshold: 20,
+            manual_review_threshold: 10,
+            ..Default::default()
+        })
+        .unwrap();
+        let check = strict.check_transaction(&customer, 10_000, "tx_1").unwrap();
+        assert!(!check.approved);
+        assert_eq!(check.required_actions, vec!["Manual review required".to_string()]);
+    }
+
+    #[test]
+    fn test_edd_amount_trigger() {
+        let service = ComplianceService::default_service();
+        let customer = approved_customer("US");
+        let threshold = ComplianceConfig::default().edd_amount_threshold;
+
+        let below = service.check_transaction(&customer, threshold, "tx_1").unwrap();
+        assert!(below.approved);
+        assert!(!below.triggers_edd);
+        assert!(below.required_actions.is_empty());
+
+        let above = service.check_transaction(&customer, threshold + 1, "tx_2").unwrap();
+        assert!(above.approved);
+        assert!(above.triggers_edd);
+        assert_eq!(
+            above.required_actions,
+            vec!["Enhanced due diligence required".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_sanctioned_wallet_blocks_transaction() {
+        use sanctions::{EntityType, SanctionListSource, SanctionsListEntry};
+
+        let service = ComplianceService::default_service();
+        service.replace_sanctions_list(Arc::new(SanctionsList::new(vec![SanctionsListEntry {
+            name: "Tornado Cash".to_string(),
+            entity_type: EntityType::Entity,
+            list_id: "SDN-TC".to_string(),
+            source: SanctionListSource::OfacSdn,
+            addresses: vec!["0x722122dF12D4e14e13Ac3b6895a86e84145b6967".to_string()],
+        }])));
+        assert!(service.screen_address("0x722122df12d4e14e13ac3b6895a86e84145b6967"));
+        assert!(!service.screen_address("0x0000000000000000000000000000000000000001"));
+
+        let mut customer = approved_customer("US");
+        assert!(service.check_transaction(&customer, 10_000, "tx_1").unwrap().approved);
+
+        customer.wallet_address = Some("0x722122DF12D4E14E13AC3B6895A86E84145B6967".to_string());
+        let check = service.check_transaction(&customer, 10_000, "tx_2").unwrap();
+        assert!(!check.approved);
+        assert_eq!(check.flags, vec![ComplianceFlag::SanctionMatch]);
+        assert_eq!(check.risk_score, 100);
+    }
+
+    #[test]
+    fn test_sanctioned_name_blocks_transaction() {
+        use sanctions::{EntityType, SanctionListSource, SanctionsListEntry};
+
+        let service = ComplianceService::default_service();
+        let mut customer = approved_customer("US");
+        customer.legal_name = Some("Bad Actor Holdings Ltd".to_string());
+        assert!(service.check_transaction(&customer, 10_000, "tx_1").unwrap().approved);
+
+        // The check reads whichever list is loaded at the time
+        service.replace_sanctions_list(Arc::new(SanctionsList::new(vec![SanctionsListEntry {
+            name: "BAD ACTOR HOLDINGS LTD".to_string(),
+            entity_type: EntityType::Entity,
+            list_id: "SDN-BAH".to_string(),
+            source: SanctionListSource::OfacSdn,
+            addresses: vec![],
+        }])));
+        let check = service.check_transaction(&customer, 10_000, "tx_2").unwrap();
+        assert!(!check.approved);
+        assert_eq!(check.flags, vec![ComplianceFlag::SanctionMatch]);
+        assert_eq!(check.risk_score, 100);
+
+        customer.legal_name = Some("Unrelated Trading Co".to_string());
+        assert!(service.check_transaction(&customer, 10_000, "tx_3").unwrap().approved);
+    }
+
+    #[test]
+    fn test_threshold_validation() {
+        assert!(ComplianceConfig::default().validate().is_ok());
+
+        let inverted = ComplianceConfig {
+            auto_reject_threshold: 50,
+            manual_review_threshold: 70,
+            ..Default::default()
+        };
+        assert!(matches!(inverted.validate(), Err(ComplianceError::InvalidConfig(_))));
+
+        let equal = ComplianceConfig {
+            auto_reject_threshold: 60,
+            manual_review_threshold: 60,
+            ..Default::default()
+        };
+        assert!(ComplianceService::try_new(equal).is_err());
+    }
+
     #[test]
     fn test_disabled_compliance() {
         let config = ComplianceConfig {
@@ -444,4 +761,45 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().approved);
     }
+
+    #[test]
+    fn test_compliance_status_db_round_trip() {
+        for status in [
+            ComplianceStatus::NotStarted,
+            ComplianceStatus::Pending,
+            ComplianceStatus::Approved,
+            ComplianceStatus::Rejected,
+            ComplianceStatus::Suspended,
+            ComplianceStatus::ReviewRequired,
+        ] {
+            assert_eq!(ComplianceStatus::from_db_str(status.to_db_str()), Some(status));
+        }
+    }
+
+    #[test]
+    fn test_admin_transitions() {
+        use ComplianceStatus::*;
+        assert!(Approved.admin_can_transition_to(&ReviewRequired));
+        assert!(Pending.admin_can_transition_to(&Suspended));
+        assert!(Suspended.admin_can_transition_to(&Approved));
+
+        // Approval without review and un-rejecting go through the application flow
+        assert!(!NotStarted.admin_can_transition_to(&Approved));
+        assert!(!Rejected.admin_can_transition_to(&ReviewRequired));
+        assert!(!ReviewRequired.admin_can_transition_to(&ReviewRequired));
+
+        for target in [NotStarted, Pending, Approved, Rejected, Suspended, ReviewRequired] {
+            let reachable = [NotStarted, Pending, Approved, Rejected, Suspended, ReviewRequired]
+                .iter()
+                .any(|from| from.admin_can_transition_to(&target));
+            assert_eq!(reachable, target.is_admin_settable(), "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn test_compliance_status_from_legacy_db_values() {
+        assert_eq!(ComplianceStatus::from_db_str("IN_PROGRESS"), Some(ComplianceStatus::Pending));
+        assert_eq!(ComplianceStatus::from_db_str("approved"), None);
+        assert_eq!(ComplianceStatus::from_db_str(""), None);
+    }
 }
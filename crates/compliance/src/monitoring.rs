@@ -215,6 +215,7 @@ impl MonitoringService {
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
     use super::*;
 
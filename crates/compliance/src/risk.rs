@@ -202,6 +202,7 @@ impl RiskEngine {
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
     use super::*;
 
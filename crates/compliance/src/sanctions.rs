@@ -1,11 +1,24 @@
 //! # Sanctions Screening Module
 //!
 //! Integration with OFAC, EU, and UN sanctions lists.
+//!
+//! `SanctionsList` loads a list file into memory, indexing names and the
+//! crypto addresses OFAC publishes as "Digital Currency Address" IDs.
+//! Supported formats:
+//!
+//! - OFAC SDN CSV (`sdn.csv`, no header row)
+//! - OFAC SDN XML (`sdn.xml`), including aliases
+//! - CSV with header `name,entity_type,list_id[,source][,addresses]`
+//! - JSON array of `SanctionsListEntry`
 
-use crate::{ComplianceError, ComplianceResult};
+use crate::{ComplianceError, ComplianceResult, ComplianceService};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use tokio::sync::RwLock;
 
 /// Sanction list sources
@@ -51,6 +64,9 @@ pub struct ScreeningMatch {
     pub list_id: String,
 }
 
+/// A sanctions list entry matched by `ComplianceService::screen_name`
+pub type SanctionHit = ScreeningMatch;
+
 /// Entity types on sanction lists
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EntityType {
@@ -60,6 +76,478 @@ pub enum EntityType {
     Aircraft,
 }
 
+impl FromStr for EntityType {
+    type Err = ComplianceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "individual" => Ok(EntityType::Individual),
+            "entity" => Ok(EntityType::Entity),
+            "vessel" => Ok(EntityType::Vessel),
+            "aircraft" => Ok(EntityType::Aircraft),
+            other => Err(ComplianceError::SanctionsListLoadFailed(format!(
+                "Unknown entity type: {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl FromStr for SanctionListSource {
+    type Err = ComplianceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "ofacsdn" | "ofac_sdn" | "ofac" => Ok(SanctionListSource::OfacSdn),
+            "euconsolidated" | "eu_consolidated" | "eu" => Ok(SanctionListSource::EuConsolidated),
+            "unsecuritycouncil" | "un_security_council" | "un" => {
+                Ok(SanctionListSource::UnSecurityCouncil)
+            }
+            "ukhmtreasury" | "uk_hm_treasury" | "uk" => Ok(SanctionListSource::UkHmTreasury),
+            other => Err(ComplianceError::SanctionsListLoadFailed(format!(
+                "Unknown list source: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// A single entry on a file-loaded sanctions list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SanctionsListEntry {
+    /// Listed name as published
+    pub name: String,
+    /// Entity type
+    pub entity_type: EntityType,
+    /// List entry / program ID
+    pub list_id: String,
+    /// Source list (defaults to OFAC SDN)
+    #[serde(default = "default_list_source")]
+    pub source: SanctionListSource,
+    /// Crypto addresses listed for this entry
+    #[serde(default)]
+    pub addresses: Vec<String>,
+}
+
+/// Column positions in OFAC's headerless `sdn.csv`
+const SDN_CSV_NAME: usize = 1;
+const SDN_CSV_TYPE: usize = 2;
+const SDN_CSV_REMARKS: usize = 11;
+
+/// OFAC's prefix for crypto address IDs, followed by the currency code
+const DIGITAL_CURRENCY_ADDRESS: &str = "Digital Currency Address - ";
+
+fn default_list_source() -> SanctionListSource {
+    SanctionListSource::OfacSdn
+}
+
+/// Immutable, file-loaded sanctions list.
+///
+/// Held behind an `Arc` in `ComplianceService` and swapped wholesale on reload,
+/// so in-flight screenings always see a consistent list.
+#[derive(Debug, Clone, Default)]
+pub struct SanctionsList {
+    entries: Vec<(SanctionsListEntry, String)>,
+    /// Normalized address -> index into `entries`
+    addresses: HashMap<String, usize>,
+    loaded_at: Option<DateTime<Utc>>,
+}
+
+impl SanctionsList {
+    /// Build a list from entries (names and addresses are normalized for matching)
+    pub fn new(entries: Vec<SanctionsListEntry>) -> Self {
+        let mut addresses = HashMap::new();
+        for (idx, entry) in entries.iter().enumerate() {
+            for address in &entry.addresses {
+                addresses.entry(normalize_address(address)).or_insert(idx);
+            }
+        }
+
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|e| {
+                    let normalized = SanctionsService::normalize(&e.name);
+                    (e, normalized)
+                })
+                .collect(),
+            addresses,
+            loaded_at: Some(Utc::now()),
+        }
+    }
+
+    /// Load from a CSV file: OFAC's `sdn.csv`, or one with header
+    /// `name,entity_type,list_id,source,addresses`.
+    ///
+    /// Fields may be double-quoted (OFAC names often contain commas).
+    /// The `source` column is optional and defaults to OFAC SDN; the optional
+    /// `addresses` column holds `;`-separated crypto addresses.
+    pub fn from_csv(path: impl AsRef<Path>) -> ComplianceResult<Self> {
+        let contents = read_list_file(path.as_ref())?;
+
+        // sdn.csv has no header; every row starts with the numeric entry ID
+        let first_field = contents
+            .lines()
+            .find(|l| !l.trim().is_empty())
+            .map(|l| split_csv_line(l).swap_remove(0));
+        if first_field.is_some_and(|f| f.trim().parse::<u64>().is_ok()) {
+            return Self::parse_ofac_sdn_csv(&contents);
+        }
+
+        let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+
+        let header = lines.next().ok_or_else(|| {
+            ComplianceError::SanctionsListLoadFailed("CSV file is empty".to_string())
+        })?;
+        let columns: Vec<String> = split_csv_line(header)
+            .into_iter()
+            .map(|c| c.trim().to_lowercase())
+            .collect();
+        let column = |name: &str| columns.iter().position(|c| c == name);
+        let (name_col, type_col, id_col) = match (column("name"), column("entity_type"), column("list_id")) {
+            (Some(n), Some(t), Some(i)) => (n, t, i),
+            _ => {
+                return Err(ComplianceError::SanctionsListLoadFailed(
+                    "CSV header must include name, entity_type, list_id".to_string(),
+                ))
+            }
+        };
+        let source_col = column("source");
+        let addresses_col = column("addresses");
+
+        let mut entries = Vec::new();
+        for (line_no, line) in lines.enumerate() {
+            let fields = split_csv_line(line);
+            let field = |idx: usize| -> ComplianceResult<&str> {
+                fields.get(idx).map(|f| f.trim()).ok_or_else(|| {
+                    ComplianceError::SanctionsListLoadFailed(format!(
+                        "Row {} is missing column {}",
+                        line_no + 2,
+                        idx + 1
+                    ))
+                })
+            };
+
+            let name = field(name_col)?;
+            if name.is_empty() {
+                continue;
+            }
+            let source = match source_col.and_then(|c| fields.get(c)) {
+                Some(raw) if !raw.trim().is_empty() => raw.parse()?,
+                _ => default_list_source(),
+            };
+
+            let addresses = addresses_col
+                .and_then(|c| fields.get(c))
+                .map(|raw| {
+                    raw.split(';')
+                        .map(str::trim)
+                        .filter(|a| !a.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            entries.push(SanctionsListEntry {
+                name: name.to_string(),
+                entity_type: field(type_col)?.parse()?,
+                list_id: field(id_col)?.to_string(),
+                source,
+                addresses,
+            });
+        }
+
+        Ok(Self::new(entries))
+    }
+
+    /// Parse OFAC's `sdn.csv`: `ent_num,SDN_Name,SDN_Type,Program,...,Remarks`
+    /// with `-0-` for empty fields. Addresses are read from the remarks.
+    fn parse_ofac_sdn_csv(contents: &str) -> ComplianceResult<Self> {
+        let mut entries = Vec::new();
+        for line in contents.lines() {
+            let fields = split_csv_line(line);
+            // Skips blank lines and the EOF marker OFAC ends the file with
+            let Ok(ent_num) = fields[0].trim().parse::<u64>() else {
+                continue;
+            };
+            let field = |idx: usize| fields.get(idx).map(|f| sdn_field(f)).unwrap_or_default();
+
+            let name = field(SDN_CSV_NAME);
+            if name.is_empty() {
+                continue;
+            }
+            entries.push(SanctionsListEntry {
+                name: name.to_string(),
+                entity_type: sdn_entity_type(field(SDN_CSV_TYPE)),
+                list_id: ent_num.to_string(),
+                source: SanctionListSource::OfacSdn,
+                addresses: digital_currency_addresses(field(SDN_CSV_REMARKS)),
+            });
+        }
+
+        if entries.is_empty() {
+            return Err(ComplianceError::SanctionsListLoadFailed(
+                "SDN CSV has no entries".to_string(),
+            ));
+        }
+        Ok(Self::new(entries))
+    }
+
+    /// Load OFAC's `sdn.xml`
+    ///
+    /// Each `sdnEntry` becomes an entry keyed by its `uid`, with its
+    /// "Digital Currency Address" IDs as addresses; each `aka` becomes an
+    /// extra entry with the same `uid` so aliases are screened too.
+    pub fn from_ofac_sdn_xml(path: impl AsRef<Path>) -> ComplianceResult<Self> {
+        Self::parse_ofac_sdn_xml(&read_list_file(path.as_ref())?)
+    }
+
+    fn parse_ofac_sdn_xml(contents: &str) -> ComplianceResult<Self> {
+        let doc = roxmltree::Document::parse(contents)
+            .map_err(|e| ComplianceError::SanctionsListLoadFailed(format!("Invalid SDN XML: {}", e)))?;
+
+        // Element names match regardless of the namespace OFAC publishes under
+        fn child_text(node: roxmltree::Node, name: &str) -> String {
+            node.children()
+                .find(|c| c.has_tag_name(name))
+                .and_then(|c| c.text())
+                .map(|t| t.trim().to_string())
+                .unwrap_or_default()
+        }
+        fn full_name(node: roxmltree::Node) -> String {
+            let last = child_text(node, "lastName");
+            match child_text(node, "firstName") {
+                first if first.is_empty() => last,
+                first if last.is_empty() => first,
+                first => format!("{}, {}", last, first),
+            }
+        }
+
+        let mut entries = Vec::new();
+        for sdn in doc.descendants().filter(|n| n.has_tag_name("sdnEntry")) {
+            let list_id = child_text(sdn, "uid");
+            let entity_type = sdn_entity_type(&child_text(sdn, "sdnType"));
+            let addresses = sdn
+                .descendants()
+                .filter(|n| n.has_tag_name("id"))
+                .filter(|id| child_text(*id, "idType").starts_with(DIGITAL_CURRENCY_ADDRESS))
+                .map(|id| child_text(id, "idNumber"))
+                .filter(|a| !a.is_empty())
+                .collect();
+
+            let name = full_name(sdn);
+            if !name.is_empty() {
+                entries.push(SanctionsListEntry {
+                    name,
+                    entity_type: entity_type.clone(),
+                    list_id: list_id.clone(),
+                    source: SanctionListSource::OfacSdn,
+                    addresses,
+                });
+            }
+
+            for aka in sdn.descendants().filter(|n| n.has_tag_name("aka")) {
+                let name = full_name(aka);
+                if name.is_empty() {
+                    continue;
+                }
+                entries.push(SanctionsListEntry {
+                    name,
+                    entity_type: entity_type.clone(),
+                    list_id: list_id.clone(),
+                    source: SanctionListSource::OfacSdn,
+                    addresses: vec![],
+                });
+            }
+        }
+
+        if entries.is_empty() {
+            return Err(ComplianceError::SanctionsListLoadFailed(
+                "SDN XML has no sdnEntry elements".to_string(),
+            ));
+        }
+        Ok(Self::new(entries))
+    }
+
+    /// Load from a JSON array of `SanctionsListEntry` objects
+    pub fn from_json(path: impl AsRef<Path>) -> ComplianceResult<Self> {
+        let contents = read_list_file(path.as_ref())?;
+        let entries: Vec<SanctionsListEntry> = serde_json::from_str(&contents)
+            .map_err(|e| ComplianceError::SanctionsListLoadFailed(e.to_string()))?;
+        Ok(Self::new(entries))
+    }
+
+    /// Load by file extension: `.json` as JSON, `.xml` as OFAC SDN XML,
+    /// anything else as CSV
+    pub fn from_path(path: impl AsRef<Path>) -> ComplianceResult<Self> {
+        let path = path.as_ref();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json(path),
+            Some(ext) if ext.eq_ignore_ascii_case("xml") => Self::from_ofac_sdn_xml(path),
+            _ => Self::from_csv(path),
+        }
+    }
+
+    /// Number of entries on the list
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the list has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of distinct crypto addresses on the list
+    pub fn address_count(&self) -> usize {
+        self.addresses.len()
+    }
+
+    /// When this list was loaded (None for the empty default list)
+    pub fn loaded_at(&self) -> Option<DateTime<Utc>> {
+        self.loaded_at
+    }
+
+    /// Screen a name against this list using the same token-overlap
+    /// matching (>= 75) as the SDN cache.
+    pub fn screen_name(&self, name: &str) -> Vec<ScreeningMatch> {
+        let query = SanctionsService::normalize(name);
+        self.entries
+            .iter()
+            .filter_map(|(entry, normalized)| {
+                let score = SanctionsService::token_similarity(&query, normalized);
+                (score >= 75).then(|| ScreeningMatch {
+                    source: entry.source,
+                    matched_name: entry.name.clone(),
+                    score,
+                    entity_type: entry.entity_type.clone(),
+                    list_id: entry.list_id.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// The entry listing `address`, if any
+    pub fn screen_address(&self, address: &str) -> Option<&SanctionsListEntry> {
+        self.addresses
+            .get(&normalize_address(address))
+            .map(|&idx| &self.entries[idx].0)
+    }
+}
+
+/// Canonical form of a crypto address for lookups
+///
+/// Hex (`0x...`) and bech32 (`bc1...`, `ltc1...`, `tb1...`) addresses are
+/// case-insensitive and are lowercased; base58 and other formats are
+/// case-sensitive and only trimmed.
+fn normalize_address(address: &str) -> String {
+    let trimmed = address.trim();
+    let lower = trimmed.to_lowercase();
+    if ["0x", "bc1", "ltc1", "tb1"].iter().any(|p| lower.starts_with(p)) {
+        lower
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// An `sdn.csv` field with OFAC's `-0-` null marker mapped to empty
+fn sdn_field(raw: &str) -> &str {
+    match raw.trim() {
+        "-0-" => "",
+        value => value,
+    }
+}
+
+/// OFAC leaves the type empty for entities
+fn sdn_entity_type(raw: &str) -> EntityType {
+    raw.parse().unwrap_or(EntityType::Entity)
+}
+
+/// Addresses from remarks like
+/// `Digital Currency Address - ETH 0x...; alt. Digital Currency Address - XBT 1...;`
+fn digital_currency_addresses(remarks: &str) -> Vec<String> {
+    remarks
+        .match_indices(DIGITAL_CURRENCY_ADDRESS)
+        .filter_map(|(idx, marker)| {
+            let mut tokens = remarks[idx + marker.len()..].split_whitespace();
+            let _currency = tokens.next()?;
+            let address = tokens.next()?.trim_end_matches([';', '.', ',']);
+            (!address.is_empty()).then(|| address.to_string())
+        })
+        .collect()
+}
+
+fn read_list_file(path: &Path) -> ComplianceResult<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        ComplianceError::SanctionsListLoadFailed(format!("{}: {}", path.display(), e))
+    })
+}
+
+/// Split one CSV line, honouring double-quoted fields and `""` escapes
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+/// Spawn a background task that reloads the sanctions list when the file changes.
+///
+/// Polls the file's modification time every `poll_interval`. On change the
+/// file is re-parsed and, if valid, atomically swapped into `service`. A file
+/// that fails to parse is logged and the previous list stays active.
+pub fn spawn_sanctions_list_reloader(
+    service: Arc<ComplianceService>,
+    path: PathBuf,
+    poll_interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let modified = |p: &Path| -> Option<SystemTime> {
+            std::fs::metadata(p).and_then(|m| m.modified()).ok()
+        };
+        let mut last_modified = modified(&path);
+        let mut interval = tokio::time::interval(poll_interval);
+
+        loop {
+            interval.tick().await;
+            let current = modified(&path);
+            if current.is_none() || current == last_modified {
+                continue;
+            }
+            last_modified = current;
+
+            match SanctionsList::from_path(&path) {
+                Ok(list) => {
+                    let (entries, addresses) = (list.len(), list.address_count());
+                    service.replace_sanctions_list(Arc::new(list));
+                    tracing::info!(path = %path.display(), entries, addresses, "Sanctions list reloaded");
+                }
+                Err(e) => {
+                    tracing::error!(
+                        path = %path.display(),
+                        error = %e,
+                        "Sanctions list reload failed, keeping previous list"
+                    );
+                }
+            }
+        }
+    })
+}
+
 /// A single entry in the in-memory SDN name cache
 #[derive(Debug, Clone)]
 struct SdnEntry {
@@ -319,6 +807,208 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    fn write_temp_list(ext: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("sanctions-{}.{}", uuid::Uuid::new_v4(), ext));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_sanctions_list_from_csv() {
+        let path = write_temp_list(
+            "csv",
+            "name,entity_type,list_id,source\n\
+             \"DOE, John\",Individual,SDN-001,OFAC_SDN\n\
+             Acme Shipping Ltd,Entity,EU-42,EU\n",
+        );
+        let list = SanctionsList::from_csv(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(list.len(), 2);
+        let matches = list.screen_name("John Doe");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].list_id, "SDN-001");
+        assert_eq!(matches[0].entity_type, EntityType::Individual);
+
+        let matches = list.screen_name("ACME Shipping Ltd.");
+        assert_eq!(matches[0].source, SanctionListSource::EuConsolidated);
+        assert!(list.screen_name("Jane Roe").is_empty());
+    }
+
+    #[test]
+    fn test_sanctions_list_from_json() {
+        let path = write_temp_list(
+            "json",
+            r#"[{"name": "Evil Corp", "entity_type": "Entity", "list_id": "SDGT-1"}]"#,
+        );
+        let list = SanctionsList::from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(list.len(), 1);
+        let matches = list.screen_name("evil corp");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].source, SanctionListSource::OfacSdn);
+    }
+
+    #[test]
+    fn test_sanctions_list_bad_file() {
+        let path = write_temp_list("csv", "name,list_id\nJohn Doe,SDN-1\n");
+        assert!(SanctionsList::from_csv(&path).is_err());
+        std::fs::remove_file(&path).ok();
+
+        assert!(SanctionsList::from_csv("/nonexistent/sdn.csv").is_err());
+    }
+
+    #[test]
+    fn test_swapped_list_changes_screening() {
+        let service = ComplianceService::default_service();
+        assert!(service.screen_name("John Doe").is_empty());
+
+        service.replace_sanctions_list(Arc::new(SanctionsList::new(vec![SanctionsListEntry {
+            name: "John Doe".to_string(),
+            entity_type: EntityType::Individual,
+            list_id: "SDN-001".to_string(),
+            source: SanctionListSource::OfacSdn,
+            addresses: vec![],
+        }])));
+        assert!(!service.screen_name("John Doe").is_empty());
+
+        service.replace_sanctions_list(Arc::new(SanctionsList::default()));
+        assert!(service.screen_name("John Doe").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reloader_picks_up_file_changes() {
+        let path = write_temp_list("csv", "name,entity_type,list_id\nJohn Doe,Individual,SDN-001\n");
+        let service = Arc::new(ComplianceService::default_service());
+        service.replace_sanctions_list(Arc::new(SanctionsList::from_csv(&path).unwrap()));
+        assert!(!service.screen_name("John Doe").is_empty());
+
+        let handle = spawn_sanctions_list_reloader(
+            service.clone(),
+            path.clone(),
+            Duration::from_millis(10),
+        );
+
+        // Ensure the new mtime differs on coarse-grained filesystems
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        std::fs::write(&path, "name,entity_type,list_id\nRichard Roe,Individual,SDN-002\n").unwrap();
+
+        let mut reloaded = false;
+        for _ in 0..200 {
+            if !service.screen_name("Richard Roe").is_empty() {
+                reloaded = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        handle.abort();
+        std::fs::remove_file(&path).ok();
+
+        assert!(reloaded, "reloader did not pick up the new list");
+        assert!(service.screen_name("John Doe").is_empty());
+    }
+
+    const SDN_CSV: &str = "36,\"AEROCARIBBEAN AIRLINES\",-0- ,\"CUBA\",-0- ,-0- ,-0- ,-0- ,-0- ,-0- ,-0- ,-0- \n\
+        2674,\"ABBAS, Abu\",\"individual\",\"SDGT\",-0- ,-0- ,-0- ,-0- ,-0- ,-0- ,-0- ,-0- \n\
+        25511,\"SUEX OTC, S.R.O.\",-0- ,\"CYBER2\",-0- ,-0- ,-0- ,-0- ,-0- ,-0- ,-0- ,\"Digital Currency Address - XBT 12HQDsicffSBaYdJ6BhnE22sfjTESmmzKx; alt. Digital Currency Address - ETH 0x2f389cE8bD8ff92De3402FFCe4691d17fC4f6535; Organization Type: Activities auxiliary to financial service.\"\n\
+        \u{1a}\n";
+
+    const SDN_XML: &str = r#"<?xml version="1.0" standalone="yes"?>
+<sdnList xmlns="https://sanctionslistservice.ofac.treas.gov/api/PublicationPreview/exports/XML">
+  <publshInformation><Publish_Date>01/02/2025</Publish_Date></publshInformation>
+  <sdnEntry>
+    <uid>2674</uid>
+    <firstName>Abu</firstName>
+    <lastName>ABBAS</lastName>
+    <sdnType>Individual</sdnType>
+    <akaList>
+      <aka><uid>201</uid><type>a.k.a.</type><lastName>ZAYDAN, Muhammad</lastName></aka>
+    </akaList>
+  </sdnEntry>
+  <sdnEntry>
+    <uid>25511</uid>
+    <lastName>SUEX OTC, S.R.O.</lastName>
+    <sdnType>Entity</sdnType>
+    <idList>
+      <id><uid>1</uid><idType>Digital Currency Address - XBT</idType><idNumber>12HQDsicffSBaYdJ6BhnE22sfjTESmmzKx</idNumber></id>
+      <id><uid>2</uid><idType>Digital Currency Address - ETH</idType><idNumber>0x2f389cE8bD8ff92De3402FFCe4691d17fC4f6535</idNumber></id>
+      <id><uid>3</uid><idType>Registration Number</idType><idNumber>07486049</idNumber></id>
+    </idList>
+  </sdnEntry>
+</sdnList>"#;
+
+    #[test]
+    fn test_ofac_sdn_csv() {
+        let path = write_temp_list("csv", SDN_CSV);
+        let list = SanctionsList::from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.address_count(), 2);
+
+        let matches = list.screen_name("Abu Abbas");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].list_id, "2674");
+        assert_eq!(matches[0].entity_type, EntityType::Individual);
+        assert_eq!(list.screen_name("Aerocaribbean Airlines")[0].entity_type, EntityType::Entity);
+
+        let entry = list.screen_address("12HQDsicffSBaYdJ6BhnE22sfjTESmmzKx").unwrap();
+        assert_eq!(entry.list_id, "25511");
+        // Hex addresses match regardless of checksum casing
+        assert!(list.screen_address(" 0x2f389ce8bd8ff92de3402ffce4691d17fc4f6535 ").is_some());
+        // Base58 is case-sensitive
+        assert!(list.screen_address("12hqdsicffsbaydj6bhne22sfjtesmmzkx").is_none());
+    }
+
+    #[test]
+    fn test_ofac_sdn_xml() {
+        let path = write_temp_list("xml", SDN_XML);
+        let list = SanctionsList::from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        // Two entries plus one alias
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.screen_name("Abu Abbas")[0].list_id, "2674");
+        assert_eq!(list.screen_name("Muhammad Zaydan")[0].list_id, "2674");
+        assert_eq!(list.address_count(), 2);
+        assert_eq!(
+            list.screen_address("0x2F389CE8BD8FF92DE3402FFCE4691D17FC4F6535").unwrap().name,
+            "SUEX OTC, S.R.O."
+        );
+        assert!(list.screen_address("07486049").is_none());
+
+        assert!(SanctionsList::parse_ofac_sdn_xml("<sdnList>").is_err());
+        assert!(SanctionsList::parse_ofac_sdn_xml("<sdnList/>").is_err());
+    }
+
+    #[test]
+    fn test_csv_addresses_column() {
+        let path = write_temp_list(
+            "csv",
+            "name,entity_type,list_id,addresses\n\
+             Evil Corp,Entity,SDN-9,0xAbC0000000000000000000000000000000000001; bc1QEXAMPLE\n",
+        );
+        let list = SanctionsList::from_csv(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(list.screen_address("0xabc0000000000000000000000000000000000001").is_some());
+        assert!(list.screen_address("bc1qexample").is_some());
+        assert!(list.screen_address("0xabc0000000000000000000000000000000000002").is_none());
+    }
+
+    #[test]
+    fn test_digital_currency_addresses() {
+        assert_eq!(
+            digital_currency_addresses(
+                "Digital Currency Address - ETH 0xabc; alt. Digital Currency Address - XBT 1xyz."
+            ),
+            vec!["0xabc".to_string(), "1xyz".to_string()]
+        );
+        assert!(digital_currency_addresses("a.k.a. 'BNC'.").is_empty());
+        assert!(digital_currency_addresses("Digital Currency Address - ETH").is_empty());
+    }
+
     #[test]
     fn test_needs_update() {
         let service = SanctionsService::new(None);
@@ -0,0 +1,270 @@
+//! Generic HTTP custody adapter.
+//!
+//! Reads bond holdings and total reserve value from a custodian (or an
+//! internal custody service) exposing a small JSON API:
+//!
+//! ```text
+//! GET {CUSTODY_API_URL}/holdings[?currency=EUR]  — array of holdings
+//! GET {CUSTODY_API_URL}/total-value              — {"total_value_usd": "..."}
+//! GET {CUSTODY_API_URL}/health                   — any 2xx when healthy
+//! ```
+//!
+//! A holding is an object with `isin`, `currency`, `face_value`,
+//! `market_value`, `yield_to_maturity` and `maturity_date` (RFC 3339), plus
+//! optional `id`, `name`, `rating`, `custodian_account_id` and `valued_at`.
+//! Amounts may be JSON strings or numbers.
+//!
+//! ## Environment Variables
+//!
+//! ```text
+//! CUSTODY_API_URL  — API base URL (selects this adapter by default)
+//! CUSTODY_API_KEY  — Bearer token (optional)
+//! ```
+
+use super::{BondHolding, CustodyAdapter, CustodyError, CustodyProof, CustodyResult, ReserveBalance};
+use chrono::{DateTime, Duration, Utc};
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Holding as returned by the custody API
+#[derive(Debug, Deserialize)]
+struct HttpHolding {
+    #[serde(default)]
+    id: Option<Uuid>,
+    isin: String,
+    #[serde(default)]
+    name: String,
+    currency: String,
+    face_value: Decimal,
+    market_value: Decimal,
+    yield_to_maturity: Decimal,
+    maturity_date: DateTime<Utc>,
+    #[serde(default)]
+    custodian_account_id: String,
+    #[serde(default)]
+    rating: Option<String>,
+    #[serde(default)]
+    valued_at: Option<DateTime<Utc>>,
+}
+
+impl HttpHolding {
+    fn into_holding(self, now: DateTime<Utc>) -> CustodyResult<BondHolding> {
+        if self.isin.trim().is_empty() {
+            return Err(CustodyError::InvalidResponse("Holding without ISIN".to_string()));
+        }
+        if self.face_value < Decimal::ZERO || self.market_value < Decimal::ZERO {
+            return Err(CustodyError::InvalidResponse(format!(
+                "Negative value for holding {}",
+                self.isin
+            )));
+        }
+
+        Ok(BondHolding {
+            id: self.id.unwrap_or_else(Uuid::new_v4),
+            name: if self.name.is_empty() { self.isin.clone() } else { self.name },
+            isin: self.isin,
+            currency: self.currency.to_uppercase(),
+            face_value: self.face_value,
+            market_value: self.market_value,
+            yield_to_maturity: self.yield_to_maturity,
+            maturity_date: self.maturity_date,
+            custodian_account_id: self.custodian_account_id,
+            rating: self.rating.filter(|r| !r.trim().is_empty()),
+            valued_at: self.valued_at.unwrap_or(now),
+        })
+    }
+}
+
+/// Total value response
+#[derive(Debug, Deserialize)]
+struct TotalValue {
+    total_value_usd: Decimal,
+}
+
+/// Custody API client
+pub struct HttpCustodyAdapter {
+    base_url: String,
+    api_key: Option<String>,
+    http: reqwest::Client,
+}
+
+impl HttpCustodyAdapter {
+    pub fn new(base_url: String, api_key: Option<String>) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .expect("Failed to build HTTP client");
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+            http,
+        }
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> CustodyResult<T> {
+        let mut request = self.http.get(format!("{}{}", self.base_url, path)).query(query);
+        if let Some(ref api_key) = self.api_key {
+            let header = HeaderValue::from_str(&format!("Bearer {}", api_key))
+                .map_err(|e| CustodyError::Auth(e.to_string()))?;
+            request = request.header(AUTHORIZATION, header);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| CustodyError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(CustodyError::Api {
+                status: response.status().as_u16(),
+                message: response.text().await.unwrap_or_default(),
+            });
+        }
+
+        response.json::<T>().await
+            .map_err(|e| CustodyError::InvalidResponse(e.to_string()))
+    }
+
+    async fn fetch_holdings(&self, query: &[(&str, &str)]) -> CustodyResult<Vec<BondHolding>> {
+        let now = Utc::now();
+        self.get_json::<Vec<HttpHolding>>("/holdings", query)
+            .await?
+            .into_iter()
+            .map(|h| h.into_holding(now))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl CustodyAdapter for HttpCustodyAdapter {
+    fn provider_name(&self) -> &str {
+        "HttpCustody"
+    }
+
+    async fn get_reserve_balance(&self, currency: &str) -> CustodyResult<ReserveBalance> {
+        let total: Decimal = self.list_holdings(currency).await?.iter().map(|h| h.market_value).sum();
+
+        Ok(ReserveBalance {
+            currency: currency.to_uppercase(),
+            total_balance: total,
+            available_balance: total,
+            locked_balance: Decimal::ZERO,
+            snapshot_at: Utc::now(),
+            custodian: self.provider_name().to_string(),
+        })
+    }
+
+    async fn get_bond_holdings(&self) -> CustodyResult<Vec<BondHolding>> {
+        self.fetch_holdings(&[]).await
+    }
+
+    async fn list_holdings(&self, currency: &str) -> CustodyResult<Vec<BondHolding>> {
+        let currency = currency.to_uppercase();
+        let holdings = self.fetch_holdings(&[("currency", &currency)]).await?;
+        // Don't rely on the server honouring the filter
+        Ok(holdings.into_iter().filter(|h| h.currency == currency).collect())
+    }
+
+    async fn get_total_value_usd(&self) -> CustodyResult<Decimal> {
+        Ok(self.get_json::<TotalValue>("/total-value", &[]).await?.total_value_usd)
+    }
+
+    async fn get_custody_proof(&self) -> CustodyResult<CustodyProof> {
+        let now = Utc::now();
+        let total_usd = self.get_total_value_usd().await?;
+        let covered = self.get_bond_holdings().await?.into_iter().map(|h| h.isin).collect();
+
+        Ok(CustodyProof {
+            id: Uuid::new_v4(),
+            custodian: self.provider_name().to_string(),
+            total_value_usd: total_usd,
+            covered_assets: covered,
+            signature: None,
+            statement_hash: None,
+            issued_at: now,
+            expires_at: now + Duration::hours(24),
+        })
+    }
+
+    async fn health_check(&self) -> CustodyResult<()> {
+        self.get_json::<serde_json::Value>("/health", &[]).await.map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serves one canned response per connection and returns the base URL
+    async fn serve(status: u16, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{}/", addr)
+    }
+
+    const HOLDINGS: &str = r#"[
+        {"isin": "DE0001102580", "name": "Bund 2.30% 2033", "currency": "eur",
+         "face_value": "5000000", "market_value": "4875000.50", "yield_to_maturity": 0.025,
+         "maturity_date": "2033-02-15T00:00:00Z", "rating": "AAA"},
+        {"isin": "US91282CJL54", "currency": "USD",
+         "face_value": "1000000", "market_value": "990000", "yield_to_maturity": "0.043",
+         "maturity_date": "2030-11-15T00:00:00Z"}
+    ]"#;
+
+    #[tokio::test]
+    async fn test_list_holdings_parses_and_filters_by_currency() {
+        let adapter = HttpCustodyAdapter::new(serve(200, HOLDINGS).await, Some("key".to_string()));
+
+        let eur = adapter.list_holdings("eur").await.unwrap();
+        assert_eq!(eur.len(), 1);
+        assert_eq!(eur[0].isin, "DE0001102580");
+        assert_eq!(eur[0].currency, "EUR");
+        assert_eq!(eur[0].market_value, Decimal::new(487500050, 2));
+        assert_eq!(eur[0].yield_to_maturity, Decimal::new(25, 3));
+        assert_eq!(eur[0].rating.as_deref(), Some("AAA"));
+
+        let all = adapter.get_bond_holdings().await.unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].name, "US91282CJL54");
+        assert_eq!(all[1].rating, None);
+
+        let balance = adapter.get_reserve_balance("EUR").await.unwrap();
+        assert_eq!(balance.total_balance, Decimal::new(487500050, 2));
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_reported() {
+        let adapter = HttpCustodyAdapter::new(serve(503, "down").await, None);
+        let err = adapter.list_holdings("EUR").await.unwrap_err();
+        assert!(matches!(err, CustodyError::Api { status: 503, .. }));
+
+        let adapter = HttpCustodyAdapter::new(serve(200, r#"[{"isin": ""}]"#).await, None);
+        let err = adapter.get_bond_holdings().await.unwrap_err();
+        assert!(matches!(err, CustodyError::InvalidResponse(_)));
+    }
+
+    #[tokio::test]
+    async fn test_total_value() {
+        let adapter = HttpCustodyAdapter::new(serve(200, r#"{"total_value_usd": "10265400.25"}"#).await, None);
+        assert_eq!(adapter.get_total_value_usd().await.unwrap(), Decimal::new(1026540025, 2));
+    }
+}
@@ -7,6 +7,7 @@
 //! `CustodyAdapter` is a trait that each custodian implementation satisfies:
 //! - `FireblocksAdapter` — production custody via Fireblocks API
 //! - `BitGoAdapter`       — alternative via BitGo API
+//! - `HttpCustodyAdapter` — generic custody API at `CUSTODY_API_URL`
 //! - `MockAdapter`        — deterministic mock for testing
 //!
 //! The automated Proof of Reserves background service in the API calls
@@ -21,6 +22,7 @@
 
 pub mod bitgo;
 pub mod fireblocks;
+pub mod http;
 pub mod mock;
 
 use chrono::{DateTime, Utc};
@@ -91,6 +93,9 @@ pub struct BondHolding {
     pub maturity_date: DateTime<Utc>,
     /// Custodian account/vault holding this bond
     pub custodian_account_id: String,
+    /// Credit rating (e.g., "AAA"), if the custodian reports one
+    #[serde(default)]
+    pub rating: Option<String>,
     /// Timestamp of the valuation
     pub valued_at: DateTime<Utc>,
 }
@@ -128,6 +133,15 @@ pub trait CustodyAdapter: Send + Sync {
     /// Get all sovereign bond holdings across all vaults
     async fn get_bond_holdings(&self) -> CustodyResult<Vec<BondHolding>>;
 
+    /// Get the bond holdings denominated in `currency` (case-insensitive)
+    async fn list_holdings(&self, currency: &str) -> CustodyResult<Vec<BondHolding>> {
+        let holdings = self.get_bond_holdings().await?;
+        Ok(holdings
+            .into_iter()
+            .filter(|h| h.currency.eq_ignore_ascii_case(currency))
+            .collect())
+    }
+
     /// Get the total value of all holdings in USD
     async fn get_total_value_usd(&self) -> CustodyResult<Decimal>;
 
@@ -140,12 +154,16 @@ pub trait CustodyAdapter: Send + Sync {
 
 /// Factory function: build the configured custody adapter from environment variables.
 ///
-/// Reads `CUSTODY_PROVIDER` (defaults to "mock"):
+/// Reads `CUSTODY_PROVIDER` (defaults to "http" when `CUSTODY_API_URL` is
+/// set, otherwise "mock"):
 /// - `"fireblocks"` — Fireblocks production adapter
+/// - `"bitgo"`      — BitGo adapter
+/// - `"http"`       — generic custody API at `CUSTODY_API_URL`
 /// - `"mock"`       — deterministic mock (dev/test)
 pub fn build_adapter_from_env() -> Box<dyn CustodyAdapter> {
+    let api_url = std::env::var("CUSTODY_API_URL").ok().filter(|url| !url.trim().is_empty());
     let provider = std::env::var("CUSTODY_PROVIDER")
-        .unwrap_or_else(|_| "mock".to_string())
+        .unwrap_or_else(|_| if api_url.is_some() { "http" } else { "mock" }.to_string())
         .to_lowercase();
 
     match provider.as_str() {
@@ -171,6 +189,13 @@ pub fn build_adapter_from_env() -> Box<dyn CustodyAdapter> {
             tracing::info!("Initializing BitGo custody adapter");
             Box::new(bitgo::BitGoAdapter::new(api_key, wallet_id, base_url))
         }
+        "http" => {
+            let base_url = api_url.expect("CUSTODY_API_URL required when CUSTODY_PROVIDER=http");
+            let api_key = std::env::var("CUSTODY_API_KEY").ok().filter(|key| !key.is_empty());
+
+            tracing::info!(url = %base_url, "Initializing HTTP custody adapter");
+            Box::new(http::HttpCustodyAdapter::new(base_url, api_key))
+        }
         _ => {
             tracing::info!("Using mock custody adapter (CUSTODY_PROVIDER={})", provider);
             Box::new(mock::MockAdapter::new())
@@ -41,6 +41,7 @@ impl MockAdapter {
                 yield_to_maturity: Decimal::from_str("0.0250").unwrap_or_default(),
                 maturity_date: now + Duration::days(365 * 9),
                 custodian_account_id: "mock-vault-001".to_string(),
+                rating: Some("AAA".to_string()),
                 valued_at: now,
             },
             BondHolding {
@@ -53,6 +54,7 @@ impl MockAdapter {
                 yield_to_maturity: Decimal::from_str("0.0310").unwrap_or_default(),
                 maturity_date: now + Duration::days(365 * 4),
                 custodian_account_id: "mock-vault-001".to_string(),
+                rating: Some("AA".to_string()),
                 valued_at: now,
             },
             BondHolding {
@@ -65,6 +67,7 @@ impl MockAdapter {
                 yield_to_maturity: Decimal::from_str("0.0420").unwrap_or_default(),
                 maturity_date: now + Duration::days(365 * 8),
                 custodian_account_id: "mock-vault-002".to_string(),
+                rating: Some("BBB".to_string()),
                 valued_at: now,
             },
         ]
@@ -0,0 +1,29 @@
+-- Basket version history for stablecoins
+-- Records which basket backed a stablecoin over each effective date range so
+-- valuations and attestations can be reconstructed after a basket migration.
+
+CREATE TABLE IF NOT EXISTS stablecoin_basket_versions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    stablecoin_id UUID NOT NULL REFERENCES stablecoins(id) ON DELETE CASCADE,
+    basket_id UUID NOT NULL REFERENCES baskets(id) ON DELETE RESTRICT,
+    -- Half-open range [effective_from, effective_to); NULL effective_to = current
+    effective_from TIMESTAMP WITH TIME ZONE NOT NULL,
+    effective_to TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT basket_version_range_valid
+        CHECK (effective_to IS NULL OR effective_to > effective_from)
+);
+
+CREATE INDEX IF NOT EXISTS idx_stablecoin_basket_versions_lookup
+    ON stablecoin_basket_versions(stablecoin_id, effective_from DESC);
+
+-- At most one open-ended (current) version per stablecoin
+CREATE UNIQUE INDEX IF NOT EXISTS idx_stablecoin_basket_versions_current
+    ON stablecoin_basket_versions(stablecoin_id)
+    WHERE effective_to IS NULL;
+
+-- Backfill: existing stablecoins are backed by their current basket since creation
+INSERT INTO stablecoin_basket_versions (stablecoin_id, basket_id, effective_from)
+SELECT id, basket_id, created_at
+FROM stablecoins
+WHERE basket_id IS NOT NULL;
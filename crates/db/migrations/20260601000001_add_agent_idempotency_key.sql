@@ -0,0 +1,14 @@
+-- Idempotent agent creation
+-- A retried create_agent call carrying the same client key returns the
+-- original wallet instead of creating a duplicate.
+
+ALTER TABLE agent_wallets
+ADD COLUMN IF NOT EXISTS idempotency_key VARCHAR(128);
+
+-- Keys are scoped per user; partial index only covers non-null keys
+CREATE UNIQUE INDEX IF NOT EXISTS idx_agent_wallets_idempotency
+ON agent_wallets(user_id, idempotency_key)
+WHERE idempotency_key IS NOT NULL;
+
+COMMENT ON COLUMN agent_wallets.idempotency_key IS
+'Client-provided key making agent creation safe to retry. The API key is never re-shown on replay.';
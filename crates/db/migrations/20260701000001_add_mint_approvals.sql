@@ -0,0 +1,14 @@
+-- Four-eyes approval for large mints
+-- Mints above MINT_APPROVAL_THRESHOLD_USD start in AWAITING_APPROVAL and only
+-- move to PENDING once a second authorized user (not the initiator) approves.
+
+ALTER TABLE operations DROP CONSTRAINT IF EXISTS operations_status_check;
+ALTER TABLE operations ADD CONSTRAINT operations_status_check
+    CHECK (status IN ('AWAITING_APPROVAL', 'PENDING', 'BOND_PURCHASE', 'SETTLEMENT', 'COMPLETED', 'FAILED', 'CANCELLED'));
+
+ALTER TABLE operations
+ADD COLUMN IF NOT EXISTS approved_by INTEGER REFERENCES users(id),
+ADD COLUMN IF NOT EXISTS approved_at TIMESTAMP WITH TIME ZONE;
+
+COMMENT ON COLUMN operations.approved_by IS
+'Second approver for mints over the approval threshold. Never the initiating user.';
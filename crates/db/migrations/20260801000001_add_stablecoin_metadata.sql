@@ -0,0 +1,12 @@
+-- First-class stablecoin metadata
+-- Token decimals and the fiat currency the stablecoin is pegged to.
+-- peg_currency stays nullable for rows created before it was tracked.
+
+ALTER TABLE stablecoins
+    ADD COLUMN IF NOT EXISTS decimals SMALLINT NOT NULL DEFAULT 18
+        CHECK (decimals BETWEEN 0 AND 36),
+    ADD COLUMN IF NOT EXISTS peg_currency VARCHAR(3);
+
+-- Symbol lookups are case-insensitive
+CREATE INDEX IF NOT EXISTS idx_stablecoins_symbol_upper
+    ON stablecoins(UPPER(symbol));
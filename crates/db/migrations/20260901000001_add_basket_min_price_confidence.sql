@@ -0,0 +1,6 @@
+-- Per-basket oracle confidence requirement
+-- NULL means any non-stale price is accepted for valuation.
+
+ALTER TABLE baskets
+    ADD COLUMN IF NOT EXISTS min_price_confidence NUMERIC(5, 4)
+        CHECK (min_price_confidence IS NULL OR (min_price_confidence >= 0 AND min_price_confidence <= 1));
@@ -0,0 +1,9 @@
+-- Mint amounts are rounded to the currency's minor unit before storage and
+-- fee computation. Keep what the client actually sent alongside the rounded
+-- value so the two can be reconciled.
+
+ALTER TABLE operations
+ADD COLUMN IF NOT EXISTS original_amount TEXT;
+
+COMMENT ON COLUMN operations.original_amount IS
+'Amount exactly as submitted. operations.amount holds the value rounded to the currency''s decimals.';
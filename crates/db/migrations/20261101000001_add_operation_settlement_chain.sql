@@ -0,0 +1,3 @@
+-- Target network for settling an operation (Chain slug, e.g. 'ethereum-sepolia').
+-- NULL for operations created before settlement chains were recorded, and for burns.
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS settlement_chain VARCHAR(32);
@@ -0,0 +1,10 @@
+-- Basket ownership and soft delete, used to cap live baskets per organization.
+-- organization/created_by are NULL for baskets created before ownership was recorded;
+-- those never count towards any organization's cap.
+ALTER TABLE baskets
+    ADD COLUMN IF NOT EXISTS organization VARCHAR(255),
+    ADD COLUMN IF NOT EXISTS created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX IF NOT EXISTS idx_baskets_organization_live
+    ON baskets(organization) WHERE deleted_at IS NULL;
@@ -0,0 +1,8 @@
+-- Allow every ComplianceStatus to be stored in users.kyc_status
+-- (adds SUSPENDED and REVIEW_REQUIRED; see ComplianceStatus::to_db_str).
+ALTER TABLE users DROP CONSTRAINT IF EXISTS users_kyc_status_check;
+ALTER TABLE users ADD CONSTRAINT users_kyc_status_check
+    CHECK (kyc_status IN (
+        'NOT_STARTED', 'IN_PROGRESS', 'PENDING_REVIEW', 'APPROVED', 'REJECTED',
+        'SUSPENDED', 'REVIEW_REQUIRED'
+    ));
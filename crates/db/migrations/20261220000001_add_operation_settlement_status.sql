@@ -0,0 +1,19 @@
+-- Settlement progress, tracked separately from processing status: a COMPLETED
+-- mint is still UNSETTLED until its settlement_date (T+1) passes.
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS settlement_status VARCHAR(16) NOT NULL DEFAULT 'UNSETTLED';
+
+ALTER TABLE operations DROP CONSTRAINT IF EXISTS operations_settlement_status_check;
+ALTER TABLE operations ADD CONSTRAINT operations_settlement_status_check
+    CHECK (settlement_status IN ('UNSETTLED', 'SETTLED', 'FAILED'));
+
+-- Backfill existing rows
+UPDATE operations SET settlement_status = 'FAILED'
+WHERE settlement_status = 'UNSETTLED' AND status IN ('FAILED', 'CANCELLED');
+
+UPDATE operations SET settlement_status = 'SETTLED'
+WHERE settlement_status = 'UNSETTLED' AND status = 'COMPLETED' AND settlement_date <= NOW();
+
+-- Settlement job: unsettled operations by due date
+CREATE INDEX IF NOT EXISTS idx_operations_unsettled
+ON operations(settlement_date)
+WHERE settlement_status = 'UNSETTLED';
@@ -0,0 +1,12 @@
+-- Per-agent daily-spend reset window. ROLLING_24H keeps the original
+-- behaviour (spend in the last 24 hours); CALENDAR_DAY resets at midnight in
+-- spend_window_tz (IANA name, 'UTC' for CalendarDayUtc).
+ALTER TABLE agent_wallets ADD COLUMN IF NOT EXISTS spend_window VARCHAR(16) NOT NULL DEFAULT 'ROLLING_24H';
+ALTER TABLE agent_wallets ADD COLUMN IF NOT EXISTS spend_window_tz VARCHAR(64);
+
+ALTER TABLE agent_wallets DROP CONSTRAINT IF EXISTS agent_wallets_spend_window_check;
+ALTER TABLE agent_wallets ADD CONSTRAINT agent_wallets_spend_window_check
+    CHECK (
+        (spend_window = 'ROLLING_24H' AND spend_window_tz IS NULL)
+        OR (spend_window = 'CALENDAR_DAY' AND spend_window_tz IS NOT NULL)
+    );
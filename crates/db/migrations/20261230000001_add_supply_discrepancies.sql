@@ -0,0 +1,16 @@
+-- Supply reconciliation: each time a stablecoin's DB-tracked total_supply
+-- differs from its on-chain totalSupply() by more than the configured
+-- tolerance, the two values are recorded here for investigation.
+CREATE TABLE IF NOT EXISTS supply_discrepancies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    stablecoin_id UUID NOT NULL REFERENCES stablecoins(id) ON DELETE CASCADE,
+    chain_id INTEGER NOT NULL,
+    db_supply NUMERIC(38, 18) NOT NULL,
+    on_chain_supply NUMERIC(38, 18) NOT NULL,
+    -- on_chain_supply - db_supply
+    difference NUMERIC(38, 18) NOT NULL,
+    detected_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_supply_discrepancies_stablecoin
+    ON supply_discrepancies(stablecoin_id, detected_at DESC);
@@ -0,0 +1,17 @@
+-- Move the remaining TEXT decimal columns to NUMERIC so amounts can be
+-- aggregated in SQL and bound directly as rust_decimal::Decimal.
+-- Follows 20260401000001_numeric_decimal_columns.sql, which did the same for
+-- price_history and stablecoins (total_supply, total_reserve_value).
+--
+-- Unconstrained NUMERIC keeps each value's stored scale, so "25.00" still
+-- reads back as 25.00 rather than being padded to a fixed scale.
+
+ALTER TABLE operations
+    ALTER COLUMN amount TYPE NUMERIC USING amount::NUMERIC,
+    ALTER COLUMN usd_value TYPE NUMERIC USING usd_value::NUMERIC,
+    ALTER COLUMN bond_requirement TYPE NUMERIC USING bond_requirement::NUMERIC,
+    ALTER COLUMN fees_charged TYPE NUMERIC USING fees_charged::NUMERIC,
+    ALTER COLUMN original_amount TYPE NUMERIC USING original_amount::NUMERIC;
+
+ALTER TABLE agent_transactions
+    ALTER COLUMN amount TYPE NUMERIC USING amount::NUMERIC;
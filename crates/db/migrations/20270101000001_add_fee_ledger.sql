@@ -0,0 +1,16 @@
+-- Fee accounting: every fee charged on a mint or burn is credited to the
+-- configured fee account in the same transaction that records the
+-- operation. Amounts are in USD, like operations.fees_charged.
+CREATE TABLE IF NOT EXISTS fee_ledger (
+    id BIGSERIAL PRIMARY KEY,
+    operation_id INTEGER NOT NULL UNIQUE REFERENCES operations(id) ON DELETE CASCADE,
+    fee_account VARCHAR(64) NOT NULL,
+    operation_type VARCHAR(10) NOT NULL CHECK (operation_type IN ('MINT', 'BURN')),
+    -- Currency of the operation the fee was charged on
+    currency VARCHAR(3) NOT NULL,
+    amount NUMERIC NOT NULL CHECK (amount >= 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_fee_ledger_account_currency
+    ON fee_ledger(fee_account, currency);
@@ -0,0 +1,15 @@
+-- Reserve history: each proof-of-reserves attestation snapshots every
+-- active stablecoin's reserve value and reserve ratio, so reserve charts
+-- show recorded data instead of a placeholder.
+CREATE TABLE IF NOT EXISTS reserve_snapshots (
+    id BIGSERIAL PRIMARY KEY,
+    -- Upper-cased stablecoin symbol
+    symbol VARCHAR(20) NOT NULL,
+    total_value NUMERIC NOT NULL CHECK (total_value >= 0),
+    -- Reserves as a percentage of supply (100 = fully backed)
+    reserve_ratio NUMERIC NOT NULL CHECK (reserve_ratio >= 0),
+    recorded_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_reserve_snapshots_symbol_recorded_at
+    ON reserve_snapshots(symbol, recorded_at);
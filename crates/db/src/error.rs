@@ -25,6 +25,13 @@ pub enum DbError {
 
     #[error("Transaction error: {0}")]
     TransactionError(String),
+
+    /// Serialization failure or deadlock; the transaction can be retried
+    #[error("Transaction conflict: {0}")]
+    TransactionConflict(String),
+
+    #[error("Invalid basket: {0}")]
+    InvalidBasket(#[from] meridian_basket::BasketError),
 }
 
 // Convert SQLx errors
@@ -32,6 +39,9 @@ impl From<sqlx::Error> for DbError {
     fn from(err: sqlx::Error) -> Self {
         match err {
             sqlx::Error::RowNotFound => DbError::NotFound("Record not found".to_string()),
+            ref transient if crate::retry::is_transient(transient) => {
+                DbError::TransactionConflict(transient.to_string())
+            }
             sqlx::Error::Database(db_err) => {
                 if let Some(constraint) = db_err.constraint() {
                     DbError::DuplicateEntry(format!("Constraint violation: {}", constraint))
@@ -6,17 +6,25 @@
 //!
 //! - Repository pattern for data access
 //! - Connection pooling with PgPool
-//! - Transaction support
+//! - Transaction support, with retry of serialization failures (`with_retry`)
 //! - Type-safe queries with SQLx (rust_decimal feature: NUMERIC ↔ Decimal)
 //! - Migration support
+//! - Pool saturation metrics and slow query logging (`timed_query!`)
+//! - Ephemeral Postgres fixture for integration tests (`test-harness` feature)
 
 mod error;
+mod metrics;
 mod models;
 mod repositories;
+mod retry;
+#[cfg(feature = "test-harness")]
+pub mod testing;
 
 pub use error::DbError;
+pub use metrics::{pool_metrics, record_query_duration, slow_query_threshold, PoolMetrics, TimedQuery};
 pub use models::*;
 pub use repositories::*;
+pub use retry::{is_transient, with_retry, RetryableError, TxFuture};
 
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::PgPool;
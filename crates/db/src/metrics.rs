@@ -0,0 +1,133 @@
+//! Connection pool and query timing metrics
+//!
+//! `pool_metrics` snapshots the pool's saturation for dashboards, and
+//! `timed_query!` (or `.timed()`) logs statements slower than
+//! `SLOW_QUERY_MS` (default 200ms) at `warn`, without needing a full APM
+//! setup. Every repository query is timed; the API's per-request query
+//! tracking uses the same threshold.
+
+use crate::Pool;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// Default slow query threshold in milliseconds
+const DEFAULT_SLOW_QUERY_THRESHOLD_MS: u64 = 200;
+
+/// Point-in-time connection pool usage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolMetrics {
+    /// Open connections, idle or in use
+    pub size: u32,
+    /// Open connections waiting to be acquired
+    pub idle: u32,
+    /// Connections currently checked out
+    pub in_use: u32,
+    /// Configured upper bound on `size`
+    pub max_connections: u32,
+}
+
+/// Snapshots the pool's connection counts
+pub fn pool_metrics(pool: &Pool) -> PoolMetrics {
+    let size = pool.size();
+    let idle = u32::try_from(pool.num_idle()).unwrap_or(u32::MAX).min(size);
+    PoolMetrics {
+        size,
+        idle,
+        in_use: size - idle,
+        max_connections: pool.options().get_max_connections(),
+    }
+}
+
+/// Queries slower than this are logged by `timed_query!`
+///
+/// Read once from `SLOW_QUERY_MS`; invalid values fall back to the default.
+pub fn slow_query_threshold() -> Duration {
+    static THRESHOLD: OnceLock<Duration> = OnceLock::new();
+    *THRESHOLD.get_or_init(|| parse_slow_query_threshold(std::env::var("SLOW_QUERY_MS").ok().as_deref()))
+}
+
+/// Logs `query` at `warn` if `elapsed` exceeds the slow query threshold
+///
+/// Returns whether the query was slow. Called by `timed_query!`.
+pub fn record_query_duration(query: &str, elapsed: Duration) -> bool {
+    let threshold = slow_query_threshold();
+    if elapsed <= threshold {
+        return false;
+    }
+    tracing::warn!(
+        query = %query,
+        elapsed_ms = elapsed.as_millis() as u64,
+        threshold_ms = threshold.as_millis() as u64,
+        "Slow database query"
+    );
+    true
+}
+
+/// Awaits a query future, logging it under `label` if it was slow
+///
+/// ```rust,no_run
+/// # async fn example(pool: &meridian_db::Pool) -> Result<(), sqlx::Error> {
+/// let (count,): (i64,) = meridian_db::timed_query!(
+///     "baskets.count",
+///     sqlx::query_as("SELECT COUNT(*) FROM baskets").fetch_one(pool)
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! timed_query {
+    ($label:expr, $query:expr) => {
+        $crate::TimedQuery::timed($query, $label).await
+    };
+}
+
+/// Method-call form of `timed_query!` for sqlx builder chains:
+/// `.fetch_one(&self.pool).timed("baskets.count").await`
+pub trait TimedQuery: Future + Sized {
+    fn timed(self, label: &'static str) -> impl Future<Output = Self::Output> {
+        async move {
+            let started = Instant::now();
+            let output = self.await;
+            record_query_duration(label, started.elapsed());
+            output
+        }
+    }
+}
+
+impl<F: Future> TimedQuery for F {}
+
+fn parse_slow_query_threshold(value: Option<&str>) -> Duration {
+    let millis = value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|ms| *ms > 0)
+        .unwrap_or(DEFAULT_SLOW_QUERY_THRESHOLD_MS);
+    Duration::from_millis(millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_slow_query_threshold() {
+        assert_eq!(parse_slow_query_threshold(None), Duration::from_millis(200));
+        assert_eq!(parse_slow_query_threshold(Some(" 250 ")), Duration::from_millis(250));
+        assert_eq!(parse_slow_query_threshold(Some("0")), Duration::from_millis(200));
+        assert_eq!(parse_slow_query_threshold(Some("fast")), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_record_query_duration_only_flags_slow_queries() {
+        assert!(!record_query_duration("fast", Duration::ZERO));
+        assert!(record_query_duration("slow", Duration::from_secs(3600)));
+    }
+
+    #[tokio::test]
+    async fn test_timed_query_returns_result() {
+        let result: Result<u8, ()> = timed_query!("noop", async { Ok(7) });
+        assert_eq!(result, Ok(7));
+        assert_eq!(async { 8 }.timed("noop").await, 8);
+    }
+}
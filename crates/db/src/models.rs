@@ -18,6 +18,7 @@ pub struct BasketRow {
     pub components: serde_json::Value,
     pub rebalance_strategy: serde_json::Value,
     pub last_rebalanced: Option<DateTime<Utc>>,
+    pub min_price_confidence: Option<Decimal>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -38,6 +39,7 @@ impl BasketRow {
             components: serde_json::to_value(&basket.components)?,
             rebalance_strategy: serde_json::to_value(&basket.rebalance_strategy)?,
             last_rebalanced: basket.last_rebalanced,
+            min_price_confidence: basket.min_price_confidence,
             created_at: basket.created_at,
             updated_at: Utc::now(),
         })
@@ -59,6 +61,7 @@ impl BasketRow {
             components: serde_json::from_value(self.components.clone())?,
             rebalance_strategy: serde_json::from_value(self.rebalance_strategy.clone())?,
             last_rebalanced: self.last_rebalanced,
+            min_price_confidence: self.min_price_confidence,
             created_at: self.created_at,
         })
     }
@@ -78,6 +81,15 @@ pub struct PriceHistoryRow {
     pub timestamp: DateTime<Utc>,
 }
 
+/// A single timestamped price, as returned by price history queries
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub price: Decimal,
+    pub source: String,
+    pub is_stale: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Request to insert a new price record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InsertPriceRequest {
@@ -99,6 +111,10 @@ pub struct StablecoinRow {
     pub contract_address: Option<String>,
     pub basket_id: Option<Uuid>,
     pub chain_id: i32,
+    /// Token decimals
+    pub decimals: i16,
+    /// ISO 4217 code of the pegged currency; `None` for legacy rows
+    pub peg_currency: Option<String>,
     pub total_supply: Decimal,
     pub total_reserve_value: Decimal,
     pub status: String,
@@ -112,10 +128,35 @@ pub struct StablecoinRow {
 pub struct CreateStablecoinRequest {
     pub name: String,
     pub symbol: String,
+    pub decimals: i16,
+    pub peg_currency: String,
     pub basket_id: Option<Uuid>,
     pub chain_id: i32,
 }
 
+/// A basket backing a stablecoin over a half-open date range
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct StablecoinBasketVersionRow {
+    pub id: Uuid,
+    pub stablecoin_id: Uuid,
+    pub basket_id: Uuid,
+    pub effective_from: DateTime<Utc>,
+    /// `None` while this is the current basket
+    pub effective_to: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ============ Reserve Models ============
+
+/// A recorded reserve snapshot for one stablecoin
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    pub total_value: Decimal,
+    /// Reserves as a percentage of supply (100 = fully backed)
+    pub reserve_ratio: Decimal,
+    pub recorded_at: DateTime<Utc>,
+}
+
 // ============ Audit Log Models ============
 
 /// Database representation of an audit log entry
@@ -1,6 +1,7 @@
 //! Audit log repository for immutable audit trail
 
 use crate::error::DbError;
+use crate::metrics::TimedQuery;
 use crate::models::{AuditLogRow, CreateAuditLogRequest};
 use crate::Pool;
 use chrono::{DateTime, Utc};
@@ -18,6 +19,7 @@ impl AuditRepository {
     }
 
     /// Inserts a new audit log entry (immutable)
+    #[tracing::instrument(name = "db.audit.log", skip_all, fields(component = "db", table = "audit_logs"), err)]
     pub async fn log(&self, request: CreateAuditLogRequest) -> Result<i64, DbError> {
         let result: (i64,) = sqlx::query_as(
             r#"
@@ -32,6 +34,7 @@ impl AuditRepository {
         .bind(request.basket_id)
         .bind(&request.details)
         .fetch_one(&self.pool)
+        .timed("audit.log")
         .await?;
 
         tracing::info!(
@@ -44,6 +47,7 @@ impl AuditRepository {
     }
 
     /// Retrieves audit logs for a specific stablecoin
+    #[tracing::instrument(name = "db.audit.get_stablecoin_logs", skip_all, fields(component = "db", table = "audit_logs"), err)]
     pub async fn get_stablecoin_logs(
         &self,
         stablecoin_id: Uuid,
@@ -61,12 +65,14 @@ impl AuditRepository {
         .bind(stablecoin_id)
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("audit.get_stablecoin_logs")
         .await?;
 
         Ok(rows)
     }
 
     /// Retrieves audit logs for a specific basket
+    #[tracing::instrument(name = "db.audit.get_basket_logs", skip_all, fields(component = "db", table = "audit_logs"), err)]
     pub async fn get_basket_logs(
         &self,
         basket_id: Uuid,
@@ -84,12 +90,14 @@ impl AuditRepository {
         .bind(basket_id)
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("audit.get_basket_logs")
         .await?;
 
         Ok(rows)
     }
 
     /// Retrieves recent audit logs
+    #[tracing::instrument(name = "db.audit.get_recent", skip_all, fields(component = "db", table = "audit_logs"), err)]
     pub async fn get_recent(&self, limit: i64) -> Result<Vec<AuditLogRow>, DbError> {
         let rows = sqlx::query_as::<_, AuditLogRow>(
             r#"
@@ -101,12 +109,14 @@ impl AuditRepository {
         )
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("audit.get_recent")
         .await?;
 
         Ok(rows)
     }
 
     /// Gets audit logs by operation type
+    #[tracing::instrument(name = "db.audit.get_by_operation", skip_all, fields(component = "db", table = "audit_logs"), err)]
     pub async fn get_by_operation(
         &self,
         operation: &str,
@@ -126,15 +136,18 @@ impl AuditRepository {
         .bind(start_time)
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("audit.get_by_operation")
         .await?;
 
         Ok(rows)
     }
 
     /// Counts total audit log entries
+    #[tracing::instrument(name = "db.audit.count", skip_all, fields(component = "db", table = "audit_logs"), err)]
     pub async fn count(&self) -> Result<i64, DbError> {
         let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM audit_logs")
             .fetch_one(&self.pool)
+            .timed("audit.count")
             .await?;
 
         Ok(result.0)
@@ -1,6 +1,7 @@
 //! Basket repository for database operations
 
 use crate::error::DbError;
+use crate::metrics::TimedQuery;
 use crate::models::BasketRow;
 use crate::Pool;
 use meridian_basket::CurrencyBasket;
@@ -18,13 +19,14 @@ impl BasketRepository {
     }
 
     /// Inserts a new basket into the database
+    #[tracing::instrument(name = "db.baskets.create", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn create(&self, basket: &CurrencyBasket) -> Result<Uuid, DbError> {
         let row = BasketRow::from_basket(basket)?;
 
         sqlx::query(
             r#"
-            INSERT INTO baskets (id, name, basket_type, components, rebalance_strategy, last_rebalanced, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO baskets (id, name, basket_type, components, rebalance_strategy, last_rebalanced, min_price_confidence, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#
         )
         .bind(row.id)
@@ -33,9 +35,11 @@ impl BasketRepository {
         .bind(&row.components)
         .bind(&row.rebalance_strategy)
         .bind(row.last_rebalanced)
+        .bind(row.min_price_confidence)
         .bind(row.created_at)
         .bind(row.updated_at)
         .execute(&self.pool)
+        .timed("baskets.create")
         .await?;
 
         tracing::info!(basket_id = %row.id, "Basket created in database");
@@ -44,27 +48,31 @@ impl BasketRepository {
     }
 
     /// Retrieves a basket by ID
+    #[tracing::instrument(name = "db.baskets.find_by_id", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn find_by_id(&self, id: Uuid) -> Result<CurrencyBasket, DbError> {
         let row = sqlx::query_as::<_, BasketRow>(
             r#"
-            SELECT id, name, basket_type, components, rebalance_strategy, last_rebalanced, created_at, updated_at
+            SELECT id, name, basket_type, components, rebalance_strategy, last_rebalanced, min_price_confidence, created_at, updated_at
             FROM baskets
-            WHERE id = $1
+            WHERE id = $1 AND deleted_at IS NULL
             "#
         )
         .bind(id)
         .fetch_one(&self.pool)
+        .timed("baskets.find_by_id")
         .await?;
 
         row.to_basket().map_err(DbError::from)
     }
 
     /// Lists all baskets with pagination
+    #[tracing::instrument(name = "db.baskets.list", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn list(&self, limit: i64, offset: i64) -> Result<Vec<CurrencyBasket>, DbError> {
         let rows = sqlx::query_as::<_, BasketRow>(
             r#"
-            SELECT id, name, basket_type, components, rebalance_strategy, last_rebalanced, created_at, updated_at
+            SELECT id, name, basket_type, components, rebalance_strategy, last_rebalanced, min_price_confidence, created_at, updated_at
             FROM baskets
+            WHERE deleted_at IS NULL
             ORDER BY created_at DESC
             LIMIT $1 OFFSET $2
             "#
@@ -72,6 +80,7 @@ impl BasketRepository {
         .bind(limit)
         .bind(offset)
         .fetch_all(&self.pool)
+        .timed("baskets.list")
         .await?;
 
         rows.into_iter()
@@ -80,15 +89,175 @@ impl BasketRepository {
     }
 
     /// Counts total number of baskets
+    #[tracing::instrument(name = "db.baskets.count", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn count(&self) -> Result<i64, DbError> {
-        let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM baskets")
+        let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM baskets WHERE deleted_at IS NULL")
             .fetch_one(&self.pool)
+            .timed("baskets.count")
+            .await?;
+
+        Ok(result.0)
+    }
+
+    /// Inserts a basket owned by an organization, unless it is already at its cap
+    ///
+    /// The count and insert run in one transaction under a per-organization
+    /// advisory lock, so concurrent creations cannot overshoot `max_live`.
+    /// Returns `None` (and inserts nothing) when the organization already has
+    /// `max_live` non-deleted baskets.
+    #[tracing::instrument(name = "db.baskets.create_for_organization", skip_all, fields(component = "db", table = "baskets"), err)]
+    pub async fn create_for_organization(
+        &self,
+        basket: &CurrencyBasket,
+        organization: &str,
+        created_by: i32,
+        max_live: i64,
+    ) -> Result<Option<Uuid>, DbError> {
+        let row = BasketRow::from_basket(basket)?;
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('baskets:' || $1))")
+            .bind(organization)
+            .execute(&mut *tx)
+            .timed("baskets.create_for_organization")
             .await?;
 
+        let (live,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM baskets WHERE organization = $1 AND deleted_at IS NULL",
+        )
+        .bind(organization)
+        .fetch_one(&mut *tx)
+        .timed("baskets.create_for_organization")
+        .await?;
+        if live >= max_live {
+            return Ok(None);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO baskets (id, name, basket_type, components, rebalance_strategy, last_rebalanced, min_price_confidence, created_at, updated_at, organization, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#
+        )
+        .bind(row.id)
+        .bind(&row.name)
+        .bind(&row.basket_type)
+        .bind(&row.components)
+        .bind(&row.rebalance_strategy)
+        .bind(row.last_rebalanced)
+        .bind(row.min_price_confidence)
+        .bind(row.created_at)
+        .bind(row.updated_at)
+        .bind(organization)
+        .bind(created_by)
+        .execute(&mut *tx)
+        .timed("baskets.create_for_organization")
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!(basket_id = %row.id, organization = %organization, "Basket created in database");
+
+        Ok(Some(row.id))
+    }
+
+    /// Counts an organization's non-deleted baskets
+    #[tracing::instrument(name = "db.baskets.count_for_organization", skip_all, fields(component = "db", table = "baskets"), err)]
+    pub async fn count_for_organization(&self, organization: &str) -> Result<i64, DbError> {
+        let result: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM baskets WHERE organization = $1 AND deleted_at IS NULL",
+        )
+        .bind(organization)
+        .fetch_one(&self.pool)
+        .timed("baskets.count_for_organization")
+        .await?;
+
         Ok(result.0)
     }
 
+    /// Saves a basket, replacing its stored name, type, strategy and components
+    ///
+    /// Weights are revalidated first, so a basket whose components were
+    /// edited in memory into an invalid state is rejected before the
+    /// database is touched. Inserts the basket if it doesn't exist yet.
+    /// Components live in the same row, so the upsert replaces them
+    /// atomically; organization and creation time are kept.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidBasket` if the weights are empty or don't sum to 100%
+    /// - `NotFound` if the basket has been soft-deleted
+    #[tracing::instrument(name = "db.baskets.update", skip_all, fields(component = "db", table = "baskets"), err)]
+    pub async fn update(&self, basket: &CurrencyBasket) -> Result<(), DbError> {
+        basket.validate_weights()?;
+        let row = BasketRow::from_basket(basket)?;
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO baskets (id, name, basket_type, components, rebalance_strategy, last_rebalanced, min_price_confidence, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW())
+            ON CONFLICT (id) DO UPDATE
+            SET name = EXCLUDED.name,
+                basket_type = EXCLUDED.basket_type,
+                components = EXCLUDED.components,
+                rebalance_strategy = EXCLUDED.rebalance_strategy,
+                last_rebalanced = EXCLUDED.last_rebalanced,
+                min_price_confidence = EXCLUDED.min_price_confidence,
+                updated_at = NOW()
+            WHERE baskets.deleted_at IS NULL
+            "#
+        )
+        .bind(row.id)
+        .bind(&row.name)
+        .bind(&row.basket_type)
+        .bind(&row.components)
+        .bind(&row.rebalance_strategy)
+        .bind(row.last_rebalanced)
+        .bind(row.min_price_confidence)
+        .bind(row.created_at)
+        .execute(&mut *tx)
+        .timed("baskets.update")
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!("Basket {} not found", row.id)));
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(basket_id = %row.id, "Basket updated in database");
+
+        Ok(())
+    }
+
+    /// Soft-deletes a basket: it disappears from reads and no longer counts
+    /// towards its organization's cap, but the row is kept
+    #[tracing::instrument(name = "db.baskets.soft_delete", skip_all, fields(component = "db", table = "baskets"), err)]
+    pub async fn soft_delete(&self, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE baskets
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .timed("baskets.soft_delete")
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!("Basket {} not found", id)));
+        }
+
+        tracing::info!(basket_id = %id, "Basket soft-deleted");
+
+        Ok(())
+    }
+
     /// Updates basket's last_rebalanced timestamp
+    #[tracing::instrument(name = "db.baskets.mark_rebalanced", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn mark_rebalanced(&self, id: Uuid) -> Result<(), DbError> {
         sqlx::query(
             r#"
@@ -99,6 +268,7 @@ impl BasketRepository {
         )
         .bind(id)
         .execute(&self.pool)
+        .timed("baskets.mark_rebalanced")
         .await?;
 
         tracing::info!(basket_id = %id, "Basket marked as rebalanced");
@@ -107,6 +277,7 @@ impl BasketRepository {
     }
 
     /// Deletes a basket by ID
+    #[tracing::instrument(name = "db.baskets.delete", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn delete(&self, id: Uuid) -> Result<(), DbError> {
         let result = sqlx::query(
             r#"
@@ -116,6 +287,7 @@ impl BasketRepository {
         )
         .bind(id)
         .execute(&self.pool)
+        .timed("baskets.delete")
         .await?;
 
         if result.rows_affected() == 0 {
@@ -128,6 +300,7 @@ impl BasketRepository {
     }
 
     /// Lists baskets by type
+    #[tracing::instrument(name = "db.baskets.find_by_type", skip_all, fields(component = "db", table = "baskets"), err)]
     pub async fn find_by_type(
         &self,
         basket_type: &str,
@@ -135,9 +308,9 @@ impl BasketRepository {
     ) -> Result<Vec<CurrencyBasket>, DbError> {
         let rows = sqlx::query_as::<_, BasketRow>(
             r#"
-            SELECT id, name, basket_type, components, rebalance_strategy, last_rebalanced, created_at, updated_at
+            SELECT id, name, basket_type, components, rebalance_strategy, last_rebalanced, min_price_confidence, created_at, updated_at
             FROM baskets
-            WHERE basket_type = $1
+            WHERE basket_type = $1 AND deleted_at IS NULL
             ORDER BY created_at DESC
             LIMIT $2
             "#
@@ -145,6 +318,7 @@ impl BasketRepository {
         .bind(basket_type)
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("baskets.find_by_type")
         .await?;
 
         rows.into_iter()
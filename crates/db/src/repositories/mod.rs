@@ -1,11 +1,19 @@
 //! Repository pattern implementations for data access
+//!
+//! Every public repository method runs inside a `db.<repo>.<method>` span
+//! tagged `component = "db"` and the table it touches. Span timings give the
+//! per-call duration in traces; failures are recorded on the span via `err`.
 
 mod audit;
 mod baskets;
 mod prices;
+mod reserves;
+mod sessions;
 mod stablecoins;
 
 pub use audit::AuditRepository;
 pub use baskets::BasketRepository;
-pub use prices::PriceRepository;
+pub use prices::{PriceRepository, MAX_PRICE_HISTORY_POINTS};
+pub use reserves::{ReserveRepository, MAX_RESERVE_HISTORY_DAYS};
+pub use sessions::SessionRepository;
 pub use stablecoins::StablecoinRepository;
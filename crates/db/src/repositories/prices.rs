@@ -1,11 +1,15 @@
 //! Price history repository
 
 use crate::error::DbError;
-use crate::models::{InsertPriceRequest, PriceHistoryRow};
+use crate::metrics::TimedQuery;
+use crate::models::{InsertPriceRequest, PriceHistoryRow, PricePoint};
 use crate::Pool;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 
+/// Most points `PriceRepository::history` returns in one call
+pub const MAX_PRICE_HISTORY_POINTS: i64 = 10_000;
+
 /// Repository for price history operations
 pub struct PriceRepository {
     pool: Pool,
@@ -18,6 +22,7 @@ impl PriceRepository {
     }
 
     /// Inserts a new price record
+    #[tracing::instrument(name = "db.prices.insert", skip_all, fields(component = "db", table = "price_history"), err)]
     pub async fn insert(&self, request: InsertPriceRequest) -> Result<i64, DbError> {
         let result: (i64,) = sqlx::query_as(
             r#"
@@ -32,6 +37,7 @@ impl PriceRepository {
         .bind(request.is_stale)
         .bind(request.round_id)
         .fetch_one(&self.pool)
+        .timed("prices.insert")
         .await?;
 
         tracing::debug!(
@@ -44,6 +50,7 @@ impl PriceRepository {
     }
 
     /// Gets the latest price for a currency pair
+    #[tracing::instrument(name = "db.prices.get_latest", skip_all, fields(component = "db", table = "price_history"), err)]
     pub async fn get_latest(&self, currency_pair: &str) -> Result<PriceHistoryRow, DbError> {
         let row = sqlx::query_as::<_, PriceHistoryRow>(
             r#"
@@ -56,12 +63,39 @@ impl PriceRepository {
         )
         .bind(currency_pair)
         .fetch_one(&self.pool)
+        .timed("prices.get_latest")
+        .await?;
+
+        Ok(row)
+    }
+
+    /// Gets the latest price for a currency pair recorded by a given source
+    #[tracing::instrument(name = "db.prices.get_latest_from_source", skip_all, fields(component = "db", table = "price_history"), err)]
+    pub async fn get_latest_from_source(
+        &self,
+        currency_pair: &str,
+        source: &str,
+    ) -> Result<PriceHistoryRow, DbError> {
+        let row = sqlx::query_as::<_, PriceHistoryRow>(
+            r#"
+            SELECT id, currency_pair, price, source, is_stale, round_id, timestamp
+            FROM price_history
+            WHERE currency_pair = $1 AND source = $2
+            ORDER BY timestamp DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(currency_pair)
+        .bind(source)
+        .fetch_one(&self.pool)
+        .timed("prices.get_latest_from_source")
         .await?;
 
         Ok(row)
     }
 
     /// Gets price history for a currency pair within a time range
+    #[tracing::instrument(name = "db.prices.get_history", skip_all, fields(component = "db", table = "price_history"), err)]
     pub async fn get_history(
         &self,
         currency_pair: &str,
@@ -85,12 +119,76 @@ impl PriceRepository {
         .bind(end_time)
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("prices.get_history")
         .await?;
 
         Ok(rows)
     }
 
+    /// Prices for a currency pair recorded in `[from, to]`, oldest first
+    ///
+    /// At most `limit` points are returned, capped at
+    /// `MAX_PRICE_HISTORY_POINTS`; a non-positive limit returns nothing.
+    /// Callers backfilling a longer range can page by passing the last
+    /// returned timestamp as the next `from`. The range scan is served by
+    /// `idx_price_history_pair_timestamp` on `(currency_pair, timestamp)`.
+    #[tracing::instrument(name = "db.prices.history", skip_all, fields(component = "db", table = "price_history"), err)]
+    pub async fn history(
+        &self,
+        pair: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PricePoint>, DbError> {
+        let limit = limit.clamp(0, MAX_PRICE_HISTORY_POINTS);
+        if limit == 0 || from > to {
+            return Ok(Vec::new());
+        }
+
+        let points = sqlx::query_as::<_, PricePoint>(
+            r#"
+            SELECT price, source, is_stale, timestamp
+            FROM price_history
+            WHERE currency_pair = $1
+                AND timestamp >= $2
+                AND timestamp <= $3
+            ORDER BY timestamp ASC, id ASC
+            LIMIT $4
+            "#,
+        )
+        .bind(pair)
+        .bind(from)
+        .bind(to)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .timed("prices.history")
+        .await?;
+
+        Ok(points)
+    }
+
+    /// Most recent price for a currency pair, or `None` if none was recorded
+    #[tracing::instrument(name = "db.prices.latest", skip_all, fields(component = "db", table = "price_history"), err)]
+    pub async fn latest(&self, pair: &str) -> Result<Option<PricePoint>, DbError> {
+        let point = sqlx::query_as::<_, PricePoint>(
+            r#"
+            SELECT price, source, is_stale, timestamp
+            FROM price_history
+            WHERE currency_pair = $1
+            ORDER BY timestamp DESC, id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(pair)
+        .fetch_optional(&self.pool)
+        .timed("prices.latest")
+        .await?;
+
+        Ok(point)
+    }
+
     /// Gets all unique currency pairs with price data
+    #[tracing::instrument(name = "db.prices.get_all_pairs", skip_all, fields(component = "db", table = "price_history"), err)]
     pub async fn get_all_pairs(&self) -> Result<Vec<String>, DbError> {
         let rows: Vec<(String,)> = sqlx::query_as(
             r#"
@@ -100,12 +198,14 @@ impl PriceRepository {
             "#,
         )
         .fetch_all(&self.pool)
+        .timed("prices.get_all_pairs")
         .await?;
 
         Ok(rows.into_iter().map(|r| r.0).collect())
     }
 
     /// Gets statistics for a currency pair
+    #[tracing::instrument(name = "db.prices.get_stats", skip_all, fields(component = "db", table = "price_history"), err)]
     pub async fn get_stats(
         &self,
         currency_pair: &str,
@@ -126,6 +226,7 @@ impl PriceRepository {
         .bind(currency_pair)
         .bind(start_time)
         .fetch_one(&self.pool)
+        .timed("prices.get_stats")
         .await?;
 
         Ok(PriceStats {
@@ -138,6 +239,7 @@ impl PriceRepository {
     }
 
     /// Deletes old price records (cleanup)
+    #[tracing::instrument(name = "db.prices.delete_older_than", skip_all, fields(component = "db", table = "price_history"), err)]
     pub async fn delete_older_than(&self, cutoff_time: DateTime<Utc>) -> Result<u64, DbError> {
         let result = sqlx::query(
             r#"
@@ -147,6 +249,7 @@ impl PriceRepository {
         )
         .bind(cutoff_time)
         .execute(&self.pool)
+        .timed("prices.delete_older_than")
         .await?;
 
         let deleted = result.rows_affected();
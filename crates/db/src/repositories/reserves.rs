@@ -0,0 +1,75 @@
+//! Reserve snapshot repository
+
+use crate::error::DbError;
+use crate::metrics::TimedQuery;
+use crate::models::HistoryPoint;
+use crate::Pool;
+
+/// Longest window `ReserveRepository::history` will return, in days
+pub const MAX_RESERVE_HISTORY_DAYS: i64 = 366;
+
+/// Repository for reserve history
+pub struct ReserveRepository {
+    pool: Pool,
+}
+
+impl ReserveRepository {
+    /// Creates a new reserve repository
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Snapshots every active stablecoin's reserve value and ratio
+    ///
+    /// The ratio is `total_reserve_value / total_supply * 100`, or 100 for a
+    /// coin with no supply. Returns the number of snapshots recorded.
+    #[tracing::instrument(name = "db.reserves.record_active", skip_all, fields(component = "db", table = "reserve_snapshots"), err)]
+    pub async fn record_active(&self) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO reserve_snapshots (symbol, total_value, reserve_ratio)
+            SELECT UPPER(symbol),
+                   COALESCE(total_reserve_value, 0),
+                   CASE
+                       WHEN COALESCE(total_supply, 0) > 0
+                           THEN COALESCE(total_reserve_value, 0) / total_supply * 100
+                       ELSE 100
+                   END
+            FROM stablecoins
+            WHERE status = 'active'
+            "#,
+        )
+        .execute(&self.pool)
+        .timed("reserves.record_active")
+        .await?;
+
+        let recorded = result.rows_affected();
+        tracing::info!(recorded = %recorded, "Reserve snapshots recorded");
+
+        Ok(recorded)
+    }
+
+    /// Snapshots for a stablecoin over the last `days` days, oldest first
+    ///
+    /// `days` is clamped to `1..=MAX_RESERVE_HISTORY_DAYS`.
+    #[tracing::instrument(name = "db.reserves.history", skip_all, fields(component = "db", table = "reserve_snapshots"), err)]
+    pub async fn history(&self, symbol: &str, days: i64) -> Result<Vec<HistoryPoint>, DbError> {
+        let days = days.clamp(1, MAX_RESERVE_HISTORY_DAYS);
+        let points = sqlx::query_as::<_, HistoryPoint>(
+            r#"
+            SELECT total_value, reserve_ratio, recorded_at
+            FROM reserve_snapshots
+            WHERE symbol = UPPER($1)
+                AND recorded_at >= NOW() - make_interval(days => $2)
+            ORDER BY recorded_at ASC, id ASC
+            "#,
+        )
+        .bind(symbol)
+        .bind(days as i32)
+        .fetch_all(&self.pool)
+        .timed("reserves.history")
+        .await?;
+
+        Ok(points)
+    }
+}
@@ -0,0 +1,35 @@
+//! Session repository for database operations
+
+use crate::error::DbError;
+use crate::metrics::TimedQuery;
+use crate::Pool;
+
+/// Repository for session maintenance
+pub struct SessionRepository {
+    pool: Pool,
+}
+
+impl SessionRepository {
+    /// Creates a new session repository
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Deletes sessions whose access token has expired (retention purge)
+    ///
+    /// Returns the number of sessions removed.
+    #[tracing::instrument(name = "db.sessions.delete_expired_sessions", skip_all, fields(component = "db", table = "sessions"), err)]
+    pub async fn delete_expired_sessions(&self) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM sessions
+            WHERE expires_at < NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .timed("sessions.delete_expired_sessions")
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,8 +1,11 @@
 //! Stablecoin repository
 
 use crate::error::DbError;
-use crate::models::{CreateStablecoinRequest, StablecoinRow};
+use crate::metrics::TimedQuery;
+use crate::models::{BasketRow, CreateStablecoinRequest, StablecoinBasketVersionRow, StablecoinRow};
 use crate::Pool;
+use chrono::{DateTime, Utc};
+use meridian_basket::CurrencyBasket;
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
@@ -18,33 +21,61 @@ impl StablecoinRepository {
     }
 
     /// Creates a new stablecoin record
+    #[tracing::instrument(name = "db.stablecoins.create", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn create(&self, request: CreateStablecoinRequest) -> Result<Uuid, DbError> {
         let id = Uuid::new_v4();
 
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query(
             r#"
-            INSERT INTO stablecoins (id, name, symbol, basket_id, chain_id)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO stablecoins (id, name, symbol, decimals, peg_currency, basket_id, chain_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             "#,
         )
         .bind(id)
         .bind(&request.name)
         .bind(&request.symbol)
+        .bind(request.decimals)
+        .bind(request.peg_currency.to_uppercase())
         .bind(request.basket_id)
         .bind(request.chain_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
+        .timed("stablecoins.create")
         .await?;
 
+        // Open the first basket version so history starts at creation
+        if let Some(basket_id) = request.basket_id {
+            sqlx::query(
+                r#"
+                INSERT INTO stablecoin_basket_versions (stablecoin_id, basket_id, effective_from)
+                VALUES ($1, $2, NOW())
+                "#,
+            )
+            .bind(id)
+            .bind(basket_id)
+            .execute(&mut *tx)
+            .timed("stablecoins.create")
+            .await?;
+        }
+
+        tx.commit().await?;
+
         tracing::info!(stablecoin_id = %id, "Stablecoin created in database");
 
         Ok(id)
     }
 
     /// Finds a stablecoin by ID
+    #[tracing::instrument(name = "db.stablecoins.find_by_id", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn find_by_id(&self, id: Uuid) -> Result<StablecoinRow, DbError> {
         let row = sqlx::query_as::<_, StablecoinRow>(
             r#"
-            SELECT id, name, symbol, contract_address, basket_id, chain_id, 
+            SELECT id, name, symbol, contract_address,
+                   COALESCE((SELECT v.basket_id FROM stablecoin_basket_versions v
+                             WHERE v.stablecoin_id = stablecoins.id AND v.effective_from <= NOW()
+                             ORDER BY v.effective_from DESC LIMIT 1), basket_id) AS basket_id,
+                   chain_id, decimals, peg_currency,
                    total_supply, total_reserve_value, status, deployed_at, created_at, updated_at
             FROM stablecoins
             WHERE id = $1
@@ -52,19 +83,54 @@ impl StablecoinRepository {
         )
         .bind(id)
         .fetch_one(&self.pool)
+        .timed("stablecoins.find_by_id")
         .await?;
 
         Ok(row)
     }
 
+    /// Finds a stablecoin by symbol (case-insensitive)
+    ///
+    /// Symbols are not unique across chains; the most recently created
+    /// stablecoin wins.
+    #[tracing::instrument(name = "db.stablecoins.find_by_symbol", skip_all, fields(component = "db", table = "stablecoins"), err)]
+    pub async fn find_by_symbol(&self, symbol: &str) -> Result<StablecoinRow, DbError> {
+        let row = sqlx::query_as::<_, StablecoinRow>(
+            r#"
+            SELECT id, name, symbol, contract_address,
+                   COALESCE((SELECT v.basket_id FROM stablecoin_basket_versions v
+                             WHERE v.stablecoin_id = stablecoins.id AND v.effective_from <= NOW()
+                             ORDER BY v.effective_from DESC LIMIT 1), basket_id) AS basket_id,
+                   chain_id, decimals, peg_currency,
+                   total_supply, total_reserve_value, status, deployed_at, created_at, updated_at
+            FROM stablecoins
+            WHERE UPPER(symbol) = UPPER($1)
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .timed("stablecoins.find_by_symbol")
+        .await?
+        .ok_or_else(|| DbError::NotFound(format!("Stablecoin {}", symbol)))?;
+
+        Ok(row)
+    }
+
     /// Finds a stablecoin by contract address
+    #[tracing::instrument(name = "db.stablecoins.find_by_contract_address", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn find_by_contract_address(
         &self,
         contract_address: &str,
     ) -> Result<StablecoinRow, DbError> {
         let row = sqlx::query_as::<_, StablecoinRow>(
             r#"
-            SELECT id, name, symbol, contract_address, basket_id, chain_id,
+            SELECT id, name, symbol, contract_address,
+                   COALESCE((SELECT v.basket_id FROM stablecoin_basket_versions v
+                             WHERE v.stablecoin_id = stablecoins.id AND v.effective_from <= NOW()
+                             ORDER BY v.effective_from DESC LIMIT 1), basket_id) AS basket_id,
+                   chain_id, decimals, peg_currency,
                    total_supply, total_reserve_value, status, deployed_at, created_at, updated_at
             FROM stablecoins
             WHERE contract_address = $1
@@ -72,12 +138,14 @@ impl StablecoinRepository {
         )
         .bind(contract_address)
         .fetch_one(&self.pool)
+        .timed("stablecoins.find_by_contract_address")
         .await?;
 
         Ok(row)
     }
 
     /// Updates contract address after deployment
+    #[tracing::instrument(name = "db.stablecoins.set_contract_address", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn set_contract_address(
         &self,
         id: Uuid,
@@ -96,6 +164,7 @@ impl StablecoinRepository {
         .bind(contract_address)
         .bind(id)
         .execute(&self.pool)
+        .timed("stablecoins.set_contract_address")
         .await?;
 
         tracing::info!(
@@ -108,6 +177,7 @@ impl StablecoinRepository {
     }
 
     /// Updates total supply and reserve value
+    #[tracing::instrument(name = "db.stablecoins.update_balances", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn update_balances(
         &self,
         id: Uuid,
@@ -127,12 +197,14 @@ impl StablecoinRepository {
         .bind(total_reserve_value)
         .bind(id)
         .execute(&self.pool)
+        .timed("stablecoins.update_balances")
         .await?;
 
         Ok(())
     }
 
     /// Updates stablecoin status
+    #[tracing::instrument(name = "db.stablecoins.update_status", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn update_status(&self, id: Uuid, status: &str) -> Result<(), DbError> {
         sqlx::query(
             r#"
@@ -144,6 +216,7 @@ impl StablecoinRepository {
         .bind(status)
         .bind(id)
         .execute(&self.pool)
+        .timed("stablecoins.update_status")
         .await?;
 
         tracing::info!(stablecoin_id = %id, status = %status, "Status updated");
@@ -152,10 +225,15 @@ impl StablecoinRepository {
     }
 
     /// Lists all stablecoins with pagination
+    #[tracing::instrument(name = "db.stablecoins.list", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn list(&self, limit: i64, offset: i64) -> Result<Vec<StablecoinRow>, DbError> {
         let rows = sqlx::query_as::<_, StablecoinRow>(
             r#"
-            SELECT id, name, symbol, contract_address, basket_id, chain_id,
+            SELECT id, name, symbol, contract_address,
+                   COALESCE((SELECT v.basket_id FROM stablecoin_basket_versions v
+                             WHERE v.stablecoin_id = stablecoins.id AND v.effective_from <= NOW()
+                             ORDER BY v.effective_from DESC LIMIT 1), basket_id) AS basket_id,
+                   chain_id, decimals, peg_currency,
                    total_supply, total_reserve_value, status, deployed_at, created_at, updated_at
             FROM stablecoins
             ORDER BY created_at DESC
@@ -165,12 +243,14 @@ impl StablecoinRepository {
         .bind(limit)
         .bind(offset)
         .fetch_all(&self.pool)
+        .timed("stablecoins.list")
         .await?;
 
         Ok(rows)
     }
 
     /// Lists stablecoins by chain ID
+    #[tracing::instrument(name = "db.stablecoins.find_by_chain", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn find_by_chain(
         &self,
         chain_id: i32,
@@ -178,7 +258,11 @@ impl StablecoinRepository {
     ) -> Result<Vec<StablecoinRow>, DbError> {
         let rows = sqlx::query_as::<_, StablecoinRow>(
             r#"
-            SELECT id, name, symbol, contract_address, basket_id, chain_id,
+            SELECT id, name, symbol, contract_address,
+                   COALESCE((SELECT v.basket_id FROM stablecoin_basket_versions v
+                             WHERE v.stablecoin_id = stablecoins.id AND v.effective_from <= NOW()
+                             ORDER BY v.effective_from DESC LIMIT 1), basket_id) AS basket_id,
+                   chain_id, decimals, peg_currency,
                    total_supply, total_reserve_value, status, deployed_at, created_at, updated_at
             FROM stablecoins
             WHERE chain_id = $1
@@ -189,15 +273,147 @@ impl StablecoinRepository {
         .bind(chain_id)
         .bind(limit)
         .fetch_all(&self.pool)
+        .timed("stablecoins.find_by_chain")
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// Migrates a stablecoin to a new backing basket as of `effective_at`
+    ///
+    /// Closes the current basket version at `effective_at` and opens a new
+    /// one, atomically. `stablecoins.basket_id` only moves now if
+    /// `effective_at` has passed; the `find_*` and `list` queries resolve the
+    /// basket through the version history, so a scheduled migration takes
+    /// effect once its time comes.
+    #[tracing::instrument(name = "db.stablecoins.migrate_basket", skip_all, fields(component = "db", table = "stablecoins"), err)]
+    pub async fn migrate_basket(
+        &self,
+        stablecoin_id: Uuid,
+        basket_id: Uuid,
+        effective_at: DateTime<Utc>,
+    ) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE stablecoin_basket_versions
+            SET effective_to = $1
+            WHERE stablecoin_id = $2 AND effective_to IS NULL
+            "#,
+        )
+        .bind(effective_at)
+        .bind(stablecoin_id)
+        .execute(&mut *tx)
+        .timed("stablecoins.migrate_basket")
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO stablecoin_basket_versions (stablecoin_id, basket_id, effective_from)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(stablecoin_id)
+        .bind(basket_id)
+        .bind(effective_at)
+        .execute(&mut *tx)
+        .timed("stablecoins.migrate_basket")
+        .await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE stablecoins
+            SET basket_id = CASE WHEN $3 <= NOW() THEN $1 ELSE basket_id END,
+                updated_at = NOW()
+            WHERE id = $2
+            "#,
+        )
+        .bind(basket_id)
+        .bind(stablecoin_id)
+        .bind(effective_at)
+        .execute(&mut *tx)
+        .timed("stablecoins.migrate_basket")
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!("Stablecoin {}", stablecoin_id)));
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(
+            stablecoin_id = %stablecoin_id,
+            basket_id = %basket_id,
+            effective_at = %effective_at,
+            "Stablecoin basket migrated"
+        );
+
+        Ok(())
+    }
+
+    /// Returns the basket that backed the stablecoin `symbol` at time `at`
+    ///
+    /// Version ranges are half-open, so a timestamp exactly on a migration
+    /// boundary resolves to the new basket.
+    #[tracing::instrument(name = "db.stablecoins.basket_at", skip_all, fields(component = "db", table = "stablecoin_basket_versions"), err)]
+    pub async fn basket_at(
+        &self,
+        symbol: &str,
+        at: DateTime<Utc>,
+    ) -> Result<CurrencyBasket, DbError> {
+        let row = sqlx::query_as::<_, BasketRow>(
+            r#"
+            SELECT b.id, b.name, b.basket_type, b.components, b.rebalance_strategy,
+                   b.last_rebalanced, b.min_price_confidence, b.created_at, b.updated_at
+            FROM stablecoin_basket_versions v
+            JOIN stablecoins s ON s.id = v.stablecoin_id
+            JOIN baskets b ON b.id = v.basket_id
+            WHERE s.symbol = $1
+              AND v.effective_from <= $2
+              AND (v.effective_to IS NULL OR v.effective_to > $2)
+            ORDER BY v.effective_from DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(symbol)
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .timed("stablecoins.basket_at")
+        .await?
+        .ok_or_else(|| DbError::NotFound(format!("No basket backed {} at {}", symbol, at)))?;
+
+        row.to_basket().map_err(DbError::from)
+    }
+
+    /// Lists the basket version history for a stablecoin, oldest first
+    #[tracing::instrument(name = "db.stablecoins.basket_history", skip_all, fields(component = "db", table = "stablecoin_basket_versions"), err)]
+    pub async fn basket_history(
+        &self,
+        stablecoin_id: Uuid,
+    ) -> Result<Vec<StablecoinBasketVersionRow>, DbError> {
+        let rows = sqlx::query_as::<_, StablecoinBasketVersionRow>(
+            r#"
+            SELECT id, stablecoin_id, basket_id, effective_from, effective_to, created_at
+            FROM stablecoin_basket_versions
+            WHERE stablecoin_id = $1
+            ORDER BY effective_from ASC
+            "#,
+        )
+        .bind(stablecoin_id)
+        .fetch_all(&self.pool)
+        .timed("stablecoins.basket_history")
         .await?;
 
         Ok(rows)
     }
 
     /// Counts total number of stablecoins
+    #[tracing::instrument(name = "db.stablecoins.count", skip_all, fields(component = "db", table = "stablecoins"), err)]
     pub async fn count(&self) -> Result<i64, DbError> {
         let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM stablecoins")
             .fetch_one(&self.pool)
+            .timed("stablecoins.count")
             .await?;
 
         Ok(result.0)
@@ -0,0 +1,138 @@
+//! Serializable transactions with retry on transient conflicts
+//!
+//! Under concurrent writes Postgres aborts some `SERIALIZABLE` transactions
+//! with `40001` (serialization failure) or `40P01` (deadlock detected).
+//! Both mean "run it again": `with_retry` does so with exponential backoff.
+
+use crate::error::DbError;
+use crate::Pool;
+use sqlx::PgConnection;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// SQLSTATE for `serialization_failure`
+const SERIALIZATION_FAILURE: &str = "40001";
+
+/// SQLSTATE for `deadlock_detected`
+const DEADLOCK_DETECTED: &str = "40P01";
+
+/// Backoff before the first retry; doubles on each further attempt
+const BASE_RETRY_BACKOFF: Duration = Duration::from_millis(20);
+
+/// Upper bound on a single backoff
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Future returned by a `with_retry` transaction body
+pub type TxFuture<'c, T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>;
+
+/// Errors that may mark a transaction as safe to retry
+pub trait RetryableError {
+    /// Whether the failed transaction can simply be run again
+    fn is_retryable(&self) -> bool;
+}
+
+impl RetryableError for DbError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, DbError::TransactionConflict(_))
+    }
+}
+
+/// Whether `err` is a serialization failure or deadlock
+pub fn is_transient(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::Database(db_err) => {
+            matches!(db_err.code().as_deref(), Some(SERIALIZATION_FAILURE | DEADLOCK_DETECTED))
+        }
+        _ => false,
+    }
+}
+
+/// Runs `f` inside a `SERIALIZABLE` transaction, retrying transient conflicts
+///
+/// The transaction commits if `f` succeeds and rolls back otherwise. When
+/// `f` or the commit fails with an error for which `is_retryable` holds,
+/// the whole transaction is run again after an exponential backoff, up to
+/// `max_retries` extra attempts; then the last error is returned. `f` must
+/// therefore be safe to call more than once.
+///
+/// ```rust,no_run
+/// use meridian_db::{with_retry, DbError, Pool};
+///
+/// # async fn example(pool: &Pool) -> Result<(), DbError> {
+/// let id: i32 = with_retry(pool, 3, |conn| {
+///     Box::pin(async move {
+///         let (id,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(conn).await?;
+///         Ok::<_, DbError>(id)
+///     })
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_retry<T, E, F>(pool: &Pool, max_retries: u32, mut f: F) -> Result<T, E>
+where
+    F: for<'c> FnMut(&'c mut PgConnection) -> TxFuture<'c, T, E>,
+    E: From<DbError> + RetryableError,
+{
+    let mut attempt = 0;
+    loop {
+        match run_serializable(pool, &mut f).await {
+            Err(err) if attempt < max_retries && err.is_retryable() => {
+                let backoff = retry_backoff(attempt);
+                attempt += 1;
+                tracing::warn!(
+                    attempt,
+                    max_retries,
+                    backoff_ms = backoff.as_millis() as u64,
+                    "Transaction conflict, retrying"
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+async fn run_serializable<T, E, F>(pool: &Pool, f: &mut F) -> Result<T, E>
+where
+    F: for<'c> FnMut(&'c mut PgConnection) -> TxFuture<'c, T, E>,
+    E: From<DbError>,
+{
+    let mut tx = pool.begin().await.map_err(DbError::from)?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE")
+        .execute(&mut *tx)
+        .await
+        .map_err(DbError::from)?;
+    let value = f(&mut tx).await?;
+    tx.commit().await.map_err(DbError::from)?;
+    Ok(value)
+}
+
+/// Backoff before retry number `attempt + 1`
+fn retry_backoff(attempt: u32) -> Duration {
+    BASE_RETRY_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_cap() {
+        assert_eq!(retry_backoff(0), Duration::from_millis(20));
+        assert_eq!(retry_backoff(1), Duration::from_millis(40));
+        assert_eq!(retry_backoff(3), Duration::from_millis(160));
+        assert_eq!(retry_backoff(10), MAX_RETRY_BACKOFF);
+        assert_eq!(retry_backoff(u32::MAX), MAX_RETRY_BACKOFF);
+    }
+
+    #[test]
+    fn test_only_conflicts_are_retryable() {
+        assert!(DbError::TransactionConflict("40001".to_string()).is_retryable());
+        assert!(!DbError::QueryError("syntax".to_string()).is_retryable());
+        assert!(!is_transient(&sqlx::Error::RowNotFound));
+    }
+}
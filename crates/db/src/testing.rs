@@ -0,0 +1,71 @@
+//! Postgres fixture for integration tests
+//!
+//! [`TestDb::start`] connects to `DATABASE_URL` when it is set. Otherwise it
+//! starts a throwaway Postgres container (testcontainers) that lives as long
+//! as the fixture. Either way migrations are applied before the pool is
+//! handed out. With no database and no Docker daemon it returns `None` so the
+//! calling test can skip.
+//!
+//! Enabled by the `test-harness` feature; never compiled into release builds.
+
+use crate::{create_pool, run_migrations, Pool};
+use testcontainers_modules::postgres::Postgres;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, ImageExt};
+
+/// Postgres image tag used for ephemeral databases
+const POSTGRES_TAG: &str = "16-alpine";
+
+/// A migrated database for one test
+pub struct TestDb {
+    /// Pool connected to the test database
+    pub pool: Pool,
+    /// Keeps the container alive; dropping it removes the database
+    _container: Option<ContainerAsync<Postgres>>,
+}
+
+impl TestDb {
+    /// Connects to (or starts) a database and runs migrations
+    ///
+    /// # Panics
+    ///
+    /// Panics if a database was reached but could not be migrated, since that
+    /// is a real failure rather than a missing environment.
+    pub async fn start() -> Option<Self> {
+        let (database_url, container) = match std::env::var("DATABASE_URL") {
+            Ok(url) => (url, None),
+            Err(_) => match start_container().await {
+                Ok((url, container)) => (url, Some(container)),
+                Err(e) => {
+                    println!("Skipping test: DATABASE_URL not set and no ephemeral Postgres ({})", e);
+                    return None;
+                }
+            },
+        };
+
+        let pool = create_pool(&database_url)
+            .await
+            .expect("Failed to create pool");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+
+        Some(Self {
+            pool,
+            _container: container,
+        })
+    }
+
+    /// Whether this fixture owns a throwaway container
+    pub fn is_ephemeral(&self) -> bool {
+        self._container.is_some()
+    }
+}
+
+async fn start_container() -> Result<(String, ContainerAsync<Postgres>), Box<dyn std::error::Error>> {
+    let container = Postgres::default().with_tag(POSTGRES_TAG).start().await?;
+    let host = container.get_host().await?;
+    let port = container.get_host_port_ipv4(5432).await?;
+    let url = format!("postgres://postgres:postgres@{}:{}/postgres", host, port);
+    Ok((url, container))
+}
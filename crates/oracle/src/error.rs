@@ -20,6 +20,26 @@ pub enum OracleError {
         deviation: Decimal,
     },
 
+    #[error("Price anomaly for {pair}: {price} is more than {z_threshold} standard deviations from recent prices")]
+    PriceAnomaly {
+        pair: String,
+        price: Decimal,
+        z_threshold: Decimal,
+    },
+
+    #[error("Price feed {pair} reports {reported} decimals, expected {expected}")]
+    DecimalsMismatch {
+        pair: String,
+        expected: u8,
+        reported: u8,
+    },
+
+    #[error("Too few non-stale price sources for {0}: {1} responded")]
+    InsufficientSources(String, usize),
+
+    #[error("No price history for {0}")]
+    InsufficientHistory(String),
+
     #[error("Invalid price data: {0}")]
     InvalidPrice(String),
 
@@ -29,11 +49,20 @@ pub enum OracleError {
     #[error("Provider error: {0}")]
     ProviderError(String),
 
+    #[error("Multicall3 address not configured")]
+    MulticallNotConfigured,
+
     #[error("Contract call failed: {0}")]
     ContractError(String),
 
     #[error("Decimal conversion error: {0}")]
     DecimalConversion(String),
+
+    #[error("Required price feeds not registered: {}", .0.join(", "))]
+    MissingRequiredFeeds(Vec<String>),
+
+    #[error("Required price feeds could not be refreshed: {}", .0.join("; "))]
+    FeedRefreshFailed(Vec<String>),
 }
 
 // Convert ethers provider errors
@@ -0,0 +1,32 @@
+//! Domain events emitted by the oracle
+//!
+//! `ChainlinkOracle::subscribe` hands out a broadcast receiver so webhook
+//! dispatch, metrics, or alerting can react to oracle conditions without the
+//! oracle knowing about them. Events are dropped when nobody is subscribed.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Buffered events per subscriber before slow receivers start lagging
+pub const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// Events published by the oracle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum OracleEvent {
+    /// A refreshed price moved further than the deviation threshold and was rejected
+    PriceDeviationDetected {
+        pair: String,
+        old: Decimal,
+        new: Decimal,
+        /// Absolute change in percent
+        deviation: Decimal,
+    },
+    /// A refreshed price was statistically out of line with recent history and was rejected
+    PriceAnomalyDetected {
+        pair: String,
+        price: Decimal,
+        /// Z-score threshold the price exceeded
+        z_threshold: Decimal,
+    },
+}
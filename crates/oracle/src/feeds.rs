@@ -28,6 +28,9 @@ pub mod mainnet_feeds {
     static MXN_USD: OnceLock<Address> = OnceLock::new();
     static INR_USD: OnceLock<Address> = OnceLock::new();
 
+    /// Decimals reported by every fiat `{CUR}/USD` feed below
+    pub const FX_FEED_DECIMALS: u8 = 8;
+
     /// Helper to parse address, falling back to zero address if somehow invalid
     /// (should never happen with hardcoded valid addresses)
     fn parse_address(hex: &str) -> Address {
@@ -80,6 +83,23 @@ pub mod mainnet_feeds {
     pub fn inr_usd() -> Address {
         *INR_USD.get_or_init(|| parse_address("0x605D5c2fBCeDb217D7987FC0951B5753069bC360"))
     }
+
+    /// Returns the `{CUR}/USD` feed address for a currency code (case-insensitive)
+    ///
+    /// `None` for currencies without a known feed, including USD itself.
+    pub fn feed_for_currency(currency_code: &str) -> Option<Address> {
+        match currency_code.to_uppercase().as_str() {
+            "EUR" => Some(eur_usd()),
+            "GBP" => Some(gbp_usd()),
+            "JPY" => Some(jpy_usd()),
+            "CNY" => Some(cny_usd()),
+            "CHF" => Some(chf_usd()),
+            "BRL" => Some(brl_usd()),
+            "MXN" => Some(mxn_usd()),
+            "INR" => Some(inr_usd()),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +128,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_feed_for_currency() {
+        assert_eq!(mainnet_feeds::feed_for_currency("eur"), Some(mainnet_feeds::eur_usd()));
+        assert_eq!(mainnet_feeds::feed_for_currency("JPY"), Some(mainnet_feeds::jpy_usd()));
+        assert_eq!(mainnet_feeds::feed_for_currency("USD"), None);
+        assert_eq!(mainnet_feeds::feed_for_currency("XYZ"), None);
+    }
+
     #[test]
     fn test_all_feeds_are_non_zero() {
         // Ensure none of our hardcoded addresses resolve to zero
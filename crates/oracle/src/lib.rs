@@ -8,8 +8,13 @@
 //! - Connect to Chainlink price feeds on Ethereum mainnet
 //! - Query real-time FX rates for 20+ currency pairs
 //! - Automatic staleness detection (>1 hour)
-//! - Deviation threshold monitoring
+//! - Background refresh task to keep cached prices warm
+//! - Deviation threshold monitoring with `OracleEvent` notifications, against
+//!   the last price or a short TWAP baseline
 //! - Support for multiple price feed sources (Chainlink primary)
+//! - Median aggregation over several feeds per pair, dropping stale sources
+//! - Batched refreshes through Multicall3 in a single RPC request
+//! - TWAP over a feed's on-chain round history via `getRoundData`
 //!
 //! ## Example
 //!
@@ -30,9 +35,11 @@
 //! ```
 
 mod error;
+mod events;
 mod feeds;
 mod oracle;
 
 pub use error::OracleError;
+pub use events::OracleEvent;
 pub use feeds::mainnet_feeds;
-pub use oracle::{ChainlinkOracle, PriceFeed, PriceFeedConfig};
+pub use oracle::{ChainlinkOracle, DeviationReference, FeedStaleness, PriceFeed, PriceFeedConfig};
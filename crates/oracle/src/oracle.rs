@@ -1,20 +1,24 @@
 //! Chainlink oracle client implementation
 
 use crate::error::OracleError;
+use crate::events::{OracleEvent, EVENT_CHANNEL_CAPACITY};
 use chrono::{DateTime, Utc};
 use ethers::{
-    contract::abigen,
+    abi::Tokenizable,
+    contract::{abigen, Multicall},
     providers::{Http, Middleware, Provider},
     types::{Address, I256, U256},
 };
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::RwLock;
-use tokio::time::timeout;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, RwLock, Semaphore};
+use tokio::task::JoinHandle;
+use tokio::time::{timeout, MissedTickBehavior};
 
 /// Configuration for a price feed
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,16 +40,132 @@ pub struct PriceFeed {
     pub address: Address,
     /// Number of decimals in the price
     pub decimals: u8,
+    /// Decimals the feed must report for `update_price` to accept it; catches
+    /// an address that points at e.g. an 18-decimal token feed instead of an
+    /// 8-decimal FX feed
+    #[serde(default)]
+    pub expected_decimals: Option<u8>,
     /// Latest price in USD
     pub latest_price: Decimal,
-    /// Latest round ID
+    /// Latest round ID (zero for aggregated feeds, whose sources have
+    /// independent rounds)
     pub latest_round: U256,
     /// Timestamp of last update
     pub updated_at: DateTime<Utc>,
-    /// Whether the price is stale (>1 hour old)
+    /// Whether the price is stale (older than the feed's staleness threshold)
     pub is_stale: bool,
     /// Human-readable description from contract
     pub description: String,
+    /// Overrides the oracle-wide staleness threshold for this pair, for
+    /// feeds with a long heartbeat (e.g. JPY/USD)
+    #[serde(default)]
+    pub stale_threshold_secs: Option<u64>,
+}
+
+impl PriceFeed {
+    /// This feed's staleness threshold: its override, else `default_seconds`
+    pub fn stale_threshold(&self, default_seconds: u64) -> u64 {
+        self.stale_threshold_secs.unwrap_or(default_seconds)
+    }
+
+    /// Confidence in `latest_price`, from 1 (just updated) falling linearly
+    /// to 0 once the price is `stale_threshold_seconds` old
+    ///
+    /// Feeds that have never been read, or are flagged stale, have zero confidence.
+    pub fn confidence(&self, stale_threshold_seconds: u64) -> Decimal {
+        self.confidence_at(Utc::now(), stale_threshold_seconds)
+    }
+
+    /// `confidence` evaluated at a given time
+    pub fn confidence_at(&self, now: DateTime<Utc>, stale_threshold_seconds: u64) -> Decimal {
+        if self.is_stale || self.latest_price <= Decimal::ZERO || stale_threshold_seconds == 0 {
+            return Decimal::ZERO;
+        }
+
+        let age = (now - self.updated_at).num_seconds().max(0) as u64;
+        if age >= stale_threshold_seconds {
+            return Decimal::ZERO;
+        }
+
+        Decimal::ONE - Decimal::from(age) / Decimal::from(stale_threshold_seconds)
+    }
+}
+
+/// Age of a single feed's cached price, for freshness monitoring
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedStaleness {
+    /// Currency pair (e.g., "EUR/USD")
+    pub pair: String,
+    /// Seconds since the cached price was last updated on chain
+    pub age_seconds: u64,
+    /// Whether the feed is flagged stale (never read, or older than the threshold)
+    pub is_stale: bool,
+}
+
+/// Baseline a refreshed price is compared against for the deviation check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DeviationReference {
+    /// The previously cached price (sensitive to single-round noise)
+    #[default]
+    LastPrice,
+    /// Time-weighted average of recent observations; falls back to the last
+    /// price until the feed has history
+    Twap { window_seconds: u64 },
+}
+
+/// Observed prices per pair, oldest first, keyed by on-chain update time
+type PriceHistory = HashMap<String, VecDeque<(DateTime<Utc>, Decimal)>>;
+
+/// Observations kept per pair for TWAP calculations
+const PRICE_HISTORY_CAPACITY: usize = 256;
+
+/// Default z-score above which a refreshed price is treated as anomalous
+pub const DEFAULT_ANOMALY_Z_SCORE: Decimal = Decimal::from_parts(4, 0, 0, false, 0);
+
+/// Default number of recent observations the z-score is measured against
+pub const DEFAULT_ANOMALY_WINDOW: usize = 30;
+
+/// Observations needed before the anomaly check has an opinion
+const ANOMALY_MIN_SAMPLES: usize = 10;
+
+/// Consecutive anomalous rounds, all on the same side of the recent mean,
+/// after which the new level is accepted as a genuine shift
+const ANOMALY_CONFIRMATIONS: usize = 3;
+
+/// Default number of non-stale sources an aggregated feed needs to update
+pub const DEFAULT_MIN_AGGREGATED_SOURCES: usize = 2;
+
+/// One underlying Chainlink aggregator of an aggregated pair
+#[derive(Debug, Clone, Copy)]
+struct FeedSource {
+    address: Address,
+    decimals: u8,
+}
+
+/// A price read from one aggregator
+#[derive(Debug, Clone, Copy)]
+struct RoundData {
+    round_id: U256,
+    price: Decimal,
+    /// Unix seconds of the round's on-chain update
+    updated_at: u64,
+}
+
+/// Raw `latestRoundData` return: (roundId, answer, startedAt, updatedAt, answeredInRound)
+type LatestRound = (u128, I256, U256, U256, u128);
+
+/// Most historical rounds `get_twap` reads, bounding its RPC calls
+const MAX_TWAP_ROUNDS: usize = 256;
+
+/// A pair to refresh in `update_prices_batch`, with its pre-refresh state
+#[derive(Debug)]
+struct BatchRead {
+    pair: String,
+    old_price: Decimal,
+    old_is_stale: bool,
+    stale_threshold: u64,
+    sources: Vec<FeedSource>,
+    aggregated: bool,
 }
 
 // Generate Chainlink AggregatorV3Interface bindings
@@ -53,6 +173,7 @@ abigen!(
     ChainlinkAggregatorV3,
     r#"[
         function latestRoundData() external view returns (uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound)
+        function getRoundData(uint80 _roundId) external view returns (uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound)
         function decimals() external view returns (uint8)
         function description() external view returns (string memory)
         function version() external view returns (uint256)
@@ -62,6 +183,9 @@ abigen!(
 /// Default timeout for RPC calls (30 seconds)
 const RPC_TIMEOUT_SECS: u64 = 30;
 
+/// Default maximum number of concurrent RPC calls made by batch operations
+pub const DEFAULT_RPC_CONCURRENCY: usize = 8;
+
 /// Chainlink oracle client for querying FX price feeds
 ///
 /// Connects to Ethereum mainnet and queries Chainlink price feed aggregators
@@ -75,6 +199,32 @@ pub struct ChainlinkOracle {
     deviation_threshold: Decimal,
     /// Staleness threshold in seconds (default: 3600 = 1 hour)
     stale_threshold_seconds: u64,
+    /// Incremented whenever cached prices change; lets callers cache derived
+    /// values (e.g. basket valuations) until the next refresh
+    price_epoch: AtomicU64,
+    /// Domain events (e.g. deviation alarms) for subscribers
+    events: broadcast::Sender<OracleEvent>,
+    /// Bounds in-flight RPC calls during batch refreshes so a rate-limited
+    /// provider is not flooded
+    rpc_permits: Arc<Semaphore>,
+    /// Number of permits in `rpc_permits`
+    rpc_concurrency: usize,
+    /// Recent observations per pair, for `get_observed_twap`
+    price_history: Arc<RwLock<PriceHistory>>,
+    /// What the deviation check compares new prices against
+    deviation_reference: DeviationReference,
+    /// Z-score above which a refresh is rejected as anomalous (zero disables)
+    anomaly_z_threshold: Decimal,
+    /// Recent observations per pair used for the anomaly z-score
+    anomaly_window: usize,
+    /// Anomalous rounds per pair held back until they confirm a shift
+    anomaly_quarantine: Arc<RwLock<PriceHistory>>,
+    /// Underlying sources of pairs registered via `register_aggregated_feed`
+    aggregated_sources: Arc<RwLock<HashMap<String, Vec<FeedSource>>>>,
+    /// Non-stale sources an aggregated pair needs for `update_price` to succeed
+    min_aggregated_sources: usize,
+    /// Multicall3 contract used by `update_prices_batch`
+    multicall_address: Option<Address>,
 }
 
 impl ChainlinkOracle {
@@ -122,9 +272,44 @@ impl ChainlinkOracle {
             price_feeds: Arc::new(RwLock::new(HashMap::new())),
             deviation_threshold,
             stale_threshold_seconds: 3600, // 1 hour
+            price_epoch: AtomicU64::new(0),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            rpc_permits: Arc::new(Semaphore::new(DEFAULT_RPC_CONCURRENCY)),
+            rpc_concurrency: DEFAULT_RPC_CONCURRENCY,
+            price_history: Arc::new(RwLock::new(HashMap::new())),
+            deviation_reference: DeviationReference::LastPrice,
+            anomaly_z_threshold: DEFAULT_ANOMALY_Z_SCORE,
+            anomaly_window: DEFAULT_ANOMALY_WINDOW,
+            anomaly_quarantine: Arc::new(RwLock::new(HashMap::new())),
+            aggregated_sources: Arc::new(RwLock::new(HashMap::new())),
+            min_aggregated_sources: DEFAULT_MIN_AGGREGATED_SOURCES,
+            multicall_address: None,
         })
     }
 
+    /// Oracle with no feeds, default settings, and an unreachable provider
+    #[cfg(test)]
+    fn for_test() -> Self {
+        Self {
+            provider: Arc::new(Provider::<Http>::try_from("http://127.0.0.1:1").unwrap()),
+            price_feeds: Arc::new(RwLock::new(HashMap::new())),
+            deviation_threshold: Decimal::new(10, 0),
+            stale_threshold_seconds: 3600,
+            price_epoch: AtomicU64::new(0),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            rpc_permits: Arc::new(Semaphore::new(DEFAULT_RPC_CONCURRENCY)),
+            rpc_concurrency: DEFAULT_RPC_CONCURRENCY,
+            price_history: Arc::new(RwLock::new(HashMap::new())),
+            deviation_reference: DeviationReference::LastPrice,
+            anomaly_z_threshold: DEFAULT_ANOMALY_Z_SCORE,
+            anomaly_window: DEFAULT_ANOMALY_WINDOW,
+            anomaly_quarantine: Arc::new(RwLock::new(HashMap::new())),
+            aggregated_sources: Arc::new(RwLock::new(HashMap::new())),
+            min_aggregated_sources: DEFAULT_MIN_AGGREGATED_SOURCES,
+            multicall_address: None,
+        }
+    }
+
     /// Registers a new price feed for a currency pair
     ///
     /// # Arguments
@@ -156,32 +341,54 @@ impl ChainlinkOracle {
         &self,
         pair: &str,
         address: Address,
+    ) -> Result<(), OracleError> {
+        self.register_price_feed_with_decimals(pair, address, None).await
+    }
+
+    /// Registers a price feed whose answers must use `expected_decimals`
+    ///
+    /// The feed is registered even if the contract reports different
+    /// decimals; every `update_price` on it then fails with
+    /// `OracleError::DecimalsMismatch` until it is re-registered. `None`
+    /// accepts whatever the contract reports, like `register_price_feed`.
+    pub async fn register_price_feed_with_decimals(
+        &self,
+        pair: &str,
+        address: Address,
+        expected_decimals: Option<u8>,
+    ) -> Result<(), OracleError> {
+        self.register_feed(pair, address, expected_decimals, None).await
+    }
+
+    /// Registers a price feed with its own staleness threshold
+    ///
+    /// `update_price` and `get_price` treat the pair as stale only once its
+    /// price is older than `stale_threshold_secs`; other pairs keep using
+    /// the oracle-wide `stale_threshold()`.
+    pub async fn register_price_feed_with_config(
+        &self,
+        pair: &str,
+        address: Address,
+        stale_threshold_secs: u64,
+    ) -> Result<(), OracleError> {
+        self.register_feed(pair, address, None, Some(stale_threshold_secs)).await
+    }
+
+    async fn register_feed(
+        &self,
+        pair: &str,
+        address: Address,
+        expected_decimals: Option<u8>,
+        stale_threshold_secs: Option<u64>,
     ) -> Result<(), OracleError> {
         tracing::info!(
             pair = %pair,
             address = %address,
+            stale_threshold_secs = ?stale_threshold_secs,
             "Registering price feed"
         );
 
-        // Create contract instance
-        let aggregator = ChainlinkAggregatorV3::new(address, Arc::clone(&self.provider));
-
-        // Query contract metadata (with timeout)
-        let decimals = timeout(
-            Duration::from_secs(RPC_TIMEOUT_SECS),
-            aggregator.decimals().call(),
-        )
-        .await
-        .map_err(|_| OracleError::ContractError("RPC timeout getting decimals".to_string()))?
-        .map_err(|e| OracleError::ContractError(format!("Failed to get decimals: {}", e)))?;
-
-        let description = timeout(
-            Duration::from_secs(RPC_TIMEOUT_SECS),
-            aggregator.description().call(),
-        )
-        .await
-        .map_err(|_| OracleError::ContractError("RPC timeout getting description".to_string()))?
-        .map_err(|e| OracleError::ContractError(format!("Failed to get description: {}", e)))?;
+        let (decimals, description) = self.read_feed_metadata(address).await?;
 
         tracing::info!(
             pair = %pair,
@@ -190,25 +397,113 @@ impl ChainlinkOracle {
             "Price feed metadata retrieved"
         );
 
+        if let Some(expected) = expected_decimals.filter(|expected| *expected != decimals) {
+            tracing::error!(
+                pair = %pair,
+                expected = expected,
+                reported = decimals,
+                "Price feed decimals do not match expected; updates will be rejected"
+            );
+        }
+
         // Create initial feed entry (marked as stale until first update)
         let feed = PriceFeed {
             pair: pair.to_string(),
             address,
             decimals,
+            expected_decimals,
             latest_price: Decimal::ZERO,
             latest_round: U256::zero(),
             updated_at: Utc::now(),
             is_stale: true,
             description,
+            stale_threshold_secs,
         };
 
-        // Store in registry
+        // Store in registry, replacing any aggregated feed for the pair
+        self.aggregated_sources.write().await.remove(pair);
         let mut feeds = self.price_feeds.write().await;
         feeds.insert(pair.to_string(), feed);
 
         Ok(())
     }
 
+    /// Registers a pair priced as the median of several Chainlink feeds
+    ///
+    /// `update_price` on the pair reads every source, discards stale or
+    /// failing ones, and stores the median of the rest, so one bad round
+    /// cannot move the price. It fails with `OracleError::InsufficientSources`
+    /// when fewer than `min_aggregated_sources()` sources are usable.
+    ///
+    /// # Errors
+    ///
+    /// `InsufficientSources` if fewer addresses than the minimum are given;
+    /// `ContractError` if any source's metadata cannot be read.
+    pub async fn register_aggregated_feed(
+        &self,
+        pair: &str,
+        addresses: Vec<Address>,
+    ) -> Result<(), OracleError> {
+        if addresses.len() < self.min_aggregated_sources {
+            return Err(OracleError::InsufficientSources(pair.to_string(), addresses.len()));
+        }
+        tracing::info!(
+            pair = %pair,
+            sources = addresses.len(),
+            "Registering aggregated price feed"
+        );
+
+        let mut sources = Vec::with_capacity(addresses.len());
+        let mut descriptions = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let (decimals, description) = self.read_feed_metadata(address).await?;
+            sources.push(FeedSource { address, decimals });
+            descriptions.push(description);
+        }
+
+        let feed = PriceFeed {
+            pair: pair.to_string(),
+            address: sources[0].address,
+            decimals: sources[0].decimals,
+            expected_decimals: None,
+            latest_price: Decimal::ZERO,
+            latest_round: U256::zero(),
+            updated_at: Utc::now(),
+            is_stale: true,
+            description: format!("Median of {}", descriptions.join(", ")),
+            stale_threshold_secs: None,
+        };
+
+        self.aggregated_sources.write().await.insert(pair.to_string(), sources);
+        self.price_feeds.write().await.insert(pair.to_string(), feed);
+
+        Ok(())
+    }
+
+    /// Reads an aggregator's `decimals()` and `description()`
+    async fn read_feed_metadata(&self, address: Address) -> Result<(u8, String), OracleError> {
+        let aggregator = ChainlinkAggregatorV3::new(address, Arc::clone(&self.provider));
+
+        // Query contract metadata (with timeout)
+        let decimals = timeout(
+            Duration::from_secs(RPC_TIMEOUT_SECS),
+            aggregator.decimals().call(),
+        )
+        .await
+        .map_err(|_| OracleError::ContractError("RPC timeout getting decimals".to_string()))?
+        .map_err(|e| OracleError::ContractError(format!("Failed to get decimals: {}", e)))?;
+
+        let description = timeout(
+            Duration::from_secs(RPC_TIMEOUT_SECS),
+            aggregator.description().call(),
+        )
+        .await
+        .map_err(|_| OracleError::ContractError("RPC timeout getting description".to_string()))?
+        .map_err(|e| OracleError::ContractError(format!("Failed to get description: {}", e)))?;
+
+        Ok((decimals, description))
+    }
+
     /// Gets the current price for a currency pair
     ///
     /// Returns cached price if available and not stale. Use `update_price()`
@@ -236,15 +531,29 @@ impl ChainlinkOracle {
     /// # Ok(())
     /// # }
     /// ```
+    #[tracing::instrument(
+        name = "oracle.get_price",
+        skip(self),
+        fields(component = "oracle", duration_ms = tracing::field::Empty, outcome = tracing::field::Empty)
+    )]
     pub async fn get_price(&self, pair: &str) -> Result<Decimal, OracleError> {
+        let start = Instant::now();
+        let result = self.read_cached_price(pair).await;
+        record_outcome(start, &result);
+        result
+    }
+
+    async fn read_cached_price(&self, pair: &str) -> Result<Decimal, OracleError> {
         let feeds = self.price_feeds.read().await;
 
         let feed = feeds
             .get(pair)
             .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))?;
 
-        if feed.is_stale {
-            let age = (Utc::now() - feed.updated_at).num_seconds() as u64;
+        // The flag is only set on refresh, so also catch prices that have
+        // aged past the feed's threshold since
+        let age = (Utc::now() - feed.updated_at).num_seconds().max(0) as u64;
+        if feed.is_stale || age > feed.stale_threshold(self.stale_threshold_seconds) {
             return Err(OracleError::StalePrice(pair.to_string(), age));
         }
 
@@ -272,13 +581,26 @@ impl ChainlinkOracle {
     /// # Ok(())
     /// # }
     /// ```
+    #[tracing::instrument(
+        name = "oracle.update_price",
+        skip(self),
+        fields(component = "oracle", duration_ms = tracing::field::Empty, outcome = tracing::field::Empty)
+    )]
     pub async fn update_price(&self, pair: &str) -> Result<Decimal, OracleError> {
+        let start = Instant::now();
+        let result = self.refresh_price(pair).await;
+        record_outcome(start, &result);
+        result
+    }
+
+    async fn refresh_price(&self, pair: &str) -> Result<Decimal, OracleError> {
         // Get feed info (need to release lock before contract call)
         let (address, decimals, old_price, old_is_stale) = {
             let feeds = self.price_feeds.read().await;
             let feed = feeds
                 .get(pair)
                 .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))?;
+            check_decimals(feed)?;
             (
                 feed.address,
                 feed.decimals,
@@ -287,76 +609,61 @@ impl ChainlinkOracle {
             )
         };
 
-        // Create contract instance
-        let aggregator = ChainlinkAggregatorV3::new(address, Arc::clone(&self.provider));
-
-        // Query latest round data (with timeout)
-        let (round_id, answer, _started_at, updated_at, _answered_in_round) = timeout(
-            Duration::from_secs(RPC_TIMEOUT_SECS),
-            aggregator.latest_round_data().call(),
-        )
-        .await
-        .map_err(|_| OracleError::ContractError("RPC timeout getting latest round data".to_string()))?
-        .map_err(|e| {
-            OracleError::ContractError(format!("Failed to get latest round data: {}", e))
-        })?;
+        let sources = self.aggregated_sources.read().await.get(pair).cloned();
+        let round = match sources {
+            Some(sources) => self.read_median_round(pair, &sources).await?,
+            None => self.read_round(pair, FeedSource { address, decimals }).await?,
+        };
 
-        tracing::debug!(
-            pair = %pair,
-            round_id = %round_id,
-            answer = %answer,
-            updated_at = %updated_at,
-            "Retrieved latest round data"
-        );
+        self.apply_round(pair, old_price, old_is_stale, round).await
+    }
 
-        // Convert Chainlink answer to Decimal
-        let price = self.chainlink_answer_to_decimal(answer, decimals)?;
+    /// Runs the staleness, deviation and anomaly checks on a freshly read
+    /// round and, if it passes, stores it as the pair's latest price
+    async fn apply_round(
+        &self,
+        pair: &str,
+        old_price: Decimal,
+        old_is_stale: bool,
+        round: RoundData,
+    ) -> Result<Decimal, OracleError> {
+        let RoundData { round_id, price, updated_at } = round;
 
         // Check staleness
+        let threshold = self.stale_threshold_for(pair).await;
         let now = Utc::now().timestamp() as u64;
-        let price_age = now.saturating_sub(updated_at.as_u64());
-        let is_stale = price_age > self.stale_threshold_seconds;
+        let price_age = now.saturating_sub(updated_at);
+        let is_stale = price_age > threshold;
 
         if is_stale {
             tracing::warn!(
                 pair = %pair,
                 age_seconds = %price_age,
-                threshold = %self.stale_threshold_seconds,
+                threshold = %threshold,
                 "Price is stale"
             );
         }
 
-        // Check for excessive price deviation (if not first update)
-        if !old_is_stale && old_price != Decimal::ZERO {
-            let deviation = ((price - old_price) / old_price * Decimal::new(100, 0)).abs();
-
-            if deviation > self.deviation_threshold {
-                tracing::warn!(
-                    pair = %pair,
-                    old_price = %old_price,
-                    new_price = %price,
-                    deviation = %deviation,
-                    threshold = %self.deviation_threshold,
-                    "Large price deviation detected"
-                );
+        let observed_at =
+            DateTime::from_timestamp(updated_at as i64, 0).unwrap_or_else(Utc::now);
 
-                return Err(OracleError::PriceDeviation {
-                    pair: pair.to_string(),
-                    old_price,
-                    new_price: price,
-                    deviation,
-                });
-            }
+        // Check for excessive price deviation (if not first update)
+        if !old_is_stale {
+            let baseline = self.deviation_baseline(pair, old_price, Utc::now()).await;
+            self.check_deviation(pair, baseline, price)?;
+            self.check_anomaly(pair, observed_at, price).await?;
         }
 
+        self.record_observation(pair, observed_at, price).await;
+
         // Update stored feed
         let mut feeds = self.price_feeds.write().await;
         if let Some(feed) = feeds.get_mut(pair) {
             feed.latest_price = price;
-            feed.latest_round = round_id.into();
-            feed.updated_at =
-                DateTime::from_timestamp(updated_at.as_u64() as i64, 0).unwrap_or_else(Utc::now);
+            feed.latest_round = round_id;
+            feed.updated_at = observed_at;
             feed.is_stale = is_stale;
+            self.price_epoch.fetch_add(1, Ordering::SeqCst);
 
             tracing::info!(
                 pair = %pair,
@@ -370,61 +677,779 @@ impl ChainlinkOracle {
         Ok(price)
     }
 
-    /// Gets information about a registered price feed
-    ///
-    /// # Arguments
+    /// Reads the latest round of one aggregator
+    async fn read_round(&self, pair: &str, source: FeedSource) -> Result<RoundData, OracleError> {
+        // Create contract instance
+        let aggregator = ChainlinkAggregatorV3::new(source.address, Arc::clone(&self.provider));
+
+        // Query latest round data (with timeout)
+        let latest = timeout(
+            Duration::from_secs(RPC_TIMEOUT_SECS),
+            aggregator.latest_round_data().call(),
+        )
+        .await
+        .map_err(|_| OracleError::ContractError("RPC timeout getting latest round data".to_string()))?
+        .map_err(|e| {
+            OracleError::ContractError(format!("Failed to get latest round data: {}", e))
+        })?;
+
+        self.round_from_latest(pair, source, latest)
+    }
+
+    /// Converts a raw `latestRoundData` return into a `RoundData`
+    fn round_from_latest(&self, pair: &str, source: FeedSource, latest: LatestRound) -> Result<RoundData, OracleError> {
+        let (round_id, answer, _started_at, updated_at, _answered_in_round) = latest;
+
+        tracing::debug!(
+            pair = %pair,
+            address = %source.address,
+            round_id = %round_id,
+            answer = %answer,
+            updated_at = %updated_at,
+            "Retrieved latest round data"
+        );
+
+        // Convert Chainlink answer to Decimal
+        let price = self.chainlink_answer_to_decimal(answer, source.decimals)?;
+
+        Ok(RoundData {
+            round_id: round_id.into(),
+            price,
+            updated_at: updated_at.as_u64(),
+        })
+    }
+
+    /// Reads every source of an aggregated pair and takes the median
+    async fn read_median_round(&self, pair: &str, sources: &[FeedSource]) -> Result<RoundData, OracleError> {
+        // Not bounded by `rpc_permits`: `update_all_prices` already holds a
+        // permit for this pair, and waiting on more could deadlock the batch
+        let reads = futures::future::join_all(
+            sources.iter().map(|source| async move { (source.address, self.read_round(pair, *source).await) }),
+        )
+        .await;
+
+        let now = Utc::now().timestamp() as u64;
+        let threshold = self.stale_threshold_for(pair).await;
+        median_of_fresh(pair, reads, now, threshold, self.min_aggregated_sources)
+    }
+
+    /// Refreshes every registered feed from the blockchain
     ///
-    /// * `pair` - Currency pair
+    /// Feeds are updated independently; a failing feed does not stop the
+    /// others. Always advances the price epoch, even if every feed failed, so
+    /// anything cached against the previous epoch is recomputed.
     ///
-    /// # Example
+    /// At most `rpc_concurrency()` feeds are read from chain at once.
     ///
-    /// ```rust,no_run
-    /// # use meridian_oracle::ChainlinkOracle;
-    /// # use rust_decimal::Decimal;
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let oracle = ChainlinkOracle::new("http://localhost:8545", Decimal::new(10, 0)).await?;
-    /// let feed = oracle.get_feed_info("EUR/USD").await?;
-    /// println!("Feed: {} ({})", feed.description, feed.address);
-    /// println!("Decimals: {}", feed.decimals);
-    /// println!("Latest price: ${}", feed.latest_price);
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub async fn get_feed_info(&self, pair: &str) -> Result<PriceFeed, OracleError> {
-        let feeds = self.price_feeds.read().await;
-        feeds
-            .get(pair)
-            .cloned()
-            .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))
-    }
+    /// Returns the refreshed prices and the per-pair errors.
+    pub async fn update_all_prices(&self) -> (HashMap<String, Decimal>, HashMap<String, OracleError>) {
+        let pairs = self.list_feeds().await;
+        let mut prices = HashMap::new();
+        let mut errors = HashMap::new();
 
-    /// Lists all registered price feeds
-    pub async fn list_feeds(&self) -> Vec<String> {
-        let feeds = self.price_feeds.read().await;
-        feeds.keys().cloned().collect()
+        let results = bounded_join(&self.rpc_permits, pairs, |pair| async move {
+            let result = self.update_price(&pair).await;
+            (pair, result)
+        })
+        .await;
+
+        for (pair, result) in results {
+            match result {
+                Ok(price) => {
+                    prices.insert(pair, price);
+                }
+                Err(e) => {
+                    tracing::warn!(pair = %pair, error = %e, "Price refresh failed");
+                    errors.insert(pair, e);
+                }
+            }
+        }
+
+        self.price_epoch.fetch_add(1, Ordering::SeqCst);
+        (prices, errors)
     }
 
-    /// Converts Chainlink's int256 answer to Decimal
+    /// Refreshes `pairs` with a single `eth_call` through Multicall3
     ///
-    /// Chainlink returns prices as int256 with a specified number of decimals.
-    /// For example, EUR/USD with 8 decimals: 108000000 = 1.08
-    fn chainlink_answer_to_decimal(
+    /// The `latestRoundData` reads of every pair, including each source of an
+    /// aggregated pair, are batched into one request; each pair then goes
+    /// through the same staleness, deviation and anomaly checks as
+    /// `update_price`. Pairs fail independently: an unregistered pair, a
+    /// reverted read or a rejected price only affects that pair's entry.
+    ///
+    /// # Errors
+    ///
+    /// `MulticallNotConfigured` if no Multicall3 address is set;
+    /// `ContractError` if the batched call itself fails or times out.
+    pub async fn update_prices_batch(
         &self,
-        answer: I256,
-        decimals: u8,
-    ) -> Result<Decimal, OracleError> {
-        // Convert I256 to string (handles negative numbers)
-        let answer_str = answer.to_string();
+        pairs: &[&str],
+    ) -> Result<HashMap<String, Result<Decimal, OracleError>>, OracleError> {
+        let multicall = self.multicall_address.ok_or(OracleError::MulticallNotConfigured)?;
+        let (reads, mut results) = self.plan_batch_reads(pairs).await;
 
-        // Parse as Decimal
-        let answer_decimal = Decimal::from_str(&answer_str)
-            .map_err(|e| OracleError::DecimalConversion(format!("Invalid answer: {}", e)))?;
+        let aggregators: Vec<Address> = reads
+            .iter()
+            .flat_map(|read| read.sources.iter().map(|source| source.address))
+            .collect();
+        if aggregators.is_empty() {
+            return Ok(results);
+        }
 
-        // Divide by 10^decimals
-        let divisor = Decimal::from(10_u64.pow(decimals as u32));
-        let price = answer_decimal / divisor;
+        let start = Instant::now();
+        let rounds = timeout(
+            Duration::from_secs(RPC_TIMEOUT_SECS),
+            latest_rounds_via_multicall(Arc::clone(&self.provider), multicall, &aggregators),
+        )
+        .await
+        .map_err(|_| OracleError::ContractError("RPC timeout in multicall price read".to_string()))??;
+        tracing::debug!(
+            pairs = reads.len(),
+            calls = aggregators.len(),
+            duration_ms = start.elapsed().as_millis() as u64,
+            "Batched latest round data via multicall"
+        );
 
-        Ok(price)
+        results.extend(self.apply_batch_reads(reads, rounds).await);
+        Ok(results)
+    }
+
+    /// Resolves each requested pair to the aggregators to read, reporting
+    /// pairs that cannot be refreshed (unregistered, wrong decimals) directly
+    async fn plan_batch_reads(
+        &self,
+        pairs: &[&str],
+    ) -> (Vec<BatchRead>, HashMap<String, Result<Decimal, OracleError>>) {
+        let mut reads = Vec::with_capacity(pairs.len());
+        let mut failed = HashMap::new();
+        let mut seen = HashSet::new();
+        {
+            let feeds = self.price_feeds.read().await;
+            for &pair in pairs {
+                if !seen.insert(pair) {
+                    continue;
+                }
+                let checked = feeds
+                    .get(pair)
+                    .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))
+                    .and_then(|feed| check_decimals(feed).map(|_| feed));
+                match checked {
+                    Ok(feed) => reads.push(BatchRead {
+                        pair: pair.to_string(),
+                        old_price: feed.latest_price,
+                        old_is_stale: feed.is_stale,
+                        stale_threshold: feed.stale_threshold(self.stale_threshold_seconds),
+                        sources: vec![FeedSource { address: feed.address, decimals: feed.decimals }],
+                        aggregated: false,
+                    }),
+                    Err(e) => {
+                        failed.insert(pair.to_string(), Err(e));
+                    }
+                }
+            }
+        }
+
+        let aggregated = self.aggregated_sources.read().await;
+        for read in &mut reads {
+            if let Some(sources) = aggregated.get(&read.pair) {
+                read.sources = sources.clone();
+                read.aggregated = true;
+            }
+        }
+
+        (reads, failed)
+    }
+
+    /// Applies batched rounds, given in the order of the reads' sources
+    async fn apply_batch_reads(
+        &self,
+        reads: Vec<BatchRead>,
+        rounds: Vec<Result<LatestRound, OracleError>>,
+    ) -> HashMap<String, Result<Decimal, OracleError>> {
+        let now = Utc::now().timestamp() as u64;
+        let mut rounds = rounds.into_iter();
+        let mut results = HashMap::with_capacity(reads.len());
+
+        for read in reads {
+            let source_rounds: Vec<(Address, Result<RoundData, OracleError>)> = read
+                .sources
+                .iter()
+                .map(|source| {
+                    let round = rounds
+                        .next()
+                        .unwrap_or_else(|| {
+                            Err(OracleError::ContractError("Multicall returned too few results".to_string()))
+                        })
+                        .and_then(|latest| self.round_from_latest(&read.pair, *source, latest));
+                    (source.address, round)
+                })
+                .collect();
+
+            let round = if read.aggregated {
+                median_of_fresh(
+                    &read.pair,
+                    source_rounds,
+                    now,
+                    read.stale_threshold,
+                    self.min_aggregated_sources,
+                )
+            } else {
+                source_rounds
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| OracleError::PriceFeedNotFound(read.pair.clone()))
+                    .and_then(|(_, round)| round)
+            };
+
+            let result = match round {
+                Ok(round) => self.apply_round(&read.pair, read.old_price, read.old_is_stale, round).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = &result {
+                tracing::warn!(pair = %read.pair, error = %e, "Batched price refresh failed");
+            }
+            results.insert(read.pair, result);
+        }
+
+        results
+    }
+
+    /// Spawns a task that refreshes every registered feed each `interval`
+    ///
+    /// Keeps the cache `get_price` reads warm without request-path calls to
+    /// `update_price`. Each pass goes through `update_all_prices`, so the
+    /// staleness, deviation and anomaly checks still apply; a feed that fails
+    /// is logged and retried next pass while the others keep updating.
+    /// A pass that overruns `interval` delays the next rather than bursting.
+    pub fn spawn_refresh_task(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let (prices, errors) = self.update_all_prices().await;
+                for (pair, error) in &errors {
+                    tracing::warn!(pair = %pair, error = %error, "Background price refresh failed");
+                }
+                tracing::debug!(
+                    refreshed = prices.len(),
+                    failed = errors.len(),
+                    epoch = self.price_epoch(),
+                    "Background price refresh completed"
+                );
+            }
+        })
+    }
+
+    /// Current price epoch; changes whenever cached prices may have changed
+    pub fn price_epoch(&self) -> u64 {
+        self.price_epoch.load(Ordering::SeqCst)
+    }
+
+    /// Price of `base` in `quote`, derived from their USD feeds
+    ///
+    /// Chainlink only publishes `{CUR}/USD` pairs, so e.g. EUR/GBP is
+    /// EUR/USD ÷ GBP/USD. Either side may be "USD" itself. Each leg is read
+    /// with `get_price`, so its `PriceFeedNotFound` or `StalePrice` is
+    /// returned as is.
+    ///
+    /// # Errors
+    ///
+    /// `InvalidPrice` if the quote leg is zero.
+    pub async fn get_cross_price(&self, base: &str, quote: &str) -> Result<Decimal, OracleError> {
+        let base_usd = self.usd_leg(base).await?;
+        let quote_usd = self.usd_leg(quote).await?;
+        if quote_usd.is_zero() {
+            return Err(OracleError::InvalidPrice(format!(
+                "{}/USD is zero; cannot derive {}/{}",
+                quote, base, quote
+            )));
+        }
+        Ok(base_usd / quote_usd)
+    }
+
+    /// Inverse quote of a registered feed, e.g. USD per EUR from EUR/USD
+    ///
+    /// `pair` may name the registered feed ("EUR/USD") or its reverse
+    /// ("USD/EUR"); the reversed form is only resolved when it isn't
+    /// registered itself. The price is read with `get_price`, so staleness
+    /// is enforced as usual.
+    ///
+    /// # Errors
+    ///
+    /// `PriceFeedNotFound` if neither form is registered, and
+    /// `DecimalConversion` if the price is zero.
+    pub async fn get_inverse_price(&self, pair: &str) -> Result<Decimal, OracleError> {
+        let registered = {
+            let feeds = self.price_feeds.read().await;
+            if feeds.contains_key(pair) {
+                pair.to_string()
+            } else {
+                pair.split_once('/')
+                    .map(|(base, quote)| format!("{}/{}", quote, base))
+                    .filter(|reversed| feeds.contains_key(reversed))
+                    .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))?
+            }
+        };
+
+        let price = self.get_price(&registered).await?;
+        if price.is_zero() {
+            return Err(OracleError::DecimalConversion(format!(
+                "{} price is zero; cannot invert",
+                registered
+            )));
+        }
+        Ok(Decimal::ONE / price)
+    }
+
+    /// USD price of one cross-rate leg; USD itself is 1
+    async fn usd_leg(&self, currency: &str) -> Result<Decimal, OracleError> {
+        if currency.eq_ignore_ascii_case("USD") {
+            return Ok(Decimal::ONE);
+        }
+        self.get_price(&format!("{}/USD", currency)).await
+    }
+
+    /// Gets information about a registered price feed
+    ///
+    /// # Arguments
+    ///
+    /// * `pair` - Currency pair
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use meridian_oracle::ChainlinkOracle;
+    /// # use rust_decimal::Decimal;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let oracle = ChainlinkOracle::new("http://localhost:8545", Decimal::new(10, 0)).await?;
+    /// let feed = oracle.get_feed_info("EUR/USD").await?;
+    /// println!("Feed: {} ({})", feed.description, feed.address);
+    /// println!("Decimals: {}", feed.decimals);
+    /// println!("Latest price: ${}", feed.latest_price);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_feed_info(&self, pair: &str) -> Result<PriceFeed, OracleError> {
+        let feeds = self.price_feeds.read().await;
+        feeds
+            .get(pair)
+            .cloned()
+            .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))
+    }
+
+    /// Age of every registered feed, sorted by pair
+    pub async fn staleness_summary(&self) -> Vec<FeedStaleness> {
+        self.staleness_summary_at(Utc::now()).await
+    }
+
+    /// `staleness_summary` evaluated at a given time
+    pub async fn staleness_summary_at(&self, now: DateTime<Utc>) -> Vec<FeedStaleness> {
+        let feeds = self.price_feeds.read().await;
+        let mut summary: Vec<FeedStaleness> = feeds
+            .values()
+            .map(|feed| {
+                let age_seconds = (now - feed.updated_at).num_seconds().max(0) as u64;
+                FeedStaleness {
+                    pair: feed.pair.clone(),
+                    age_seconds,
+                    is_stale: feed.is_stale || age_seconds > feed.stale_threshold(self.stale_threshold_seconds),
+                }
+            })
+            .collect();
+        summary.sort_by(|a, b| a.pair.cmp(&b.pair));
+        summary
+    }
+
+    /// Lists all registered price feeds
+    pub async fn list_feeds(&self) -> Vec<String> {
+        let feeds = self.price_feeds.read().await;
+        feeds.keys().cloned().collect()
+    }
+
+    /// Verifies that every required pair has a registered price feed
+    ///
+    /// Intended for startup health checks so a missing critical feed is
+    /// surfaced immediately rather than on the first mint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::MissingRequiredFeeds` listing every required
+    /// pair that is not registered, in the order given.
+    pub async fn verify_required_feeds(&self, required: &[&str]) -> Result<(), OracleError> {
+        let feeds = self.price_feeds.read().await;
+        let missing: Vec<String> = required
+            .iter()
+            .filter(|pair| !feeds.contains_key(**pair))
+            .map(|pair| pair.to_string())
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(OracleError::MissingRequiredFeeds(missing))
+        }
+    }
+
+    /// Verifies that every required pair is registered *and* refreshable
+    ///
+    /// Stronger than `verify_required_feeds`: each feed is read from chain via
+    /// `update_price`, so a registered feed with a bad address or an
+    /// unreachable RPC fails here instead of on the first live-rate request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::MissingRequiredFeeds` if any pair is unregistered,
+    /// otherwise `OracleError::FeedRefreshFailed` listing each pair that could
+    /// not be refreshed along with its error.
+    pub async fn verify_live_feeds(&self, required: &[&str]) -> Result<(), OracleError> {
+        self.verify_required_feeds(required).await?;
+
+        let results = bounded_join(&self.rpc_permits, required.iter(), |pair| async move {
+            (pair, self.update_price(pair).await)
+        })
+        .await;
+
+        let failed: Vec<String> = results
+            .into_iter()
+            .filter_map(|(pair, result)| result.err().map(|e| format!("{}: {}", pair, e)))
+            .collect();
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(OracleError::FeedRefreshFailed(failed))
+        }
+    }
+
+    /// Converts Chainlink's int256 answer to Decimal
+    ///
+    /// Chainlink returns prices as int256 with a specified number of decimals.
+    /// For example, EUR/USD with 8 decimals: 108000000 = 1.08
+    fn chainlink_answer_to_decimal(
+        &self,
+        answer: I256,
+        decimals: u8,
+    ) -> Result<Decimal, OracleError> {
+        // Convert I256 to string (handles negative numbers)
+        let answer_str = answer.to_string();
+
+        // Parse as Decimal
+        let answer_decimal = Decimal::from_str(&answer_str)
+            .map_err(|e| OracleError::DecimalConversion(format!("Invalid answer: {}", e)))?;
+
+        // Divide by 10^decimals
+        let divisor = Decimal::from(10_u64.pow(decimals as u32));
+        let price = answer_decimal / divisor;
+
+        Ok(price)
+    }
+
+    /// Time-weighted average price for `pair` over the last `window`, read
+    /// from the feed's on-chain round history
+    ///
+    /// Walks back from the latest round with `getRoundData` until it reaches
+    /// the round in effect at the start of the window, so the average does
+    /// not depend on how often this process happened to refresh. Each round
+    /// counts for as long as it was the latest. At most 256 rounds are read;
+    /// the walk also stops at the first round of the aggregator's current
+    /// phase. Aggregated pairs use their first source's rounds.
+    ///
+    /// # Errors
+    ///
+    /// - `PriceFeedNotFound` if the pair is not registered
+    /// - `InsufficientHistory` if fewer than two rounds fall in the window
+    /// - `ContractError` if a round cannot be read
+    pub async fn get_twap(&self, pair: &str, window: Duration) -> Result<Decimal, OracleError> {
+        let (address, decimals) = {
+            let feeds = self.price_feeds.read().await;
+            let feed = feeds
+                .get(pair)
+                .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))?;
+            (feed.address, feed.decimals)
+        };
+
+        let now = Utc::now();
+        let window_start = (now.timestamp() as u64).saturating_sub(window.as_secs());
+        let rounds = rounds_since(Arc::clone(&self.provider), address, window_start).await?;
+
+        // Oldest first, as `time_weighted_average` expects
+        let mut observations = VecDeque::with_capacity(rounds.len());
+        for (_, answer, _, updated_at, _) in rounds.into_iter().rev() {
+            let price = self.chainlink_answer_to_decimal(answer, decimals)?;
+            let at = DateTime::from_timestamp(updated_at.as_u64() as i64, 0)
+                .ok_or_else(|| OracleError::InvalidPrice(format!("Invalid round timestamp {}", updated_at)))?;
+            observations.push_back((at, price));
+        }
+
+        twap_over_rounds(pair, &observations, window.as_secs(), now)
+    }
+
+    /// Time-weighted average price for `pair` over the last `window_seconds`
+    ///
+    /// Built from prices seen by `update_price`; each observation counts for
+    /// as long as it was the latest round. Unlike `get_twap` this makes no
+    /// RPC calls, but only covers rounds this process refreshed.
+    pub async fn get_observed_twap(&self, pair: &str, window_seconds: u64) -> Result<Decimal, OracleError> {
+        if !self.price_feeds.read().await.contains_key(pair) {
+            return Err(OracleError::PriceFeedNotFound(pair.to_string()));
+        }
+        self.twap_at(pair, window_seconds, Utc::now())
+            .await
+            .ok_or_else(|| OracleError::InsufficientHistory(pair.to_string()))
+    }
+
+    async fn twap_at(&self, pair: &str, window_seconds: u64, now: DateTime<Utc>) -> Option<Decimal> {
+        let history = self.price_history.read().await;
+        time_weighted_average(history.get(pair)?, window_seconds, now)
+    }
+
+    /// Remembers a price for TWAP; re-reads of an already seen round are ignored
+    async fn record_observation(&self, pair: &str, at: DateTime<Utc>, price: Decimal) {
+        let mut history = self.price_history.write().await;
+        let observations = history.entry(pair.to_string()).or_default();
+        if observations.back().is_some_and(|(last, _)| *last >= at) {
+            return;
+        }
+        observations.push_back((at, price));
+        if observations.len() > PRICE_HISTORY_CAPACITY {
+            observations.pop_front();
+        }
+    }
+
+    /// Price the deviation check compares a refresh of `pair` against
+    async fn deviation_baseline(&self, pair: &str, last_price: Decimal, now: DateTime<Utc>) -> Decimal {
+        match self.deviation_reference {
+            DeviationReference::LastPrice => last_price,
+            DeviationReference::Twap { window_seconds } => self
+                .twap_at(pair, window_seconds, now)
+                .await
+                .unwrap_or(last_price),
+        }
+    }
+
+    /// Rejects `new_price` if it moved more than the deviation threshold from
+    /// `old_price`, publishing `OracleEvent::PriceDeviationDetected` first
+    fn check_deviation(
+        &self,
+        pair: &str,
+        old_price: Decimal,
+        new_price: Decimal,
+    ) -> Result<(), OracleError> {
+        if old_price == Decimal::ZERO {
+            return Ok(());
+        }
+
+        let deviation = ((new_price - old_price) / old_price * Decimal::new(100, 0)).abs();
+        if deviation <= self.deviation_threshold {
+            return Ok(());
+        }
+
+        tracing::warn!(
+            pair = %pair,
+            old_price = %old_price,
+            new_price = %new_price,
+            deviation = %deviation,
+            threshold = %self.deviation_threshold,
+            "Large price deviation detected"
+        );
+
+        // No subscribers is fine; the error below still reaches the caller
+        let _ = self.events.send(OracleEvent::PriceDeviationDetected {
+            pair: pair.to_string(),
+            old: old_price,
+            new: new_price,
+            deviation,
+        });
+
+        Err(OracleError::PriceDeviation {
+            pair: pair.to_string(),
+            old_price,
+            new_price,
+            deviation,
+        })
+    }
+
+    /// Whether `new_price` is more than `anomaly_z_threshold()` standard
+    /// deviations from the mean of the last `anomaly_window()` observations
+    ///
+    /// Catches moves that are small in percentage terms but far outside how
+    /// the feed normally behaves. Never flags a pair with fewer than 10
+    /// observations or a perfectly flat history.
+    pub async fn is_anomalous(&self, pair: &str, new_price: Decimal) -> bool {
+        if self.anomaly_z_threshold <= Decimal::ZERO {
+            return false;
+        }
+        exceeds_z_score(&self.recent_prices(pair).await, new_price, self.anomaly_z_threshold)
+    }
+
+    /// Last `anomaly_window` observed prices of `pair`, newest first
+    async fn recent_prices(&self, pair: &str) -> Vec<Decimal> {
+        let history = self.price_history.read().await;
+        history
+            .get(pair)
+            .map(|observations| {
+                observations
+                    .iter()
+                    .rev()
+                    .take(self.anomaly_window)
+                    .map(|(_, price)| *price)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rejects an anomalous `new_price` observed at `at`, publishing
+    /// `OracleEvent::PriceAnomalyDetected` first
+    ///
+    /// Rejected rounds are quarantined rather than dropped: once
+    /// `ANOMALY_CONFIRMATIONS` distinct rounds in a row land on the same side
+    /// of the recent mean, the move is a sustained shift, not a spike. The
+    /// round is then accepted and the quarantined rounds replace the pair's
+    /// history, so later rounds are judged against the new level.
+    async fn check_anomaly(&self, pair: &str, at: DateTime<Utc>, new_price: Decimal) -> Result<(), OracleError> {
+        if !self.is_anomalous(pair, new_price).await {
+            self.anomaly_quarantine.write().await.remove(pair);
+            return Ok(());
+        }
+
+        if let Some(confirmed) = self.quarantine_round(pair, at, new_price).await {
+            tracing::warn!(
+                pair = %pair,
+                price = %new_price,
+                confirmations = confirmed.len(),
+                "Sustained price shift accepted after quarantine"
+            );
+            self.price_history.write().await.insert(pair.to_string(), confirmed);
+            return Ok(());
+        }
+
+        tracing::warn!(
+            pair = %pair,
+            price = %new_price,
+            z_threshold = %self.anomaly_z_threshold,
+            window = self.anomaly_window,
+            "Price anomaly detected"
+        );
+
+        let _ = self.events.send(OracleEvent::PriceAnomalyDetected {
+            pair: pair.to_string(),
+            price: new_price,
+            z_threshold: self.anomaly_z_threshold,
+        });
+
+        Err(OracleError::PriceAnomaly {
+            pair: pair.to_string(),
+            price: new_price,
+            z_threshold: self.anomaly_z_threshold,
+        })
+    }
+
+    /// Adds an anomalous round to `pair`'s quarantine
+    ///
+    /// A round on the other side of the recent mean from those already held
+    /// starts the quarantine over; re-reads of a held round are ignored.
+    /// Returns the quarantined rounds, oldest first, once they confirm a shift.
+    async fn quarantine_round(
+        &self,
+        pair: &str,
+        at: DateTime<Utc>,
+        price: Decimal,
+    ) -> Option<VecDeque<(DateTime<Utc>, Decimal)>> {
+        let recent = self.recent_prices(pair).await;
+        let mean = recent.iter().sum::<Decimal>() / Decimal::from(recent.len().max(1));
+
+        let mut quarantine = self.anomaly_quarantine.write().await;
+        let rounds = quarantine.entry(pair.to_string()).or_default();
+        if rounds.back().is_some_and(|(last, _)| *last >= at) {
+            return None;
+        }
+        if rounds.back().is_some_and(|(_, last)| (*last > mean) != (price > mean)) {
+            rounds.clear();
+        }
+        rounds.push_back((at, price));
+
+        if rounds.len() < ANOMALY_CONFIRMATIONS {
+            return None;
+        }
+        quarantine.remove(pair)
+    }
+
+    /// Subscribes to oracle domain events
+    pub fn subscribe(&self) -> broadcast::Receiver<OracleEvent> {
+        self.events.subscribe()
+    }
+
+    /// Gets the deviation check baseline
+    pub fn deviation_reference(&self) -> DeviationReference {
+        self.deviation_reference
+    }
+
+    /// Sets the deviation check baseline
+    pub fn set_deviation_reference(&mut self, reference: DeviationReference) {
+        self.deviation_reference = reference;
+    }
+
+    /// Gets the anomaly z-score threshold
+    pub fn anomaly_z_threshold(&self) -> Decimal {
+        self.anomaly_z_threshold
+    }
+
+    /// Sets the anomaly z-score threshold; zero disables the check
+    pub fn set_anomaly_z_threshold(&mut self, z_score: Decimal) {
+        self.anomaly_z_threshold = z_score;
+    }
+
+    /// Gets the number of recent observations the z-score is measured against
+    pub fn anomaly_window(&self) -> usize {
+        self.anomaly_window
+    }
+
+    /// Sets the anomaly window, clamped to what the check and history can hold
+    pub fn set_anomaly_window(&mut self, observations: usize) {
+        self.anomaly_window = observations.clamp(ANOMALY_MIN_SAMPLES, PRICE_HISTORY_CAPACITY);
+    }
+
+    /// Non-stale sources an aggregated pair needs for `update_price` to succeed
+    pub fn min_aggregated_sources(&self) -> usize {
+        self.min_aggregated_sources
+    }
+
+    /// Sets the minimum usable sources for aggregated pairs (minimum 1)
+    pub fn set_min_aggregated_sources(&mut self, sources: usize) {
+        self.min_aggregated_sources = sources.max(1);
+    }
+
+    /// Multicall3 contract used by `update_prices_batch`, if configured
+    pub fn multicall_address(&self) -> Option<Address> {
+        self.multicall_address
+    }
+
+    /// Sets the Multicall3 contract for `update_prices_batch`, normally
+    /// `meridian_chains::Chain::multicall3_address` for the oracle's network
+    pub fn set_multicall_address(&mut self, address: Option<Address>) {
+        self.multicall_address = address;
+    }
+
+    /// Confidence (0-1) in the cached price for `pair`; see `PriceFeed::confidence`
+    pub async fn get_price_confidence(&self, pair: &str) -> Result<Decimal, OracleError> {
+        let feeds = self.price_feeds.read().await;
+        let feed = feeds
+            .get(pair)
+            .ok_or_else(|| OracleError::PriceFeedNotFound(pair.to_string()))?;
+        Ok(feed.confidence(feed.stale_threshold(self.stale_threshold_seconds)))
+    }
+
+    /// Maximum number of concurrent RPC calls made by batch operations
+    pub fn rpc_concurrency(&self) -> usize {
+        self.rpc_concurrency
+    }
+
+    /// Sets the batch RPC concurrency limit (minimum 1)
+    pub fn set_rpc_concurrency(&mut self, limit: usize) {
+        let limit = limit.max(1);
+        self.rpc_permits = Arc::new(Semaphore::new(limit));
+        self.rpc_concurrency = limit;
     }
 
     /// Gets the staleness threshold in seconds
@@ -432,39 +1457,934 @@ impl ChainlinkOracle {
         self.stale_threshold_seconds
     }
 
-    /// Sets the staleness threshold in seconds
-    pub fn set_stale_threshold(&mut self, seconds: u64) {
-        self.stale_threshold_seconds = seconds;
+    /// Staleness threshold in effect for `pair`: its override, else the default
+    async fn stale_threshold_for(&self, pair: &str) -> u64 {
+        self.price_feeds
+            .read()
+            .await
+            .get(pair)
+            .map_or(self.stale_threshold_seconds, |feed| feed.stale_threshold(self.stale_threshold_seconds))
+    }
+
+    /// Sets the staleness threshold in seconds
+    pub fn set_stale_threshold(&mut self, seconds: u64) {
+        self.stale_threshold_seconds = seconds;
+    }
+}
+
+/// Runs `f` for every item concurrently, holding a semaphore permit per call
+///
+/// Results are returned in input order.
+async fn bounded_join<I, F, Fut, T>(permits: &Semaphore, items: I, f: F) -> Vec<T>
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> Fut,
+    Fut: std::future::Future<Output = T>,
+{
+    futures::future::join_all(items.into_iter().map(|item| {
+        let call = f(item);
+        async move {
+            // The semaphore is never closed, so acquire cannot fail
+            let _permit = permits.acquire().await.expect("RPC semaphore closed");
+            call.await
+        }
+    }))
+    .await
+}
+
+/// `latestRoundData` of each aggregator in one `eth_call` through Multicall3
+///
+/// Each call may fail on its own (e.g. an address that reverts) without
+/// failing the batch; such entries are `ContractError`s. Results are in
+/// input order.
+async fn latest_rounds_via_multicall<M: Middleware>(
+    client: Arc<M>,
+    multicall: Address,
+    aggregators: &[Address],
+) -> Result<Vec<Result<LatestRound, OracleError>>, OracleError> {
+    let mut batch = Multicall::new(Arc::clone(&client), Some(multicall))
+        .await
+        .map_err(|e| OracleError::ContractError(format!("Failed to create multicall: {}", e)))?;
+    for &address in aggregators {
+        let aggregator = ChainlinkAggregatorV3::new(address, Arc::clone(&client));
+        batch.add_call(aggregator.latest_round_data(), true);
+    }
+
+    let returns = batch
+        .call_raw()
+        .await
+        .map_err(|e| OracleError::ContractError(format!("Multicall price read failed: {}", e)))?;
+
+    Ok(returns
+        .into_iter()
+        .zip(aggregators)
+        .map(|(returned, address)| {
+            let token = returned.map_err(|_| {
+                OracleError::ContractError(format!("latestRoundData reverted for {:?}", address))
+            })?;
+            LatestRound::from_token(token).map_err(|e| {
+                OracleError::ContractError(format!("Invalid latestRoundData from {:?}: {}", address, e))
+            })
+        })
+        .collect())
+}
+
+/// Rounds of an aggregator from the latest back to the one in effect at
+/// `window_start` (Unix seconds), newest first
+///
+/// Stops early at the first round of the current phase, at an incomplete
+/// round, or after `MAX_TWAP_ROUNDS`.
+async fn rounds_since<M: Middleware>(
+    client: Arc<M>,
+    address: Address,
+    window_start: u64,
+) -> Result<Vec<LatestRound>, OracleError> {
+    let aggregator = ChainlinkAggregatorV3::new(address, client);
+
+    let latest = timeout(Duration::from_secs(RPC_TIMEOUT_SECS), aggregator.latest_round_data().call())
+        .await
+        .map_err(|_| OracleError::ContractError("RPC timeout getting latest round data".to_string()))?
+        .map_err(|e| OracleError::ContractError(format!("Failed to get latest round data: {}", e)))?;
+    let mut rounds = vec![latest];
+
+    while rounds.len() < MAX_TWAP_ROUNDS {
+        let (round_id, _, _, updated_at, _) = rounds[rounds.len() - 1];
+        // Round IDs are (phase << 64) | aggregator round; earlier rounds of a
+        // previous phase belong to a different aggregator
+        if updated_at.as_u64() < window_start || (round_id as u64) <= 1 {
+            break;
+        }
+
+        let previous = timeout(
+            Duration::from_secs(RPC_TIMEOUT_SECS),
+            aggregator.get_round_data(round_id - 1).call(),
+        )
+        .await
+        .map_err(|_| OracleError::ContractError("RPC timeout getting round data".to_string()))?
+        .map_err(|e| OracleError::ContractError(format!("Failed to get round {}: {}", round_id - 1, e)))?;
+        if previous.3.is_zero() {
+            break;
+        }
+        rounds.push(previous);
+    }
+
+    Ok(rounds)
+}
+
+/// TWAP over on-chain rounds (oldest first), requiring at least two rounds
+/// inside the window
+fn twap_over_rounds(
+    pair: &str,
+    rounds: &VecDeque<(DateTime<Utc>, Decimal)>,
+    window_seconds: u64,
+    now: DateTime<Utc>,
+) -> Result<Decimal, OracleError> {
+    let window_start = now - chrono::Duration::seconds(window_seconds as i64);
+    let in_window = rounds.iter().filter(|(at, _)| *at >= window_start && *at <= now).count();
+    if in_window < 2 {
+        return Err(OracleError::InsufficientHistory(pair.to_string()));
+    }
+    time_weighted_average(rounds, window_seconds, now).ok_or_else(|| OracleError::InsufficientHistory(pair.to_string()))
+}
+
+/// Rejects a feed whose reported decimals differ from its `expected_decimals`
+fn check_decimals(feed: &PriceFeed) -> Result<(), OracleError> {
+    match feed.expected_decimals {
+        Some(expected) if expected != feed.decimals => Err(OracleError::DecimalsMismatch {
+            pair: feed.pair.clone(),
+            expected,
+            reported: feed.decimals,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Median of the non-stale successful reads of an aggregated pair
+///
+/// Stale or failed sources are logged and dropped. The result carries the
+/// oldest contributing update time, so the aggregate is never fresher than
+/// its inputs, and a zero round ID.
+fn median_of_fresh(
+    pair: &str,
+    reads: Vec<(Address, Result<RoundData, OracleError>)>,
+    now: u64,
+    stale_threshold_seconds: u64,
+    min_sources: usize,
+) -> Result<RoundData, OracleError> {
+    let mut fresh = Vec::with_capacity(reads.len());
+    for (address, read) in reads {
+        match read {
+            Ok(round) if now.saturating_sub(round.updated_at) <= stale_threshold_seconds => fresh.push(round),
+            Ok(round) => tracing::warn!(
+                pair = %pair,
+                address = %address,
+                age_seconds = now.saturating_sub(round.updated_at),
+                "Discarding stale aggregated price source"
+            ),
+            Err(e) => tracing::warn!(
+                pair = %pair,
+                address = %address,
+                error = %e,
+                "Aggregated price source failed"
+            ),
+        }
+    }
+
+    if fresh.len() < min_sources.max(1) {
+        return Err(OracleError::InsufficientSources(pair.to_string(), fresh.len()));
+    }
+
+    let mut prices: Vec<Decimal> = fresh.iter().map(|round| round.price).collect();
+    let price = median(&mut prices).ok_or_else(|| OracleError::InsufficientSources(pair.to_string(), 0))?;
+    let updated_at = fresh.iter().map(|round| round.updated_at).min().unwrap_or(now);
+
+    Ok(RoundData { round_id: U256::zero(), price, updated_at })
+}
+
+/// Middle value, or the mean of the two middle values for an even count
+fn median(values: &mut [Decimal]) -> Option<Decimal> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / Decimal::TWO)
+    } else {
+        Some(values[mid])
+    }
+}
+
+/// Whether `price` is more than `z_threshold` population standard deviations
+/// from the mean of `window`
+///
+/// Compares squares to avoid a square root: `|x - mean| / sd > z` is
+/// `(x - mean)^2 > z^2 * variance`.
+fn exceeds_z_score(window: &[Decimal], price: Decimal, z_threshold: Decimal) -> bool {
+    if window.len() < ANOMALY_MIN_SAMPLES {
+        return false;
+    }
+    let count = Decimal::from(window.len());
+    let mean = window.iter().sum::<Decimal>() / count;
+    let variance = window.iter().map(|p| (p - mean) * (p - mean)).sum::<Decimal>() / count;
+    if variance.is_zero() {
+        return false;
+    }
+    let distance = price - mean;
+    distance * distance > z_threshold * z_threshold * variance
+}
+
+/// Time-weighted average of `observations` over `[now - window_seconds, now]`
+///
+/// Each observation holds until the next one, the newest until `now`; the
+/// price in force when the window opens is carried in from before it.
+/// Returns `None` if nothing had been observed by `now`.
+fn time_weighted_average(
+    observations: &VecDeque<(DateTime<Utc>, Decimal)>,
+    window_seconds: u64,
+    now: DateTime<Utc>,
+) -> Option<Decimal> {
+    let window_start = now - chrono::Duration::seconds(window_seconds as i64);
+    let seen: Vec<&(DateTime<Utc>, Decimal)> = observations.iter().filter(|(at, _)| *at <= now).collect();
+    let (_, latest) = **seen.last()?;
+
+    let mut weighted = Decimal::ZERO;
+    let mut total_seconds = 0i64;
+    for (i, (at, price)) in seen.iter().enumerate() {
+        let held_from = (*at).max(window_start);
+        let held_until = seen.get(i + 1).map_or(now, |(next, _)| *next);
+        let seconds = (held_until - held_from).num_seconds();
+        if seconds > 0 {
+            weighted += *price * Decimal::from(seconds);
+            total_seconds += seconds;
+        }
+    }
+
+    if total_seconds == 0 {
+        return Some(latest);
+    }
+    Some(weighted / Decimal::from(total_seconds))
+}
+
+/// Record duration and outcome on the current oracle span
+fn record_outcome<T>(start: Instant, result: &Result<T, OracleError>) {
+    let span = tracing::Span::current();
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+    span.record("outcome", if result.is_ok() { "ok" } else { "error" });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chainlink_answer_conversion() {
+        let oracle = ChainlinkOracle::for_test();
+
+        // EUR/USD: 1.08 with 8 decimals = 108000000
+        let answer = I256::from(108000000);
+        let price = oracle.chainlink_answer_to_decimal(answer, 8).unwrap();
+        assert_eq!(price, Decimal::new(108, 2)); // 1.08
+
+        // GBP/USD: 1.27 with 8 decimals = 127000000
+        let answer = I256::from(127000000);
+        let price = oracle.chainlink_answer_to_decimal(answer, 8).unwrap();
+        assert_eq!(price, Decimal::new(127, 2)); // 1.27
+
+        // JPY/USD: 0.0067 with 8 decimals = 670000
+        let answer = I256::from(670000);
+        let price = oracle.chainlink_answer_to_decimal(answer, 8).unwrap();
+        assert_eq!(price, Decimal::new(67, 4)); // 0.0067
+    }
+
+    /// Oracle with fresh EUR, GBP and JPY feeds priced from Chainlink answers
+    async fn cross_rate_oracle() -> ChainlinkOracle {
+        let oracle = ChainlinkOracle::for_test();
+        for (pair, answer) in [("EUR/USD", 108000000), ("GBP/USD", 127000000), ("JPY/USD", 670000)] {
+            let mut feed = test_feed(pair);
+            feed.latest_price = oracle.chainlink_answer_to_decimal(I256::from(answer), 8).unwrap();
+            feed.is_stale = false;
+            oracle.price_feeds.write().await.insert(pair.to_string(), feed);
+        }
+        oracle
+    }
+
+    #[tokio::test]
+    async fn test_cross_price_divides_usd_legs() {
+        let oracle = cross_rate_oracle().await;
+
+        let eur_gbp = oracle.get_cross_price("EUR", "GBP").await.unwrap();
+        assert_eq!(eur_gbp, Decimal::new(108, 2) / Decimal::new(127, 2));
+        assert_eq!(eur_gbp.round_dp(4), Decimal::new(8504, 4));
+
+        // 1.08 / 0.0067 yen per euro
+        let eur_jpy = oracle.get_cross_price("EUR", "JPY").await.unwrap();
+        assert_eq!(eur_jpy.round_dp(2), Decimal::new(16119, 2));
+
+        assert_eq!(oracle.get_cross_price("EUR", "USD").await.unwrap(), Decimal::new(108, 2));
+        assert_eq!(
+            oracle.get_cross_price("USD", "GBP").await.unwrap(),
+            Decimal::ONE / Decimal::new(127, 2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inverse_price_of_registered_and_reversed_pairs() {
+        let oracle = cross_rate_oracle().await;
+        let usd_per_eur = Decimal::ONE / Decimal::new(108, 2);
+
+        assert_eq!(oracle.get_inverse_price("EUR/USD").await.unwrap(), usd_per_eur);
+        assert_eq!(oracle.get_inverse_price("USD/EUR").await.unwrap(), usd_per_eur);
+        assert_eq!(oracle.get_inverse_price("USD/JPY").await.unwrap().round_dp(2), Decimal::new(14925, 2));
+
+        let missing = oracle.get_inverse_price("USD/CHF").await.unwrap_err();
+        assert!(matches!(missing, OracleError::PriceFeedNotFound(ref pair) if pair == "USD/CHF"));
+    }
+
+    #[tokio::test]
+    async fn test_inverse_price_rejects_zero_and_stale() {
+        let oracle = cross_rate_oracle().await;
+        oracle.price_feeds.write().await.get_mut("GBP/USD").unwrap().latest_price = Decimal::ZERO;
+        oracle.price_feeds.write().await.get_mut("EUR/USD").unwrap().is_stale = true;
+
+        let zero = oracle.get_inverse_price("USD/GBP").await.unwrap_err();
+        assert!(matches!(zero, OracleError::DecimalConversion(_)));
+        let stale = oracle.get_inverse_price("USD/EUR").await.unwrap_err();
+        assert!(matches!(stale, OracleError::StalePrice(ref pair, _) if pair == "EUR/USD"));
+    }
+
+    #[tokio::test]
+    async fn test_cross_price_propagates_leg_errors() {
+        let oracle = cross_rate_oracle().await;
+
+        let missing = oracle.get_cross_price("EUR", "CHF").await.unwrap_err();
+        assert!(matches!(missing, OracleError::PriceFeedNotFound(ref pair) if pair == "CHF/USD"));
+
+        oracle.price_feeds.write().await.get_mut("GBP/USD").unwrap().is_stale = true;
+        let stale = oracle.get_cross_price("GBP", "EUR").await.unwrap_err();
+        assert!(matches!(stale, OracleError::StalePrice(ref pair, _) if pair == "GBP/USD"));
+    }
+
+    #[tokio::test]
+    async fn test_cross_price_rejects_zero_quote() {
+        let oracle = cross_rate_oracle().await;
+        oracle.price_feeds.write().await.get_mut("GBP/USD").unwrap().latest_price = Decimal::ZERO;
+
+        let err = oracle.get_cross_price("EUR", "GBP").await.unwrap_err();
+        assert!(matches!(err, OracleError::InvalidPrice(_)));
+        assert_eq!(oracle.get_cross_price("GBP", "EUR").await.unwrap(), Decimal::ZERO);
+    }
+
+    fn test_feed(pair: &str) -> PriceFeed {
+        PriceFeed {
+            pair: pair.to_string(),
+            address: Address::zero(),
+            decimals: 8,
+            expected_decimals: None,
+            latest_price: Decimal::ZERO,
+            latest_round: U256::zero(),
+            updated_at: Utc::now(),
+            is_stale: true,
+            description: format!("{} test feed", pair),
+            stale_threshold_secs: None,
+        }
+    }
+
+    /// JPY/USD with a 2-hour override next to EUR/USD on the 1-hour default
+    async fn per_feed_threshold_oracle() -> ChainlinkOracle {
+        let oracle = ChainlinkOracle::for_test();
+        let mut jpy = test_feed("JPY/USD");
+        jpy.stale_threshold_secs = Some(7200);
+        let mut feeds = oracle.price_feeds.write().await;
+        feeds.insert("JPY/USD".to_string(), jpy);
+        feeds.insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+        drop(feeds);
+        oracle
+    }
+
+    #[tokio::test]
+    async fn test_update_uses_per_feed_stale_threshold() {
+        let oracle = per_feed_threshold_oracle().await;
+        let ninety_minutes_ago = Utc::now().timestamp() as u64 - 5400;
+        let round = |price| RoundData { round_id: U256::one(), price, updated_at: ninety_minutes_ago };
+
+        oracle.apply_round("JPY/USD", Decimal::ZERO, true, round(Decimal::new(67, 4))).await.unwrap();
+        oracle.apply_round("EUR/USD", Decimal::ZERO, true, round(Decimal::new(108, 2))).await.unwrap();
+
+        assert!(!oracle.get_feed_info("JPY/USD").await.unwrap().is_stale);
+        assert_eq!(oracle.get_price("JPY/USD").await.unwrap(), Decimal::new(67, 4));
+
+        assert!(oracle.get_feed_info("EUR/USD").await.unwrap().is_stale);
+        assert!(matches!(oracle.get_price("EUR/USD").await, Err(OracleError::StalePrice(_, _))));
+    }
+
+    #[tokio::test]
+    async fn test_get_price_ages_out_by_feed_threshold() {
+        let oracle = per_feed_threshold_oracle().await;
+        {
+            let mut feeds = oracle.price_feeds.write().await;
+            for feed in feeds.values_mut() {
+                // Fresh when last refreshed, but 90 minutes have passed since
+                feed.latest_price = Decimal::ONE;
+                feed.is_stale = false;
+                feed.updated_at = Utc::now() - chrono::Duration::minutes(90);
+            }
+        }
+
+        assert_eq!(oracle.get_price("JPY/USD").await.unwrap(), Decimal::ONE);
+        assert!(matches!(oracle.get_price("EUR/USD").await, Err(OracleError::StalePrice(_, _))));
+
+        let summary = oracle.staleness_summary().await;
+        assert!(summary.iter().any(|s| s.pair == "EUR/USD" && s.is_stale));
+        assert!(summary.iter().any(|s| s.pair == "JPY/USD" && !s.is_stale));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn test_update_rejects_unexpected_decimals() {
+        let oracle = ChainlinkOracle::for_test();
+        // Points at an 18-decimal token feed instead of an 8-decimal FX feed
+        let mut wrong = test_feed("EUR/USD");
+        wrong.decimals = 18;
+        wrong.expected_decimals = Some(8);
+        let mut right = test_feed("GBP/USD");
+        right.expected_decimals = Some(8);
+        {
+            let mut feeds = oracle.price_feeds.write().await;
+            feeds.insert(wrong.pair.clone(), wrong);
+            feeds.insert(right.pair.clone(), right);
+        }
+
+        let err = oracle.update_price("EUR/USD").await.unwrap_err();
+        assert!(matches!(
+            err,
+            OracleError::DecimalsMismatch { ref pair, expected: 8, reported: 18 } if pair == "EUR/USD"
+        ));
+
+        // Matching decimals pass the check and go on to the (unreachable) RPC
+        let err = oracle.update_price("GBP/USD").await.unwrap_err();
+        assert!(matches!(err, OracleError::ContractError(_)));
+    }
 
     #[test]
-    fn test_chainlink_answer_conversion() {
+    fn test_check_decimals_is_optional() {
+        let mut feed = test_feed("EUR/USD");
+        feed.decimals = 18;
+        assert!(check_decimals(&feed).is_ok());
+        feed.expected_decimals = Some(18);
+        assert!(check_decimals(&feed).is_ok());
+        feed.expected_decimals = Some(8);
+        assert!(check_decimals(&feed).is_err());
+    }
+
+    fn latest(answer: i64, updated_at: u64) -> LatestRound {
+        (7, I256::from(answer), U256::from(updated_at), U256::from(updated_at), 7)
+    }
+
+    #[tokio::test]
+    async fn test_multicall_decodes_each_call() {
+        let returned = |success: bool, data: Vec<u8>| {
+            ethers::abi::Token::Tuple(vec![ethers::abi::Token::Bool(success), ethers::abi::Token::Bytes(data)])
+        };
+        let round_data = ethers::abi::encode(&latest(108000000, 1_700_000_000).into_token().into_tuple().unwrap());
+        let aggregate3 = ethers::abi::encode(&[ethers::abi::Token::Array(vec![
+            returned(true, round_data),
+            returned(false, Vec::new()),
+        ])]);
+
+        let (provider, mock) = Provider::mocked();
+        mock.push::<ethers::types::Bytes, _>(ethers::types::Bytes::from(aggregate3)).unwrap();
+
+        let eur = Address::from_low_u64_be(1);
+        let reverting = Address::from_low_u64_be(2);
+        let rounds = latest_rounds_via_multicall(Arc::new(provider), Address::from_low_u64_be(3), &[eur, reverting])
+            .await
+            .unwrap();
+
+        assert_eq!(rounds.len(), 2);
+        let (round_id, answer, _, updated_at, _) = *rounds[0].as_ref().unwrap();
+        assert_eq!(round_id, 7);
+        assert_eq!(answer, I256::from(108000000));
+        assert_eq!(updated_at, U256::from(1_700_000_000u64));
+        assert!(matches!(rounds[1], Err(OracleError::ContractError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_batch_applies_checks_per_pair() {
+        let oracle = cross_rate_oracle().await;
+        let now = Utc::now().timestamp() as u64;
+
+        let (reads, failed) = oracle.plan_batch_reads(&["EUR/USD", "GBP/USD", "JPY/USD", "CHF/USD", "EUR/USD"]).await;
+        assert_eq!(reads.len(), 3);
+        assert!(matches!(failed["CHF/USD"], Err(OracleError::PriceFeedNotFound(_))));
+
+        let results = oracle
+            .apply_batch_reads(
+                reads,
+                vec![
+                    Ok(latest(109000000, now)),
+                    // 1.27 -> 2.00 breaches the 10% deviation threshold
+                    Ok(latest(200000000, now)),
+                    Err(OracleError::ContractError("reverted".to_string())),
+                ],
+            )
+            .await;
+
+        assert_eq!(*results["EUR/USD"].as_ref().unwrap(), Decimal::new(109, 2));
+        assert!(matches!(results["GBP/USD"], Err(OracleError::PriceDeviation { .. })));
+        assert!(matches!(results["JPY/USD"], Err(OracleError::ContractError(_))));
+
+        assert_eq!(oracle.get_price("EUR/USD").await.unwrap(), Decimal::new(109, 2));
+        assert_eq!(oracle.get_price("GBP/USD").await.unwrap(), Decimal::new(127, 2));
+    }
+
+    #[tokio::test]
+    async fn test_batch_marks_stale_rounds() {
+        let oracle = ChainlinkOracle::for_test();
+        oracle.price_feeds.write().await.insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+        let old = Utc::now().timestamp() as u64 - 7200;
+
+        let (reads, _) = oracle.plan_batch_reads(&["EUR/USD"]).await;
+        let results = oracle.apply_batch_reads(reads, vec![Ok(latest(108000000, old))]).await;
+
+        assert_eq!(*results["EUR/USD"].as_ref().unwrap(), Decimal::new(108, 2));
+        assert!(oracle.get_feed_info("EUR/USD").await.unwrap().is_stale);
+    }
+
+    #[tokio::test]
+    async fn test_batch_requires_multicall_address() {
+        let mut oracle = cross_rate_oracle().await;
+        let err = oracle.update_prices_batch(&["EUR/USD"]).await.unwrap_err();
+        assert!(matches!(err, OracleError::MulticallNotConfigured));
+
+        // Nothing to read: no RPC round-trip
+        oracle.set_multicall_address(Some(Address::from_low_u64_be(3)));
+        let results = oracle.update_prices_batch(&["CHF/USD"]).await.unwrap();
+        assert!(matches!(results["CHF/USD"], Err(OracleError::PriceFeedNotFound(_))));
+
+        // The batched call itself failing fails the whole batch
+        let err = oracle.update_prices_batch(&["EUR/USD"]).await.unwrap_err();
+        assert!(matches!(err, OracleError::ContractError(_)));
+    }
+
+    fn round(price: &str, updated_at: u64) -> RoundData {
+        RoundData { round_id: U256::one(), price: Decimal::from_str(price).unwrap(), updated_at }
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&mut []), None);
+        let mut odd = [Decimal::from(3), Decimal::from(1), Decimal::from(1000)];
+        assert_eq!(median(&mut odd), Some(Decimal::from(3)));
+        let mut even = [Decimal::from(4), Decimal::from(1), Decimal::from(2), Decimal::from(3)];
+        assert_eq!(median(&mut even), Some(Decimal::new(25, 1)));
+    }
+
+    #[test]
+    fn test_median_of_fresh_resists_single_bad_round() {
+        let now = 10_000;
+        let reads = vec![
+            (Address::zero(), Ok(round("1.08", now - 60))),
+            (Address::zero(), Ok(round("1.09", now - 30))),
+            // Wildly off (e.g. a misreported round) but outvoted
+            (Address::zero(), Ok(round("108", now - 10))),
+        ];
+        let aggregate = median_of_fresh("EUR/USD", reads, now, 3600, 2).unwrap();
+        assert_eq!(aggregate.price, Decimal::new(109, 2));
+        // Never fresher than the oldest contributing source
+        assert_eq!(aggregate.updated_at, now - 60);
+        assert_eq!(aggregate.round_id, U256::zero());
+    }
+
+    #[test]
+    fn test_median_of_fresh_discards_stale_and_failed_sources() {
+        let now = 10_000;
+        let reads = vec![
+            (Address::zero(), Ok(round("1.08", now - 60))),
+            (Address::zero(), Ok(round("0.50", now - 7200))),
+            (Address::zero(), Err(OracleError::ContractError("timeout".to_string()))),
+        ];
+        let err = median_of_fresh("EUR/USD", reads, now, 3600, 2).unwrap_err();
+        assert!(matches!(err, OracleError::InsufficientSources(ref pair, 1) if pair == "EUR/USD"));
+
+        let reads = vec![
+            (Address::zero(), Ok(round("1.08", now - 60))),
+            (Address::zero(), Ok(round("0.50", now - 7200))),
+        ];
+        let aggregate = median_of_fresh("EUR/USD", reads, now, 3600, 1).unwrap();
+        assert_eq!(aggregate.price, Decimal::new(108, 2));
+    }
+
+    #[tokio::test]
+    async fn test_aggregated_update_fails_without_enough_sources() {
+        let oracle = ChainlinkOracle::for_test();
+        oracle.price_feeds.write().await.insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+        let source = FeedSource { address: Address::zero(), decimals: 8 };
+        oracle
+            .aggregated_sources
+            .write()
+            .await
+            .insert("EUR/USD".to_string(), vec![source; 3]);
+
+        // Every source is unreachable
+        let err = oracle.update_price("EUR/USD").await.unwrap_err();
+        assert!(matches!(err, OracleError::InsufficientSources(ref pair, 0) if pair == "EUR/USD"));
+    }
+
+    #[tokio::test]
+    async fn test_register_aggregated_feed_requires_minimum_sources() {
+        let mut oracle = ChainlinkOracle::for_test();
+        assert_eq!(oracle.min_aggregated_sources(), DEFAULT_MIN_AGGREGATED_SOURCES);
+
+        let err = oracle
+            .register_aggregated_feed("EUR/USD", vec![Address::zero()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OracleError::InsufficientSources(_, 1)));
+        assert!(oracle.list_feeds().await.is_empty());
+
+        oracle.set_min_aggregated_sources(0);
+        assert_eq!(oracle.min_aggregated_sources(), 1);
+    }
+
+    #[test]
+    fn test_price_feed_confidence_decays_with_age() {
+        let now = Utc::now();
+        let mut feed = test_feed("EUR/USD");
+        feed.latest_price = Decimal::new(108, 2);
+        feed.is_stale = false;
+
+        feed.updated_at = now;
+        assert_eq!(feed.confidence_at(now, 3600), Decimal::ONE);
+
+        feed.updated_at = now - chrono::Duration::seconds(900);
+        assert_eq!(feed.confidence_at(now, 3600), Decimal::new(75, 2));
+
+        feed.updated_at = now - chrono::Duration::seconds(3600);
+        assert_eq!(feed.confidence_at(now, 3600), Decimal::ZERO);
+
+        // Flagged stale or never read: no confidence
+        feed.updated_at = now;
+        feed.is_stale = true;
+        assert_eq!(feed.confidence_at(now, 3600), Decimal::ZERO);
+        assert_eq!(test_feed("GBP/USD").confidence_at(now, 3600), Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_staleness_summary_reports_feed_age() {
+        let now = Utc::now();
+        let mut eur = test_feed("EUR/USD");
+        eur.updated_at = now - chrono::Duration::seconds(7200);
+        let mut gbp = test_feed("GBP/USD");
+        gbp.updated_at = now - chrono::Duration::seconds(30);
+        gbp.is_stale = false;
+
+        let mut feeds = HashMap::new();
+        feeds.insert(eur.pair.clone(), eur);
+        feeds.insert(gbp.pair.clone(), gbp);
+
         let oracle = ChainlinkOracle {
-            provider: Arc::new(Provider::<Http>::try_from("http://localhost:8545").unwrap()),
-            price_feeds: Arc::new(RwLock::new(HashMap::new())),
-            deviation_threshold: Decimal::new(10, 0),
-            stale_threshold_seconds: 3600,
+            price_feeds: Arc::new(RwLock::new(feeds)),
+            ..ChainlinkOracle::for_test()
         };
 
-        // EUR/USD: 1.08 with 8 decimals = 108000000
-        let answer = I256::from(108000000);
-        let price = oracle.chainlink_answer_to_decimal(answer, 8).unwrap();
-        assert_eq!(price, Decimal::new(108, 2)); // 1.08
+        let summary = oracle.staleness_summary_at(now).await;
+        assert_eq!(
+            summary,
+            vec![
+                FeedStaleness { pair: "EUR/USD".to_string(), age_seconds: 7200, is_stale: true },
+                FeedStaleness { pair: "GBP/USD".to_string(), age_seconds: 30, is_stale: false },
+            ]
+        );
+    }
 
-        // GBP/USD: 1.27 with 8 decimals = 127000000
-        let answer = I256::from(127000000);
-        let price = oracle.chainlink_answer_to_decimal(answer, 8).unwrap();
-        assert_eq!(price, Decimal::new(127, 2)); // 1.27
+    #[tokio::test]
+    async fn test_bounded_join_limits_in_flight_calls() {
+        use std::sync::atomic::AtomicUsize;
 
-        // JPY/USD: 0.0067 with 8 decimals = 670000
-        let answer = I256::from(670000);
-        let price = oracle.chainlink_answer_to_decimal(answer, 8).unwrap();
-        assert_eq!(price, Decimal::new(67, 4)); // 0.0067
+        // Counting mock provider: tracks how many "RPC calls" overlap
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let mock_rpc = |i: usize| {
+            let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i * 2
+            }
+        };
+
+        let permits = Semaphore::new(3);
+        let results = bounded_join(&permits, 0..20, mock_rpc).await;
+
+        assert_eq!(results, (0..20).map(|i| i * 2).collect::<Vec<_>>());
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_set_rpc_concurrency() {
+        let mut oracle = ChainlinkOracle::for_test();
+        assert_eq!(oracle.rpc_concurrency(), 8);
+
+        oracle.set_rpc_concurrency(2);
+        assert_eq!(oracle.rpc_concurrency(), 2);
+        assert_eq!(oracle.rpc_permits.available_permits(), 2);
+
+        oracle.set_rpc_concurrency(0);
+        assert_eq!(oracle.rpc_concurrency(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_verify_required_feeds() {
+        let mut feeds = HashMap::new();
+        feeds.insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+        feeds.insert("GBP/USD".to_string(), test_feed("GBP/USD"));
+
+        let oracle = ChainlinkOracle {
+            price_feeds: Arc::new(RwLock::new(feeds)),
+            ..ChainlinkOracle::for_test()
+        };
+
+        assert!(oracle.verify_required_feeds(&["EUR/USD", "GBP/USD"]).await.is_ok());
+        assert!(oracle.verify_required_feeds(&[]).await.is_ok());
+
+        let err = oracle
+            .verify_required_feeds(&["EUR/USD", "JPY/USD", "BRL/USD"])
+            .await
+            .unwrap_err();
+        match &err {
+            OracleError::MissingRequiredFeeds(missing) => {
+                assert_eq!(missing, &vec!["JPY/USD".to_string(), "BRL/USD".to_string()]);
+            }
+            other => panic!("Expected MissingRequiredFeeds, got {:?}", other),
+        }
+        assert_eq!(
+            err.to_string(),
+            "Required price feeds not registered: JPY/USD, BRL/USD"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_live_feeds() {
+        let mut feeds = HashMap::new();
+        feeds.insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+
+        // Nothing listens on port 1, so every refresh fails fast
+        let oracle = ChainlinkOracle {
+            price_feeds: Arc::new(RwLock::new(feeds)),
+            ..ChainlinkOracle::for_test()
+        };
+
+        assert!(oracle.verify_live_feeds(&[]).await.is_ok());
+        assert!(matches!(
+            oracle.verify_live_feeds(&["EUR/USD", "GBP/USD"]).await,
+            Err(OracleError::MissingRequiredFeeds(_))
+        ));
+        match oracle.verify_live_feeds(&["EUR/USD"]).await {
+            Err(OracleError::FeedRefreshFailed(failed)) => {
+                assert_eq!(failed.len(), 1);
+                assert!(failed[0].starts_with("EUR/USD: "));
+            }
+            other => panic!("Expected FeedRefreshFailed, got {:?}", other),
+        }
+    }
+
+    /// A closed span's name and recorded fields
+    type CapturedSpan = (String, HashMap<String, String>);
+
+    /// Captures closed spans for telemetry assertions
+    #[derive(Clone, Default)]
+    struct SpanCapture(Arc<std::sync::Mutex<Vec<CapturedSpan>>>);
+
+    struct SpanFields(HashMap<String, String>);
+
+    impl tracing::field::Visit for SpanFields {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for SpanCapture
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = SpanFields(HashMap::new());
+            attrs.record(&mut fields);
+            ctx.span(id).unwrap().extensions_mut().insert(fields);
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            if let Some(fields) = ctx.span(id).unwrap().extensions_mut().get_mut::<SpanFields>() {
+                values.record(fields);
+            }
+        }
+
+        fn on_close(&self, id: tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+            let span = ctx.span(&id).unwrap();
+            let fields = span
+                .extensions()
+                .get::<SpanFields>()
+                .map(|f| f.0.clone())
+                .unwrap_or_default();
+            self.0.lock().unwrap().push((span.name().to_string(), fields));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oracle_calls_emit_spans() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let capture = SpanCapture::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let oracle = ChainlinkOracle::for_test();
+        assert!(oracle.get_price("EUR/USD").await.is_err());
+
+        let spans = capture.0.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| name == "oracle.get_price")
+            .expect("oracle.get_price span emitted");
+        assert_eq!(fields.get("component").map(String::as_str), Some("oracle"));
+        assert_eq!(fields.get("pair").map(String::as_str), Some("EUR/USD"));
+        assert_eq!(fields.get("outcome").map(String::as_str), Some("error"));
+        assert!(fields.contains_key("duration_ms"));
+    }
+
+    #[tokio::test]
+    async fn test_update_all_prices_advances_epoch() {
+        let oracle = ChainlinkOracle::for_test();
+
+        assert_eq!(oracle.price_epoch(), 0);
+        let (prices, errors) = oracle.update_all_prices().await;
+        assert!(prices.is_empty() && errors.is_empty());
+        assert_eq!(oracle.price_epoch(), 1);
+
+        oracle.update_all_prices().await;
+        assert_eq!(oracle.price_epoch(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_task_keeps_running_through_failures() {
+        let oracle = Arc::new(ChainlinkOracle::for_test());
+        // Registered but unreachable: every refresh of it fails
+        oracle.price_feeds.write().await.insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+
+        let handle = Arc::clone(&oracle).spawn_refresh_task(Duration::from_millis(20));
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert!(!handle.is_finished(), "refresh loop must survive feed errors");
+        assert!(oracle.price_epoch() >= 2, "expected repeated passes, got {}", oracle.price_epoch());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_deviation_over_threshold_publishes_event() {
+        let oracle = ChainlinkOracle::for_test();
+        let mut events = oracle.subscribe();
+
+        // 1.00 -> 1.20 is a 20% move against a 10% threshold
+        let result = oracle.check_deviation("EUR/USD", Decimal::new(100, 2), Decimal::new(120, 2));
+        assert!(matches!(result, Err(OracleError::PriceDeviation { .. })));
+
+        let event = events.try_recv().expect("deviation event published");
+        assert_eq!(
+            event,
+            OracleEvent::PriceDeviationDetected {
+                pair: "EUR/USD".to_string(),
+                old: Decimal::new(100, 2),
+                new: Decimal::new(120, 2),
+                deviation: Decimal::new(20, 0),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deviation_within_threshold_is_silent() {
+        let mut oracle = ChainlinkOracle::for_test();
+        let mut events = oracle.subscribe();
+
+        assert!(oracle
+            .check_deviation("EUR/USD", Decimal::new(100, 2), Decimal::new(105, 2))
+            .is_ok());
+        // First price for a feed has nothing to compare against
+        assert!(oracle
+            .check_deviation("EUR/USD", Decimal::ZERO, Decimal::new(105, 2))
+            .is_ok());
+        assert!(events.try_recv().is_err());
+
+        // Tightening the threshold turns the same move into an alarm
+        oracle.deviation_threshold = Decimal::new(2, 0);
+        assert!(oracle
+            .check_deviation("EUR/USD", Decimal::new(100, 2), Decimal::new(105, 2))
+            .is_err());
+        assert!(matches!(
+            events.try_recv(),
+            Ok(OracleEvent::PriceDeviationDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn test_deviation_without_subscribers_still_errors() {
+        let oracle = ChainlinkOracle::for_test();
+        let result = oracle.check_deviation("GBP/USD", Decimal::new(100, 2), Decimal::new(50, 2));
+        assert!(matches!(result, Err(OracleError::PriceDeviation { .. })));
     }
 
     #[tokio::test]
@@ -472,4 +2392,290 @@ mod tests {
         let result = ChainlinkOracle::new("invalid://url", Decimal::new(10, 0)).await;
         assert!(result.is_err());
     }
+
+    fn history(now: DateTime<Utc>, points: &[(i64, Decimal)]) -> VecDeque<(DateTime<Utc>, Decimal)> {
+        points
+            .iter()
+            .map(|(secs_ago, price)| (now - chrono::Duration::seconds(*secs_ago), *price))
+            .collect()
+    }
+
+    #[test]
+    fn test_time_weighted_average() {
+        let now = Utc::now();
+        let observations = history(now, &[(600, Decimal::new(100, 2)), (300, Decimal::new(110, 2))]);
+
+        // Half the window at each price
+        assert_eq!(time_weighted_average(&observations, 600, now), Some(Decimal::new(105, 2)));
+        // Window entirely after the second observation
+        assert_eq!(time_weighted_average(&observations, 300, now), Some(Decimal::new(110, 2)));
+        // 1.00 carried in for the first 150s of a 450s window
+        assert_eq!(
+            time_weighted_average(&observations, 450, now).unwrap().round_dp(4),
+            Decimal::new(10667, 4)
+        );
+        // Zero-length window is the latest price; nothing observed yet is None
+        assert_eq!(time_weighted_average(&observations, 0, now), Some(Decimal::new(110, 2)));
+        assert_eq!(time_weighted_average(&VecDeque::new(), 600, now), None);
+    }
+
+    fn round_response(round_id: u128, answer: i64, updated_at: u64) -> ethers::types::Bytes {
+        let round: LatestRound = (round_id, I256::from(answer), U256::from(updated_at), U256::from(updated_at), round_id);
+        ethers::abi::encode(&round.into_token().into_tuple().unwrap()).into()
+    }
+
+    #[tokio::test]
+    async fn test_rounds_since_walks_back_to_window_start() {
+        let now = Utc::now().timestamp() as u64;
+        let phase = 2u128 << 64;
+        let (provider, mock) = Provider::mocked();
+        // Answered last call first: round 3 predates the window and ends the walk
+        mock.push::<ethers::types::Bytes, _>(round_response(phase | 3, 100000000, now - 1000)).unwrap();
+        mock.push::<ethers::types::Bytes, _>(round_response(phase | 4, 110000000, now - 400)).unwrap();
+        mock.push::<ethers::types::Bytes, _>(round_response(phase | 5, 120000000, now - 100)).unwrap();
+
+        let rounds = rounds_since(Arc::new(provider), Address::zero(), now - 600).await.unwrap();
+        let ids: Vec<u128> = rounds.iter().map(|round| round.0).collect();
+        assert_eq!(ids, vec![phase | 5, phase | 4, phase | 3]);
+    }
+
+    #[tokio::test]
+    async fn test_rounds_since_stops_at_phase_start() {
+        let now = Utc::now().timestamp() as u64;
+        let (provider, mock) = Provider::mocked();
+        mock.push::<ethers::types::Bytes, _>(round_response((3u128 << 64) | 1, 120000000, now - 100)).unwrap();
+
+        let rounds = rounds_since(Arc::new(provider), Address::zero(), now - 600).await.unwrap();
+        assert_eq!(rounds.len(), 1);
+    }
+
+    #[test]
+    fn test_twap_over_rounds() {
+        let now = Utc::now();
+        let at = |seconds_ago: i64| now - chrono::Duration::seconds(seconds_ago);
+        let rounds: VecDeque<_> = vec![
+            (at(1200), Decimal::new(100, 2)),
+            (at(600), Decimal::new(110, 2)),
+            (at(300), Decimal::new(120, 2)),
+        ]
+        .into();
+
+        // 1.00 held for the first 300s of the window, then 1.10 and 1.20 for 300s each
+        assert_eq!(twap_over_rounds("EUR/USD", &rounds, 900, now).unwrap(), Decimal::new(110, 2));
+
+        // Only one round inside a 400s window
+        assert!(matches!(
+            twap_over_rounds("EUR/USD", &rounds, 400, now),
+            Err(OracleError::InsufficientHistory(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_twap_requires_registered_feed() {
+        let oracle = ChainlinkOracle::for_test();
+        assert!(matches!(
+            oracle.get_twap("EUR/USD", Duration::from_secs(900)).await,
+            Err(OracleError::PriceFeedNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_observed_twap_requires_feed_and_history() {
+        let oracle = ChainlinkOracle::for_test();
+        assert!(matches!(
+            oracle.get_observed_twap("EUR/USD", 900).await,
+            Err(OracleError::PriceFeedNotFound(_))
+        ));
+
+        oracle
+            .price_feeds
+            .write()
+            .await
+            .insert("EUR/USD".to_string(), test_feed("EUR/USD"));
+        assert!(matches!(
+            oracle.get_observed_twap("EUR/USD", 900).await,
+            Err(OracleError::InsufficientHistory(_))
+        ));
+
+        oracle
+            .record_observation("EUR/USD", Utc::now() - chrono::Duration::seconds(60), Decimal::new(108, 2))
+            .await;
+        assert_eq!(oracle.get_observed_twap("EUR/USD", 900).await.unwrap(), Decimal::new(108, 2));
+    }
+
+    #[tokio::test]
+    async fn test_record_observation_skips_seen_rounds_and_caps_history() {
+        let oracle = ChainlinkOracle::for_test();
+        let start = Utc::now();
+        oracle.record_observation("EUR/USD", start, Decimal::new(108, 2)).await;
+        oracle.record_observation("EUR/USD", start, Decimal::new(200, 2)).await;
+        assert_eq!(oracle.price_history.read().await["EUR/USD"].len(), 1);
+
+        for i in 1..=PRICE_HISTORY_CAPACITY as i64 + 10 {
+            oracle
+                .record_observation("EUR/USD", start + chrono::Duration::seconds(i), Decimal::ONE)
+                .await;
+        }
+        assert_eq!(oracle.price_history.read().await["EUR/USD"].len(), PRICE_HISTORY_CAPACITY);
+    }
+
+    /// 1.00 for most of the window, then a single noisy round at 1.09
+    async fn spiked_oracle(now: DateTime<Utc>, reference: DeviationReference) -> ChainlinkOracle {
+        let mut oracle = ChainlinkOracle::for_test();
+        oracle.set_deviation_reference(reference);
+        for (secs_ago, price) in [(900, Decimal::new(100, 2)), (60, Decimal::new(109, 2))] {
+            oracle
+                .record_observation("EUR/USD", now - chrono::Duration::seconds(secs_ago), price)
+                .await;
+        }
+        oracle
+    }
+
+    #[tokio::test]
+    async fn test_spike_trips_last_price_deviation() {
+        let now = Utc::now();
+        let oracle = spiked_oracle(now, DeviationReference::LastPrice).await;
+        let last = Decimal::new(109, 2);
+
+        let baseline = oracle.deviation_baseline("EUR/USD", last, now).await;
+        assert_eq!(baseline, last);
+        // 1.09 -> 0.98 is a ~10.1% move
+        assert!(matches!(
+            oracle.check_deviation("EUR/USD", baseline, Decimal::new(98, 2)),
+            Err(OracleError::PriceDeviation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_spike_tolerated_against_twap_baseline() {
+        let now = Utc::now();
+        let oracle = spiked_oracle(now, DeviationReference::Twap { window_seconds: 900 }).await;
+        let mut events = oracle.subscribe();
+
+        // (1.00 * 840 + 1.09 * 60) / 900 = 1.006
+        let baseline = oracle.deviation_baseline("EUR/USD", Decimal::new(109, 2), now).await;
+        assert_eq!(baseline, Decimal::new(1006, 3));
+        assert!(oracle
+            .check_deviation("EUR/USD", baseline, Decimal::new(98, 2))
+            .is_ok());
+        assert!(events.try_recv().is_err());
+
+        // A genuine move is still caught against the TWAP
+        assert!(oracle
+            .check_deviation("EUR/USD", baseline, Decimal::new(120, 2))
+            .is_err());
+    }
+
+    /// EUR/USD oscillating 1.0800-1.0820 over 20 rounds
+    async fn oscillating_oracle() -> ChainlinkOracle {
+        let oracle = ChainlinkOracle::for_test();
+        let start = Utc::now() - chrono::Duration::hours(1);
+        for i in 0..20i64 {
+            let price = Decimal::new(10800 + (i % 3) * 10, 4);
+            oracle
+                .record_observation("EUR/USD", start + chrono::Duration::seconds(i * 60), price)
+                .await;
+        }
+        oracle
+    }
+
+    #[tokio::test]
+    async fn test_price_far_outside_recent_distribution_is_anomalous() {
+        let oracle = oscillating_oracle().await;
+
+        // Within the usual range
+        assert!(!oracle.is_anomalous("EUR/USD", Decimal::new(10815, 4)).await);
+        // Only a ~0.5% move, well under the 10% deviation threshold, but
+        // dozens of standard deviations away from the recent window
+        assert!(oracle.is_anomalous("EUR/USD", Decimal::new(10860, 4)).await);
+        assert!(oracle.is_anomalous("EUR/USD", Decimal::new(10750, 4)).await);
+        assert!(oracle
+            .check_deviation("EUR/USD", Decimal::new(10810, 4), Decimal::new(10860, 4))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_anomaly_rejection_publishes_event() {
+        let oracle = oscillating_oracle().await;
+        let mut events = oracle.subscribe();
+
+        let result = oracle.check_anomaly("EUR/USD", Utc::now(), Decimal::new(10860, 4)).await;
+        assert!(matches!(result, Err(OracleError::PriceAnomaly { ref pair, .. }) if pair == "EUR/USD"));
+        assert_eq!(
+            events.try_recv().unwrap(),
+            OracleEvent::PriceAnomalyDetected {
+                pair: "EUR/USD".to_string(),
+                price: Decimal::new(10860, 4),
+                z_threshold: DEFAULT_ANOMALY_Z_SCORE,
+            }
+        );
+
+        assert!(oracle.check_anomaly("EUR/USD", Utc::now(), Decimal::new(10812, 4)).await.is_ok());
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sustained_shift_accepted_after_quarantine() {
+        let oracle = oscillating_oracle().await;
+        let now = Utc::now();
+        let round = |secs_ago: i64| now - chrono::Duration::seconds(secs_ago);
+        let anomaly = |result: Result<(), OracleError>| matches!(result, Err(OracleError::PriceAnomaly { .. }));
+
+        // A spike that reverses direction starts the quarantine over
+        assert!(anomaly(oracle.check_anomaly("EUR/USD", round(300), Decimal::new(10860, 4)).await));
+        assert!(anomaly(oracle.check_anomaly("EUR/USD", round(240), Decimal::new(10750, 4)).await));
+
+        // The feed settles ~0.5% higher; re-reading a held round doesn't count
+        assert!(anomaly(oracle.check_anomaly("EUR/USD", round(180), Decimal::new(10860, 4)).await));
+        assert!(anomaly(oracle.check_anomaly("EUR/USD", round(180), Decimal::new(10860, 4)).await));
+        assert!(anomaly(oracle.check_anomaly("EUR/USD", round(120), Decimal::new(10862, 4)).await));
+        assert!(oracle
+            .check_anomaly("EUR/USD", round(60), Decimal::new(10861, 4))
+            .await
+            .is_ok());
+
+        // The quarantined rounds are now the history the next round is judged against
+        let history = oracle.price_history.read().await;
+        let prices: Vec<Decimal> = history["EUR/USD"].iter().map(|(_, price)| *price).collect();
+        assert_eq!(prices, vec![Decimal::new(10860, 4), Decimal::new(10862, 4), Decimal::new(10861, 4)]);
+        drop(history);
+        assert!(!oracle.is_anomalous("EUR/USD", Decimal::new(10863, 4)).await);
+        assert!(oracle.anomaly_quarantine.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_anomaly_threshold_is_configurable() {
+        let mut oracle = oscillating_oracle().await;
+        // Mean 1.08095, sd ~0.0008: 1.0820 sits ~1.3 sd out
+        assert!(!oracle.is_anomalous("EUR/USD", Decimal::new(10820, 4)).await);
+        oracle.set_anomaly_z_threshold(Decimal::ONE);
+        assert!(oracle.is_anomalous("EUR/USD", Decimal::new(10820, 4)).await);
+
+        oracle.set_anomaly_z_threshold(Decimal::ZERO);
+        assert!(!oracle.is_anomalous("EUR/USD", Decimal::new(20000, 4)).await);
+
+        oracle.set_anomaly_window(1);
+        assert_eq!(oracle.anomaly_window(), ANOMALY_MIN_SAMPLES);
+    }
+
+    #[test]
+    fn test_z_score_needs_samples_and_spread() {
+        let few = vec![Decimal::ONE, Decimal::TWO];
+        assert!(!exceeds_z_score(&few, Decimal::new(100, 0), DEFAULT_ANOMALY_Z_SCORE));
+
+        let flat = vec![Decimal::ONE; ANOMALY_MIN_SAMPLES];
+        assert!(!exceeds_z_score(&flat, Decimal::new(100, 0), DEFAULT_ANOMALY_Z_SCORE));
+    }
+
+    #[tokio::test]
+    async fn test_twap_baseline_falls_back_to_last_price_without_history() {
+        let mut oracle = ChainlinkOracle::for_test();
+        oracle.set_deviation_reference(DeviationReference::Twap { window_seconds: 900 });
+        assert_eq!(
+            oracle.deviation_reference(),
+            DeviationReference::Twap { window_seconds: 900 }
+        );
+        let baseline = oracle.deviation_baseline("GBP/USD", Decimal::new(127, 2), Utc::now()).await;
+        assert_eq!(baseline, Decimal::new(127, 2));
+    }
 }